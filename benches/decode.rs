@@ -0,0 +1,76 @@
+use chrono::{TimeZone, Utc};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use gribberish::encoding::{DataRepresentationTemplateKind, LatLngGrid, MessageBuilder};
+use gribberish::message::Message;
+use gribberish::sections::indicator::Discipline;
+use std::fs::File;
+use std::io::Read;
+
+// A global 0.25 degree temperature field, the size of a GFS message
+fn gfs_message(packing: DataRepresentationTemplateKind) -> Vec<u8> {
+    let (rows, columns) = (721, 1440);
+    let values = (0..rows * columns)
+        .map(|i| 250.0 + ((i / columns) as f64 * 0.02).sin() * 30.0 + ((i % columns) as f64 * 0.01).cos() * 5.0)
+        .collect::<Vec<f64>>();
+
+    MessageBuilder::new(Discipline::Meteorological, 0, 0)
+        .reference_date(Utc.with_ymd_and_hms(2021, 3, 4, 12, 0, 0).unwrap())
+        .grid(LatLngGrid {
+            start: (90.0, 0.0),
+            end: (-90.0, 359.75),
+            shape: (rows, columns),
+        })
+        .packing(packing)
+        .decimal_places(2)
+        .data(values)
+        .build()
+        .expect("failed to build grib message")
+}
+
+fn wave_messages() -> Vec<u8> {
+    let mut data = Vec::new();
+    File::open("tests/data/multi_1.at_10m.t12z.f147.grib2")
+        .and_then(|mut f| f.read_to_end(&mut data))
+        .expect("failed to read wave model messages");
+    data
+}
+
+fn decode_gfs(c: &mut Criterion) {
+    let mut group = c.benchmark_group("gfs");
+    for (name, packing) in [
+        ("simple", DataRepresentationTemplateKind::SimplePacking),
+        ("complex", DataRepresentationTemplateKind::ComplexPacking),
+    ] {
+        let raw = gfs_message(packing);
+        let message = Message::parse(&raw, 0).unwrap();
+        group.throughput(Throughput::Elements(message.data_ref().unwrap().len() as u64));
+
+        group.bench_function(BenchmarkId::new("data", name), |b| {
+            b.iter(|| Message::parse(&raw, 0).unwrap().data().unwrap())
+        });
+        group.bench_function(BenchmarkId::new("data_into", name), |b| {
+            let mut out = vec![0.0; message.data_ref().unwrap().len()];
+            b.iter(|| Message::parse(&raw, 0).unwrap().data_into(&mut out).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn decode_wave_model(c: &mut Criterion) {
+    let data = wave_messages();
+    let mut group = c.benchmark_group("wave model");
+
+    group.bench_function("parse", |b| b.iter(|| Message::parse_all(&data).len()));
+    group.bench_function("data", |b| {
+        b.iter(|| {
+            Message::parse_all(&data)
+                .iter()
+                .map(|message| message.data().unwrap().len())
+                .sum::<usize>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, decode_gfs, decode_wave_model);
+criterion_main!(benches);
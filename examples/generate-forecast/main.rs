@@ -9,13 +9,9 @@ extern crate tokio;
 use bytes::Bytes;
 use chrono::prelude::*;
 use futures::{stream, StreamExt};
-use gribberish::message::Message;
 use reqwest::Url;
 use tokio::time::Instant;
-use std::collections::HashMap;
 use std::error::Error;
-use std::fs::File;
-use std::io::Read;
 use std::vec::Vec;
 
 fn generate_grib_url(date: &DateTime<Utc>, valid_hour: i32) -> Url {
@@ -28,7 +24,7 @@ fn generate_grib_url(date: &DateTime<Utc>, valid_hour: i32) -> Url {
     Url::parse(&raw_url).unwrap()
 }
 
-pub fn mean(data: &Vec<f64>) -> f64 {
+pub fn mean(data: &[f64]) -> f64 {
     let filtered_data: Vec<_> = data
         .iter()
         .filter(|v| !v.is_nan())
@@ -63,10 +59,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
     let results: Vec<Option<Bytes>> = stream::iter(urls.into_iter().map(|url| async move {
         let rurl = Url::parse(url.as_str()).unwrap();
         match reqwest::get(rurl).await {
-            Ok(resp) => match resp.bytes().await {
-                Ok(b) => Some(b),
-                Err(_) => None,
-            },
+            Ok(resp) => resp.bytes().await.ok(),
             Err(_) => None,
         }
     }))
@@ -102,7 +95,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
         .iter()
         .map(|m| format!("{} ({})", (m.0).variable_abbreviation.clone(), (m.0).units ))
         .collect();
-    if vars.len() == 0 {
+    if vars.is_empty() {
         return Err(Box::from("No variables read"));
     }
     vars.insert(0, String::from("TIME"));
@@ -117,12 +110,12 @@ async fn main() -> Result<(), Box<dyn Error>> {
                     Ok(vals) => *vals,
                     Err(err) => {
                         println!("{}", err);
-                        std::f64::NAN
+                        f64::NAN
                     }
                 };
                 format!("{:.2}", value)
             }).collect();
-        if point_data.len() > 0 {
+        if !point_data.is_empty() {
             point_data.insert(0, dt[0].0.forecast_date.to_rfc3339());
         }
 
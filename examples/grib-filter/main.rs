@@ -10,12 +10,13 @@ use std::error::Error;
 use std::fmt;
 use std::clone::Clone;
 use std::ops::Range;
-use std::time::{Duration, Instant};
+use std::time::Instant;
 use futures::{stream, StreamExt};
 use chrono::prelude::*;
 use reqwest::{Url};
 use bytes::Bytes;
 
+#[allow(dead_code)]
 #[derive(Clone, Debug)]
 enum NOAAModelType {
     MultiGridWave,
@@ -51,6 +52,7 @@ struct NOAAModelUrlBuilder<'a> {
     variables: Vec<String>,
 }
 
+#[allow(dead_code)]
 impl<'a> NOAAModelUrlBuilder<'a> {
     pub fn new(
         model_type: NOAAModelType,
@@ -149,7 +151,7 @@ impl<'a> NOAAModelUrlBuilder<'a> {
     }
 
     fn build_vars(&self) -> String {
-        if self.variables.len() > 0 {
+        if !self.variables.is_empty() {
         self.variables
             .iter()
             .map(|v| format!("&var_{}=on", *v))
@@ -161,7 +163,7 @@ impl<'a> NOAAModelUrlBuilder<'a> {
     }
 }
 
-pub fn mean(data: &Vec<f64>) -> f64 {
+pub fn mean(data: &[f64]) -> f64 {
     let filtered_data: Vec<_> = data
         .iter()
         .filter(|v| !v.is_nan())
@@ -199,12 +201,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
         async move {
             let rurl = Url::parse(url.as_str()).unwrap();
             match reqwest::get(rurl).await {
-                Ok(resp) => {
-                    match resp.bytes().await {
-                        Ok(b) => Some(b),
-                        Err(_) => None,
-                    }
-                },
+                Ok(resp) => resp.bytes().await.ok(),
                 Err(_) => None,
             }
     })).buffered(8).collect().await;
@@ -237,7 +234,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
         .iter()
         .map(|m| format!("{} ({})", (m.0).variable_abbreviation.clone(), (m.0).units ))
         .collect();
-    if vars.len() == 0 {
+    if vars.is_empty() {
         return Err(Box::from("No variables read"));
     }
     vars.insert(0, String::from("TIME"));
@@ -250,11 +247,11 @@ async fn main() -> Result<(), Box<dyn Error>> {
             .map(|d| {
                 let value = match &d.1 {
                     Ok(vals) => mean(vals),
-                    Err(_) => std::f64::NAN,
+                    Err(_) => f64::NAN,
                 };
                 format!("{:.2}", value)
             }).collect();
-        if point_data.len() > 0 {
+        if !point_data.is_empty() {
             point_data.insert(0, dt[0].0.forecast_date.to_rfc3339());
         }
 
@@ -1,15 +1,12 @@
 extern crate chrono;
 extern crate gribberish;
 
-use chrono::prelude::*;
-use std::error::Error;
 use gribberish::message::Message;
 use std::fs::File;
 use std::io::Read;
 use std::vec::Vec;
 use std::env;
 use std::process;
-use std::fmt::Display;
 
 fn main() {
     let args: Vec<String> = env::args().collect();
@@ -28,26 +25,18 @@ fn main() {
 
     println!("GRIB2 file read: {}", grib_path);
     println!("Message count: {}", messages.len());
-    println!("{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}", 
-        "Message #", 
-        "Variable", 
-        "Units", 
-        "Date", 
-        "Region", 
-        "Grid Resolution",
-        "Grid",
-        "Data Template Id", 
-        "Data Point Count");
-    println!("------------------------------------------------------------------------------------------------------------");
+    println!("Message #\tCenter\tVariable\tUnits\tDate\tRegion\tGrid Resolution\tGrid\tData Template Id\tData Point Count");
+    println!("--------------------------------------------------------------------------------------------------------------------");
 
     messages.iter().enumerate().for_each(|m| {
         if let Ok(metadata) = m.1.metadata() {
-            println!("{}\t{}\t{}\t{}\t{:?}\t{:?}\t{:?}\t{}\t{}", 
+            println!("{}\t{}\t{}\t{}\t{}\t{:?}\t{:?}\t{:?}\t{}\t{}", 
                 m.0, 
+                metadata.center,
                 metadata.variable_abbreviation, 
                 metadata.units, 
                 metadata.forecast_date, 
-                metadata.region, 
+                metadata.bounds, 
                 metadata.location_resolution,
                 metadata.location_grid,
                 metadata.data_template_number, 
@@ -0,0 +1,138 @@
+#ifndef GRIBBERISH_H
+#define GRIBBERISH_H
+
+/* Generated by cbindgen from src/ffi.rs, do not edit by hand */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * What the calls without a pointer to hand back return, with the reason for an error left for
+ * gribberish_last_error
+ */
+typedef enum {
+  GRIB_STATUS_OK = 0,
+  GRIB_STATUS_ERROR = -1,
+} GribStatus;
+
+/**
+ * The messages of a GRIB file copied out of the caller's buffer. Each message is parsed again
+ * when it is asked about, so the handle is no more than the bytes and where the messages start.
+ */
+typedef struct GribFile GribFile;
+
+/**
+ * The times, in seconds since the unix epoch, and the grid of a message
+ */
+typedef struct {
+  uint8_t discipline;
+  int64_t reference_time;
+  int64_t forecast_time;
+  size_t rows;
+  size_t columns;
+  size_t point_count;
+} GribMetadata;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * The message of the last call on this thread that failed, or null. The string belongs to the
+ * library and lasts until the next failure on the thread.
+ */
+const char *gribberish_last_error(void);
+
+/**
+ * Copies the buffer and finds the messages in it, stopping at the first that is not valid.
+ * Returns null on failure. The handle is freed with gribberish_file_free.
+ *
+ * # Safety
+ * The data must point to at least length readable bytes.
+ */
+GribFile *gribberish_file_open(const uint8_t *data, size_t length);
+
+/**
+ * # Safety
+ * The handle must be null or come from gribberish_file_open, and is not used again.
+ */
+void gribberish_file_free(GribFile *file);
+
+/**
+ * # Safety
+ * The handle must be null or a handle from gribberish_file_open that is not yet freed.
+ */
+size_t gribberish_file_message_count(const GribFile *handle);
+
+/**
+ * The wgrib2 style inventory of the file, one line for each message. Returns null on failure.
+ * The string is freed with gribberish_string_free.
+ *
+ * # Safety
+ * The handle must be null or a handle from gribberish_file_open that is not yet freed.
+ */
+char *gribberish_file_inventory(const GribFile *handle);
+
+/**
+ * # Safety
+ * The handle must be null or a handle from gribberish_file_open that is not yet freed, and the
+ * metadata must point to a GribMetadata to fill.
+ */
+GribStatus gribberish_message_metadata(const GribFile *handle,
+                                       size_t index,
+                                       GribMetadata *metadata);
+
+/**
+ * The parameter name, abbreviation and units, and the level as the inventory writes it. Each
+ * returns null on failure and a string freed with gribberish_string_free otherwise.
+ *
+ * # Safety
+ * The handle must be null or a handle from gribberish_file_open that is not yet freed.
+ */
+char *gribberish_message_name(const GribFile *handle, size_t index);
+
+/**
+ * # Safety
+ * The handle must be null or a handle from gribberish_file_open that is not yet freed.
+ */
+char *gribberish_message_abbreviation(const GribFile *handle, size_t index);
+
+/**
+ * # Safety
+ * The handle must be null or a handle from gribberish_file_open that is not yet freed.
+ */
+char *gribberish_message_units(const GribFile *handle, size_t index);
+
+/**
+ * # Safety
+ * The handle must be null or a handle from gribberish_file_open that is not yet freed.
+ */
+char *gribberish_message_level(const GribFile *handle, size_t index);
+
+/**
+ * Decodes the message into the values in the order they are stored, NaN where there is no data.
+ * The values must hold at least the point count of the metadata.
+ *
+ * # Safety
+ * The handle must be null or a handle from gribberish_file_open that is not yet freed, and the
+ * values must point to length writable doubles.
+ */
+GribStatus gribberish_message_data(const GribFile *handle,
+                                   size_t index,
+                                   double *values,
+                                   size_t length);
+
+/**
+ * # Safety
+ * The string must be null or come from this library, and is not used again.
+ */
+void gribberish_string_free(char *string);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* GRIBBERISH_H */
@@ -21,19 +21,32 @@ pub fn display_description(input: TokenStream) -> TokenStream {
     }
 }
 
+// The variant holding a single value, which takes every code that is not listed
+fn unknown_variant(enum_data: &ItemEnum) -> Option<&syn::Variant> {
+    enum_data.variants.iter().find(|v| matches!(&v.fields, syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1))
+}
+
 fn generate_display_impl(enum_data: &ItemEnum) -> TokenStream {
     let name: &syn::Ident = &enum_data.ident;
-    let variants: &syn::punctuated::Punctuated<syn::Variant, syn::token::Comma> = &enum_data.variants;
-    let variant_names = variants.into_iter().map(|v| v.ident.clone());
-    let variant_descriptions = variants
-        .into_iter()
-        .map(|v| {
-            let desc_attribute = v.attrs.iter().find(|a| a.path.is_ident("description"));
-            match desc_attribute {
-                Some(a) => a.tokens.to_string().replace("=", "").replace("\"", "").trim().to_string(),
-                _ => v.ident.to_string().to_lowercase(),
-            }
-        });
+    let variants = enum_data.variants.iter().filter(|v| matches!(v.fields, syn::Fields::Unit));
+    let variant_names = variants.clone().map(|v| v.ident.clone());
+    let description = |v: &syn::Variant| {
+        let desc_attribute = v.attrs.iter().find(|a| a.path.is_ident("description"));
+        match desc_attribute {
+            Some(a) => a.tokens.to_string().replace("=", "").replace("\"", "").trim().to_string(),
+            _ => v.ident.to_string().to_lowercase(),
+        }
+    };
+    let variant_descriptions = variants.map(description);
+
+    // Unknown codes are described along with their value
+    let unknown_arm = unknown_variant(enum_data).map(|v| {
+        let ident = &v.ident;
+        let unknown_description = description(v);
+        quote! {
+            #name::#ident(value) => return write!(f, "{} ({})", #unknown_description, value),
+        }
+    });
 
     (quote! {
         impl std::fmt::Display for #name {
@@ -42,6 +55,7 @@ fn generate_display_impl(enum_data: &ItemEnum) -> TokenStream {
                     #(
                         #name::#variant_names => #variant_descriptions,
                     )*
+                    #unknown_arm
                 };
                 write!(f, "{}", description)
             }
@@ -66,33 +80,103 @@ pub fn from_value(input: TokenStream) -> TokenStream {
     }
 }
 
+// The unknown variant holds the code itself, its repr may be wider to keep room for the discriminant,
+// so its field type decides the value type when there is one and the repr otherwise
+fn is_u16_valued(enum_data: &ItemEnum) -> bool {
+    let is_u16 = |path: &syn::Path| path.is_ident("u16");
+    match unknown_variant(enum_data).map(|v| &v.fields) {
+        Some(syn::Fields::Unnamed(fields)) => matches!(&fields.unnamed[0].ty, syn::Type::Path(ty) if is_u16(&ty.path)),
+        _ => enum_data
+            .attrs
+            .iter()
+            .filter(|a| a.path.is_ident("repr"))
+            .filter_map(|a| a.parse_meta().ok())
+            .any(|meta| match meta {
+                syn::Meta::List(list) => list
+                    .nested
+                    .iter()
+                    .any(|nested| matches!(nested, syn::NestedMeta::Meta(syn::Meta::Path(path)) if is_u16(path))),
+                _ => false,
+            }),
+    }
+}
+
 fn generate_from_value_impl(enum_data: &ItemEnum) -> TokenStream {
     let name: &syn::Ident = &enum_data.ident;
-    let variants: &syn::punctuated::Punctuated<syn::Variant, syn::token::Comma> = &enum_data.variants;
-    let variant_names = variants.into_iter().map(|v| v.ident.clone());
-    let default_variant_name = variant_names.clone().last().clone().unwrap();
-    let variant_values = variants.into_iter().map(|v| match &v.discriminant {
-        Some((_, expr)) => match expr {
-            syn::Expr::Lit(value) => match &value.lit {
-                syn::Lit::Int(i) => i.base10_parse().unwrap_or(254u8),
-                _ => 253u8,
-            },
-            _ => 252u8
-        },
-        None => 251u8,
-    });
+    let variants = enum_data.variants.iter().filter(|v| matches!(v.fields, syn::Fields::Unit));
+    let variant_names = variants.clone().map(|v| v.ident.clone()).collect::<Vec<_>>();
+    let default_variant_name = variant_names.last().unwrap();
+    let is_u16 = is_u16_valued(enum_data);
+
+    let value_type = if is_u16 { quote!(u16) } else { quote!(u8) };
+    let variant_values = variants
+        .map(|v| match &v.discriminant {
+            Some((_, syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(i), .. }))) if is_u16 => {
+                let value: u16 = i.base10_parse().unwrap_or(65534u16);
+                quote!(#value)
+            }
+            _ if is_u16 => quote!(65533u16),
+            Some((_, expr)) => {
+                let value = match expr {
+                    syn::Expr::Lit(value) => match &value.lit {
+                        syn::Lit::Int(i) => i.base10_parse().unwrap_or(254u8),
+                        _ => 253u8,
+                    },
+                    _ => 252u8
+                };
+                quote!(#value)
+            }
+            None => quote!(251u8),
+        })
+        .collect::<Vec<_>>();
+
+    // Codes that are not listed go to the unknown variant when there is one, keeping their value,
+    // and otherwise to the last variant
+    let (fallback, to_value) = match unknown_variant(enum_data) {
+        Some(unknown) => {
+            let unknown = &unknown.ident;
+            let to_value = quote! {
+                impl std::convert::From<#name> for #value_type {
+                    fn from(value: #name) -> Self {
+                        match value {
+                            #(
+                                #name::#variant_names => #variant_values,
+                            )*
+                            #name::#unknown(value) => value,
+                        }
+                    }
+                }
+            };
+            (quote!(#name::#unknown(value)), Some(to_value))
+        }
+        None => (quote!(#name::#default_variant_name), None),
+    };
 
     (quote! {
-        impl std::convert::From<u8> for #name {
-            fn from(value: u8) -> Self {
+        impl std::convert::From<#value_type> for #name {
+            fn from(value: #value_type) -> Self {
                 match value {
                     #(
                         #variant_values => #name::#variant_names,
                     )*
-                    _ => #name::#default_variant_name
+                    _ => #fallback
                 }
             }
         }
+
+        impl #name {
+            // Only the codes that are listed
+            pub fn from_value(value: #value_type) -> Option<#name> {
+                match value {
+                    #(
+                        #variant_values => Some(#name::#variant_names),
+                    )*
+                    _ => None,
+                }
+            }
+        }
+
+        #to_value
     }).into()
 }
 
@@ -119,6 +203,8 @@ fn generate_parameter_attributes(enum_data: &ItemEnum) -> TokenStream {
     let variant_names_first = variants.into_iter().map(|v| v.ident.clone());
     let variant_names_second = variants.into_iter().map(|v| v.ident.clone());
     let variant_names_third = variants.into_iter().map(|v| v.ident.clone());
+    let variant_names_fourth = variants.into_iter().map(|v| v.ident.clone());
+    let variant_names_fifth = variants.into_iter().map(|v| v.ident.clone());
     let variant_names = variants
         .into_iter()
         .map(|v| {
@@ -172,6 +258,14 @@ fn generate_parameter_attributes(enum_data: &ItemEnum) -> TokenStream {
                     )*
                 }
             }
+
+            pub fn parameters() -> Vec<(u8, Parameter)> {
+                vec![
+                    #(
+                        (#name::#variant_names_fourth as u8, Parameter::from(#name::#variant_names_fifth)),
+                    )*
+                ]
+            }
         }
 
         impl std::convert::From<#name> for Parameter {
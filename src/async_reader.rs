@@ -0,0 +1,162 @@
+use crate::error::GribError;
+use crate::reader::{memory_length, message_length, section_header, set_message_length, MessageHeader, SectionHeader};
+use std::io::{ErrorKind, SeekFrom};
+use std::vec::Vec;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
+
+// The async counterpart of GribReader, for streaming messages from sources such as object storage
+pub struct AsyncGribReader<R: AsyncRead + AsyncSeek + Unpin> {
+    reader: R,
+}
+
+impl<R: AsyncRead + AsyncSeek + Unpin> AsyncGribReader<R> {
+    pub fn new(reader: R) -> AsyncGribReader<R> {
+        AsyncGribReader { reader }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    // Scans every message from the start of the reader
+    pub async fn headers(&mut self) -> Result<Vec<MessageHeader>, GribError> {
+        self.seek(0).await?;
+
+        let mut headers = Vec::new();
+        while let Some(header) = self.next_header().await? {
+            headers.push(header);
+        }
+
+        Ok(headers)
+    }
+
+    // Scans the message at the current position and leaves the reader at the start of the next one
+    pub async fn next_header(&mut self) -> Result<Option<MessageHeader>, GribError> {
+        let offset = self
+            .reader
+            .seek(SeekFrom::Current(0))
+            .await
+            .map_err(|e| GribError::Io(format!("Failed to read position: {}", e)))?;
+
+        let mut indicator = [0u8; 16];
+        if !self.read_exact_or_eof(&mut indicator, offset).await? {
+            return Ok(None);
+        }
+
+        let length = message_length(&indicator, offset)?;
+        let mut sections = vec![SectionHeader {
+            number: 0,
+            offset,
+            length: 16,
+        }];
+
+        let end = offset + length;
+        let mut section_offset = offset + 16;
+        while section_offset < end {
+            let mut section_start = [0u8; 4];
+            self.read_exact(&mut section_start).await?;
+
+            if &section_start == b"7777" {
+                sections.push(SectionHeader {
+                    number: 8,
+                    offset: section_offset,
+                    length: 4,
+                });
+                break;
+            }
+
+            let mut number = [0u8; 1];
+            self.read_exact(&mut number).await?;
+
+            let section = section_header(&section_start, number[0], section_offset, end)?;
+            section_offset += section.length;
+            sections.push(section);
+            self.seek(section_offset).await?;
+        }
+
+        if sections.last().map(|s| s.number) != Some(8) {
+            return Err(GribError::InvalidMessage {
+                offset,
+                reason: GribError::MissingSection(8).to_string(),
+            });
+        }
+
+        self.seek(end).await?;
+
+        Ok(Some(MessageHeader {
+            offset,
+            length,
+            discipline: indicator[6].into(),
+            sections,
+        }))
+    }
+
+    // The complete message, ready for Message::parse
+    pub async fn read_message(&mut self, header: &MessageHeader) -> Result<Vec<u8>, GribError> {
+        self.read_range(header.offset, header.length).await
+    }
+
+    pub async fn read_section(&mut self, section: &SectionHeader) -> Result<Vec<u8>, GribError> {
+        self.read_range(section.offset, section.length).await
+    }
+
+    // The message with an empty data section, which is enough to read its metadata
+    // without loading the packed values
+    pub async fn read_message_metadata(&mut self, header: &MessageHeader) -> Result<Vec<u8>, GribError> {
+        let mut message = Vec::new();
+        for section in &header.sections {
+            if section.number == 7 {
+                message.extend_from_slice(&5u32.to_be_bytes());
+                message.push(7);
+            } else {
+                message.extend(self.read_section(section).await?);
+            }
+        }
+
+        set_message_length(&mut message);
+        Ok(message)
+    }
+
+    async fn read_range(&mut self, offset: u64, length: u64) -> Result<Vec<u8>, GribError> {
+        self.seek(offset).await?;
+
+        let mut data = vec![0u8; memory_length(length)?];
+        self.read_exact(&mut data).await?;
+        Ok(data)
+    }
+
+    async fn seek(&mut self, offset: u64) -> Result<(), GribError> {
+        self.reader
+            .seek(SeekFrom::Start(offset))
+            .await
+            .map(|_| ())
+            .map_err(|e| GribError::Io(format!("Failed to seek to offset {}: {}", offset, e)))
+    }
+
+    async fn read_exact(&mut self, buffer: &mut [u8]) -> Result<(), GribError> {
+        self.reader
+            .read_exact(buffer)
+            .await
+            .map(|_| ())
+            .map_err(|e| GribError::Io(format!("Failed to read message data: {}", e)))
+    }
+
+    // Returns false if the reader is already at the end, and errors if it ends part way through
+    async fn read_exact_or_eof(&mut self, buffer: &mut [u8], offset: u64) -> Result<bool, GribError> {
+        let mut read = 0;
+        while read < buffer.len() {
+            match self.reader.read(&mut buffer[read..]).await {
+                Ok(0) => break,
+                Ok(n) => read += n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(GribError::Io(format!("Failed to read message data: {}", e))),
+            }
+        }
+
+        match read {
+            0 => Ok(false),
+            n if n == buffer.len() => Ok(true),
+            _ => Err(GribError::UnexpectedEof { offset }),
+        }
+    }
+}
@@ -0,0 +1,382 @@
+use crate::error::GribError;
+use crate::message::Message;
+use crate::polygon::Polygon;
+use crate::templates::grid::{longitude_difference, scanned_index};
+use std::collections::{HashMap, HashSet};
+
+// The lines along which the field equals the level, as (latitude, longitude) vertices. Lines
+// that close on themselves end where they start, the rest run to the edge of the grid or of
+// the points without data.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Isoline {
+    pub level: f64,
+    pub lines: Vec<Vec<(f64, f64)>>,
+}
+
+// The areas where the field is at least the lower level and below the upper one
+#[derive(Clone, Debug, PartialEq)]
+pub struct Isoband {
+    pub lower: f64,
+    pub upper: f64,
+    pub polygons: Vec<Polygon>,
+}
+
+impl Isoline {
+    // A GeoJSON feature with the lines as a MultiLineString
+    pub fn to_geojson(&self) -> String {
+        let lines = self.lines.iter().map(|line| coordinates(line, false)).collect::<Vec<_>>();
+        format!(
+            r#"{{"type":"Feature","properties":{{"level":{}}},"geometry":{{"type":"MultiLineString","coordinates":[{}]}}}}"#,
+            self.level,
+            lines.join(",")
+        )
+    }
+}
+
+impl Isoband {
+    // A GeoJSON feature with the polygons as a MultiPolygon
+    pub fn to_geojson(&self) -> String {
+        let polygons = self
+            .polygons
+            .iter()
+            .map(|polygon| {
+                let rings = std::iter::once(&polygon.exterior)
+                    .chain(polygon.interiors.iter())
+                    .map(|ring| coordinates(ring, true))
+                    .collect::<Vec<_>>();
+                format!("[{}]", rings.join(","))
+            })
+            .collect::<Vec<_>>();
+        format!(
+            r#"{{"type":"Feature","properties":{{"lower":{},"upper":{}}},"geometry":{{"type":"MultiPolygon","coordinates":[{}]}}}}"#,
+            self.lower,
+            self.upper,
+            polygons.join(",")
+        )
+    }
+}
+
+// Gathers features such as those of Isoline::to_geojson into a GeoJSON feature collection
+pub fn feature_collection<I: IntoIterator<Item = String>>(features: I) -> String {
+    format!(
+        r#"{{"type":"FeatureCollection","features":[{}]}}"#,
+        features.into_iter().collect::<Vec<_>>().join(",")
+    )
+}
+
+// Traces each level through the grid with marching triangles, splitting every cell along its
+// diagonal so saddle cells are never ambiguous. Cells with a corner missing data are left out.
+pub fn isolines(message: &Message, levels: &[f64]) -> Result<Vec<Isoline>, GribError> {
+    let field = Field::new(message)?;
+    Ok(levels
+        .iter()
+        .map(|level| Isoline {
+            level: *level,
+            lines: field.isoline(*level),
+        })
+        .collect())
+}
+
+// The bands between each pair of consecutive levels, once the levels are sorted. The pieces of
+// each triangle are merged across the grid, so every band comes back as whole polygons with
+// holes where the field leaves the band.
+pub fn isobands(message: &Message, levels: &[f64]) -> Result<Vec<Isoband>, GribError> {
+    let field = Field::new(message)?;
+    let mut levels = levels.to_vec();
+    levels.sort_by(f64::total_cmp);
+
+    Ok(levels
+        .windows(2)
+        .map(|band| Isoband {
+            lower: band[0],
+            upper: band[1],
+            polygons: field.isoband(band[0], band[1]),
+        })
+        .collect())
+}
+
+// A grid point, or where a level crosses the edge between two grid points. Crossings are keyed
+// on the points rather than their position so neighbouring triangles meet exactly.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Vertex {
+    Point(usize),
+    Crossing(usize, usize, u64),
+}
+
+// The values and locations of the grid points in rows, whatever order they are stored in
+struct Field {
+    columns: usize,
+    rows: usize,
+    values: Vec<f64>,
+    locations: Vec<(f64, f64)>,
+}
+
+impl Field {
+    fn new(message: &Message) -> Result<Field, GribError> {
+        let shape = message.shape()?;
+        let scanning_mode = message.grid_template()?.scanning_mode();
+        let (data, locations) = (message.data()?, message.data_locations()?);
+        let point_count = shape.0 * shape.1;
+        if data.len() < point_count || locations.len() < point_count {
+            return Err(GribError::InvalidData("The data does not cover the grid".into()));
+        }
+
+        let stored = (0..point_count).map(|point| scanned_index(scanning_mode, shape, point % shape.1, point / shape.1));
+        Ok(Field {
+            columns: shape.1,
+            rows: shape.0,
+            values: stored.clone().map(|index| data[index]).collect(),
+            locations: stored.map(|index| locations[index]).collect(),
+        })
+    }
+
+    // The two triangles of each cell, both counter clockwise when i runs right and j up
+    fn triangles(&self) -> impl Iterator<Item = [usize; 3]> + '_ {
+        let columns = self.columns;
+        (0..self.rows.saturating_sub(1))
+            .flat_map(move |j| {
+                (0..columns.saturating_sub(1)).flat_map(move |i| {
+                    let corner = |di: usize, dj: usize| (j + dj) * columns + i + di;
+                    vec![[corner(0, 0), corner(1, 0), corner(1, 1)], [corner(0, 0), corner(1, 1), corner(0, 1)]]
+                })
+            })
+            .filter(move |triangle| triangle.iter().all(|point| !self.values[*point].is_nan()))
+    }
+
+    // Where the level crosses the edge, None when both ends are on the same side. Points equal
+    // to the level count as above it, and crossings at one end are that grid point.
+    fn crossing(&self, a: usize, b: usize, level: f64) -> Option<Vertex> {
+        let (value_a, value_b) = (self.values[a], self.values[b]);
+        if (value_a < level) == (value_b < level) {
+            None
+        } else if value_a == level {
+            Some(Vertex::Point(a))
+        } else if value_b == level {
+            Some(Vertex::Point(b))
+        } else {
+            Some(Vertex::Crossing(a.min(b), a.max(b), level.to_bits()))
+        }
+    }
+
+    // How far along the edge from a to b the level is
+    fn fraction(&self, a: usize, b: usize, level: f64) -> f64 {
+        (level - self.values[a]) / (self.values[b] - self.values[a])
+    }
+
+    // Interpolates along the edge for crossings, in grid space or between locations
+    fn interpolate(&self, vertex: Vertex, position: impl Fn(usize) -> (f64, f64), difference: impl Fn(f64, f64) -> f64) -> (f64, f64) {
+        match vertex {
+            Vertex::Point(point) => position(point),
+            Vertex::Crossing(a, b, level) => {
+                let t = self.fraction(a, b, f64::from_bits(level));
+                let ((y_a, x_a), (y_b, x_b)) = (position(a), position(b));
+                (y_a + t * (y_b - y_a), x_a + t * difference(x_b, x_a))
+            }
+        }
+    }
+
+    fn location(&self, vertex: Vertex) -> (f64, f64) {
+        self.interpolate(vertex, |point| self.locations[point], longitude_difference)
+    }
+
+    // As (j, i), the same way round as locations
+    fn grid_position(&self, vertex: Vertex) -> (f64, f64) {
+        let columns = self.columns;
+        self.interpolate(vertex, |point| ((point / columns) as f64, (point % columns) as f64), |b, a| b - a)
+    }
+
+    fn isoline(&self, level: f64) -> Vec<Vec<(f64, f64)>> {
+        let segments = self
+            .triangles()
+            .filter_map(|[a, b, c]| {
+                let crossings = [(a, b), (b, c), (c, a)]
+                    .iter()
+                    .filter_map(|(p, q)| self.crossing(*p, *q, level))
+                    .collect::<Vec<_>>();
+                match crossings[..] {
+                    [start, end] if start != end => Some((start, end)),
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        join_lines(&segments)
+            .into_iter()
+            .map(|line| line.into_iter().map(|vertex| self.location(vertex)).collect())
+            .collect()
+    }
+
+    fn isoband(&self, lower: f64, upper: f64) -> Vec<Polygon> {
+        let pieces = self
+            .triangles()
+            .map(|triangle| self.band_piece(triangle, lower, upper))
+            .filter(|piece| piece.len() >= 3)
+            .collect::<Vec<_>>();
+
+        let rings = join_rings(&pieces);
+        let positions = rings
+            .iter()
+            .map(|ring| ring.iter().map(|vertex| self.grid_position(*vertex)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let areas = positions.iter().map(|ring| signed_area(ring)).collect::<Vec<_>>();
+
+        // Outlines run counter clockwise like the triangles, holes the other way
+        let exteriors = (0..rings.len()).filter(|k| areas[*k] > 0.0).collect::<Vec<_>>();
+        let mut polygons = exteriors
+            .iter()
+            .map(|k| Polygon::new(rings[*k].iter().map(|vertex| self.location(*vertex)).collect()))
+            .collect::<Vec<_>>();
+
+        for hole in (0..rings.len()).filter(|k| areas[*k] < 0.0) {
+            // Outlines can sit within the holes of others, so the hole belongs to the smallest
+            // outline around it
+            let owner = exteriors
+                .iter()
+                .enumerate()
+                .filter(|(_, exterior)| ring_contains(&positions[**exterior], positions[hole][0]))
+                .min_by(|(_, a), (_, b)| areas[**a].total_cmp(&areas[**b]));
+            if let Some((owner, _)) = owner {
+                polygons[owner].interiors.push(rings[hole].iter().map(|vertex| self.location(*vertex)).collect());
+            }
+        }
+
+        polygons
+    }
+
+    // The part of the triangle within the band, as the grid points inside it and the crossings
+    // met walking around its edges. The field is linear over the triangle, so this is convex.
+    fn band_piece(&self, triangle: [usize; 3], lower: f64, upper: f64) -> Vec<Vertex> {
+        let inside = |point: usize| self.values[point] >= lower && self.values[point] < upper;
+
+        let mut piece: Vec<Vertex> = Vec::new();
+        for k in 0..3 {
+            let (p, q) = (triangle[k], triangle[(k + 1) % 3]);
+            if inside(p) {
+                piece.push(Vertex::Point(p));
+            }
+
+            let mut crossings = [lower, upper]
+                .iter()
+                .filter_map(|level| self.crossing(p, q, *level).map(|vertex| (self.fraction(p, q, *level), vertex)))
+                .collect::<Vec<_>>();
+            crossings.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+            for (_, vertex) in crossings {
+                if piece.last() != Some(&vertex) {
+                    piece.push(vertex);
+                }
+            }
+        }
+
+        if piece.len() > 1 && piece.first() == piece.last() {
+            piece.pop();
+        }
+        piece
+    }
+}
+
+// Joins segments sharing an end into lines, starting from the open ends so lines that reach
+// the edge of the data are not broken in two
+fn join_lines(segments: &[(Vertex, Vertex)]) -> Vec<Vec<Vertex>> {
+    let mut touching: HashMap<Vertex, Vec<usize>> = HashMap::new();
+    for (k, (a, b)) in segments.iter().enumerate() {
+        touching.entry(*a).or_default().push(k);
+        touching.entry(*b).or_default().push(k);
+    }
+
+    let open_ends = segments
+        .iter()
+        .flat_map(|(a, b)| vec![*a, *b])
+        .filter(|vertex| touching[vertex].len() == 1)
+        .collect::<Vec<_>>();
+    let starts = open_ends.into_iter().chain(segments.iter().map(|(a, _)| *a));
+
+    let mut used = vec![false; segments.len()];
+    let mut lines = Vec::new();
+    for start in starts {
+        let mut line = vec![start];
+        let mut current = start;
+        while let Some(k) = touching[&current].iter().copied().find(|k| !used[*k]) {
+            used[k] = true;
+            let (a, b) = segments[k];
+            current = if a == current { b } else { a };
+            line.push(current);
+        }
+
+        if line.len() > 1 {
+            lines.push(line);
+        }
+    }
+    lines
+}
+
+// Neighbouring pieces share the edges between them in opposite directions, so dropping those
+// leaves the outlines of the band to follow around
+fn join_rings(pieces: &[Vec<Vertex>]) -> Vec<Vec<Vertex>> {
+    let edges = pieces
+        .iter()
+        .flat_map(|piece| (0..piece.len()).map(move |k| (piece[k], piece[(k + 1) % piece.len()])))
+        .collect::<Vec<_>>();
+
+    let mut boundary: HashSet<(Vertex, Vertex)> = HashSet::new();
+    for (a, b) in &edges {
+        if !boundary.remove(&(*b, *a)) {
+            boundary.insert((*a, *b));
+        }
+    }
+
+    let mut outgoing: HashMap<Vertex, Vec<Vertex>> = HashMap::new();
+    for edge in edges.iter().filter(|edge| boundary.contains(edge)) {
+        outgoing.entry(edge.0).or_default().push(edge.1);
+    }
+
+    let mut rings = Vec::new();
+    for (start, _) in edges.iter().filter(|edge| boundary.contains(edge)) {
+        let mut ring = Vec::new();
+        let mut current = *start;
+        while let Some(next) = outgoing.get_mut(&current).and_then(Vec::pop) {
+            ring.push(current);
+            current = next;
+            if current == *start {
+                break;
+            }
+        }
+
+        if ring.len() >= 3 {
+            rings.push(ring);
+        }
+    }
+    rings
+}
+
+fn signed_area(ring: &[(f64, f64)]) -> f64 {
+    (0..ring.len())
+        .map(|k| {
+            let ((y_a, x_a), (y_b, x_b)) = (ring[k], ring[(k + 1) % ring.len()]);
+            x_a * y_b - x_b * y_a
+        })
+        .sum::<f64>()
+        / 2.0
+}
+
+fn ring_contains(ring: &[(f64, f64)], (y, x): (f64, f64)) -> bool {
+    let mut inside = false;
+    for k in 0..ring.len() {
+        let ((y_a, x_a), (y_b, x_b)) = (ring[k], ring[(k + ring.len() - 1) % ring.len()]);
+        if (y_a > y) != (y_b > y) && x < x_a + (y - y_a) / (y_b - y_a) * (x_b - x_a) {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+// GeoJSON positions run (longitude, latitude), and polygon rings repeat their first position
+pub(crate) fn coordinates(vertices: &[(f64, f64)], closed: bool) -> String {
+    let mut positions = vertices
+        .iter()
+        .map(|(latitude, longitude)| format!("[{},{}]", longitude, latitude))
+        .collect::<Vec<_>>();
+    if closed && vertices.first() != vertices.last() {
+        positions.push(positions[0].clone());
+    }
+    format!("[{}]", positions.join(","))
+}
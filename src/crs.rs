@@ -0,0 +1,172 @@
+use crate::error::GribError;
+use crate::templates::grid::{
+    earth_radius, longitude_difference, scaled_earth_value, EarthShape, GridDefinitionTemplate, LambertConformalTemplate,
+    LatitudeLongitudeGridTemplate, RotatedLatitudeLongitudeGridTemplate,
+};
+use crate::templates::template::TemplateType;
+
+// The coordinate reference system of a grid, as a PROJ definition for handing to other
+// geospatial tools along with transforms between locations, the grid's own projected
+// coordinates and fractional grid indices. The transforms follow the sphere this crate
+// projects on, the PROJ definition keeps the earth shape the grid gives.
+pub struct GridCrs<'a> {
+    data: &'a [u8],
+    template_number: u16,
+}
+
+impl<'a> GridCrs<'a> {
+    // From the data of a grid definition section
+    pub(crate) fn new(data: &'a [u8], template_number: u16) -> Result<GridCrs<'a>, GribError> {
+        match template_number {
+            0 | 1 | 30 => Ok(GridCrs { data, template_number }),
+            number => Err(GribError::UnsupportedTemplate { kind: TemplateType::Grid, number }),
+        }
+    }
+
+    pub fn proj_string(&self) -> String {
+        let earth = earth_definition(self.data);
+        match self.template_number {
+            1 => {
+                let template = RotatedLatitudeLongitudeGridTemplate::new(self.data);
+                format!(
+                    "+proj=ob_tran +o_proj=longlat +o_lat_p={} +o_lon_p={} +lon_0={} {} +no_defs",
+                    -template.south_pole_latitude(),
+                    template.rotation_angle(),
+                    template.south_pole_longitude(),
+                    earth
+                )
+            }
+            30 => {
+                let template = LambertConformalTemplate::new(self.data);
+                format!(
+                    "+proj=lcc +lat_1={} +lat_2={} +lat_0={} +lon_0={} +x_0=0 +y_0=0 {} +units=m +no_defs",
+                    template.first_standard_parallel(),
+                    template.second_standard_parallel(),
+                    template.increment_latitude(),
+                    template.orientation_longitude(),
+                    earth
+                )
+            }
+            _ => format!("+proj=longlat {} +no_defs", earth),
+        }
+    }
+
+    // Only latitude longitude grids on WGS84 have an EPSG code of their own
+    pub fn epsg(&self) -> Option<u32> {
+        match (self.template_number, EarthShape::from(self.data[14])) {
+            (0, EarthShape::WGS84) => Some(4326),
+            _ => None,
+        }
+    }
+
+    // The location in the grid's projected coordinates: metres from the projection origin for
+    // Lambert conformal grids, (longitude, latitude) in rotated or plain degrees otherwise
+    pub fn to_projected(&self, latitude: f64, longitude: f64) -> (f64, f64) {
+        match self.template_number {
+            1 => {
+                let (latitude, longitude) = RotatedLatitudeLongitudeGridTemplate::new(self.data).to_rotated(latitude, longitude);
+                (longitude, latitude)
+            }
+            30 => {
+                let template = LambertConformalTemplate::new(self.data);
+                let (x, y) = template.project(latitude, longitude);
+                (x, y - self.lambert_origin_offset(&template))
+            }
+            _ => (longitude, latitude),
+        }
+    }
+
+    pub fn from_projected(&self, x: f64, y: f64) -> (f64, f64) {
+        match self.template_number {
+            1 => RotatedLatitudeLongitudeGridTemplate::new(self.data).to_geographic(y, x),
+            30 => {
+                let template = LambertConformalTemplate::new(self.data);
+                template.unproject(x, y + self.lambert_origin_offset(&template))
+            }
+            _ => (y, x),
+        }
+    }
+
+    // The fractional (column, row) of the location, counted from the first grid point the way
+    // the grid is scanned like Message::value_at
+    pub fn to_grid(&self, latitude: f64, longitude: f64) -> (f64, f64) {
+        match self.template_number {
+            30 => {
+                let template = LambertConformalTemplate::new(self.data);
+                let ((x, y), (x_step, y_step)) = template.grid_steps();
+                let (position_x, position_y) = template.project(latitude, longitude);
+                ((position_x - x) / x_step, (position_y - y) / y_step)
+            }
+            1 => {
+                let template = RotatedLatitudeLongitudeGridTemplate::new(self.data);
+                let (latitude, longitude) = template.to_rotated(latitude, longitude);
+                axis_position(&template.rotated_grid(), latitude, longitude)
+            }
+            _ => axis_position(&LatitudeLongitudeGridTemplate::new(self.data), latitude, longitude),
+        }
+    }
+
+    pub fn from_grid(&self, i: f64, j: f64) -> (f64, f64) {
+        match self.template_number {
+            30 => {
+                let template = LambertConformalTemplate::new(self.data);
+                let ((x, y), (x_step, y_step)) = template.grid_steps();
+                template.unproject(x + i * x_step, y + j * y_step)
+            }
+            1 => {
+                let template = RotatedLatitudeLongitudeGridTemplate::new(self.data);
+                let (latitude, longitude) = axis_location(&template.rotated_grid(), i, j);
+                template.to_geographic(latitude, longitude)
+            }
+            _ => axis_location(&LatitudeLongitudeGridTemplate::new(self.data), i, j),
+        }
+    }
+
+    #[cfg(feature = "geotiff")]
+    pub(crate) fn template_number(&self) -> u16 {
+        self.template_number
+    }
+
+    // The templates project with the pole at the origin, PROJ puts it at the latitude of origin
+    fn lambert_origin_offset(&self, template: &LambertConformalTemplate) -> f64 {
+        template.project(template.increment_latitude(), template.orientation_longitude()).1
+    }
+}
+
+fn axis_position(grid: &LatitudeLongitudeGridTemplate, latitude: f64, longitude: f64) -> (f64, f64) {
+    let i = longitude_difference(longitude, grid.start_longitude()).rem_euclid(360.0) / grid.i_direction_increment();
+    let j = (latitude - grid.start_latitude()) / grid.j_direction_increment();
+    (i, j)
+}
+
+fn axis_location(grid: &LatitudeLongitudeGridTemplate, i: f64, j: f64) -> (f64, f64) {
+    (
+        grid.start_latitude() + j * grid.latitude_resolution(),
+        grid.start_longitude() + i * grid.longitude_resolution(),
+    )
+}
+
+fn earth_definition(data: &[u8]) -> String {
+    match EarthShape::from(data[14]) {
+        EarthShape::OblateIAGGRS80 => "+ellps=GRS80".into(),
+        EarthShape::WGS84 => "+datum=WGS84".into(),
+        EarthShape::OblateIAU | EarthShape::OblateKM | EarthShape::OblateM => {
+            let (semi_major, semi_minor) = earth_axes(data);
+            format!("+a={} +b={}", semi_major, semi_minor)
+        }
+        _ => format!("+R={}", earth_radius(data)),
+    }
+}
+
+// The semi major and semi minor axes of the earth the grid is defined on, in metres, which are
+// the same for the spherical shapes
+pub(crate) fn earth_axes(data: &[u8]) -> (f64, f64) {
+    match EarthShape::from(data[14]) {
+        EarthShape::OblateIAU => (6_378_160.0, 6_356_775.0),
+        EarthShape::OblateKM => (scaled_earth_value(data, 20) * 1000.0, scaled_earth_value(data, 25) * 1000.0),
+        EarthShape::OblateIAGGRS80 => (6_378_137.0, 6_356_752.314_140_356),
+        EarthShape::WGS84 => (6_378_137.0, 6_356_752.314_245_179),
+        EarthShape::OblateM => (scaled_earth_value(data, 20), scaled_earth_value(data, 25)),
+        _ => (earth_radius(data), earth_radius(data)),
+    }
+}
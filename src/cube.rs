@@ -0,0 +1,234 @@
+use crate::error::GribError;
+use crate::message::Message;
+use crate::templates::grid::scanned_index;
+use crate::templates::product::tables::FixedSurfaceTypes;
+use crate::winds::grid_section;
+use chrono::{DateTime, Utc};
+use gribberish_types::Parameter;
+use std::collections::{BTreeMap, BTreeSet};
+
+// Messages on one grid gathered the way the array formats lay them out. Messages holding the
+// same parameter on the same type of surface become one variable over time and level, so a
+// forecast run of temperatures on pressure levels is a single (time, level, y, x) variable.
+// The times and the levels of each type of surface are shared by every variable.
+pub(crate) struct Cube<'m, 'a> {
+    // The earliest reference date, which times are counted from
+    pub epoch: DateTime<Utc>,
+    // Whether every message comes from the same reference date
+    pub single_reference: bool,
+    pub times: Vec<DateTime<Utc>>,
+    // The ascending level values of each type of surface that gives them
+    pub levels: BTreeMap<u8, Vec<f64>>,
+    pub variables: Vec<CubeVariable<'m, 'a>>,
+    pub shape: (usize, usize),
+    // Regular latitude longitude grids have axes, other grids give every location
+    pub regular: bool,
+    first: &'m Message<'a>,
+    // Where each point is stored, for points in rows of increasing i
+    stored: Vec<usize>,
+}
+
+pub(crate) struct CubeVariable<'m, 'a> {
+    // The parameter abbreviation, with the level name when it is found on more than one surface
+    pub name: String,
+    pub parameter: Parameter,
+    pub surface: u8,
+    // The message at each time and level index
+    pub fields: BTreeMap<(usize, usize), &'m Message<'a>>,
+}
+
+impl<'m, 'a> Cube<'m, 'a> {
+    pub fn new(messages: &'m [Message<'a>]) -> Result<Cube<'m, 'a>, GribError> {
+        let first = unwrap_or_return!(messages.first(), GribError::InvalidData("No messages were given to write".into()));
+        let grid = grid_section(first)?;
+        for message in &messages[1..] {
+            if grid_section(message)? != grid {
+                return Err(GribError::InvalidData("Messages written together must share a grid".into()));
+            }
+        }
+
+        let mut fields = Vec::with_capacity(messages.len());
+        let mut times = BTreeSet::new();
+        let mut reference_dates = BTreeSet::new();
+        let mut levels: BTreeMap<u8, Vec<f64>> = BTreeMap::new();
+        for message in messages {
+            let template = message.product_template()?;
+            let time = message.forecast_date()?;
+            let surface = u8::from(template.first_fixed_surface_type());
+            let level = template.first_fixed_surface_value();
+            times.insert(time);
+            reference_dates.insert(message.reference_date()?);
+            if let Some(level) = level {
+                levels.entry(surface).or_default().push(level);
+            }
+            fields.push((message, message.parameter()?, time, surface, level));
+        }
+        for values in levels.values_mut() {
+            values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            values.dedup();
+        }
+        let times = times.into_iter().collect::<Vec<_>>();
+
+        let mut variables: BTreeMap<(String, u8), CubeVariable> = BTreeMap::new();
+        for (message, parameter, time, surface, level) in fields {
+            let level_index = match (levels.get(&surface), level) {
+                (None, _) => 0,
+                (Some(values), Some(level)) => values.iter().position(|value| *value == level).unwrap_or(0),
+                (Some(_), None) => {
+                    return Err(GribError::InvalidData(format!(
+                        "{} is on a {} without a level value",
+                        parameter.abbrev,
+                        FixedSurfaceTypes::from(surface)
+                    )))
+                }
+            };
+            let time_index = times.iter().position(|value| *value == time).unwrap_or(0);
+
+            let abbrev = parameter.abbrev.clone();
+            let variable = variables.entry((abbrev.clone(), surface)).or_insert_with(|| CubeVariable {
+                name: abbrev,
+                parameter,
+                surface,
+                fields: BTreeMap::new(),
+            });
+            if variable.fields.insert((time_index, level_index), message).is_some() {
+                return Err(GribError::InvalidData(format!(
+                    "More than one message holds {} at {} on the same level",
+                    variable.parameter.abbrev, time
+                )));
+            }
+        }
+
+        let mut abbrev_counts = BTreeMap::new();
+        for (abbrev, _) in variables.keys() {
+            *abbrev_counts.entry(abbrev.clone()).or_insert(0) += 1;
+        }
+        let variables = variables
+            .into_iter()
+            .map(|((abbrev, surface), mut variable)| {
+                if abbrev_counts[&abbrev] > 1 {
+                    variable.name = format!("{}_{}", abbrev, level_name(surface));
+                }
+                variable
+            })
+            .collect();
+
+        let grid_template = first.grid_template()?;
+        let shape = first.shape()?;
+        let scanning_mode = grid_template.scanning_mode();
+        Ok(Cube {
+            epoch: *reference_dates.iter().next().unwrap(),
+            single_reference: reference_dates.len() == 1,
+            times,
+            levels,
+            variables,
+            shape,
+            regular: grid_template.is_regular(),
+            first,
+            stored: (0..shape.0 * shape.1)
+                .map(|point| scanned_index(scanning_mode, shape, point % shape.1, point / shape.1))
+                .collect(),
+        })
+    }
+
+    // The times as hours since the epoch
+    pub fn hours(&self) -> Vec<f64> {
+        self.times.iter().map(|time| (*time - self.epoch).num_seconds() as f64 / 3600.0).collect()
+    }
+
+    // The number of levels of the variable, None when its surface has no level values
+    pub fn level_count(&self, variable: &CubeVariable) -> Option<usize> {
+        self.levels.get(&variable.surface).map(Vec::len)
+    }
+
+    // The latitude and longitude axes of a regular grid, or the location of every point in rows
+    pub fn locations(&self) -> Result<(Vec<f64>, Vec<f64>), GribError> {
+        if self.regular {
+            return Ok((self.first.latitudes()?, self.first.longitudes()?));
+        }
+
+        let locations = self.first.data_locations()?;
+        if locations.len() < self.stored.len() {
+            return Err(GribError::InvalidData("The grid locations do not cover the grid".into()));
+        }
+        Ok(self.stored.iter().map(|index| locations[*index]).unzip())
+    }
+
+    // The values of the message in rows of increasing i, NaN where there is no data
+    pub fn plane(&self, message: &Message) -> Result<Vec<f64>, GribError> {
+        let data = message.data()?;
+        if data.len() < self.stored.len() {
+            return Err(GribError::InvalidData("The data does not cover the grid".into()));
+        }
+        Ok(self.stored.iter().map(|index| data[*index]).collect())
+    }
+}
+
+pub(crate) fn hours_since(epoch: &DateTime<Utc>) -> String {
+    format!("hours since {}", epoch.format("%Y-%m-%d %H:%M:%S"))
+}
+
+// The name of the level dimension and coordinate for a type of surface
+pub(crate) fn level_name(surface: u8) -> String {
+    match FixedSurfaceTypes::from(surface) {
+        FixedSurfaceTypes::IsobaricSurface => "isobaric".into(),
+        FixedSurfaceTypes::SpecificAltitudeAboveMeanSeaLevel => "altitude_above_msl".into(),
+        FixedSurfaceTypes::SpecifiedHeightLevelAboveGround => "height_above_ground".into(),
+        FixedSurfaceTypes::SigmaLevel => "sigma".into(),
+        FixedSurfaceTypes::DepthBelowLandSurface => "depth_below_surface".into(),
+        _ => format!("level_{}", surface),
+    }
+}
+
+// The CF attributes of the level coordinate for a type of surface
+pub(crate) fn level_attributes(surface: u8) -> &'static [(&'static str, &'static str)] {
+    match FixedSurfaceTypes::from(surface) {
+        FixedSurfaceTypes::IsobaricSurface => &[("standard_name", "air_pressure"), ("units", "Pa"), ("positive", "down")],
+        FixedSurfaceTypes::SpecificAltitudeAboveMeanSeaLevel => &[("standard_name", "altitude"), ("units", "m"), ("positive", "up")],
+        FixedSurfaceTypes::SpecifiedHeightLevelAboveGround => &[("standard_name", "height"), ("units", "m"), ("positive", "up")],
+        FixedSurfaceTypes::SigmaLevel => &[("standard_name", "atmosphere_sigma_coordinate"), ("units", "1"), ("positive", "down")],
+        FixedSurfaceTypes::DepthBelowLandSurface => &[("standard_name", "depth"), ("units", "m"), ("positive", "down")],
+        _ => &[],
+    }
+}
+
+// The CF standard names of the parameters in common use
+pub(crate) fn standard_name(abbrev: &str) -> Option<&'static str> {
+    Some(match abbrev {
+        "TMP" => "air_temperature",
+        "DPT" => "dew_point_temperature",
+        "RH" => "relative_humidity",
+        "SPFH" => "specific_humidity",
+        "UGRD" => "eastward_wind",
+        "VGRD" => "northward_wind",
+        "WIND" => "wind_speed",
+        "GUST" => "wind_speed_of_gust",
+        "PRES" => "air_pressure",
+        "PRMSL" => "air_pressure_at_mean_sea_level",
+        "HGT" => "geopotential_height",
+        "APCP" => "precipitation_amount",
+        "TCDC" => "cloud_area_fraction",
+        "VIS" => "visibility_in_air",
+        "HTSGW" => "sea_surface_wave_significant_height",
+        "WTMP" => "sea_surface_temperature",
+        _ => return None,
+    })
+}
+
+// The tables write units without spaces between the terms, which UDUNITS does not read
+pub(crate) fn cf_units(unit: &str) -> String {
+    match unit {
+        "ms-1" => "m s-1",
+        "kgm-2" => "kg m-2",
+        "kgm-2s-1" => "kg m-2 s-1",
+        "kgkg-1" => "kg kg-1",
+        "Wm-2" => "W m-2",
+        "m2s-2" => "m2 s-2",
+        "Km2kg-1s-1" => "K m2 kg-1 s-1",
+        "Pas-1" => "Pa s-1",
+        "gpm" => "m",
+        "Proportion" | "Numeric" | "Non-Dim" => "1",
+        unit => unit,
+    }
+    .to_string()
+}
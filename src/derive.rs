@@ -0,0 +1,23 @@
+use crate::error::GribError;
+use crate::message::Message;
+use crate::winds::rotate_winds;
+
+// Wind speed, in the units of the components, and the meteorological direction the wind blows
+// from in degrees clockwise from north. Grid relative components are rotated to the earth first,
+// calm points have a direction of zero and points missing either component are NaN in both.
+pub fn wind_speed_direction(u: &Message, v: &Message) -> Result<(Vec<f64>, Vec<f64>), GribError> {
+    if u.reference_date()? != v.reference_date()? || u.forecast_date()? != v.forecast_date()? {
+        return Err(GribError::InvalidData("The U and V components are not valid at the same time".into()));
+    }
+
+    let (u_values, v_values) = rotate_winds(u, v)?;
+    Ok(u_values
+        .iter()
+        .zip(v_values.iter())
+        .map(|(u, v)| {
+            let speed = u.hypot(*v);
+            let direction = if speed == 0.0 { 0.0 } else { (-u).atan2(-v).to_degrees().rem_euclid(360.0) };
+            (speed, direction)
+        })
+        .unzip())
+}
@@ -0,0 +1,259 @@
+use crate::error::GribError;
+use super::packing::{DataRepresentationTemplateKind, PackedData};
+use super::simple_packing::{self, PackingPrecision};
+use crate::utils::signed_i16_to_bytes;
+
+// Candidate group lengths, the one giving the smallest data section is used
+const GROUP_LENGTHS: [usize; 8] = [4, 8, 16, 32, 64, 128, 256, 512];
+
+struct Grouping {
+    length: usize,
+    references: Vec<u64>,
+    widths: Vec<u64>,
+}
+
+impl Grouping {
+    fn new(values: &[u64], length: usize) -> Grouping {
+        let (references, widths) = values
+            .chunks(length)
+            .map(|group| {
+                let min = *group.iter().min().unwrap_or(&0);
+                let max = *group.iter().max().unwrap_or(&0);
+                (min, bit_count(max - min) as u64)
+            })
+            .unzip();
+
+        Grouping { length, references, widths }
+    }
+
+    fn reference_bit_count(&self) -> usize {
+        bit_count(*self.references.iter().max().unwrap_or(&0))
+    }
+
+    fn width_reference(&self) -> u64 {
+        *self.widths.iter().min().unwrap_or(&0)
+    }
+
+    fn width_bit_count(&self) -> usize {
+        bit_count(*self.widths.iter().max().unwrap_or(&0) - self.width_reference())
+    }
+
+    fn packed_bit_count(&self) -> usize {
+        let group_count = self.references.len();
+        let references = byte_aligned(group_count * self.reference_bit_count());
+        let widths = byte_aligned(group_count * self.width_bit_count());
+        let values: usize = self.widths.iter().map(|w| *w as usize * self.length).sum();
+        references + widths + values
+    }
+}
+
+pub fn pack(data: &[f64], precision: PackingPrecision) -> Result<PackedData, GribError> {
+    let scaled = simple_packing::scale(data, precision)?;
+    let value_count = scaled.values.len();
+
+    let mut template = Vec::with_capacity(38);
+    template.extend_from_slice(&scaled.reference_value.to_be_bytes());
+    template.extend_from_slice(&signed_i16_to_bytes(scaled.binary_scale_factor));
+    template.extend_from_slice(&signed_i16_to_bytes(scaled.decimal_scale_factor));
+
+    // A constant field is stored entirely in the reference value with no groups
+    if scaled.bit_count == 0 || value_count == 0 {
+        template.extend_from_slice(&[0, 0, 1, 0]);
+        template.extend_from_slice(&[0xFF; 8]);
+        template.extend_from_slice(&0u32.to_be_bytes());
+        template.extend_from_slice(&[0, 0]);
+        template.extend_from_slice(&0u32.to_be_bytes());
+        template.push(1);
+        template.extend_from_slice(&0u32.to_be_bytes());
+        template.extend_from_slice(&[0, 1, 1]);
+
+        return Ok(PackedData {
+            template_number: DataRepresentationTemplateKind::ComplexPacking.template_number(),
+            template,
+            data: Vec::new(),
+        });
+    }
+
+    let order = value_count.min(2);
+    let values = scaled.values.iter().map(|v| *v as i64).collect::<Vec<i64>>();
+    let differences = (order..value_count)
+        .map(|i| match order {
+            1 => values[i] - values[i - 1],
+            _ => values[i] - 2 * values[i - 1] + values[i - 2],
+        })
+        .collect::<Vec<i64>>();
+    let minimum_difference = differences.iter().cloned().min().unwrap_or(0);
+
+    // The first values are written explicitly, so their slots only hold placeholders
+    let residuals = std::iter::repeat_n(0, order)
+        .chain(differences.iter().map(|d| (d - minimum_difference) as u64))
+        .collect::<Vec<u64>>();
+
+    let largest_descriptor = values[..order]
+        .iter()
+        .chain(std::iter::once(&minimum_difference))
+        .map(|v| v.unsigned_abs())
+        .max()
+        .unwrap_or(0);
+    let descriptor_octets = (bit_count(largest_descriptor) + 1).div_ceil(8);
+
+    let grouping = GROUP_LENGTHS
+        .iter()
+        .map(|length| Grouping::new(&residuals, *length))
+        .min_by_key(|grouping| grouping.packed_bit_count())
+        .unwrap();
+
+    let group_count = grouping.references.len();
+    let reference_bit_count = grouping.reference_bit_count();
+    let width_reference = grouping.width_reference();
+    let width_bit_count = grouping.width_bit_count();
+    let last_group_length = value_count - (group_count - 1) * grouping.length;
+
+    let mut writer = BitWriter::default();
+    for value in values[..order].iter().chain(std::iter::once(&minimum_difference)) {
+        writer.write_signed(*value, descriptor_octets * 8);
+    }
+
+    for reference in &grouping.references {
+        writer.write(*reference, reference_bit_count);
+    }
+    writer.align();
+
+    for width in &grouping.widths {
+        writer.write(width - width_reference, width_bit_count);
+    }
+    writer.align();
+
+    // All groups share a length besides the last, so no bits are needed for the scaled lengths
+    for ((group, reference), width) in residuals
+        .chunks(grouping.length)
+        .zip(grouping.references.iter())
+        .zip(grouping.widths.iter())
+    {
+        for value in group {
+            writer.write(value - reference, *width as usize);
+        }
+    }
+
+    template.push(reference_bit_count as u8);
+    // Original values are floating point
+    template.push(0);
+    // General group splitting with no missing values
+    template.extend_from_slice(&[1, 0]);
+    template.extend_from_slice(&[0xFF; 8]);
+    template.extend_from_slice(&(group_count as u32).to_be_bytes());
+    template.push(width_reference as u8);
+    template.push(width_bit_count as u8);
+    template.extend_from_slice(&(grouping.length as u32).to_be_bytes());
+    template.push(1);
+    template.extend_from_slice(&(last_group_length as u32).to_be_bytes());
+    template.push(0);
+    template.push(order as u8);
+    template.push(descriptor_octets as u8);
+
+    Ok(PackedData {
+        template_number: DataRepresentationTemplateKind::ComplexPacking.template_number(),
+        template,
+        data: writer.into_bytes(),
+    })
+}
+
+fn bit_count(value: u64) -> usize {
+    (64 - value.leading_zeros()) as usize
+}
+
+fn byte_aligned(bits: usize) -> usize {
+    bits.div_ceil(8) * 8
+}
+
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u64,
+    buffered_bits: usize,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u64, count: usize) {
+        for i in (0..count).rev() {
+            self.buffer = (self.buffer << 1) | ((value >> i) & 1);
+            self.buffered_bits += 1;
+
+            if self.buffered_bits == 8 {
+                self.bytes.push(self.buffer as u8);
+                self.buffer = 0;
+                self.buffered_bits = 0;
+            }
+        }
+    }
+
+    // Sign and magnitude, with the sign in the first bit
+    fn write_signed(&mut self, value: i64, count: usize) {
+        self.write(if value < 0 { 1 } else { 0 }, 1);
+        self.write(value.unsigned_abs(), count - 1);
+    }
+
+    fn align(&mut self) {
+        if self.buffered_bits > 0 {
+            self.write(0, 8 - self.buffered_bits);
+        }
+    }
+
+    fn into_bytes(mut self) -> Vec<u8> {
+        self.align();
+        self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::templates::data_representation::{ComplexSpatialDifferencingDataRepresentationTemplate, DataRepresentationTemplate};
+
+    fn unpack(data: &[f64], precision: PackingPrecision) -> Vec<f64> {
+        let packed = pack(data, precision).unwrap();
+
+        let mut section = vec![0, 0, 0, 0, 5];
+        section.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        section.extend_from_slice(&packed.template_number.to_be_bytes());
+        section.extend_from_slice(&packed.template);
+        assert_eq!(section.len(), 49);
+
+        let template = ComplexSpatialDifferencingDataRepresentationTemplate::new(&section);
+        template.unpack_all(&packed.data).unwrap()
+    }
+
+    #[test]
+    fn complex_packing_round_trip() {
+        let data = (0..1000)
+            .map(|i| 280.0 + (i as f64 * 0.05).sin() * 12.5 + (i % 7) as f64 * 0.25)
+            .collect::<Vec<f64>>();
+
+        let unpacked = unpack(&data, PackingPrecision::DecimalPlaces(2));
+        assert_eq!(unpacked.len(), data.len());
+        for (unpacked, value) in unpacked.iter().zip(data.iter()) {
+            assert!((unpacked - value).abs() <= 0.005 + 1e-9);
+        }
+    }
+
+    #[test]
+    fn complex_packing_large_integers() {
+        let data = vec![100000001.0, 100000004.0, 100000002.0, 100000003.0];
+        assert_eq!(unpack(&data, PackingPrecision::Auto), data);
+    }
+
+    #[test]
+    fn complex_packing_is_smaller_for_smooth_fields() {
+        let data = (0..10000).map(|i| 1000.0 + i as f64 * 0.1).collect::<Vec<f64>>();
+        let simple = simple_packing::pack(&data, PackingPrecision::DecimalPlaces(1)).unwrap();
+        let complex = pack(&data, PackingPrecision::DecimalPlaces(1)).unwrap();
+        assert!(complex.data.len() * 4 < simple.packed.len());
+    }
+
+    #[test]
+    fn complex_packing_short_and_constant_fields() {
+        assert_eq!(unpack(&[4.0], PackingPrecision::Auto), vec![4.0]);
+        assert_eq!(unpack(&[4.0, -2.0], PackingPrecision::Auto), vec![4.0, -2.0]);
+        assert_eq!(unpack(&[7.5; 6], PackingPrecision::Auto), vec![7.5; 6]);
+    }
+}
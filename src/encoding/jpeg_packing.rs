@@ -0,0 +1,53 @@
+use crate::error::GribError;
+use super::packing::{scaling_template, DataRepresentationTemplateKind, PackedData};
+use super::simple_packing::{self, PackingPrecision};
+use crate::utils::encode_jpeg_data;
+
+pub fn pack(data: &[f64], shape: (usize, usize), precision: PackingPrecision) -> Result<PackedData, GribError> {
+    let scaled = simple_packing::scale(data, precision)?;
+    let (rows, columns) = shape;
+
+    // A constant field is stored entirely in the reference value with no image
+    let image = if scaled.bit_count == 0 {
+        Vec::new()
+    } else {
+        encode_jpeg_data(&scaled.values, columns, rows, scaled.bit_count)?
+    };
+
+    let mut template = scaling_template(scaled.reference_value, scaled.binary_scale_factor, scaled.decimal_scale_factor, scaled.bit_count);
+    // Lossless compression, so there is no compression ratio
+    template.extend_from_slice(&[0, 255]);
+
+    Ok(PackedData {
+        template_number: DataRepresentationTemplateKind::Jpeg2000.template_number(),
+        template,
+        data: image,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::templates::data_representation::{DataRepresentationTemplate, JPEGDataRepresentationTemplate};
+
+    #[test]
+    fn jpeg_packing_round_trip() {
+        let data = (0..300)
+            .map(|i| -5.0 + ((i / 20) as f64 * 0.4).sin() * 3.0 + (i % 20) as f64 * 0.1)
+            .collect::<Vec<f64>>();
+        let packed = pack(&data, (15, 20), PackingPrecision::DecimalPlaces(2)).unwrap();
+
+        let mut section = vec![0, 0, 0, 0, 5];
+        section.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        section.extend_from_slice(&packed.template_number.to_be_bytes());
+        section.extend_from_slice(&packed.template);
+        assert_eq!(section.len(), 23);
+
+        let template = JPEGDataRepresentationTemplate::new(&section);
+        let unpacked = template.unpack_all(&packed.data).unwrap();
+        assert_eq!(unpacked.len(), data.len());
+        for (unpacked, value) in unpacked.iter().zip(data.iter()) {
+            assert!((unpacked - value).abs() <= 0.005 + 1e-9);
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use crate::error::GribError;
+use crate::sections::identification::{Center, GribDataType};
+use crate::sections::indicator::Discipline;
+use crate::templates::product::tables::{EnsembleForecastType, FixedSurfaceTypes, GeneratingProcess, StatisticalProcess};
+use super::sections::{
+    assemble_message, BitmapSectionBuilder, DataRepresentationSectionBuilder, DataSectionBuilder, EnsembleMember,
+    GridDefinitionSectionBuilder, IdentificationSectionBuilder, LatLngGrid, ProductDefinitionSectionBuilder,
+};
+use super::packing::{self, DataRepresentationTemplateKind};
+use super::simple_packing::PackingPrecision;
+use chrono::{DateTime, Duration, Utc};
+use std::vec::Vec;
+
+pub struct MessageBuilder {
+    discipline: Discipline,
+    category: u8,
+    parameter: u8,
+    center: Center,
+    subcenter: u16,
+    reference_date: Option<DateTime<Utc>>,
+    forecast_date: Option<DateTime<Utc>>,
+    fixed_surface: (FixedSurfaceTypes, f64),
+    ensemble: Option<EnsembleMember>,
+    statistical_process: Option<(StatisticalProcess, Duration)>,
+    grid: Option<LatLngGrid>,
+    packing: DataRepresentationTemplateKind,
+    precision: PackingPrecision,
+    data: Vec<f64>,
+}
+
+impl MessageBuilder {
+    pub fn new(discipline: Discipline, category: u8, parameter: u8) -> MessageBuilder {
+        MessageBuilder {
+            discipline,
+            category,
+            parameter,
+            center: Center::Missing,
+            subcenter: 0,
+            reference_date: None,
+            forecast_date: None,
+            fixed_surface: (FixedSurfaceTypes::GroundOrWater, 0.0),
+            ensemble: None,
+            statistical_process: None,
+            grid: None,
+            packing: DataRepresentationTemplateKind::SimplePacking,
+            precision: PackingPrecision::Auto,
+            data: Vec::new(),
+        }
+    }
+
+    pub fn center(mut self, center: Center, subcenter: u16) -> Self {
+        self.center = center;
+        self.subcenter = subcenter;
+        self
+    }
+
+    pub fn reference_date(mut self, reference_date: DateTime<Utc>) -> Self {
+        self.reference_date = Some(reference_date);
+        self
+    }
+
+    pub fn forecast_date(mut self, forecast_date: DateTime<Utc>) -> Self {
+        self.forecast_date = Some(forecast_date);
+        self
+    }
+
+    pub fn fixed_surface(mut self, surface_type: FixedSurfaceTypes, value: f64) -> Self {
+        self.fixed_surface = (surface_type, value);
+        self
+    }
+
+    pub fn ensemble_member(mut self, forecast_type: EnsembleForecastType, perturbation_number: u8, forecast_count: u8) -> Self {
+        self.ensemble = Some(EnsembleMember {
+            forecast_type,
+            perturbation_number,
+            forecast_count,
+        });
+        self
+    }
+
+    // The field is processed over the interval of the given length ending at the forecast date
+    pub fn statistical_process(mut self, process: StatisticalProcess, length: Duration) -> Self {
+        self.statistical_process = Some((process, length));
+        self
+    }
+
+    pub fn grid(mut self, grid: LatLngGrid) -> Self {
+        self.grid = Some(grid);
+        self
+    }
+
+    pub fn bits_per_value(mut self, bit_count: u8) -> Self {
+        self.precision = PackingPrecision::BitCount(bit_count);
+        self
+    }
+
+    pub fn decimal_places(mut self, decimal_places: i16) -> Self {
+        self.precision = PackingPrecision::DecimalPlaces(decimal_places);
+        self
+    }
+
+    pub fn packing(mut self, packing: DataRepresentationTemplateKind) -> Self {
+        self.packing = packing;
+        self
+    }
+
+    pub fn precision(mut self, precision: PackingPrecision) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    pub fn data(mut self, data: Vec<f64>) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn build(self) -> Result<Vec<u8>, GribError> {
+        let reference_date = unwrap_or_return!(
+            self.reference_date,
+            GribError::Encoding("A reference date is required to build a message".into())
+        );
+        let forecast_date = self.forecast_date.unwrap_or(reference_date);
+
+        let grid = unwrap_or_return!(
+            self.grid,
+            GribError::Encoding("A grid is required to build a message".into())
+        );
+
+        if grid.grid_point_count() != self.data.len() {
+            return Err(GribError::Encoding(format!(
+                "Grid expects {} data points but {} were given",
+                grid.grid_point_count(),
+                self.data.len()
+            )));
+        }
+
+        let is_local = u8::from(self.discipline.clone()) >= 192 || self.category >= 192 || self.parameter >= 192;
+
+        let mut identification = IdentificationSectionBuilder::new(self.center, self.subcenter, reference_date);
+        identification.local_table_version = if is_local { 1 } else { 0 };
+        identification.data_type = if forecast_date > reference_date {
+            GribDataType::Forecast
+        } else {
+            GribDataType::Analysis
+        };
+
+        // Missing values are left out of the packed data and marked in a bitmap instead
+        let (values, bitmap, shape) = if self.data.iter().any(|v| v.is_nan()) {
+            let bitmap = self.data.iter().map(|v| !v.is_nan()).collect::<Vec<bool>>();
+            let values = self.data.iter().cloned().filter(|v| !v.is_nan()).collect::<Vec<f64>>();
+            let shape = (1, values.len());
+            (values, Some(bitmap), shape)
+        } else {
+            (self.data, None, grid.shape)
+        };
+
+        let grid_definition = GridDefinitionSectionBuilder::new(grid);
+
+        let mut product_definition = match self.statistical_process {
+            Some((process, length)) => ProductDefinitionSectionBuilder::new(self.category, self.parameter)
+                .forecast_offset(forecast_date - length - reference_date)?
+                .statistical_process(process, forecast_date, length)?,
+            None => ProductDefinitionSectionBuilder::new(self.category, self.parameter)
+                .forecast_offset(forecast_date - reference_date)?,
+        };
+        product_definition.first_fixed_surface = self.fixed_surface;
+        product_definition.ensemble = self.ensemble;
+        if self.ensemble.is_some() && forecast_date > reference_date {
+            product_definition.generating_process = GeneratingProcess::EnsembleForecast;
+        }
+
+        let packed = packing::pack(&values, shape, self.packing, self.precision)?;
+        let data_representation = DataRepresentationSectionBuilder::from_packed(values.len() as u32, &packed);
+        let bitmap = BitmapSectionBuilder { bitmap };
+        let data = DataSectionBuilder { data: packed.data };
+
+        assemble_message(
+            self.discipline,
+            &[
+                &identification,
+                &grid_definition,
+                &product_definition,
+                &data_representation,
+                &bitmap,
+                &data,
+            ],
+        )
+    }
+}
@@ -0,0 +1,15 @@
+pub mod simple_packing;
+pub mod complex_packing;
+#[cfg(feature = "jpeg2000-encoder")]
+pub mod jpeg_packing;
+#[cfg(feature = "png")]
+pub mod png_packing;
+pub mod packing;
+pub mod sections;
+pub mod message_builder;
+pub mod writer;
+
+pub use packing::{DataRepresentationTemplateKind, PackedData};
+pub use sections::{LatLngGrid, SectionBuilder};
+pub use message_builder::MessageBuilder;
+pub use writer::{split, GribWriter};
@@ -0,0 +1,85 @@
+use crate::error::GribError;
+use super::complex_packing;
+#[cfg(feature = "jpeg2000-encoder")]
+use super::jpeg_packing;
+#[cfg(feature = "png")]
+use super::png_packing;
+use super::simple_packing::{self, PackingPrecision, SimplePackedData};
+use crate::utils::signed_i16_to_bytes;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DataRepresentationTemplateKind {
+    SimplePacking,
+    ComplexPacking,
+    #[cfg(feature = "jpeg2000-encoder")]
+    Jpeg2000,
+    #[cfg(feature = "png")]
+    Png,
+}
+
+impl DataRepresentationTemplateKind {
+    pub fn template_number(&self) -> u16 {
+        match self {
+            DataRepresentationTemplateKind::SimplePacking => 0,
+            DataRepresentationTemplateKind::ComplexPacking => 3,
+            #[cfg(feature = "jpeg2000-encoder")]
+            DataRepresentationTemplateKind::Jpeg2000 => 40,
+            #[cfg(feature = "png")]
+            DataRepresentationTemplateKind::Png => 41,
+        }
+    }
+
+    pub fn from_template_number(template_number: u16) -> Option<DataRepresentationTemplateKind> {
+        match template_number {
+            0 => Some(DataRepresentationTemplateKind::SimplePacking),
+            3 => Some(DataRepresentationTemplateKind::ComplexPacking),
+            #[cfg(feature = "jpeg2000-encoder")]
+            40 => Some(DataRepresentationTemplateKind::Jpeg2000),
+            #[cfg(feature = "png")]
+            41 => Some(DataRepresentationTemplateKind::Png),
+            _ => None,
+        }
+    }
+}
+
+// The data representation template contents following the template number, and the matching data section payload
+pub struct PackedData {
+    pub template_number: u16,
+    pub template: Vec<u8>,
+    pub data: Vec<u8>,
+}
+
+impl From<SimplePackedData> for PackedData {
+    fn from(packed: SimplePackedData) -> Self {
+        PackedData {
+            template_number: DataRepresentationTemplateKind::SimplePacking.template_number(),
+            template: scaling_template(packed.reference_value, packed.binary_scale_factor, packed.decimal_scale_factor, packed.bit_count),
+            data: packed.packed,
+        }
+    }
+}
+
+// The reference value, scale factors, bit count and original field type shared by the grid point templates
+pub(crate) fn scaling_template(reference_value: f32, binary_scale_factor: i16, decimal_scale_factor: i16, bit_count: u8) -> Vec<u8> {
+    let mut template = Vec::with_capacity(10);
+    template.extend_from_slice(&reference_value.to_be_bytes());
+    template.extend_from_slice(&signed_i16_to_bytes(binary_scale_factor));
+    template.extend_from_slice(&signed_i16_to_bytes(decimal_scale_factor));
+    template.push(bit_count);
+    // Original values are floating point
+    template.push(0);
+    template
+}
+
+// The shape is the (row, column) layout of the values, used by the image based packings
+#[allow(unused_variables)]
+pub fn pack(data: &[f64], shape: (usize, usize), kind: DataRepresentationTemplateKind, precision: PackingPrecision) -> Result<PackedData, GribError> {
+    match kind {
+        DataRepresentationTemplateKind::SimplePacking => simple_packing::pack(data, precision).map(PackedData::from),
+        DataRepresentationTemplateKind::ComplexPacking => complex_packing::pack(data, precision),
+        #[cfg(feature = "jpeg2000-encoder")]
+        DataRepresentationTemplateKind::Jpeg2000 => jpeg_packing::pack(data, shape, precision),
+        #[cfg(feature = "png")]
+        DataRepresentationTemplateKind::Png => png_packing::pack(data, shape, precision),
+    }
+}
@@ -0,0 +1,51 @@
+use crate::error::GribError;
+use super::packing::{scaling_template, DataRepresentationTemplateKind, PackedData};
+use super::simple_packing::{self, PackingPrecision};
+use crate::utils::{encode_png_data, png_bit_depth};
+
+pub fn pack(data: &[f64], shape: (usize, usize), precision: PackingPrecision) -> Result<PackedData, GribError> {
+    let scaled = simple_packing::scale(data, precision)?;
+    let (rows, columns) = shape;
+
+    // A constant field is stored entirely in the reference value with no image
+    let (bit_count, image) = if scaled.bit_count == 0 {
+        (0, Vec::new())
+    } else {
+        let depth = png_bit_depth(scaled.bit_count);
+        (depth, encode_png_data(&scaled.values, columns, rows, depth)?)
+    };
+
+    Ok(PackedData {
+        template_number: DataRepresentationTemplateKind::Png.template_number(),
+        template: scaling_template(scaled.reference_value, scaled.binary_scale_factor, scaled.decimal_scale_factor, bit_count),
+        data: image,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::templates::data_representation::{DataRepresentationTemplate, PNGDataRepresentationTemplate};
+
+    #[test]
+    fn png_packing_round_trip() {
+        let data = (0..300)
+            .map(|i| 1000.0 + (i / 20) as f64 * 1.5 - (i % 20) as f64 * 0.3)
+            .collect::<Vec<f64>>();
+        let packed = pack(&data, (15, 20), PackingPrecision::DecimalPlaces(1)).unwrap();
+
+        let mut section = vec![0, 0, 0, 0, 5];
+        section.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        section.extend_from_slice(&packed.template_number.to_be_bytes());
+        section.extend_from_slice(&packed.template);
+        assert_eq!(section.len(), 21);
+        assert_eq!(section[19], 16);
+
+        let template = PNGDataRepresentationTemplate::new(&section);
+        let unpacked = template.unpack_all(&packed.data).unwrap();
+        assert_eq!(unpacked.len(), data.len());
+        for (unpacked, value) in unpacked.iter().zip(data.iter()) {
+            assert!((unpacked - value).abs() <= 0.05 + 1e-9);
+        }
+    }
+}
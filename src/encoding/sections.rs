@@ -0,0 +1,546 @@
+use crate::error::GribError;
+use crate::sections::identification::{Center, GribDataType, ProductionStatus, ReferenceDataSignificance};
+use crate::sections::indicator::Discipline;
+use crate::templates::grid::EarthShape;
+use crate::templates::product::tables::{EnsembleForecastType, FixedSurfaceTypes, GeneratingProcess, StatisticalProcess, TimeUnit};
+use crate::utils::{pack_bits, signed_to_bytes};
+use super::packing::PackedData;
+use chrono::{DateTime, Datelike, Duration, Timelike, Utc};
+use std::convert::TryFrom;
+use std::vec::Vec;
+
+const EDITION: u8 = 2;
+const MASTER_TABLE_VERSION: u8 = 2;
+
+pub trait SectionBuilder {
+    fn number(&self) -> u8;
+    fn payload(&self) -> Result<Vec<u8>, GribError>;
+
+    fn to_bytes(&self) -> Result<Vec<u8>, GribError> {
+        let payload = self.payload()?;
+        let length = u32::try_from(payload.len() + 5)
+            .map_err(|_| GribError::Encoding(format!("Section {} is too large, sections are limited to 4 GiB", self.number())))?;
+
+        let mut section = Vec::with_capacity(payload.len() + 5);
+        section.extend_from_slice(&length.to_be_bytes());
+        section.push(self.number());
+        section.extend(payload);
+        Ok(section)
+    }
+}
+
+pub struct IndicatorSectionBuilder {
+    pub discipline: Discipline,
+    pub total_length: u64,
+}
+
+impl IndicatorSectionBuilder {
+    pub fn new(discipline: Discipline, total_length: u64) -> IndicatorSectionBuilder {
+        IndicatorSectionBuilder { discipline, total_length }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut section = Vec::with_capacity(16);
+        section.extend_from_slice(b"GRIB");
+        section.extend_from_slice(&[0, 0, u8::from(self.discipline.clone()), EDITION]);
+        section.extend_from_slice(&self.total_length.to_be_bytes());
+        section
+    }
+}
+
+pub struct IdentificationSectionBuilder {
+    pub center: Center,
+    pub subcenter: u16,
+    pub master_table_version: u8,
+    pub local_table_version: u8,
+    pub reference_date_significance: ReferenceDataSignificance,
+    pub reference_date: DateTime<Utc>,
+    pub production_status: ProductionStatus,
+    pub data_type: GribDataType,
+}
+
+impl IdentificationSectionBuilder {
+    pub fn new(center: Center, subcenter: u16, reference_date: DateTime<Utc>) -> IdentificationSectionBuilder {
+        IdentificationSectionBuilder {
+            center,
+            subcenter,
+            master_table_version: MASTER_TABLE_VERSION,
+            local_table_version: 0,
+            reference_date_significance: ReferenceDataSignificance::StartOfForecast,
+            reference_date,
+            production_status: ProductionStatus::Operational,
+            data_type: GribDataType::Forecast,
+        }
+    }
+}
+
+impl SectionBuilder for IdentificationSectionBuilder {
+    fn number(&self) -> u8 {
+        1
+    }
+
+    fn payload(&self) -> Result<Vec<u8>, GribError> {
+        let mut data = Vec::with_capacity(16);
+        data.extend_from_slice(&self.center.id().to_be_bytes());
+        data.extend_from_slice(&self.subcenter.to_be_bytes());
+        data.push(self.master_table_version);
+        data.push(self.local_table_version);
+        data.push(u8::from(self.reference_date_significance));
+        data.extend_from_slice(&(self.reference_date.year() as u16).to_be_bytes());
+        data.push(self.reference_date.month() as u8);
+        data.push(self.reference_date.day() as u8);
+        data.push(self.reference_date.hour() as u8);
+        data.push(self.reference_date.minute() as u8);
+        data.push(self.reference_date.second() as u8);
+        data.push(u8::from(self.production_status));
+        data.push(u8::from(self.data_type));
+        Ok(data)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LatLngGrid {
+    pub start: (f64, f64),
+    pub end: (f64, f64),
+    pub shape: (usize, usize),
+}
+
+impl LatLngGrid {
+    pub fn latitude_count(&self) -> usize {
+        self.shape.0
+    }
+
+    pub fn longitude_count(&self) -> usize {
+        self.shape.1
+    }
+
+    pub fn latitude_resolution(&self) -> f64 {
+        resolution(self.start.0, self.end.0, self.latitude_count())
+    }
+
+    pub fn longitude_resolution(&self) -> f64 {
+        resolution(self.start.1, self.end.1, self.longitude_count())
+    }
+
+    pub fn grid_point_count(&self) -> usize {
+        self.latitude_count() * self.longitude_count()
+    }
+}
+
+pub struct GridDefinitionSectionBuilder {
+    pub grid: LatLngGrid,
+    pub earth_shape: EarthShape,
+}
+
+impl GridDefinitionSectionBuilder {
+    pub fn new(grid: LatLngGrid) -> GridDefinitionSectionBuilder {
+        GridDefinitionSectionBuilder {
+            grid,
+            earth_shape: EarthShape::Spherical2,
+        }
+    }
+}
+
+impl SectionBuilder for GridDefinitionSectionBuilder {
+    fn number(&self) -> u8 {
+        3
+    }
+
+    fn payload(&self) -> Result<Vec<u8>, GribError> {
+        let grid = &self.grid;
+        if grid.latitude_count() == 0 || grid.longitude_count() == 0 {
+            return Err(GribError::Encoding("Grid must have at least one latitude and longitude".into()));
+        }
+
+        let mut data = Vec::with_capacity(67);
+        data.push(0);
+        data.extend_from_slice(&(grid.grid_point_count() as u32).to_be_bytes());
+        data.extend_from_slice(&[0, 0]);
+        // Template 3.0, latitude longitude
+        data.extend_from_slice(&0u16.to_be_bytes());
+        data.push(u8::from(self.earth_shape));
+        data.extend_from_slice(&[0; 15]);
+        data.extend_from_slice(&(grid.longitude_count() as u32).to_be_bytes());
+        data.extend_from_slice(&(grid.latitude_count() as u32).to_be_bytes());
+        data.extend_from_slice(&0u32.to_be_bytes());
+        data.extend_from_slice(&0xFFFF_FFFFu32.to_be_bytes());
+        data.extend_from_slice(&signed_to_bytes(micro_degrees(grid.start.0)));
+        data.extend_from_slice(&(micro_degrees(normalize_longitude(grid.start.1)) as u32).to_be_bytes());
+        // Both direction increments are given
+        data.push(0x30);
+        data.extend_from_slice(&signed_to_bytes(micro_degrees(grid.end.0)));
+        data.extend_from_slice(&(micro_degrees(normalize_longitude(grid.end.1)) as u32).to_be_bytes());
+        data.extend_from_slice(&(micro_degrees(grid.longitude_resolution()) as u32).to_be_bytes());
+        data.extend_from_slice(&(micro_degrees(grid.latitude_resolution()) as u32).to_be_bytes());
+        // Points scan in the +j direction when latitudes increase
+        data.push(if grid.end.0 > grid.start.0 { 0x40 } else { 0 });
+        Ok(data)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EnsembleMember {
+    pub forecast_type: EnsembleForecastType,
+    pub perturbation_number: u8,
+    pub forecast_count: u8,
+}
+
+// A statistically processed field over a single continuous time range
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StatisticalInterval {
+    pub process: StatisticalProcess,
+    pub end_date: DateTime<Utc>,
+    pub time_unit: TimeUnit,
+    pub length: u32,
+}
+
+pub struct ProductDefinitionSectionBuilder {
+    pub category: u8,
+    pub parameter: u8,
+    pub generating_process: GeneratingProcess,
+    pub time_unit: TimeUnit,
+    pub forecast_time: u32,
+    pub first_fixed_surface: (FixedSurfaceTypes, f64),
+    pub second_fixed_surface: Option<(FixedSurfaceTypes, f64)>,
+    pub ensemble: Option<EnsembleMember>,
+    pub statistical_interval: Option<StatisticalInterval>,
+}
+
+impl ProductDefinitionSectionBuilder {
+    pub fn new(category: u8, parameter: u8) -> ProductDefinitionSectionBuilder {
+        ProductDefinitionSectionBuilder {
+            category,
+            parameter,
+            generating_process: GeneratingProcess::Analysis,
+            time_unit: TimeUnit::Hour,
+            forecast_time: 0,
+            first_fixed_surface: (FixedSurfaceTypes::GroundOrWater, 0.0),
+            second_fixed_surface: None,
+            ensemble: None,
+            statistical_interval: None,
+        }
+    }
+
+    pub fn forecast_offset(mut self, offset: Duration) -> Result<Self, GribError> {
+        if offset < Duration::zero() {
+            return Err(GribError::Encoding("The forecast date cannot be before the reference date".into()));
+        }
+
+        let (time_unit, forecast_time) = time_range(offset);
+        self.time_unit = time_unit;
+        self.forecast_time = forecast_time;
+        self.generating_process = if offset > Duration::zero() { GeneratingProcess::Forecast } else { GeneratingProcess::Analysis };
+        Ok(self)
+    }
+
+    // The forecast offset marks the start of the interval, which runs for the given length
+    pub fn statistical_process(mut self, process: StatisticalProcess, end_date: DateTime<Utc>, length: Duration) -> Result<Self, GribError> {
+        if length < Duration::zero() {
+            return Err(GribError::Encoding("The statistical processing interval cannot be negative".into()));
+        }
+
+        let (time_unit, length) = time_range(length);
+        self.statistical_interval = Some(StatisticalInterval {
+            process,
+            end_date,
+            time_unit,
+            length,
+        });
+        Ok(self)
+    }
+
+    pub fn template_number(&self) -> u16 {
+        match (self.ensemble.is_some(), self.statistical_interval.is_some()) {
+            (false, false) => 0,
+            (true, false) => 1,
+            (false, true) => 8,
+            (true, true) => 11,
+        }
+    }
+}
+
+impl SectionBuilder for ProductDefinitionSectionBuilder {
+    fn number(&self) -> u8 {
+        4
+    }
+
+    fn payload(&self) -> Result<Vec<u8>, GribError> {
+        let (first_type, first_value) = self.first_fixed_surface;
+        let (first_scale_factor, first_scaled_value) = scaled_surface_value(first_value)?;
+
+        let mut data = Vec::with_capacity(29);
+        data.extend_from_slice(&0u16.to_be_bytes());
+        data.extend_from_slice(&self.template_number().to_be_bytes());
+        data.push(self.category);
+        data.push(self.parameter);
+        data.push(u8::from(self.generating_process));
+        data.push(0);
+        data.push(255);
+        data.extend_from_slice(&0u16.to_be_bytes());
+        data.push(0);
+        data.push(u8::from(self.time_unit));
+        data.extend_from_slice(&self.forecast_time.to_be_bytes());
+        data.push(u8::from(first_type));
+        data.push(first_scale_factor);
+        data.extend_from_slice(&first_scaled_value.to_be_bytes());
+
+        match self.second_fixed_surface {
+            Some((second_type, second_value)) => {
+                let (second_scale_factor, second_scaled_value) = scaled_surface_value(second_value)?;
+                data.push(u8::from(second_type));
+                data.push(second_scale_factor);
+                data.extend_from_slice(&second_scaled_value.to_be_bytes());
+            }
+            None => {
+                data.extend_from_slice(&[255, 255]);
+                data.extend_from_slice(&0xFFFF_FFFFu32.to_be_bytes());
+            }
+        }
+
+        // Templates 4.1 and 4.11 describe the ensemble member
+        if let Some(ensemble) = self.ensemble {
+            data.push(u8::from(ensemble.forecast_type));
+            data.push(ensemble.perturbation_number);
+            data.push(ensemble.forecast_count);
+        }
+
+        // Templates 4.8 and 4.11 describe the end of the interval and a single time range
+        if let Some(interval) = self.statistical_interval {
+            data.extend_from_slice(&(interval.end_date.year() as u16).to_be_bytes());
+            data.push(interval.end_date.month() as u8);
+            data.push(interval.end_date.day() as u8);
+            data.push(interval.end_date.hour() as u8);
+            data.push(interval.end_date.minute() as u8);
+            data.push(interval.end_date.second() as u8);
+            data.push(1);
+            data.extend_from_slice(&0u32.to_be_bytes());
+            data.push(u8::from(interval.process));
+            // Successive fields share the start of the forecast and the forecast time is incremented
+            data.push(2);
+            data.push(u8::from(interval.time_unit));
+            data.extend_from_slice(&interval.length.to_be_bytes());
+            // The process is continuous, so there is no increment between fields
+            data.push(u8::from(interval.time_unit));
+            data.extend_from_slice(&0u32.to_be_bytes());
+        }
+
+        Ok(data)
+    }
+}
+
+pub struct DataRepresentationSectionBuilder {
+    pub data_point_count: u32,
+    pub template_number: u16,
+    pub template: Vec<u8>,
+}
+
+impl DataRepresentationSectionBuilder {
+    pub fn from_packed(data_point_count: u32, packed: &PackedData) -> DataRepresentationSectionBuilder {
+        DataRepresentationSectionBuilder {
+            data_point_count,
+            template_number: packed.template_number,
+            template: packed.template.clone(),
+        }
+    }
+}
+
+impl SectionBuilder for DataRepresentationSectionBuilder {
+    fn number(&self) -> u8 {
+        5
+    }
+
+    fn payload(&self) -> Result<Vec<u8>, GribError> {
+        let mut data = Vec::with_capacity(self.template.len() + 6);
+        data.extend_from_slice(&self.data_point_count.to_be_bytes());
+        data.extend_from_slice(&self.template_number.to_be_bytes());
+        data.extend_from_slice(&self.template);
+        Ok(data)
+    }
+}
+
+pub struct BitmapSectionBuilder {
+    pub bitmap: Option<Vec<bool>>,
+}
+
+impl SectionBuilder for BitmapSectionBuilder {
+    fn number(&self) -> u8 {
+        6
+    }
+
+    fn payload(&self) -> Result<Vec<u8>, GribError> {
+        match &self.bitmap {
+            Some(bitmap) => {
+                let bits = bitmap.iter().map(|b| *b as u32).collect::<Vec<u32>>();
+                let mut data = vec![0];
+                data.extend(pack_bits(&bits, 1));
+                Ok(data)
+            }
+            // No bitmap applies to this product
+            None => Ok(vec![255]),
+        }
+    }
+}
+
+pub struct DataSectionBuilder {
+    pub data: Vec<u8>,
+}
+
+impl SectionBuilder for DataSectionBuilder {
+    fn number(&self) -> u8 {
+        7
+    }
+
+    fn payload(&self) -> Result<Vec<u8>, GribError> {
+        Ok(self.data.clone())
+    }
+}
+
+pub struct EndSectionBuilder;
+
+impl EndSectionBuilder {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        b"7777".to_vec()
+    }
+}
+
+// Frames the given sections with the indicator and end sections into a complete message
+pub fn assemble_message(discipline: Discipline, sections: &[&dyn SectionBuilder]) -> Result<Vec<u8>, GribError> {
+    let mut body = Vec::new();
+    for section in sections {
+        body.extend(section.to_bytes()?);
+    }
+
+    let end = EndSectionBuilder.to_bytes();
+    let total_length = 16 + body.len() + end.len();
+
+    let mut message = IndicatorSectionBuilder::new(discipline, total_length as u64).to_bytes();
+    message.extend(body);
+    message.extend(end);
+    Ok(message)
+}
+
+fn resolution(start: f64, end: f64, count: usize) -> f64 {
+    if count < 2 {
+        0.0
+    } else {
+        (end - start).abs() / (count - 1) as f64
+    }
+}
+
+// Picks the coarsest time unit that represents the duration exactly
+fn time_range(duration: Duration) -> (TimeUnit, u32) {
+    let seconds = duration.num_seconds();
+    if seconds % 3600 == 0 {
+        (TimeUnit::Hour, (seconds / 3600) as u32)
+    } else if seconds % 60 == 0 {
+        (TimeUnit::Minute, (seconds / 60) as u32)
+    } else {
+        (TimeUnit::Seconds, seconds as u32)
+    }
+}
+
+fn micro_degrees(value: f64) -> i32 {
+    (value * 1_000_000.0).round() as i32
+}
+
+fn normalize_longitude(longitude: f64) -> f64 {
+    longitude.rem_euclid(360.0)
+}
+
+// Find the smallest decimal scale factor that represents the surface value exactly
+fn scaled_surface_value(value: f64) -> Result<(u8, u32), GribError> {
+    if !value.is_finite() || value < 0.0 {
+        return Err(GribError::Encoding(format!("Fixed surface value {} cannot be encoded", value)));
+    }
+
+    for scale_factor in 0..10 {
+        let scaled = value * 10f64.powi(scale_factor);
+        if scaled > u32::MAX as f64 {
+            break;
+        }
+
+        if (scaled - scaled.round()).abs() < 1e-6 {
+            return Ok((scale_factor as u8, scaled.round() as u32));
+        }
+    }
+
+    Err(GribError::Encoding(format!("Fixed surface value {} cannot be encoded", value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sections::identification::IdentificationSection;
+    use crate::sections::product_definition::ProductDefinitionSection;
+    use crate::templates::product::ProductTemplate;
+    use chrono::TimeZone;
+
+    #[test]
+    fn identification_round_trip() {
+        let reference_date = Utc.with_ymd_and_hms(2021, 3, 4, 12, 30, 15).unwrap();
+        let bytes = IdentificationSectionBuilder::new(Center::ECMWF, 4, reference_date)
+            .to_bytes()
+            .unwrap();
+        assert_eq!(bytes.len(), 21);
+
+        let section = IdentificationSection::from_data(&bytes);
+        assert_eq!(section.center(), Center::ECMWF);
+        assert_eq!(section.subcenter_id(), 4);
+        assert_eq!(section.reference_date(), reference_date);
+        assert_eq!(section.data_type(), GribDataType::Forecast);
+    }
+
+    #[test]
+    fn product_definition_round_trip() {
+        let mut builder = ProductDefinitionSectionBuilder::new(2, 2)
+            .forecast_offset(Duration::minutes(90))
+            .unwrap();
+        builder.first_fixed_surface = (FixedSurfaceTypes::IsobaricSurface, 85000.0);
+        let bytes = builder.to_bytes().unwrap();
+        assert_eq!(bytes.len(), 34);
+
+        let section = ProductDefinitionSection::from_data(&bytes);
+        let template = match section.product_definition_template(0, Center::NCEP.id(), 0) {
+            ProductTemplate::HorizontalAnalysisForecast(template) => template,
+            _ => panic!("expected a horizontal analysis forecast template"),
+        };
+        assert_eq!(template.time_unit(), TimeUnit::Minute);
+        assert_eq!(template.forecast_time(), 90);
+        assert_eq!(template.pressure_hpa(), Some(850.0));
+        assert_eq!(template.second_fixed_surface_value(), None);
+    }
+
+    #[test]
+    fn statistical_ensemble_product_definition() {
+        let end_date = Utc.with_ymd_and_hms(2021, 3, 5, 0, 0, 0).unwrap();
+        let mut builder = ProductDefinitionSectionBuilder::new(1, 8)
+            .forecast_offset(Duration::hours(6))
+            .unwrap()
+            .statistical_process(StatisticalProcess::Accumulation, end_date, Duration::hours(6))
+            .unwrap();
+        assert_eq!(builder.template_number(), 8);
+        let bytes = builder.to_bytes().unwrap();
+        assert_eq!(bytes.len(), 58);
+        assert_eq!(&bytes[34..41], &[0x07, 0xE5, 3, 5, 0, 0, 0]);
+        assert_eq!(bytes[46], u8::from(StatisticalProcess::Accumulation));
+        assert_eq!(&bytes[49..53], &6u32.to_be_bytes());
+
+        builder.ensemble = Some(EnsembleMember {
+            forecast_type: EnsembleForecastType::PositivelyPerturbed,
+            perturbation_number: 3,
+            forecast_count: 21,
+        });
+        assert_eq!(builder.template_number(), 11);
+        let bytes = builder.to_bytes().unwrap();
+        assert_eq!(bytes.len(), 61);
+        assert_eq!(&bytes[34..37], &[3, 3, 21]);
+
+        let section = ProductDefinitionSection::from_data(&bytes);
+        let template = match section.product_definition_template(0, Center::NCEP.id(), 0) {
+            ProductTemplate::HorizontalAnalysisForecast(template) => template,
+            _ => panic!("expected a horizontal analysis forecast template"),
+        };
+        assert_eq!(template.forecast_time(), 6);
+        assert_eq!(template.parameter().unwrap().abbrev, "APCP");
+    }
+}
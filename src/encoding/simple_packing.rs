@@ -0,0 +1,278 @@
+use crate::error::GribError;
+use crate::utils::{grib_power, pack_bits};
+
+pub const DEFAULT_BIT_COUNT: u8 = 16;
+const MAX_BIT_COUNT: u8 = 32;
+const MAX_DETECTED_DECIMAL_PLACES: i16 = 6;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PackingPrecision {
+    // Keep the decimal precision of the data if it has one, otherwise use the default bit count
+    Auto,
+    BitCount(u8),
+    DecimalPlaces(i16),
+    // Reuse known decimal and binary scale factors, such as those of an existing message
+    ScaleFactors(i16, i16),
+}
+
+// Data reduced to non negative integers, such that value = (R + X * 2^E) * 10^-D
+pub struct ScaledData {
+    pub reference_value: f32,
+    pub binary_scale_factor: i16,
+    pub decimal_scale_factor: i16,
+    pub bit_count: u8,
+    pub values: Vec<u32>,
+}
+
+pub struct SimplePackedData {
+    pub reference_value: f32,
+    pub binary_scale_factor: i16,
+    pub decimal_scale_factor: i16,
+    pub bit_count: u8,
+    pub packed: Vec<u8>,
+}
+
+pub fn pack(data: &[f64], precision: PackingPrecision) -> Result<SimplePackedData, GribError> {
+    let scaled = scale(data, precision)?;
+
+    Ok(SimplePackedData {
+        reference_value: scaled.reference_value,
+        binary_scale_factor: scaled.binary_scale_factor,
+        decimal_scale_factor: scaled.decimal_scale_factor,
+        bit_count: scaled.bit_count,
+        packed: pack_bits(&scaled.values, scaled.bit_count as usize),
+    })
+}
+
+pub fn scale(data: &[f64], precision: PackingPrecision) -> Result<ScaledData, GribError> {
+    if data.iter().any(|v| !v.is_finite()) {
+        return Err(GribError::Encoding("Simple packing requires finite data values".into()));
+    }
+
+    match precision {
+        PackingPrecision::BitCount(bit_count) => scale_bit_count(data, 0, bit_count),
+        PackingPrecision::DecimalPlaces(decimal_scale_factor) => scale_decimal_places(data, decimal_scale_factor),
+        PackingPrecision::ScaleFactors(decimal_scale_factor, binary_scale_factor) => {
+            scale_factors(data, decimal_scale_factor, binary_scale_factor)
+        }
+        PackingPrecision::Auto => match detect_decimal_places(data) {
+            Some(decimal_scale_factor) => scale_decimal_places(data, decimal_scale_factor),
+            None => scale_bit_count(data, 0, DEFAULT_BIT_COUNT),
+        },
+    }
+}
+
+// Values are stored as whole numbers after decimal scaling, using only as many bits as the range needs
+fn scale_decimal_places(data: &[f64], decimal_scale_factor: i16) -> Result<ScaledData, GribError> {
+    let (min, max) = scaled_range(data, decimal_scale_factor);
+    let range = (max - reference_below(min) as f64).round();
+    let bit_count = (range + 1.0).log2().ceil();
+
+    if bit_count > MAX_BIT_COUNT as f64 {
+        return scale_bit_count(data, decimal_scale_factor, MAX_BIT_COUNT);
+    }
+
+    scale_values(data, decimal_scale_factor, 0, bit_count as u8)
+}
+
+fn scale_factors(data: &[f64], decimal_scale_factor: i16, binary_scale_factor: i16) -> Result<ScaledData, GribError> {
+    let (min, max) = scaled_range(data, decimal_scale_factor);
+    let range = ((max - reference_below(min) as f64) * grib_power(-(binary_scale_factor as i32), 2)).round();
+    let bit_count = (range + 1.0).log2().ceil();
+
+    if bit_count > MAX_BIT_COUNT as f64 {
+        return scale_bit_count(data, decimal_scale_factor, MAX_BIT_COUNT);
+    }
+
+    scale_values(data, decimal_scale_factor, binary_scale_factor, bit_count as u8)
+}
+
+fn scale_bit_count(data: &[f64], decimal_scale_factor: i16, bit_count: u8) -> Result<ScaledData, GribError> {
+    if bit_count > MAX_BIT_COUNT {
+        return Err(GribError::Encoding(format!("Invalid bits per value size of {}", bit_count)));
+    }
+
+    let (min, max) = scaled_range(data, decimal_scale_factor);
+    let binary_scale_factor = if bit_count == 0 || max == min {
+        0
+    } else {
+        let max_packed = ((1u64 << bit_count) - 1) as f64;
+        ((max - reference_below(min) as f64) / max_packed).log2().ceil() as i16
+    };
+
+    scale_values(data, decimal_scale_factor, binary_scale_factor, bit_count)
+}
+
+fn scale_values(data: &[f64], decimal_scale_factor: i16, binary_scale_factor: i16, bit_count: u8) -> Result<ScaledData, GribError> {
+    let (min, max) = scaled_range(data, decimal_scale_factor);
+    if data.is_empty() || bit_count == 0 || max == min {
+        // A constant field is stored entirely in the reference value
+        return Ok(ScaledData {
+            reference_value: if data.is_empty() { 0.0 } else { min as f32 },
+            binary_scale_factor: 0,
+            decimal_scale_factor,
+            bit_count: 0,
+            values: vec![0; data.len()],
+        });
+    }
+
+    let reference_value = reference_below(min);
+    let reference = reference_value as f64;
+    let dscale = grib_power(decimal_scale_factor as i32, 10);
+    let bscale = grib_power(-(binary_scale_factor as i32), 2);
+    let max_packed = ((1u64 << bit_count) - 1) as f64;
+    let values = data
+        .iter()
+        .map(|v| {
+            let value = ((v * dscale - reference) * bscale).round();
+            if value > max_packed {
+                return Err(GribError::Encoding(format!("Value {} does not fit in {} bits", v, bit_count)));
+            }
+            Ok(value as u32)
+        })
+        .collect::<Result<Vec<u32>, GribError>>()?;
+
+    Ok(ScaledData {
+        reference_value,
+        binary_scale_factor,
+        decimal_scale_factor,
+        bit_count,
+        values,
+    })
+}
+
+// The reference value is stored as an f32, which is rounded towards negative infinity so no
+// value scales to below zero
+fn reference_below(min: f64) -> f32 {
+    let reference = min as f32;
+    if reference as f64 > min {
+        reference.next_down()
+    } else {
+        reference
+    }
+}
+
+fn scaled_range(data: &[f64], decimal_scale_factor: i16) -> (f64, f64) {
+    let dscale = grib_power(decimal_scale_factor as i32, 10);
+    let min = data.iter().cloned().fold(f64::INFINITY, f64::min) * dscale;
+    let max = data.iter().cloned().fold(f64::NEG_INFINITY, f64::max) * dscale;
+    (min, max)
+}
+
+// The fewest decimal places that represent every value exactly, if there are few enough
+fn detect_decimal_places(data: &[f64]) -> Option<i16> {
+    (0..=MAX_DETECTED_DECIMAL_PLACES).find(|decimal_scale_factor| {
+        let dscale = grib_power(*decimal_scale_factor as i32, 10);
+        data.iter().all(|v| {
+            let scaled = v * dscale;
+            (scaled - scaled.round()).abs() <= 1e-9 * scaled.abs().max(1.0)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unpack(packed: &SimplePackedData) -> Vec<f64> {
+        let bscale = grib_power(packed.binary_scale_factor as i32, 2);
+        let dscale = grib_power(-(packed.decimal_scale_factor as i32), 10);
+        let bit_count = packed.bit_count as usize;
+        let bits = packed
+            .packed
+            .iter()
+            .flat_map(|b| (0..8).rev().map(move |i| (b >> i) & 1))
+            .collect::<Vec<u8>>();
+
+        bits.chunks_exact(bit_count)
+            .map(|chunk| chunk.iter().fold(0u32, |acc, b| (acc << 1) | *b as u32) as f64)
+            .map(|raw| (packed.reference_value as f64 + raw * bscale) * dscale)
+            .collect()
+    }
+
+    #[test]
+    fn pack_within_precision() {
+        let data = vec![270.5, 271.25, 280.0, 299.75, 285.125];
+        let packed = pack(&data, PackingPrecision::BitCount(16)).unwrap();
+        assert_eq!(packed.bit_count, 16);
+        assert_eq!(packed.packed.len(), 10);
+
+        let tolerance = grib_power(packed.binary_scale_factor as i32, 2) * 0.5;
+        for (unpacked, value) in unpack(&packed).iter().zip(data.iter()) {
+            assert!((unpacked - value).abs() <= tolerance);
+        }
+    }
+
+    #[test]
+    fn pack_decimal_places() {
+        let data = vec![1013.25, 1009.5, 1021.75, 998.0];
+        let packed = pack(&data, PackingPrecision::DecimalPlaces(1)).unwrap();
+        assert_eq!(packed.decimal_scale_factor, 1);
+        assert_eq!(packed.binary_scale_factor, 0);
+        assert_eq!(packed.bit_count, 8);
+
+        for (unpacked, value) in unpack(&packed).iter().zip(data.iter()) {
+            assert!((unpacked - value).abs() <= 0.05 + 1e-9);
+        }
+    }
+
+    #[test]
+    fn pack_auto_precision() {
+        let data = vec![0.25, 1.5, 3.75, 2.0];
+        let packed = pack(&data, PackingPrecision::Auto).unwrap();
+        assert_eq!(packed.decimal_scale_factor, 2);
+        assert_eq!(packed.bit_count, 9);
+        for (unpacked, value) in unpack(&packed).iter().zip(data.iter()) {
+            assert!((unpacked - value).abs() < 1e-9);
+        }
+
+        let data = vec![0.1234567891, 2.0, 3.0];
+        let packed = pack(&data, PackingPrecision::Auto).unwrap();
+        assert_eq!(packed.decimal_scale_factor, 0);
+        assert_eq!(packed.bit_count, DEFAULT_BIT_COUNT);
+    }
+
+    #[test]
+    fn pack_scale_factors() {
+        let data = vec![0.5, 2.0, 7.25, 3.75];
+        let packed = pack(&data, PackingPrecision::ScaleFactors(1, -2)).unwrap();
+        assert_eq!(packed.decimal_scale_factor, 1);
+        assert_eq!(packed.binary_scale_factor, -2);
+        assert_eq!(packed.bit_count, 9);
+
+        for (unpacked, value) in unpack(&packed).iter().zip(data.iter()) {
+            assert!((unpacked - value).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn pack_reference_below_minimum() {
+        // 270.1 rounds up when stored as an f32
+        let data = vec![270.1, 270.35, 271.6];
+        let packed = pack(&data, PackingPrecision::BitCount(24)).unwrap();
+        assert!(packed.reference_value as f64 <= 270.1);
+
+        let tolerance = grib_power(packed.binary_scale_factor as i32, 2) * 0.5;
+        for (unpacked, value) in unpack(&packed).iter().zip(data.iter()) {
+            assert!((unpacked - value).abs() <= tolerance);
+        }
+    }
+
+    #[test]
+    fn pack_large_integers() {
+        // The f32 reference is several units below the minimum, so the range needs another bit
+        let data = vec![100000001.0, 100000004.0, 100000002.0, 100000003.0];
+        for precision in [PackingPrecision::Auto, PackingPrecision::ScaleFactors(0, 0)] {
+            let packed = pack(&data, precision).unwrap();
+            assert_eq!(unpack(&packed)[..data.len()], data[..]);
+        }
+    }
+
+    #[test]
+    fn pack_constant_field() {
+        let packed = pack(&[5.0; 4], PackingPrecision::Auto).unwrap();
+        assert_eq!(packed.bit_count, 0);
+        assert_eq!(packed.reference_value, 5.0);
+        assert!(packed.packed.is_empty());
+    }
+}
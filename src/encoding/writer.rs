@@ -0,0 +1,107 @@
+use crate::error::GribError;
+use crate::message::Message;
+use std::collections::hash_map::{Entry, HashMap};
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+pub struct GribWriter<W: Write> {
+    writer: W,
+    message_count: usize,
+}
+
+impl GribWriter<BufWriter<File>> {
+    pub fn create<P: AsRef<Path>>(path: P) -> Result<Self, GribError> {
+        let file = File::create(path.as_ref())
+            .map_err(|e| GribError::Io(format!("Failed to create {}: {}", path.as_ref().display(), e)))?;
+        Ok(GribWriter::new(BufWriter::new(file)))
+    }
+
+    // Opens an existing file so new messages are concatenated after the ones it holds
+    pub fn append<P: AsRef<Path>>(path: P) -> Result<Self, GribError> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path.as_ref())
+            .map_err(|e| GribError::Io(format!("Failed to open {}: {}", path.as_ref().display(), e)))?;
+        Ok(GribWriter::new(BufWriter::new(file)))
+    }
+}
+
+impl<W: Write> GribWriter<W> {
+    pub fn new(writer: W) -> GribWriter<W> {
+        GribWriter {
+            writer,
+            message_count: 0,
+        }
+    }
+
+    pub fn message_count(&self) -> usize {
+        self.message_count
+    }
+
+    // Parsed messages are written back untouched, so the output matches the source bytes
+    pub fn write_message(&mut self, message: &Message) -> Result<(), GribError> {
+        self.writer
+            .write_all(message.raw_bytes())
+            .map_err(|e| GribError::Io(format!("Failed to write message: {}", e)))?;
+
+        self.message_count += 1;
+        Ok(())
+    }
+
+    // Writes an encoded message, such as one from MessageBuilder::build
+    pub fn write_raw(&mut self, message: &[u8]) -> Result<(), GribError> {
+        if message.len() < 20 || &message[0..4] != b"GRIB" || &message[message.len() - 4..] != b"7777" {
+            return Err(GribError::Encoding("Data is not a complete GRIB message".into()));
+        }
+
+        self.writer
+            .write_all(message)
+            .map_err(|e| GribError::Io(format!("Failed to write message: {}", e)))?;
+
+        self.message_count += 1;
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Result<W, GribError> {
+        self.writer
+            .flush()
+            .map_err(|e| GribError::Io(format!("Failed to flush messages: {}", e)))?;
+        Ok(self.writer)
+    }
+}
+
+// Writes each message to the file chosen for it, skipping those with no path.
+// Messages given the same path are written to that file together, in order.
+pub fn split<F>(messages: &[Message], path_for_message: F) -> Result<Vec<PathBuf>, GribError>
+where
+    F: Fn(usize, &Message) -> Option<PathBuf>,
+{
+    let mut paths: Vec<PathBuf> = Vec::new();
+    let mut writers: HashMap<PathBuf, GribWriter<BufWriter<File>>> = HashMap::new();
+
+    for (index, message) in messages.iter().enumerate() {
+        let path = match path_for_message(index, message) {
+            Some(path) => path,
+            None => continue,
+        };
+
+        let writer = match writers.entry(path) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let writer = GribWriter::create(entry.key())?;
+                paths.push(entry.key().clone());
+                entry.insert(writer)
+            }
+        };
+
+        writer.write_message(message)?;
+    }
+
+    for (_, writer) in writers {
+        writer.finish()?;
+    }
+
+    Ok(paths)
+}
@@ -0,0 +1,83 @@
+use crate::error::GribError;
+use crate::message::Message;
+use crate::winds::grid_section;
+
+// The mean and spread of the members at each grid point, leaving out members without data at
+// the point. Points where no member has data are NaN.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnsembleStatistics {
+    pub member_count: usize,
+    pub mean: Vec<f64>,
+    // The standard deviation of the members about the mean
+    pub spread: Vec<f64>,
+}
+
+pub fn ensemble_statistics(members: &[Message]) -> Result<EnsembleStatistics, GribError> {
+    let values = member_values(members)?;
+
+    let (mean, spread) = (0..values[0].len())
+        .map(|index| {
+            let point = values.iter().map(|member| member[index]).filter(|value| !value.is_nan());
+            let (count, sum) = point.clone().fold((0, 0.0), |(count, sum), value| (count + 1, sum + value));
+            if count == 0 {
+                return (f64::NAN, f64::NAN);
+            }
+
+            let mean = sum / count as f64;
+            let variance = point.map(|value| (value - mean).powi(2)).sum::<f64>() / count as f64;
+            (mean, variance.sqrt())
+        })
+        .unzip();
+
+    Ok(EnsembleStatistics {
+        member_count: members.len(),
+        mean,
+        spread,
+    })
+}
+
+// The fraction of members with data at each grid point whose value is above the threshold
+pub fn exceedance_probability(members: &[Message], threshold: f64) -> Result<Vec<f64>, GribError> {
+    let values = member_values(members)?;
+
+    Ok((0..values[0].len())
+        .map(|index| {
+            let (count, exceeding) = values
+                .iter()
+                .map(|member| member[index])
+                .filter(|value| !value.is_nan())
+                .fold((0, 0), |(count, exceeding), value| (count + 1, exceeding + (value > threshold) as usize));
+            if count == 0 {
+                f64::NAN
+            } else {
+                exceeding as f64 / count as f64
+            }
+        })
+        .collect())
+}
+
+// The decoded values of each member, once the members are known to describe the same field
+fn member_values(members: &[Message]) -> Result<Vec<Vec<f64>>, GribError> {
+    let first = unwrap_or_return!(members.first(), GribError::InvalidData("No ensemble members were given".into()));
+    let (grid, parameter, forecast_date) = (grid_section(first)?, first.parameter()?, first.forecast_date()?);
+    let level = surface(first)?;
+
+    for member in &members[1..] {
+        if grid_section(member)? != grid {
+            return Err(GribError::InvalidData("The ensemble members are not on the same grid".into()));
+        }
+        if member.parameter()? != parameter || surface(member)? != level {
+            return Err(GribError::InvalidData("The ensemble members do not hold the same parameter and level".into()));
+        }
+        if member.forecast_date()? != forecast_date {
+            return Err(GribError::InvalidData("The ensemble members are not valid at the same time".into()));
+        }
+    }
+
+    members.iter().map(|member| member.data()).collect()
+}
+
+fn surface(message: &Message) -> Result<(u8, Option<f64>), GribError> {
+    let template = message.product_template()?;
+    Ok((u8::from(template.first_fixed_surface_type()), template.first_fixed_surface_value()))
+}
@@ -0,0 +1,96 @@
+use crate::templates::template::TemplateType;
+use thiserror::Error;
+
+#[derive(Error, Clone, Debug, PartialEq)]
+pub enum GribError {
+    // The data ends part way through whatever starts at the offset
+    #[error("Unexpected end of data after offset {offset:#X}")]
+    UnexpectedEof { offset: u64 },
+    #[error("{}", edition_message(*.0))]
+    UnsupportedEdition(u8),
+    #[error("{kind} template {number} is not supported")]
+    UnsupportedTemplate { kind: TemplateType, number: u16 },
+    #[error("Invalid section {number} {reason} at offset {offset:#X}")]
+    InvalidSection { number: u8, offset: u64, reason: String },
+    #[error("{} section not found", section_name(*.0))]
+    MissingSection(u8),
+    #[error("Invalid message at offset {offset:#X}: {reason}")]
+    InvalidMessage { offset: u64, reason: String },
+    // The grid, bitmap and data representation sections disagree on the number of points
+    #[error("{} section gives {found} points but {expected} are expected", section_name(*.section))]
+    PointCountMismatch { section: u8, expected: usize, found: usize },
+    #[error("Parameter {number} of category {category} in discipline {discipline} is not supported")]
+    UnknownParameter { discipline: u8, category: u8, number: u8 },
+    #[error("{0}")]
+    InvalidInventory(String),
+    #[error("No data available at index {0}")]
+    MissingData(usize),
+    #[error("{0}")]
+    OutOfRange(String),
+    #[error("Unable to convert from {from} to {to}")]
+    UnitConversion { from: String, to: String },
+    #[error("The buffer holds {length} values but {required} are needed")]
+    BufferTooSmall { length: usize, required: usize },
+    // Packed data that can not be decoded
+    #[error("{0}")]
+    InvalidData(String),
+    // Values or options that can not be written to a message
+    #[error("{0}")]
+    Encoding(String),
+    #[error("{0}")]
+    Io(String),
+}
+
+fn edition_message(edition: u8) -> String {
+    match edition {
+        1 => "GRIB edition 1 is not supported, GRIB1 messages can be decoded with the grib1 feature".into(),
+        edition => format!("GRIB edition {} is not supported", edition),
+    }
+}
+
+fn section_name(number: u8) -> &'static str {
+    match number {
+        0 => "Indicator",
+        1 => "Identification",
+        2 => "Local use",
+        3 => "Grid definition",
+        4 => "Product definition",
+        5 => "Data representation",
+        6 => "Bitmap",
+        7 => "Data",
+        8 => "End",
+        _ => "Unknown",
+    }
+}
+
+impl From<std::io::Error> for GribError {
+    fn from(error: std::io::Error) -> GribError {
+        GribError::Io(error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GribError;
+    use crate::templates::template::TemplateType;
+
+    #[test]
+    fn describe_errors() {
+        assert!(GribError::UnsupportedEdition(1).to_string().contains("grib1 feature"));
+        assert_eq!(GribError::UnsupportedEdition(3).to_string(), "GRIB edition 3 is not supported");
+        assert_eq!(GribError::MissingSection(7).to_string(), "Data section not found");
+        assert_eq!(
+            GribError::UnsupportedTemplate { kind: TemplateType::Grid, number: 30 }.to_string(),
+            "Grid definition template 30 is not supported"
+        );
+        assert_eq!(
+            GribError::InvalidSection { number: 5, offset: 0x3A2F10, reason: "length".into() }.to_string(),
+            "Invalid section 5 length at offset 0x3A2F10"
+        );
+        assert_eq!(
+            GribError::PointCountMismatch { section: 5, expected: 49, found: 48 }.to_string(),
+            "Data representation section gives 48 points but 49 are expected"
+        );
+        assert_eq!(GribError::UnexpectedEof { offset: 255 }.to_string(), "Unexpected end of data after offset 0xFF");
+    }
+}
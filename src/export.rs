@@ -0,0 +1,206 @@
+use crate::contour::{coordinates, feature_collection};
+use crate::error::GribError;
+use crate::message::Message;
+use crate::templates::grid::{longitude_difference, scanned_index};
+use crate::templates::product::tables::FixedSurfaceTypes;
+use crate::winds::grid_section;
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeMap, BTreeSet};
+
+// What the features of a GeoJSON export are
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GeoJsonGeometry {
+    // A point feature for every grid point with its value, null where there is no data
+    Points,
+    // A single polygon feature running around the edge of the grid
+    Boundary,
+}
+
+// The message as a GeoJSON feature collection for quick looks in web map tools
+pub fn to_geojson(message: &Message, geometry: GeoJsonGeometry) -> Result<String, GribError> {
+    match geometry {
+        GeoJsonGeometry::Points => {
+            let features = message.data_with_locations()?.map(|(latitude, longitude, value)| {
+                format!(
+                    r#"{{"type":"Feature","properties":{{"value":{}}},"geometry":{{"type":"Point","coordinates":[{},{}]}}}}"#,
+                    json_number(value),
+                    longitude,
+                    latitude
+                )
+            });
+            Ok(feature_collection(features))
+        }
+        GeoJsonGeometry::Boundary => {
+            let parameter = match message.variable_abbrev() {
+                Ok(abbrev) => format!(r#""{}""#, abbrev),
+                Err(_) => "null".into(),
+            };
+            let feature = format!(
+                r#"{{"type":"Feature","properties":{{"parameter":{},"valid_time":"{}"}},"geometry":{{"type":"Polygon","coordinates":[{}]}}}}"#,
+                parameter,
+                message.forecast_date()?.to_rfc3339(),
+                coordinates(&boundary(message)?, true)
+            );
+            Ok(feature_collection(std::iter::once(feature)))
+        }
+    }
+}
+
+// A CoverageJSON grid coverage of one parameter over the times and levels of the messages,
+// which must share a grid and type of surface. A single message is a cube of one time and at
+// most one level. The x and y axes are the longitudes and latitudes, so only regular latitude
+// longitude grids can be written. Times and levels without a message are null.
+pub fn to_coverage_json(messages: &[Message]) -> Result<String, GribError> {
+    let first = unwrap_or_return!(messages.first(), GribError::InvalidData("No messages were given to write".into()));
+    let grid_template = first.grid_template()?;
+    if !grid_template.is_regular() {
+        return Err(GribError::InvalidData("CoverageJSON grids need latitude and longitude axes".into()));
+    }
+    let (grid, parameter) = (grid_section(first)?, first.parameter()?);
+    let surface = first.product_template()?.first_fixed_surface_type();
+
+    let mut fields = Vec::with_capacity(messages.len());
+    for message in messages {
+        let template = message.product_template()?;
+        if grid_section(message)? != grid {
+            return Err(GribError::InvalidData("The messages of a coverage must share a grid".into()));
+        }
+        if message.parameter()? != parameter || template.first_fixed_surface_type() != surface {
+            return Err(GribError::InvalidData("The messages of a coverage must hold one parameter on one type of surface".into()));
+        }
+        fields.push((message, message.forecast_date()?, template.first_fixed_surface_value()));
+    }
+
+    let times = fields.iter().map(|(_, time, _)| *time).collect::<BTreeSet<DateTime<Utc>>>();
+    let mut levels = fields.iter().filter_map(|(_, _, level)| *level).collect::<Vec<_>>();
+    levels.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    levels.dedup();
+    if !levels.is_empty() && fields.iter().any(|(_, _, level)| level.is_none()) {
+        return Err(GribError::InvalidData(format!("Some messages on a {} have no level value", surface)));
+    }
+
+    // Each message fills the (y, x) plane at its time and level
+    let (latitudes, longitudes) = (first.latitudes()?, first.longitudes()?);
+    let shape = (latitudes.len(), longitudes.len());
+    let point_count = shape.0 * shape.1;
+    let scanning_mode = grid_template.scanning_mode();
+    let mut planes = BTreeMap::new();
+    for (message, time, level) in &fields {
+        let time_index = times.iter().position(|value| value == time).unwrap_or(0);
+        let level_index = level.and_then(|level| levels.iter().position(|value| *value == level)).unwrap_or(0);
+        if planes.insert((time_index, level_index), *message).is_some() {
+            return Err(GribError::InvalidData(format!(
+                "More than one message holds {} at {} on the same level",
+                parameter.abbrev, time
+            )));
+        }
+    }
+
+    let level_count = levels.len().max(1);
+    let mut values = vec!["null".to_string(); times.len() * level_count * point_count];
+    for ((time_index, level_index), message) in planes {
+        let data = message.data()?;
+        let start = (time_index * level_count + level_index) * point_count;
+        for (point, value) in values[start..start + point_count].iter_mut().enumerate() {
+            let index = scanned_index(scanning_mode, shape, point % shape.1, point / shape.1);
+            *value = json_number(data.get(index).copied().unwrap_or(f64::NAN));
+        }
+    }
+
+    let numbers = |values: &[f64]| values.iter().map(|value| value.to_string()).collect::<Vec<_>>().join(",");
+    let times = times
+        .iter()
+        .map(|time| format!(r#""{}""#, time.format("%Y-%m-%dT%H:%M:%SZ")))
+        .collect::<Vec<_>>();
+
+    let mut axes = vec![
+        format!(r#""x":{{"values":[{}]}}"#, numbers(&longitudes)),
+        format!(r#""y":{{"values":[{}]}}"#, numbers(&latitudes)),
+    ];
+    let mut referencing =
+        vec![r#"{"coordinates":["x","y"],"system":{"type":"GeographicCRS","id":"http://www.opengis.net/def/crs/OGC/1.3/CRS84"}}"#.to_string()];
+    let mut axis_names = vec![r#""t""#];
+    let mut range_shape = vec![times.len()];
+    if !levels.is_empty() {
+        axes.push(format!(r#""z":{{"values":[{}]}}"#, numbers(&levels)));
+        referencing.push(format!(r#"{{"coordinates":["z"],"system":{{"type":"VerticalCRS","cs":{{"csAxes":[{}]}}}}}}"#, vertical_axis(surface)));
+        axis_names.push(r#""z""#);
+        range_shape.push(levels.len());
+    }
+    axes.push(format!(r#""t":{{"values":[{}]}}"#, times.join(",")));
+    referencing.push(r#"{"coordinates":["t"],"system":{"type":"TemporalRS","calendar":"Gregorian"}}"#.to_string());
+    axis_names.extend([r#""y""#, r#""x""#]);
+    range_shape.extend([shape.0, shape.1]);
+
+    let domain = format!(
+        r#"{{"type":"Domain","domainType":"Grid","axes":{{{}}},"referencing":[{}]}}"#,
+        axes.join(","),
+        referencing.join(",")
+    );
+    let parameters = format!(
+        r#"{{"{}":{{"type":"Parameter","description":{{"en":"{}"}},"unit":{{"symbol":"{}"}},"observedProperty":{{"label":{{"en":"{}"}}}}}}}}"#,
+        parameter.abbrev, parameter.name, parameter.unit, parameter.name
+    );
+    let ranges = format!(
+        r#"{{"{}":{{"type":"NdArray","dataType":"float","axisNames":[{}],"shape":[{}],"values":[{}]}}}}"#,
+        parameter.abbrev,
+        axis_names.join(","),
+        range_shape.iter().map(|size| size.to_string()).collect::<Vec<_>>().join(","),
+        values.join(",")
+    );
+
+    Ok(format!(
+        r#"{{"type":"Coverage","domain":{},"parameters":{},"ranges":{}}}"#,
+        domain, parameters, ranges
+    ))
+}
+
+// The axis of the vertical coordinate system for levels on the type of surface
+fn vertical_axis(surface: FixedSurfaceTypes) -> String {
+    let (name, direction, unit) = match surface {
+        FixedSurfaceTypes::IsobaricSurface => ("Pressure".to_string(), "down", Some("Pa")),
+        FixedSurfaceTypes::SpecifiedHeightLevelAboveGround => ("Height above ground".to_string(), "up", Some("m")),
+        FixedSurfaceTypes::SpecificAltitudeAboveMeanSeaLevel => ("Altitude".to_string(), "up", Some("m")),
+        FixedSurfaceTypes::DepthBelowLandSurface => ("Depth below land surface".to_string(), "down", Some("m")),
+        surface => (surface.to_string(), "up", None),
+    };
+    let unit = unit.map(|unit| format!(r#","unit":{{"symbol":"{}"}}"#, unit)).unwrap_or_default();
+    format!(r#"{{"name":{{"en":"{}"}},"direction":"{}"{}}}"#, name, direction, unit)
+}
+
+// The locations of the points around the edge of the grid, counter clockwise from the first
+// point when i runs east and j north. Longitudes are kept within 180 degrees of the one before
+// so the ring does not jump across the antimeridian.
+fn boundary(message: &Message) -> Result<Vec<(f64, f64)>, GribError> {
+    let (rows, columns) = message.shape()?;
+    let scanning_mode = message.grid_template()?.scanning_mode();
+    let locations = message.data_locations()?;
+    if rows == 0 || columns == 0 || locations.len() < rows * columns {
+        return Err(GribError::InvalidData("The grid has no points to bound".into()));
+    }
+
+    let edge = (0..columns)
+        .map(|i| (i, 0))
+        .chain((1..rows).map(|j| (columns - 1, j)))
+        .chain((0..columns.saturating_sub(1)).rev().map(|i| (i, rows - 1)))
+        .chain((1..rows.saturating_sub(1)).rev().map(|j| (0, j)));
+
+    let mut ring: Vec<(f64, f64)> = Vec::with_capacity(2 * (rows + columns));
+    for (i, j) in edge {
+        let (latitude, longitude) = locations[scanned_index(scanning_mode, (rows, columns), i, j)];
+        let longitude = match ring.last() {
+            Some((_, previous)) => previous + longitude_difference(longitude, *previous),
+            None => longitude,
+        };
+        ring.push((latitude, longitude));
+    }
+    Ok(ring)
+}
+
+fn json_number(value: f64) -> String {
+    if value.is_finite() {
+        value.to_string()
+    } else {
+        "null".into()
+    }
+}
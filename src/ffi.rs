@@ -0,0 +1,224 @@
+use crate::error::GribError;
+use crate::idx::{level_description, message_inventory};
+use crate::message::Message;
+use std::cell::RefCell;
+use std::ffi::{c_char, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+
+/// What the calls without a pointer to hand back return, with the reason for an error left for
+/// gribberish_last_error
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GribStatus {
+    Ok = 0,
+    Error = -1,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// The messages of a GRIB file copied out of the caller's buffer. Each message is parsed again
+/// when it is asked about, so the handle is no more than the bytes and where the messages start.
+pub struct GribFile {
+    bytes: Vec<u8>,
+    offsets: Vec<usize>,
+}
+
+impl GribFile {
+    fn message(&self, index: usize) -> Result<Message<'_>, GribError> {
+        let offset = unwrap_or_return!(
+            self.offsets.get(index),
+            GribError::OutOfRange(format!("There is no message {} in a file of {}", index, self.offsets.len()))
+        );
+        Message::parse(&self.bytes, *offset)
+    }
+}
+
+/// The times, in seconds since the unix epoch, and the grid of a message
+#[repr(C)]
+pub struct GribMetadata {
+    pub discipline: u8,
+    pub reference_time: i64,
+    pub forecast_time: i64,
+    pub rows: usize,
+    pub columns: usize,
+    pub point_count: usize,
+}
+
+fn set_last_error(message: String) {
+    let message = CString::new(message.replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(message));
+}
+
+// Runs the body, keeping its error or panic for gribberish_last_error instead of letting either
+// cross into the caller
+fn guard<T, F: FnOnce() -> Result<T, GribError>>(failed: T, body: F) -> T {
+    match catch_unwind(AssertUnwindSafe(body)) {
+        Ok(Ok(value)) => value,
+        Ok(Err(error)) => {
+            set_last_error(error.to_string());
+            failed
+        }
+        Err(_) => {
+            set_last_error("gribberish panicked".into());
+            failed
+        }
+    }
+}
+
+fn file<'f>(file: *const GribFile) -> Result<&'f GribFile, GribError> {
+    // Safety: a handle is either null or was handed out by gribberish_file_open and not yet freed
+    unsafe { file.as_ref() }.ok_or_else(|| GribError::InvalidData("The file handle is null".into()))
+}
+
+fn c_string(value: String) -> *mut c_char {
+    CString::new(value.replace('\0', " ")).map(CString::into_raw).unwrap_or(ptr::null_mut())
+}
+
+/// The message of the last call on this thread that failed, or null. The string belongs to the
+/// library and lasts until the next failure on the thread.
+#[no_mangle]
+pub extern "C" fn gribberish_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| last.borrow().as_ref().map(|message| message.as_ptr()).unwrap_or(ptr::null()))
+}
+
+/// Copies the buffer and finds the messages in it, stopping at the first that is not valid.
+/// Returns null on failure. The handle is freed with gribberish_file_free.
+///
+/// # Safety
+/// The data must point to at least length readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn gribberish_file_open(data: *const u8, length: usize) -> *mut GribFile {
+    guard(ptr::null_mut(), || {
+        if data.is_null() {
+            return Err(GribError::InvalidData("The data is null".into()));
+        }
+        let bytes = std::slice::from_raw_parts(data, length).to_vec();
+        let offsets = Message::parse_all(&bytes).iter().map(Message::offset).collect();
+        Ok(Box::into_raw(Box::new(GribFile { bytes, offsets })))
+    })
+}
+
+/// # Safety
+/// The handle must be null or come from gribberish_file_open, and is not used again.
+#[no_mangle]
+pub unsafe extern "C" fn gribberish_file_free(file: *mut GribFile) {
+    if !file.is_null() {
+        drop(Box::from_raw(file));
+    }
+}
+
+/// # Safety
+/// The handle must be null or a handle from gribberish_file_open that is not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn gribberish_file_message_count(handle: *const GribFile) -> usize {
+    guard(0, || Ok(file(handle)?.offsets.len()))
+}
+
+/// The wgrib2 style inventory of the file, one line for each message. Returns null on failure.
+/// The string is freed with gribberish_string_free.
+///
+/// # Safety
+/// The handle must be null or a handle from gribberish_file_open that is not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn gribberish_file_inventory(handle: *const GribFile) -> *mut c_char {
+    guard(ptr::null_mut(), || {
+        let file = file(handle)?;
+        let mut inventory = String::new();
+        for (index, offset) in file.offsets.iter().enumerate() {
+            inventory.push_str(&message_inventory(index + 1, *offset as u64, &file.message(index)?)?);
+        }
+        Ok(c_string(inventory))
+    })
+}
+
+/// # Safety
+/// The handle must be null or a handle from gribberish_file_open that is not yet freed, and the
+/// metadata must be null or point to memory for a GribMetadata, which need not be initialized.
+#[no_mangle]
+pub unsafe extern "C" fn gribberish_message_metadata(handle: *const GribFile, index: usize, metadata: *mut GribMetadata) -> GribStatus {
+    guard(GribStatus::Error, || {
+        if metadata.is_null() {
+            return Err(GribError::InvalidData("The metadata is null".into()));
+        }
+        let message = file(handle)?.message(index)?;
+        let (rows, columns) = message.shape()?;
+        // The caller's GribMetadata may be uninitialized, so it is written without being read
+        metadata.write(GribMetadata {
+            discipline: message.discipline()?.into(),
+            reference_time: message.reference_date()?.timestamp(),
+            forecast_time: message.forecast_date()?.timestamp(),
+            rows,
+            columns,
+            point_count: message.metadata()?.data_point_count,
+        });
+        Ok(GribStatus::Ok)
+    })
+}
+
+fn message_string<F>(handle: *const GribFile, index: usize, value: F) -> *mut c_char
+where
+    F: FnOnce(&Message) -> Result<String, GribError>,
+{
+    guard(ptr::null_mut(), || Ok(c_string(value(&file(handle)?.message(index)?)?)))
+}
+
+/// The parameter name, abbreviation and units, and the level as the inventory writes it. Each
+/// returns null on failure and a string freed with gribberish_string_free otherwise.
+///
+/// # Safety
+/// The handle must be null or a handle from gribberish_file_open that is not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn gribberish_message_name(handle: *const GribFile, index: usize) -> *mut c_char {
+    message_string(handle, index, |message| message.variable_name())
+}
+
+/// # Safety
+/// The handle must be null or a handle from gribberish_file_open that is not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn gribberish_message_abbreviation(handle: *const GribFile, index: usize) -> *mut c_char {
+    message_string(handle, index, |message| message.variable_abbrev())
+}
+
+/// # Safety
+/// The handle must be null or a handle from gribberish_file_open that is not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn gribberish_message_units(handle: *const GribFile, index: usize) -> *mut c_char {
+    message_string(handle, index, |message| Ok(message.parameter()?.unit))
+}
+
+/// # Safety
+/// The handle must be null or a handle from gribberish_file_open that is not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn gribberish_message_level(handle: *const GribFile, index: usize) -> *mut c_char {
+    message_string(handle, index, |message| Ok(level_description(&message.product_template()?)))
+}
+
+/// Decodes the message into the values in the order they are stored, NaN where there is no data.
+/// The values must hold at least the point count of the metadata.
+///
+/// # Safety
+/// The handle must be null or a handle from gribberish_file_open that is not yet freed, and the
+/// values must point to length writable doubles.
+#[no_mangle]
+pub unsafe extern "C" fn gribberish_message_data(handle: *const GribFile, index: usize, values: *mut f64, length: usize) -> GribStatus {
+    guard(GribStatus::Error, || {
+        if values.is_null() {
+            return Err(GribError::InvalidData("The values are null".into()));
+        }
+        let message = file(handle)?.message(index)?;
+        message.data_into(std::slice::from_raw_parts_mut(values, length))?;
+        Ok(GribStatus::Ok)
+    })
+}
+
+/// # Safety
+/// The string must be null or come from this library, and is not used again.
+#[no_mangle]
+pub unsafe extern "C" fn gribberish_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
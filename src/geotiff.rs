@@ -0,0 +1,136 @@
+use crate::crs::earth_axes;
+use crate::error::GribError;
+use crate::message::Message;
+use crate::templates::grid::{scanned_index, EarthShape, LambertConformalTemplate};
+use crate::templates::template::TemplateType;
+use crate::winds::grid_section;
+use std::io::Cursor;
+use std::path::Path;
+use tiff::encoder::{colortype, TiffEncoder};
+use tiff::tags::Tag;
+
+// The GeoTIFF codes for what the model space is, how pixels cover it and the units it is in
+const MODEL_TYPE_PROJECTED: u16 = 1;
+const MODEL_TYPE_GEOGRAPHIC: u16 = 2;
+const RASTER_PIXEL_IS_AREA: u16 = 1;
+const USER_DEFINED: u16 = 32767;
+const ANGULAR_UNIT_DEGREE: u16 = 9102;
+const LINEAR_UNIT_METRE: u16 = 9001;
+const LAMBERT_CONFORMAL_CONIC_2SP: u16 = 8;
+
+// Encodes the message as a single band GeoTIFF of 32 bit floats with the first row to the north
+// and the first column to the west, whatever order the grid is scanned in. Missing values are
+// NaN, which the GDAL no data tag records. Latitude longitude grids are written in degrees and
+// Lambert conformal grids in metres on their own projection.
+pub fn to_geotiff(message: &Message) -> Result<Vec<u8>, GribError> {
+    let crs = message.crs()?;
+    let template_number = crs.template_number();
+    if template_number == 1 {
+        return Err(GribError::UnsupportedTemplate { kind: TemplateType::Grid, number: template_number });
+    }
+
+    // The steps between grid points in projected coordinates give which way the grid runs
+    let projected = |i: f64, j: f64| {
+        let (latitude, longitude) = crs.from_grid(i, j);
+        crs.to_projected(latitude, longitude)
+    };
+    let (rows, columns) = message.shape()?;
+    let origin = projected(0.0, 0.0);
+    let x_step = projected(1.0, 0.0).0 - origin.0;
+    let y_step = projected(0.0, 1.0).1 - origin.1;
+    let column = |c: usize| if x_step >= 0.0 { c } else { columns - 1 - c };
+    let row = |r: usize| if y_step <= 0.0 { r } else { rows - 1 - r };
+
+    let data = message.data()?;
+    let scanning_mode = message.grid_template()?.scanning_mode();
+    let raster = (0..rows * columns)
+        .map(|pixel| {
+            let index = scanned_index(scanning_mode, (rows, columns), column(pixel % columns), row(pixel / columns));
+            data.get(index).map(|value| *value as f32).unwrap_or(f32::NAN)
+        })
+        .collect::<Vec<_>>();
+
+    // Pixels are areas, so the north west corner is half a step out from the first point
+    let (x_scale, y_scale) = (x_step.abs(), y_step.abs());
+    let (west, north) = projected(column(0) as f64, row(0) as f64);
+    let tie_point = [0.0, 0.0, 0.0, west - x_scale / 2.0, north + y_scale / 2.0, 0.0];
+    let (keys, doubles, ascii) = geo_keys(grid_section(message)?, template_number, &crs.proj_string());
+
+    let mut tiff = Cursor::new(Vec::new());
+    let tiff_error = |e: tiff::TiffError| GribError::Encoding(format!("Failed to encode the GeoTIFF: {}", e));
+    let mut encoder = TiffEncoder::new(&mut tiff).map_err(tiff_error)?;
+    let mut image = encoder
+        .new_image::<colortype::Gray32Float>(columns as u32, rows as u32)
+        .map_err(tiff_error)?;
+    let directory = image.encoder();
+    directory.write_tag(Tag::ModelPixelScaleTag, &[x_scale, y_scale, 0.0][..]).map_err(tiff_error)?;
+    directory.write_tag(Tag::ModelTiepointTag, &tie_point[..]).map_err(tiff_error)?;
+    directory.write_tag(Tag::GeoKeyDirectoryTag, &keys[..]).map_err(tiff_error)?;
+    directory.write_tag(Tag::GeoDoubleParamsTag, &doubles[..]).map_err(tiff_error)?;
+    directory.write_tag(Tag::GeoAsciiParamsTag, ascii.as_str()).map_err(tiff_error)?;
+    directory.write_tag(Tag::GdalNodata, "nan").map_err(tiff_error)?;
+    image.write_data(&raster).map_err(tiff_error)?;
+
+    Ok(tiff.into_inner())
+}
+
+pub fn write_geotiff<P: AsRef<Path>>(message: &Message, path: P) -> Result<(), GribError> {
+    let path = path.as_ref();
+    let tiff = to_geotiff(message)?;
+    std::fs::write(path, tiff).map_err(|e| GribError::Io(format!("Failed to write {}: {}", path.display(), e)))
+}
+
+// The GeoKey directory with the double and ascii values it points into. WGS84 grids use the
+// EPSG geographic system, other earths are written out as user defined ellipsoids.
+fn geo_keys(data: &[u8], template_number: u16, citation: &str) -> (Vec<u16>, Vec<f64>, String) {
+    let mut keys: Vec<[u16; 4]> = Vec::new();
+    let mut doubles = Vec::new();
+    let mut ascii = String::new();
+    let mut add_double = |keys: &mut Vec<[u16; 4]>, id: u16, value: f64| {
+        keys.push([id, Tag::GeoDoubleParamsTag.to_u16(), 1, doubles.len() as u16]);
+        doubles.push(value);
+    };
+    let mut add_ascii = |keys: &mut Vec<[u16; 4]>, id: u16, value: &str| {
+        keys.push([id, Tag::GeoAsciiParamsTag.to_u16(), value.len() as u16 + 1, ascii.len() as u16]);
+        ascii.push_str(value);
+        ascii.push('|');
+    };
+
+    let projected = template_number == 30;
+    keys.push([1024, 0, 1, if projected { MODEL_TYPE_PROJECTED } else { MODEL_TYPE_GEOGRAPHIC }]);
+    keys.push([1025, 0, 1, RASTER_PIXEL_IS_AREA]);
+    add_ascii(&mut keys, 1026, citation);
+
+    if EarthShape::from(data[14]) == EarthShape::WGS84 {
+        keys.push([2048, 0, 1, 4326]);
+    } else {
+        let (semi_major, semi_minor) = earth_axes(data);
+        keys.push([2048, 0, 1, USER_DEFINED]);
+        add_ascii(&mut keys, 2049, "GRIB earth");
+        keys.push([2050, 0, 1, USER_DEFINED]);
+        keys.push([2054, 0, 1, ANGULAR_UNIT_DEGREE]);
+        keys.push([2056, 0, 1, USER_DEFINED]);
+        add_double(&mut keys, 2057, semi_major);
+        add_double(&mut keys, 2058, semi_minor);
+    }
+
+    if projected {
+        let template = LambertConformalTemplate::new(data);
+        keys.push([3072, 0, 1, USER_DEFINED]);
+        keys.push([3074, 0, 1, USER_DEFINED]);
+        keys.push([3075, 0, 1, LAMBERT_CONFORMAL_CONIC_2SP]);
+        keys.push([3076, 0, 1, LINEAR_UNIT_METRE]);
+        add_double(&mut keys, 3078, template.first_standard_parallel());
+        add_double(&mut keys, 3079, template.second_standard_parallel());
+        add_double(&mut keys, 3082, 0.0);
+        add_double(&mut keys, 3083, 0.0);
+        add_double(&mut keys, 3084, template.orientation_longitude());
+        add_double(&mut keys, 3085, template.increment_latitude());
+    }
+
+    // The directory starts with its version and the number of keys, which are sorted by id
+    keys.sort_by_key(|key| key[0]);
+    let mut directory = vec![1, 1, 0, keys.len() as u16];
+    directory.extend(keys.iter().flatten());
+    (directory, doubles, ascii)
+}
@@ -0,0 +1,434 @@
+use crate::error::GribError;
+use crate::sections::identification::Center;
+use crate::sections::indicator::detect_edition;
+use crate::utils::{grib_power, read_i16_from_bytes, read_u16_from_bytes, BitReader};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use std::vec::Vec;
+
+// GRIB1 lengths are three bytes
+fn read_u24_from_bytes(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 3)?;
+    Some(((bytes[0] as u32) << 16) | ((bytes[1] as u32) << 8) | bytes[2] as u32)
+}
+
+// Latitudes and longitudes are three byte sign and magnitude millidegrees
+fn read_i24_from_bytes(data: &[u8], offset: usize) -> Option<i32> {
+    let value = read_u24_from_bytes(data, offset)?;
+    if value & 0x80_0000 == 0 {
+        Some(value as i32)
+    } else {
+        Some(-((value & 0x7f_ffff) as i32))
+    }
+}
+
+// The reference value is an IBM single precision float rather than IEEE
+fn read_ibm_f32_from_bytes(data: &[u8], offset: usize) -> Option<f64> {
+    let bytes = data.get(offset..offset + 4)?;
+    let sign = if bytes[0] & 0x80 == 0 { 1.0 } else { -1.0 };
+    let exponent = (bytes[0] & 0x7f) as i32 - 64;
+    let mantissa = read_u24_from_bytes(bytes, 1)? as f64 / (1 << 24) as f64;
+    Some(sign * mantissa * 16f64.powi(exponent))
+}
+
+fn read_section(message: &[u8], offset: usize) -> Result<&[u8], GribError> {
+    let length = read_u24_from_bytes(message, offset).unwrap_or(0) as usize;
+
+    // The end marker follows the last section
+    if length < 3 || offset + length > message.len() - 4 {
+        return Err(GribError::InvalidData(format!("Invalid GRIB1 section length at offset {}", offset)));
+    }
+
+    Ok(&message[offset..offset + length])
+}
+
+// A regular latitude longitude grid, GRIB1 data representation type 0
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Grib1LatLngGrid {
+    pub longitude_count: usize,
+    pub latitude_count: usize,
+    // The first and last grid points as (latitude, longitude)
+    pub start: (f64, f64),
+    pub end: (f64, f64),
+    pub scanning_mode: u8,
+}
+
+impl Grib1LatLngGrid {
+    pub fn latitudes(&self) -> Vec<f64> {
+        spaced(self.start.0, self.end.0, self.latitude_count)
+    }
+
+    pub fn longitudes(&self) -> Vec<f64> {
+        // The grid may cross the antimeridian or prime meridian in the scanning direction
+        let mut end = self.end.1;
+        if self.scanning_mode & 0x80 == 0 && end < self.start.1 {
+            end += 360.0;
+        } else if self.scanning_mode & 0x80 != 0 && end > self.start.1 {
+            end -= 360.0;
+        }
+
+        spaced(self.start.1, end, self.longitude_count)
+    }
+
+    // The location of every grid point, in the order the values are stored
+    pub fn locations(&self) -> Vec<(f64, f64)> {
+        let latitudes = self.latitudes();
+        let longitudes = self.longitudes();
+
+        if self.scanning_mode & 0x20 == 0 {
+            latitudes
+                .iter()
+                .flat_map(|lat| longitudes.iter().map(move |lng| (*lat, *lng)))
+                .collect()
+        } else {
+            longitudes
+                .iter()
+                .flat_map(|lng| latitudes.iter().map(move |lat| (*lat, *lng)))
+                .collect()
+        }
+    }
+}
+
+fn spaced(start: f64, end: f64, count: usize) -> Vec<f64> {
+    if count < 2 {
+        return vec![start; count];
+    }
+
+    let step = (end - start) / (count - 1) as f64;
+    (0..count).map(|i| start + step * i as f64).collect()
+}
+
+// An edition 1 message. Only grid point data with simple packing on latitude longitude grids
+// can be decoded, which covers most of what archives still serve as GRIB1.
+pub struct Grib1Message<'a> {
+    data: &'a [u8],
+    product_definition: &'a [u8],
+    grid_definition: Option<&'a [u8]>,
+    bitmap: Option<&'a [u8]>,
+    binary_data: &'a [u8],
+}
+
+impl<'a> Grib1Message<'a> {
+    pub fn parse(data: &'a [u8], offset: usize) -> Result<Grib1Message<'a>, GribError> {
+        let remaining = &data[offset.min(data.len())..];
+        match detect_edition(remaining) {
+            Some(1) => {}
+            Some(edition) => return Err(GribError::UnsupportedEdition(edition)),
+            None => {
+                return Err(GribError::InvalidMessage {
+                    offset: offset as u64,
+                    reason: "No GRIB message found".into(),
+                })
+            }
+        }
+
+        let length = read_u24_from_bytes(remaining, 4).unwrap_or(0) as usize;
+        if length < 8 || length > remaining.len() {
+            return Err(GribError::InvalidMessage {
+                offset: offset as u64,
+                reason: "The message is truncated".into(),
+            });
+        }
+
+        let data = &remaining[..length];
+        if &data[length - 4..] != b"7777" {
+            return Err(GribError::InvalidMessage {
+                offset: offset as u64,
+                reason: "The message has no end section".into(),
+            });
+        }
+
+        let product_definition = read_section(data, 8)?;
+        if product_definition.len() < 28 {
+            return Err(GribError::InvalidData("Invalid GRIB1 product definition section".into()));
+        }
+
+        let flags = product_definition[7];
+        let mut section_offset = 8 + product_definition.len();
+
+        let grid_definition = if flags & 0x80 != 0 {
+            let section = read_section(data, section_offset)?;
+            section_offset += section.len();
+            Some(section)
+        } else {
+            None
+        };
+
+        let bitmap = if flags & 0x40 != 0 {
+            let section = read_section(data, section_offset)?;
+            section_offset += section.len();
+            Some(section)
+        } else {
+            None
+        };
+
+        let binary_data = read_section(data, section_offset)?;
+        if binary_data.len() < 11 {
+            return Err(GribError::InvalidData("Invalid GRIB1 binary data section".into()));
+        }
+
+        Ok(Grib1Message {
+            data,
+            product_definition,
+            grid_definition,
+            bitmap,
+            binary_data,
+        })
+    }
+
+    // Parses consecutive GRIB1 messages, stopping at the first that is not valid
+    pub fn parse_all(data: &'a [u8]) -> Vec<Grib1Message<'a>> {
+        let mut messages = Vec::new();
+        let mut offset = 0;
+        while offset < data.len() {
+            match Grib1Message::parse(data, offset) {
+                Ok(message) => {
+                    offset += message.len();
+                    messages.push(message);
+                }
+                Err(_) => break,
+            }
+        }
+
+        messages
+    }
+
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn raw_bytes(&self) -> &'a [u8] {
+        self.data
+    }
+
+    pub fn table_version(&self) -> u8 {
+        self.product_definition[3]
+    }
+
+    pub fn center(&self) -> Center {
+        self.product_definition[4].into()
+    }
+
+    pub fn subcenter(&self) -> u8 {
+        self.product_definition[25]
+    }
+
+    pub fn generating_process(&self) -> u8 {
+        self.product_definition[5]
+    }
+
+    // The parameter number within the parameter table given by table_version
+    pub fn parameter(&self) -> u8 {
+        self.product_definition[8]
+    }
+
+    pub fn level_type(&self) -> u8 {
+        self.product_definition[9]
+    }
+
+    // Some level types split this into two single byte values, such as the top and bottom of a layer
+    pub fn level(&self) -> u16 {
+        read_u16_from_bytes(self.product_definition, 10).unwrap_or(0)
+    }
+
+    pub fn reference_date(&self) -> Result<DateTime<Utc>, GribError> {
+        let pds = self.product_definition;
+        let year = (pds[24] as i32 - 1) * 100 + pds[12] as i32;
+        Utc.with_ymd_and_hms(year, pds[13].into(), pds[14].into(), pds[15].into(), pds[16].into(), 0)
+            .single()
+            .ok_or_else(|| GribError::InvalidData("Invalid GRIB1 reference date".into()))
+    }
+
+    pub fn forecast_date(&self) -> Result<DateTime<Utc>, GribError> {
+        let pds = self.product_definition;
+        let periods = match pds[20] {
+            0 | 1 => pds[18] as i64,
+            // Averages and accumulations are valid at the end of the period
+            2..=5 => pds[19] as i64,
+            10 => read_u16_from_bytes(pds, 18).unwrap_or(0) as i64,
+            indicator => return Err(GribError::InvalidData(format!("GRIB1 time range indicator {} is not supported", indicator))),
+        };
+
+        let unit = match pds[17] {
+            0 => Duration::minutes(1),
+            1 => Duration::hours(1),
+            2 => Duration::days(1),
+            10 => Duration::hours(3),
+            11 => Duration::hours(6),
+            12 => Duration::hours(12),
+            254 => Duration::seconds(1),
+            unit => return Err(GribError::InvalidData(format!("GRIB1 time unit {} is not supported", unit))),
+        };
+
+        Ok(self.reference_date()? + unit * periods as i32)
+    }
+
+    pub fn decimal_scale_factor(&self) -> i16 {
+        read_i16_from_bytes(self.product_definition, 26).unwrap_or(0)
+    }
+
+    pub fn grid(&self) -> Result<Grib1LatLngGrid, GribError> {
+        let gds = unwrap_or_return!(self.grid_definition, GribError::InvalidData("Message has no grid definition section".into()));
+        if gds.len() < 28 {
+            return Err(GribError::InvalidData("Invalid GRIB1 grid definition section".into()));
+        }
+
+        if gds[5] != 0 {
+            return Err(GribError::InvalidData(format!("GRIB1 grid type {} is not supported", gds[5])));
+        }
+
+        let millidegrees = |offset| read_i24_from_bytes(gds, offset).unwrap_or(0) as f64 / 1000.0;
+        Ok(Grib1LatLngGrid {
+            longitude_count: read_u16_from_bytes(gds, 6).unwrap_or(0) as usize,
+            latitude_count: read_u16_from_bytes(gds, 8).unwrap_or(0) as usize,
+            start: (millidegrees(10), millidegrees(13)),
+            end: (millidegrees(17), millidegrees(20)),
+            scanning_mode: gds[27],
+        })
+    }
+
+    pub fn data_locations(&self) -> Result<Vec<(f64, f64)>, GribError> {
+        Ok(self.grid()?.locations())
+    }
+
+    // The value of every grid point, with NaN where the bitmap marks a point as missing
+    pub fn data(&self) -> Result<Vec<f64>, GribError> {
+        let bds = self.binary_data;
+        let flags = bds[3];
+        if flags & 0x80 != 0 {
+            return Err(GribError::InvalidData("GRIB1 spherical harmonic data is not supported".into()));
+        } else if flags & 0x40 != 0 {
+            return Err(GribError::InvalidData("Only GRIB1 simple packing is supported".into()));
+        }
+
+        let bit_count = bds[10] as usize;
+        let binary_scale = grib_power(read_i16_from_bytes(bds, 4).unwrap_or(0).into(), 2);
+        let decimal_scale = grib_power(-(self.decimal_scale_factor() as i32), 10);
+        let reference_value = unwrap_or_return!(read_ibm_f32_from_bytes(bds, 6), GribError::InvalidData("Invalid GRIB1 reference value".into()));
+
+        let bitmap = match self.bitmap {
+            Some(bms) if bms.len() < 6 => return Err(GribError::InvalidData("Invalid GRIB1 bitmap section".into())),
+            Some(bms) if read_u16_from_bytes(bms, 4) != Some(0) => {
+                return Err(GribError::InvalidData("Predefined GRIB1 bitmaps are not supported".into()))
+            }
+            Some(bms) => {
+                let mut reader = BitReader::new(&bms[6..]);
+                let mut bitmap = Vec::with_capacity(reader.remaining_bits());
+                while let Ok(bit) = reader.read_bit() {
+                    bitmap.push(bit);
+                }
+                Some(bitmap)
+            }
+            None => None,
+        };
+
+        let packed_bits = (bds.len() - 11) * 8 - (flags & 0x0f) as usize;
+        let point_count = match (&self.grid_definition, &bitmap) {
+            (Some(_), _) => {
+                let grid = self.grid()?;
+                grid.longitude_count * grid.latitude_count
+            }
+            (None, Some(bitmap)) => bitmap.len(),
+            (None, None) if bit_count > 0 => packed_bits / bit_count,
+            (None, None) => return Err(GribError::InvalidData("Unable to determine the number of GRIB1 data points".into())),
+        };
+
+        let value_count = match &bitmap {
+            Some(bitmap) => bitmap.iter().take(point_count).filter(|b| **b).count(),
+            None => point_count,
+        };
+
+        let values = if bit_count == 0 {
+            vec![reference_value * decimal_scale; value_count]
+        } else {
+            if value_count * bit_count > packed_bits {
+                return Err(GribError::InvalidData("GRIB1 binary data section is shorter than the data points".into()));
+            }
+
+            let mut reader = BitReader::new(&bds[11..]);
+            let mut values = Vec::with_capacity(value_count);
+            for _ in 0..value_count {
+                let raw_value = reader.read(bit_count)? as f64;
+                values.push((reference_value + raw_value * binary_scale) * decimal_scale);
+            }
+            values
+        };
+
+        match bitmap {
+            Some(bitmap) => {
+                let mut values = values.into_iter();
+                Ok(bitmap
+                    .iter()
+                    .take(point_count)
+                    .map(|b| if *b { values.next().unwrap_or(f64::NAN) } else { f64::NAN })
+                    .collect())
+            }
+            None => Ok(values),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A 3x2 grid of 2 m temperatures with one missing point
+    fn grib1_message() -> Vec<u8> {
+        let product_definition = [
+            0, 0, 28, 2, 7, 81, 255, 0xC0, 11, 105, 0, 2, 21, 3, 4, 12, 0, 1, 6, 0, 0, 0, 0, 0, 21, 0, 0, 1,
+        ];
+        let grid_definition = [
+            0, 0, 32, 0, 255, 0, 0, 3, 0, 2, 0x00, 0xA4, 0x10, 0x81, 0x19, 0x40, 0x80, 0x00, 0xA0, 0x28, 0x81, 0x11,
+            0x70, 0x03, 0xE8, 0x03, 0xE8, 0, 0, 0, 0, 0,
+        ];
+        let bitmap = [0, 0, 7, 2, 0, 0, 0b1101_1100];
+        let binary_data = [0, 0, 14, 0x04, 0, 0, 0x43, 0xAF, 0x00, 0x00, 4, 0x05, 0xAF, 0x30];
+
+        let mut message = b"GRIB".to_vec();
+        message.extend_from_slice(&[0, 0, 93, 1]);
+        message.extend_from_slice(&product_definition);
+        message.extend_from_slice(&grid_definition);
+        message.extend_from_slice(&bitmap);
+        message.extend_from_slice(&binary_data);
+        message.extend_from_slice(b"7777");
+        message
+    }
+
+    #[test]
+    fn read_grib1_message() {
+        let data = grib1_message();
+        let message = Grib1Message::parse(&data, 0).unwrap();
+        assert_eq!(message.len(), 93);
+        assert_eq!(message.center(), Center::NCEP);
+        assert_eq!(message.parameter(), 11);
+        assert_eq!((message.level_type(), message.level()), (105, 2));
+        assert_eq!(message.reference_date(), Ok(Utc.with_ymd_and_hms(2021, 3, 4, 12, 0, 0).unwrap()));
+        assert_eq!(message.forecast_date(), Ok(Utc.with_ymd_and_hms(2021, 3, 4, 18, 0, 0).unwrap()));
+
+        let grid = message.grid().unwrap();
+        assert_eq!(grid.latitudes(), vec![42.0, 41.0]);
+        assert_eq!(grid.longitudes(), vec![-72.0, -71.0, -70.0]);
+        assert_eq!(message.data_locations().unwrap()[4], (41.0, -71.0));
+
+        let values = message.data().unwrap();
+        let expected = [280.0, 280.5, f64::NAN, 281.0, 281.5, 280.3];
+        assert_eq!(values.len(), expected.len());
+        for (value, expected) in values.iter().zip(expected.iter()) {
+            assert!((value.is_nan() && expected.is_nan()) || (value - expected).abs() < 1e-9);
+        }
+
+        let mut concatenated = data.clone();
+        concatenated.extend_from_slice(&data);
+        assert_eq!(Grib1Message::parse_all(&concatenated).len(), 2);
+    }
+
+    #[test]
+    fn reject_other_editions() {
+        let mut data = grib1_message();
+        data[7] = 2;
+        assert!(Grib1Message::parse(&data, 0).is_err());
+
+        let data = grib1_message();
+        assert!(Grib1Message::parse(&data[..50], 0).is_err());
+    }
+}
@@ -0,0 +1,65 @@
+use crate::error::GribError;
+use crate::reader::RangeRead;
+use reqwest::blocking::Client;
+use reqwest::header::RANGE;
+use reqwest::StatusCode;
+use std::vec::Vec;
+
+// Reads byte ranges of a remote file with HTTP Range requests, such as a GRIB file
+// served over HTTPS or from a public or presigned S3 url
+pub struct HttpRangeReader {
+    client: Client,
+    url: String,
+}
+
+impl HttpRangeReader {
+    pub fn new(url: &str) -> HttpRangeReader {
+        HttpRangeReader::with_client(Client::new(), url)
+    }
+
+    pub fn with_client(client: Client, url: &str) -> HttpRangeReader {
+        HttpRangeReader {
+            client,
+            url: url.to_string(),
+        }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+impl RangeRead for HttpRangeReader {
+    fn read_range(&mut self, offset: u64, length: u64) -> Result<Vec<u8>, GribError> {
+        if length == 0 {
+            return Ok(Vec::new());
+        }
+
+        let response = self
+            .client
+            .get(&self.url)
+            .header(RANGE, format!("bytes={}-{}", offset, offset.saturating_add(length) - 1))
+            .send()
+            .map_err(|e| GribError::Io(format!("Failed to request {}: {}", self.url, e)))?;
+
+        let status = response.status();
+        match status {
+            // The range starts past the end of the file
+            StatusCode::RANGE_NOT_SATISFIABLE => Ok(Vec::new()),
+            StatusCode::PARTIAL_CONTENT => response
+                .bytes()
+                .map(|b| b.to_vec())
+                .map_err(|e| GribError::Io(format!("Failed to read response from {}: {}", self.url, e))),
+            // The server ignored the range and sent the whole file
+            StatusCode::OK => {
+                let body = response
+                    .bytes()
+                    .map_err(|e| GribError::Io(format!("Failed to read response from {}: {}", self.url, e)))?;
+                let start = offset.min(body.len() as u64) as usize;
+                let end = offset.saturating_add(length).min(body.len() as u64) as usize;
+                Ok(body[start..end].to_vec())
+            }
+            _ => Err(GribError::Io(format!("Request for {} failed with status {}", self.url, status))),
+        }
+    }
+}
@@ -0,0 +1,402 @@
+use crate::error::GribError;
+use crate::message::Message;
+use crate::reader::{message_length, GribReader, RangeRead};
+use crate::templates::product::tables::{EnsembleForecastType, FixedSurfaceTypes, StatisticalProcess, TimeUnit};
+use crate::templates::product::HorizontalAnalysisForecastTemplate;
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use std::vec::Vec;
+
+// A line of a wgrib2 style inventory, such as
+// 1:0:d=2021030400:PRMSL:mean sea level:anl:
+#[derive(Clone, Debug, PartialEq)]
+pub struct IdxRecord {
+    // Submessages are numbered with a suffix, such as 3.2
+    pub message_number: String,
+    pub offset: u64,
+    pub reference_date: DateTime<Utc>,
+    pub variable: String,
+    pub level: String,
+    pub forecast: String,
+    // Anything after the forecast, such as ensemble or probability descriptions
+    pub extra: Vec<String>,
+}
+
+impl IdxRecord {
+    pub fn parse(line: &str) -> Result<IdxRecord, GribError> {
+        let fields = line.trim_end().split(':').collect::<Vec<&str>>();
+        if fields.len() < 6 {
+            return Err(GribError::InvalidInventory(format!("Invalid inventory line: {}", line)));
+        }
+
+        let offset = fields[1]
+            .parse::<u64>()
+            .map_err(|_| GribError::InvalidInventory(format!("Invalid offset in inventory line: {}", line)))?;
+
+        let date = fields[2].trim_start_matches("d=");
+        let reference_date = NaiveDateTime::parse_from_str(&format!("{}0000", date), "%Y%m%d%H%M%S")
+            .map(|d| Utc.from_utc_datetime(&d))
+            .map_err(|_| GribError::InvalidInventory(format!("Invalid date in inventory line: {}", line)))?;
+
+        let extra = fields[6..]
+            .iter()
+            .filter(|f| !f.is_empty())
+            .map(|f| f.to_string())
+            .collect();
+
+        Ok(IdxRecord {
+            message_number: fields[0].to_string(),
+            offset,
+            reference_date,
+            variable: fields[3].to_string(),
+            level: fields[4].to_string(),
+            forecast: fields[5].to_string(),
+            extra,
+        })
+    }
+}
+
+pub fn parse_idx(text: &str) -> Result<Vec<IdxRecord>, GribError> {
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(IdxRecord::parse)
+        .collect()
+}
+
+// A span of the GRIB file, the end is exclusive and unknown for the final message
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ByteRange {
+    pub start: u64,
+    pub end: Option<u64>,
+}
+
+impl ByteRange {
+    // The value of an HTTP Range header, which uses an inclusive end
+    pub fn http_range(&self) -> String {
+        match self.end {
+            Some(end) => format!("bytes={}-{}", self.start, end - 1),
+            None => format!("bytes={}-", self.start),
+        }
+    }
+}
+
+// The byte ranges holding the selected records. Each message ends where the next one starts,
+// and neighbouring messages are merged so they can be fetched in a single request.
+pub fn byte_ranges<F>(records: &[IdxRecord], selected: F) -> Vec<ByteRange>
+where
+    F: Fn(&IdxRecord) -> bool,
+{
+    let mut offsets = records.iter().map(|r| r.offset).collect::<Vec<u64>>();
+    offsets.sort_unstable();
+    offsets.dedup();
+
+    let mut selected_offsets = records
+        .iter()
+        .filter(|r| selected(r))
+        .map(|r| r.offset)
+        .collect::<Vec<u64>>();
+    selected_offsets.sort_unstable();
+    selected_offsets.dedup();
+
+    let mut ranges: Vec<ByteRange> = Vec::new();
+    for start in selected_offsets {
+        let end = offsets.iter().find(|o| **o > start).cloned();
+
+        match ranges.last_mut() {
+            Some(last) if last.end == Some(start) => last.end = end,
+            _ => ranges.push(ByteRange { start, end }),
+        }
+    }
+
+    ranges
+}
+
+// Reads the messages holding the given records, using the indicator section for each message length
+pub fn read_messages<R: RangeRead>(reader: &mut R, records: &[&IdxRecord]) -> Result<Vec<Vec<u8>>, GribError> {
+    let mut offsets = records.iter().map(|r| r.offset).collect::<Vec<u64>>();
+    offsets.dedup();
+
+    offsets
+        .into_iter()
+        .map(|offset| {
+            let indicator = reader.read_range(offset, 16)?;
+            if indicator.len() < 16 {
+                return Err(GribError::InvalidMessage {
+                    offset,
+                    reason: "No GRIB message found".into(),
+                });
+            }
+
+            let length = message_length(&indicator, offset)?;
+            let message = reader.read_range(offset, length)?;
+            if (message.len() as u64) < length {
+                return Err(GribError::InvalidMessage {
+                    offset,
+                    reason: "The message is truncated".into(),
+                });
+            }
+
+            Ok(message)
+        })
+        .collect()
+}
+
+// Scans the messages of a GRIB file into a wgrib2 compatible inventory, one line per field
+pub fn generate_idx<R: RangeRead>(reader: R) -> Result<String, GribError> {
+    let mut reader = GribReader::new(reader);
+    let headers = reader.headers()?;
+
+    let mut inventory = String::new();
+    for (i, header) in headers.iter().enumerate() {
+        let metadata = reader.read_message_metadata(header)?;
+        let message = Message::parse(&metadata, 0)?;
+        inventory.push_str(&message_inventory(i + 1, header.offset, &message)?);
+    }
+
+    Ok(inventory)
+}
+
+// The inventory lines of a message, each ending with a newline. Messages repeating sections 3
+// to 7 get a line for every field, numbered 3.1, 3.2 and so on, which all share the offset of the
+// message as wgrib2 writes them.
+pub fn message_inventory(message_number: usize, offset: u64, message: &Message) -> Result<String, GribError> {
+    let fields = message.fields();
+    if fields.len() < 2 {
+        return Ok(format!("{}\n", inventory_line(message_number, offset, message)?));
+    }
+
+    fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let line = numbered_inventory_line(&format!("{}.{}", message_number, i + 1), offset, field)?;
+            Ok(format!("{}\n", line))
+        })
+        .collect()
+}
+
+pub fn inventory_line(message_number: usize, offset: u64, message: &Message) -> Result<String, GribError> {
+    numbered_inventory_line(&message_number.to_string(), offset, message)
+}
+
+fn numbered_inventory_line(message_number: &str, offset: u64, message: &Message) -> Result<String, GribError> {
+    let reference_date = message.reference_date()?;
+    let variable = match message.variable_abbrev() {
+        Ok(abbrev) => abbrev,
+        Err(_) => unknown_variable(message),
+    };
+
+    let mut fields = vec![
+        message_number.to_string(),
+        offset.to_string(),
+        format!("d={}", reference_date.format("%Y%m%d%H")),
+        variable,
+    ];
+
+    match message.product_template() {
+        Ok(template) => {
+            fields.push(level_description(&template));
+            fields.push(forecast_description(&template));
+            if let Some(ensemble) = ensemble_description(&template) {
+                fields.push(ensemble);
+            }
+        }
+        Err(_) => {
+            fields.push("unknown level".into());
+            fields.push("unknown".into());
+        }
+    }
+
+    Ok(format!("{}:", fields.join(":")))
+}
+
+pub(crate) fn unknown_variable(message: &Message) -> String {
+    let discipline = message.discipline().map(u8::from).unwrap_or(255);
+    match message.product_template() {
+        Ok(template) => format!(
+            "var discipline={} parmcat={} parm={}",
+            discipline,
+            template.category_value(),
+            template.parameter_value()
+        ),
+        Err(_) => format!("var discipline={}", discipline),
+    }
+}
+
+pub(crate) fn level_description(template: &HorizontalAnalysisForecastTemplate) -> String {
+    let surface_type = template.first_fixed_surface_type();
+    let first = template.first_fixed_surface_value();
+    let second = match template.second_fixed_surface_type() {
+        FixedSurfaceTypes::Missing => None,
+        _ => template.second_fixed_surface_value(),
+    };
+
+    // Layers are written as the range between the two surfaces
+    let value = |scale: f64| match (first, second) {
+        (Some(first), Some(second)) => format!("{}-{}", first * scale, second * scale),
+        (Some(first), None) => format!("{}", first * scale),
+        _ => String::new(),
+    };
+
+    match surface_type {
+        FixedSurfaceTypes::GroundOrWater => "surface".into(),
+        FixedSurfaceTypes::MeanSeaLevel => "mean sea level".into(),
+        FixedSurfaceTypes::EntireAtmosphere | FixedSurfaceTypes::EntireAtmosphereLayer => "entire atmosphere".into(),
+        FixedSurfaceTypes::IsobaricSurface => format!("{} mb", value(0.01)),
+        FixedSurfaceTypes::SpecificAltitudeAboveMeanSeaLevel => format!("{} m above mean sea level", value(1.0)),
+        FixedSurfaceTypes::SpecifiedHeightLevelAboveGround => format!("{} m above ground", value(1.0)),
+        FixedSurfaceTypes::SigmaLevel => format!("{} sigma level", value(1.0)),
+        FixedSurfaceTypes::HybridLevel => format!("{} hybrid level", value(1.0)),
+        FixedSurfaceTypes::DepthBelowLandSurface => format!("{} m below ground", value(1.0)),
+        FixedSurfaceTypes::DepthBelowSeaLevel => format!("{} m below sea level", value(1.0)),
+        FixedSurfaceTypes::OrderedSequence => format!("{} in sequence", value(1.0)),
+        surface_type => match first {
+            Some(_) => format!("{} {}", value(1.0), surface_type),
+            None => surface_type.to_string(),
+        },
+    }
+}
+
+fn time_unit_description(time_unit: TimeUnit) -> &'static str {
+    match time_unit {
+        TimeUnit::Minute => "min",
+        TimeUnit::Hour => "hour",
+        TimeUnit::Day => "day",
+        TimeUnit::Month => "month",
+        TimeUnit::Year => "year",
+        TimeUnit::Seconds => "sec",
+        _ => "unit",
+    }
+}
+
+pub(crate) fn forecast_description(template: &HorizontalAnalysisForecastTemplate) -> String {
+    let time_unit = template.time_unit();
+    let forecast_time = template.forecast_time();
+
+    let process = match template.statistical_process() {
+        Some(StatisticalProcess::Average) => "ave",
+        Some(StatisticalProcess::Accumulation) => "acc",
+        Some(StatisticalProcess::Maximum) => "max",
+        Some(StatisticalProcess::Minimum) => "min",
+        Some(_) => "proc",
+        None if forecast_time == 0 => return "anl".into(),
+        None => return format!("{} {} fcst", forecast_time, time_unit_description(time_unit)),
+    };
+
+    // The forecast time marks the start of the range, its end is reported in the same unit
+    let (range_unit, range_length) = template.time_range().unwrap_or((time_unit, 0));
+    let range_seconds = range_unit.duration(range_length as i64).num_seconds();
+    let unit_seconds = time_unit.duration(1).num_seconds().max(1);
+    let end = forecast_time as i64 + range_seconds / unit_seconds;
+
+    format!("{}-{} {} {} fcst", forecast_time, end, time_unit_description(time_unit), process)
+}
+
+fn ensemble_description(template: &HorizontalAnalysisForecastTemplate) -> Option<String> {
+    let (forecast_type, perturbation_number, _) = template.ensemble_member()?;
+    let description = match forecast_type {
+        EnsembleForecastType::HighResolutionControl => "ENS=hi-res ctl".into(),
+        EnsembleForecastType::LowResolutionControl => "ENS=low-res ctl".into(),
+        EnsembleForecastType::NegativelyPerturbed => format!("ENS=-{}", perturbation_number),
+        EnsembleForecastType::PositivelyPerturbed => format!("ENS=+{}", perturbation_number),
+        _ => format!("ENS={}", perturbation_number),
+    };
+
+    Some(description)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INVENTORY: &str = "1:0:d=2021030400:PRMSL:mean sea level:anl:
+2:233264:d=2021030400:CLWMR:1 hybrid level:anl:
+3:366184:d=2021030400:UGRD:10 m above ground:6 hour fcst:
+3.2:366184:d=2021030400:VGRD:10 m above ground:6 hour fcst:
+4:600000:d=2021030400:APCP:surface:0-6 hour acc fcst:ENS=+4
+";
+
+    #[test]
+    fn parse_inventory() {
+        let records = parse_idx(INVENTORY).unwrap();
+        assert_eq!(records.len(), 5);
+        assert_eq!(records[1].offset, 233264);
+        assert_eq!(records[1].variable, "CLWMR");
+        assert_eq!(records[1].level, "1 hybrid level");
+        assert_eq!(records[0].reference_date, Utc.with_ymd_and_hms(2021, 3, 4, 0, 0, 0).unwrap());
+        assert_eq!(records[3].message_number, "3.2");
+        assert_eq!(records[4].forecast, "0-6 hour acc fcst");
+        assert_eq!(records[4].extra, vec!["ENS=+4".to_string()]);
+
+        assert!(IdxRecord::parse("1:abc:d=2021030400:PRMSL:mean sea level:anl:").is_err());
+        assert!(IdxRecord::parse("1:0:d=20210304").is_err());
+    }
+
+    #[test]
+    fn inventory_byte_ranges() {
+        let records = parse_idx(INVENTORY).unwrap();
+
+        let ranges = byte_ranges(&records, |r| r.variable == "VGRD");
+        assert_eq!(ranges, vec![ByteRange { start: 366184, end: Some(600000) }]);
+        assert_eq!(ranges[0].http_range(), "bytes=366184-599999");
+
+        let ranges = byte_ranges(&records, |r| r.variable != "CLWMR");
+        assert_eq!(
+            ranges,
+            vec![
+                ByteRange { start: 0, end: Some(233264) },
+                ByteRange { start: 366184, end: None },
+            ]
+        );
+        assert_eq!(ranges[1].http_range(), "bytes=366184-");
+    }
+
+    #[test]
+    fn describe_inventory_lines() {
+        use crate::encoding::{LatLngGrid, MessageBuilder};
+        use crate::sections::indicator::Discipline;
+        use chrono::Duration;
+
+        let reference_date = Utc.with_ymd_and_hms(2021, 3, 4, 0, 0, 0).unwrap();
+        let build = |builder: MessageBuilder| {
+            builder
+                .reference_date(reference_date)
+                .grid(LatLngGrid {
+                    start: (30.0, -80.0),
+                    end: (30.5, -79.5),
+                    shape: (2, 2),
+                })
+                .data(vec![1.0, 2.0, 3.0, 4.0])
+                .build()
+                .unwrap()
+        };
+
+        let raw = build(
+            MessageBuilder::new(Discipline::Meteorological, 0, 0)
+                .forecast_date(reference_date + Duration::hours(6))
+                .fixed_surface(FixedSurfaceTypes::SpecifiedHeightLevelAboveGround, 2.0),
+        );
+        let message = Message::parse(&raw, 0).unwrap();
+        assert_eq!(inventory_line(1, 0, &message).unwrap(), "1:0:d=2021030400:TMP:2 m above ground:6 hour fcst:");
+
+        let raw = build(
+            MessageBuilder::new(Discipline::Meteorological, 0, 0)
+                .fixed_surface(FixedSurfaceTypes::IsobaricSurface, 50000.0),
+        );
+        let message = Message::parse(&raw, 0).unwrap();
+        assert_eq!(inventory_line(2, 100, &message).unwrap(), "2:100:d=2021030400:TMP:500 mb:anl:");
+
+        let raw = build(
+            MessageBuilder::new(Discipline::Meteorological, 1, 8)
+                .forecast_date(reference_date + Duration::hours(12))
+                .ensemble_member(EnsembleForecastType::PositivelyPerturbed, 4, 30)
+                .statistical_process(StatisticalProcess::Accumulation, Duration::hours(6)),
+        );
+        let message = Message::parse(&raw, 0).unwrap();
+        let line = inventory_line(3, 200, &message).unwrap();
+        assert_eq!(line, "3:200:d=2021030400:APCP:surface:6-12 hour acc fcst:ENS=+4:");
+
+        let record = IdxRecord::parse(&line).unwrap();
+        assert_eq!(record.forecast, "6-12 hour acc fcst");
+        assert_eq!(record.extra, vec!["ENS=+4".to_string()]);
+    }
+}
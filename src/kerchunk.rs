@@ -0,0 +1,83 @@
+use crate::cube::Cube;
+use crate::error::GribError;
+use crate::message::Message;
+use crate::zarr::{chunk_keys, json_string, StoreMetadata};
+use std::collections::BTreeSet;
+
+// A kerchunk reference set (version 1) that opens the GRIB file at the url as the Zarr store
+// write_zarr would write, without converting any data. Metadata and coordinates are inlined and
+// every data chunk points at the byte range of the message holding it, which kerchunk's grib
+// codec decodes. The offsets are those of the messages in the data they were parsed from, so
+// the messages should be parsed from the whole file.
+pub fn kerchunk_references(messages: &[Message], url: &str) -> Result<String, GribError> {
+    let cube = Cube::new(messages)?;
+
+    // The codec hands back the values in the order they are stored, which has to be the rows
+    // of the chunk, so grids scanned by column or in alternating rows can not be referenced
+    let scanning_mode = messages[0].grid_template()?.scanning_mode();
+    if scanning_mode & 0x30 != 0 {
+        return Err(GribError::InvalidData("Only grids stored in rows can be referenced".into()));
+    }
+    let mut offsets = BTreeSet::new();
+    if !messages.iter().all(|message| offsets.insert(message.offset())) {
+        return Err(GribError::InvalidData("Messages holding several fields can not be referenced field by field".into()));
+    }
+
+    let store = StoreMetadata::new(&cube, |variable| {
+        format!(
+            r#""compressor":null,"filters":[{{"id":"grib","var":{},"dtype":"float32"}}]"#,
+            json_string(&variable.name)
+        )
+    })?;
+
+    let mut references = Vec::new();
+    for (key, document) in &store.documents {
+        references.push(format!("{}:{}", json_string(key), json_string(document)));
+    }
+    references.push(format!("{}:{}", json_string(".zmetadata"), json_string(&store.consolidated())));
+    for (key, chunk) in &store.coordinates {
+        references.push(format!("{}:{}", json_string(key), json_string(&format!("base64:{}", base64(chunk)))));
+    }
+
+    let url = json_string(url);
+    for variable in &cube.variables {
+        for (key, message) in chunk_keys(&cube, variable) {
+            references.push(format!("{}:[{},{},{}]", json_string(&key), url, message.offset(), message.len()));
+        }
+    }
+
+    Ok(format!(r#"{{"version":1,"refs":{{{}}}}}"#, references.join(",")))
+}
+
+// Standard base64 with padding, which kerchunk reads inlined binary chunks as
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for group in bytes.chunks(3) {
+        let bits = group.iter().enumerate().fold(0u32, |bits, (k, byte)| bits | (*byte as u32) << (16 - 8 * k));
+        for k in 0..4 {
+            if k <= group.len() {
+                encoded.push(ALPHABET[(bits >> (18 - 6 * k) & 0x3F) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::base64;
+
+    #[test]
+    fn encode_base64() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+        assert_eq!(base64(&[0xFF, 0xEF]), "/+8=");
+    }
+}
@@ -5,6 +5,47 @@ extern crate gribberish_macros;
 mod macros;
 
 mod utils;
+pub mod error;
 pub mod sections;
 pub mod templates;
 pub mod message;
+pub mod encoding;
+pub mod reader;
+pub mod idx;
+pub mod report;
+pub mod location_index;
+pub mod regrid;
+pub mod derive;
+pub mod units;
+pub mod polygon;
+pub mod contour;
+pub mod series;
+pub mod ensemble;
+pub mod export;
+#[cfg(feature = "proj")]
+pub mod crs;
+#[cfg(feature = "netcdf")]
+pub mod netcdf;
+#[cfg(feature = "geotiff")]
+pub mod geotiff;
+#[cfg(feature = "parquet")]
+pub mod parquet;
+#[cfg(feature = "zarr")]
+pub mod zarr;
+#[cfg(feature = "kerchunk")]
+pub mod kerchunk;
+#[cfg(feature = "render")]
+pub mod render;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(any(feature = "netcdf", feature = "zarr"))]
+mod cube;
+pub mod winds;
+#[cfg(feature = "async")]
+pub mod async_reader;
+#[cfg(feature = "http")]
+pub mod http;
+#[cfg(feature = "grib1")]
+pub mod grib1;
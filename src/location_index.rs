@@ -0,0 +1,416 @@
+use crate::error::GribError;
+use crate::templates::grid::{scanned_index, GridDefinitionTemplate};
+use std::vec::Vec;
+
+// Index units a location may sit past the first or last row or column and still belong to it
+const EDGE_TOLERANCE: f64 = 1e-6;
+
+// How a value at a location is made from the grid points around it
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InterpolationMethod {
+    // The value of the single nearest grid point
+    Nearest,
+    // Weighs the four grid points of the cell holding the location
+    Bilinear,
+    // Catmull-Rom weights over the sixteen grid points around the cell, reproducing curvature
+    // that bilinear weights flatten
+    Bicubic,
+}
+
+// Finds the grid point nearest to a location. Built once per grid so repeated point queries
+// only pay for the lookup, with plain arithmetic for regular grids and a kd tree otherwise.
+pub struct LocationIndex {
+    kind: IndexKind,
+}
+
+enum IndexKind {
+    Regular {
+        latitude: Axis,
+        longitude: Axis,
+        scanning_mode: u8,
+    },
+    Tree(KdTree),
+}
+
+impl LocationIndex {
+    pub fn from_grid(grid: &dyn GridDefinitionTemplate) -> LocationIndex {
+        if grid.is_regular() {
+            LocationIndex::regular(&grid.latitudes(), &grid.longitudes(), grid.scanning_mode())
+        } else {
+            LocationIndex::from_locations(grid.locations())
+        }
+    }
+
+    // A grid where every row shares the latitude and every column shares the longitude,
+    // both evenly spaced, with its values stored in the order of the scanning mode flags
+    pub fn regular(latitudes: &[f64], longitudes: &[f64], scanning_mode: u8) -> LocationIndex {
+        LocationIndex {
+            kind: IndexKind::Regular {
+                latitude: Axis::new(latitudes, false),
+                longitude: Axis::new(longitudes, true),
+                scanning_mode,
+            },
+        }
+    }
+
+    // A grid of arbitrary locations, such as a projected or curvilinear grid
+    pub fn from_locations(locations: Vec<(f64, f64)>) -> LocationIndex {
+        LocationIndex {
+            kind: IndexKind::Tree(KdTree::new(&locations)),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match &self.kind {
+            IndexKind::Regular { latitude, longitude, .. } => latitude.count * longitude.count,
+            IndexKind::Tree(tree) => tree.points.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // The index of the grid point nearest to the location. Regular grids reject locations
+    // outside of the grid, any other grid always answers with its nearest point.
+    pub fn nearest(&self, latitude: f64, longitude: f64) -> Result<usize, GribError> {
+        match &self.kind {
+            IndexKind::Regular {
+                latitude: latitudes,
+                longitude: longitudes,
+                scanning_mode,
+            } => {
+                let row = unwrap_or_return!(
+                    latitudes.position(latitude).map(|position| latitudes.index(position)),
+                    GribError::OutOfRange(format!("Latitude {} is out of range", latitude))
+                );
+
+                let column = longitudes.index(column_position(longitudes, longitude)?);
+                Ok(scanned_index(*scanning_mode, (latitudes.count, longitudes.count), column, row))
+            }
+            IndexKind::Tree(tree) => {
+                let nearest = unwrap_or_return!(
+                    tree.nearest(latitude, longitude),
+                    GribError::OutOfRange("Unable to find the nearest location in an empty grid".into())
+                );
+                Ok(nearest)
+            }
+        }
+    }
+
+    // The grid points to weigh for a value at the location, along with their weights which add
+    // up to one. Only regular grids have cells to interpolate across, any other grid always
+    // answers with its nearest point.
+    pub fn neighbors(&self, latitude: f64, longitude: f64, method: InterpolationMethod) -> Result<Vec<(usize, f64)>, GribError> {
+        let (latitudes, longitudes, scanning_mode) = match &self.kind {
+            IndexKind::Regular {
+                latitude,
+                longitude,
+                scanning_mode,
+            } if method != InterpolationMethod::Nearest => (latitude, longitude, *scanning_mode),
+            _ => return self.nearest(latitude, longitude).map(|index| vec![(index, 1.0)]),
+        };
+
+        let row = unwrap_or_return!(
+            latitudes.position(latitude),
+            GribError::OutOfRange(format!("Latitude {} is out of range", latitude))
+        );
+        let column = column_position(longitudes, longitude)?;
+
+        let rows = latitudes.weights(row, method);
+        let columns = longitudes.weights(column, method);
+        let shape = (latitudes.count, longitudes.count);
+        Ok(rows
+            .iter()
+            .flat_map(|(row, row_weight)| {
+                columns
+                    .iter()
+                    .map(move |(column, column_weight)| {
+                        (scanned_index(scanning_mode, shape, *column, *row), row_weight * column_weight)
+                    })
+            })
+            .collect())
+    }
+}
+
+// Weighs the values of the neighbors, leaving out those without data and scaling up the rest. None
+// when no neighbor with any weight has data.
+pub(crate) fn weighted_mean(neighbors: &[(usize, f64)], values: &[Option<f64>]) -> Option<f64> {
+    let (total, weight) = neighbors
+        .iter()
+        .zip(values.iter())
+        .filter_map(|((_, weight), value)| value.map(|value| (value * weight, *weight)))
+        .fold((0.0, 0.0), |(total, weights), (value, weight)| (total + value, weights + weight));
+
+    if weight.abs() < 1e-12 {
+        return None;
+    }
+    Some(total / weight)
+}
+
+// Longitudes may be given on either -180 to 180 or 0 to 360
+fn column_position(longitudes: &Axis, longitude: f64) -> Result<f64, GribError> {
+    let position = unwrap_or_return!(
+        longitudes
+            .position(longitude)
+            .or_else(|| longitudes.position(longitude + 360.0))
+            .or_else(|| longitudes.position(longitude - 360.0)),
+        GribError::OutOfRange(format!("Longitude {} is out of range", longitude))
+    );
+    Ok(position)
+}
+
+struct Axis {
+    start: f64,
+    step: f64,
+    count: usize,
+    // Longitudes circling the globe carry on from the last column back to the first
+    cyclic: bool,
+}
+
+impl Axis {
+    fn new(values: &[f64], may_wrap: bool) -> Axis {
+        let start = values.first().copied().unwrap_or(0.0);
+        let step = match values.len() {
+            0 | 1 => 0.0,
+            count => (values[count - 1] - start) / (count - 1) as f64,
+        };
+
+        Axis {
+            start,
+            step,
+            count: values.len(),
+            cyclic: may_wrap && values.len() > 1 && ((step * values.len() as f64).abs() - 360.0).abs() < EDGE_TOLERANCE,
+        }
+    }
+
+    // Where the value falls along the axis, counted in grid points from the first
+    fn position(&self, value: f64) -> Option<f64> {
+        let position = if self.step == 0.0 {
+            value - self.start
+        } else {
+            (value - self.start) / self.step
+        };
+
+        let last = if self.cyclic { self.count as f64 } else { self.count as f64 - 1.0 };
+        if self.count == 0 || !(-EDGE_TOLERANCE..=last + EDGE_TOLERANCE).contains(&position) {
+            return None;
+        }
+
+        Some(position.clamp(0.0, last))
+    }
+
+    fn index(&self, position: f64) -> usize {
+        let index = position.round() as usize;
+        if self.cyclic {
+            index % self.count
+        } else {
+            index.min(self.count - 1)
+        }
+    }
+
+    // Past the edges of the grid, the edge points stand in for the missing ones
+    fn neighbor(&self, index: isize) -> usize {
+        if self.cyclic {
+            index.rem_euclid(self.count as isize) as usize
+        } else {
+            index.clamp(0, self.count as isize - 1) as usize
+        }
+    }
+
+    fn weights(&self, position: f64, method: InterpolationMethod) -> Vec<(usize, f64)> {
+        let last_cell = if self.cyclic { self.count - 1 } else { self.count.saturating_sub(2) };
+        let cell = (position.floor() as usize).min(last_cell);
+        let t = position - cell as f64;
+        let cell = cell as isize;
+
+        match method {
+            _ if self.count == 1 => vec![(0, 1.0)],
+            InterpolationMethod::Nearest => vec![(self.index(position), 1.0)],
+            InterpolationMethod::Bilinear => vec![(self.neighbor(cell), 1.0 - t), (self.neighbor(cell + 1), t)],
+            InterpolationMethod::Bicubic => vec![
+                (self.neighbor(cell - 1), (-t * t * t + 2.0 * t * t - t) / 2.0),
+                (self.neighbor(cell), (3.0 * t * t * t - 5.0 * t * t + 2.0) / 2.0),
+                (self.neighbor(cell + 1), (-3.0 * t * t * t + 4.0 * t * t + t) / 2.0),
+                (self.neighbor(cell + 2), (t * t * t - t * t) / 2.0),
+            ],
+        }
+    }
+}
+
+// Points are stored as unit vectors so distances hold across the poles and the antimeridian.
+// The tree is implicit, each slice of the order is split around its middle entry.
+struct KdTree {
+    points: Vec<[f64; 3]>,
+    order: Vec<usize>,
+}
+
+impl KdTree {
+    fn new(locations: &[(f64, f64)]) -> KdTree {
+        let points = locations
+            .iter()
+            .map(|(latitude, longitude)| unit_vector(*latitude, *longitude))
+            .collect::<Vec<_>>();
+
+        let mut order = (0..points.len()).collect::<Vec<_>>();
+        build(&points, &mut order, 0);
+
+        KdTree { points, order }
+    }
+
+    fn nearest(&self, latitude: f64, longitude: f64) -> Option<usize> {
+        let target = unit_vector(latitude, longitude);
+        let mut best = None;
+        self.search(&target, &self.order, 0, &mut best);
+        best.map(|(index, _)| index)
+    }
+
+    fn search(&self, target: &[f64; 3], order: &[usize], depth: usize, best: &mut Option<(usize, f64)>) {
+        if order.is_empty() {
+            return;
+        }
+
+        let middle = order.len() / 2;
+        let point = &self.points[order[middle]];
+        let distance = squared_distance(point, target);
+        if best.is_none_or(|(_, best_distance)| distance < best_distance) {
+            *best = Some((order[middle], distance));
+        }
+
+        let axis = depth % 3;
+        let difference = target[axis] - point[axis];
+        let (near, far) = if difference < 0.0 {
+            (&order[..middle], &order[middle + 1..])
+        } else {
+            (&order[middle + 1..], &order[..middle])
+        };
+
+        self.search(target, near, depth + 1, best);
+        if best.is_none_or(|(_, best_distance)| difference * difference < best_distance) {
+            self.search(target, far, depth + 1, best);
+        }
+    }
+}
+
+fn build(points: &[[f64; 3]], order: &mut [usize], depth: usize) {
+    if order.len() <= 1 {
+        return;
+    }
+
+    let axis = depth % 3;
+    let middle = order.len() / 2;
+    order.select_nth_unstable_by(middle, |a, b| points[*a][axis].total_cmp(&points[*b][axis]));
+
+    let (before, after) = order.split_at_mut(middle);
+    build(points, before, depth + 1);
+    build(points, &mut after[1..], depth + 1);
+}
+
+fn unit_vector(latitude: f64, longitude: f64) -> [f64; 3] {
+    let (latitude, longitude) = (latitude.to_radians(), longitude.to_radians());
+    [
+        latitude.cos() * longitude.cos(),
+        latitude.cos() * longitude.sin(),
+        latitude.sin(),
+    ]
+}
+
+fn squared_distance(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    (0..3).map(|i| (a[i] - b[i]) * (a[i] - b[i])).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InterpolationMethod, LocationIndex};
+
+    #[test]
+    fn find_regular_grid_points() {
+        let latitudes = [50.0, 49.75, 49.5, 49.25];
+        let longitudes = [280.0, 280.5, 281.0];
+        let index = LocationIndex::regular(&latitudes, &longitudes, 0);
+        assert_eq!(index.len(), 12);
+
+        assert_eq!(index.nearest(50.0, 280.0), Ok(0));
+        assert_eq!(index.nearest(49.5, 281.0), Ok(8));
+        assert_eq!(index.nearest(49.3, 280.4), Ok(10));
+        assert_eq!(index.nearest(49.25, -79.0), Ok(11));
+        assert!(index.nearest(50.2, 280.0).is_err());
+        assert!(index.nearest(49.5, 281.2).is_err());
+    }
+
+    #[test]
+    fn find_scanned_grid_points() {
+        let latitudes = [50.0, 49.75, 49.5, 49.25];
+        let longitudes = [280.0, 280.5, 281.0];
+
+        // Scanned a column at a time
+        let index = LocationIndex::regular(&latitudes, &longitudes, 0x20);
+        assert_eq!(index.nearest(49.5, 281.0), Ok(10));
+        assert_eq!(index.nearest(49.75, 280.0), Ok(1));
+        let mut neighbors = index.neighbors(49.625, 280.125, InterpolationMethod::Bilinear).unwrap();
+        neighbors.sort_by_key(|(index, _)| *index);
+        assert_eq!(neighbors, vec![(1, 0.375), (2, 0.375), (5, 0.125), (6, 0.125)]);
+
+        // Every other row running east to west
+        let index = LocationIndex::regular(&latitudes, &longitudes, 0x10);
+        assert_eq!(index.nearest(49.5, 281.0), Ok(8));
+        assert_eq!(index.nearest(49.75, 280.0), Ok(5));
+    }
+
+    #[test]
+    fn weigh_surrounding_grid_points() {
+        let latitudes = [50.0, 49.75, 49.5, 49.25];
+        let longitudes = [280.0, 280.5, 281.0];
+        let index = LocationIndex::regular(&latitudes, &longitudes, 0);
+
+        let mut neighbors = index.neighbors(49.625, 280.125, InterpolationMethod::Bilinear).unwrap();
+        neighbors.sort_by_key(|(index, _)| *index);
+        assert_eq!(neighbors, vec![(3, 0.375), (4, 0.125), (6, 0.375), (7, 0.125)]);
+        assert_eq!(index.neighbors(49.3, 280.4, InterpolationMethod::Nearest).unwrap(), vec![(10, 1.0)]);
+
+        // The weights add up to one even where the edge points stand in for those past the grid
+        for method in [InterpolationMethod::Bilinear, InterpolationMethod::Bicubic] {
+            for (latitude, longitude) in [(49.9, 280.1), (49.25, 281.0), (49.6, -79.3)] {
+                let total = index.neighbors(latitude, longitude, method).unwrap().iter().map(|(_, w)| w).sum::<f64>();
+                assert!((total - 1.0).abs() < 1e-12);
+            }
+        }
+        assert!(index.neighbors(50.2, 280.0, InterpolationMethod::Bilinear).is_err());
+
+        // Global grids interpolate between the last column and the first
+        let longitudes = (0..8).map(|i| i as f64 * 45.0).collect::<Vec<_>>();
+        let index = LocationIndex::regular(&[10.0], &longitudes, 0);
+        let mut neighbors = index.neighbors(10.0, 337.5, InterpolationMethod::Bilinear).unwrap();
+        neighbors.sort_by_key(|(index, _)| *index);
+        assert_eq!(neighbors, vec![(0, 0.5), (7, 0.5)]);
+        assert_eq!(index.nearest(10.0, 359.0), Ok(0));
+    }
+
+    #[test]
+    fn find_curvilinear_grid_points() {
+        let locations = (0..20)
+            .flat_map(|row| (0..30).map(move |column| (row as f64 * 0.7 + column as f64 * 0.1, column as f64 * 11.0 - 160.0 + row as f64)))
+            .collect::<Vec<_>>();
+
+        let index = LocationIndex::from_locations(locations.clone());
+        assert_eq!(index.len(), locations.len());
+
+        for (expected, (latitude, longitude)) in locations.iter().enumerate() {
+            assert_eq!(index.nearest(*latitude, *longitude), Ok(expected));
+        }
+
+        // Matches a brute force search, including across the antimeridian
+        for target in [(5.0, 179.0), (-3.0, -170.0), (12.3, 45.6), (60.0, 0.0)] {
+            let distance = |(latitude, longitude): (f64, f64)| {
+                let (a, b) = (super::unit_vector(latitude, longitude), super::unit_vector(target.0, target.1));
+                super::squared_distance(&a, &b)
+            };
+            let expected = (0..locations.len())
+                .min_by(|a, b| distance(locations[*a]).total_cmp(&distance(locations[*b])))
+                .unwrap();
+            assert_eq!(index.nearest(target.0, target.1), Ok(expected));
+        }
+
+        assert!(LocationIndex::from_locations(Vec::new()).nearest(0.0, 0.0).is_err());
+    }
+}
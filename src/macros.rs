@@ -1,5 +1,3 @@
-#[macro_use]
-
 #[macro_export]
 macro_rules! unwrap_or_return {
     ( $e:expr, $err:expr  ) => {
@@ -9,3 +7,20 @@ macro_rules! unwrap_or_return {
         }
     }
 }
+
+// Enters a debug span until the end of the enclosing block when the tracing feature is enabled.
+// Subscribers that report span closes get the time spent inside it.
+macro_rules! trace_span {
+    ( $($args:tt)* ) => {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!($($args)*).entered();
+    }
+}
+
+// Emits a debug event when the tracing feature is enabled
+macro_rules! trace_event {
+    ( $($args:tt)* ) => {
+        #[cfg(feature = "tracing")]
+        tracing::debug!($($args)*);
+    }
+}
@@ -1,15 +1,336 @@
-use crate::{sections::{indicator::Discipline, section::Section}, templates::{product::ProductTemplate}};
+use crate::error::GribError;
+use crate::{sections::{grib_section::GribSection, identification::Center, indicator::{detect_edition, Discipline}, bitmap::{BitmapSection, Bitmask}, grid_definition::GridDefinitionSection, section::Section}, templates::product::{HorizontalAnalysisForecastTemplate, ProductTemplate}};
+use crate::encoding::{packing, DataRepresentationTemplateKind, SectionBuilder};
+use crate::encoding::sections::{BitmapSectionBuilder, DataRepresentationSectionBuilder, DataSectionBuilder, GridDefinitionSectionBuilder, LatLngGrid};
+use crate::templates::data_representation::DataRepresentationTemplate;
+use crate::templates::template::TemplateType;
+use crate::templates::grid::{longitude_difference, scanned_index, scanned_position, GridDefinitionTemplate, LatLngBounds, LatitudeLongitudeGridTemplate};
+use crate::encoding::simple_packing::PackingPrecision;
+use crate::location_index::{weighted_mean, InterpolationMethod, LocationIndex};
+use crate::units::UnitRegistry;
+use crate::utils::{read_i16_from_bytes, read_u32_from_bytes, read_u64_from_bytes};
 use chrono::{DateTime, Utc};
 use gribberish_types::Parameter;
+use std::ops::Range;
+use std::sync::OnceLock;
 use std::vec::Vec;
 
+type DataTemplate<'a> = Box<dyn DataRepresentationTemplate<f64> + 'a>;
+
+// The values of only the grid points that hold data, and the mask of which points those are
+pub struct MaskedData<'a> {
+    pub values: Vec<f64>,
+    pub mask: Bitmask<'a>,
+}
+
+impl<'a> MaskedData<'a> {
+    // Every grid point, with None where the mask marks a point as missing
+    pub fn iter(&self) -> impl Iterator<Item = Option<f64>> + '_ {
+        let mut values = self.values.iter();
+        self.mask
+            .iter()
+            .map(move |present| if present { values.next().copied() } else { None })
+    }
+
+    // Every grid point, with NaN where the mask marks a point as missing
+    pub fn expand(&self) -> Vec<f64> {
+        self.expand_with(MissingValue::Nan)
+    }
+
+    pub fn expand_with(&self, missing: MissingValue) -> Vec<f64> {
+        self.iter().map(|value| value.unwrap_or(missing.value())).collect()
+    }
+}
+
+// What stands in for points without data, whether the bitmap masks them or the packing marks
+// them as missing. Values are decoded with NaN and the policy applied as they are handed back,
+// data_options gives None instead.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MissingValue {
+    Nan,
+    Sentinel(f64),
+}
+
+impl MissingValue {
+    pub fn value(&self) -> f64 {
+        match self {
+            MissingValue::Nan => f64::NAN,
+            MissingValue::Sentinel(value) => *value,
+        }
+    }
+
+    pub fn apply(&self, value: f64) -> f64 {
+        if value.is_nan() {
+            self.value()
+        } else {
+            value
+        }
+    }
+}
+
+// The range and spread of a field's values, with points without data counted apart. Without
+// any values the rest are NaN.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FieldStatistics {
+    pub count: usize,
+    pub missing_count: usize,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    // The population standard deviation
+    pub std_dev: f64,
+}
+
+impl FieldStatistics {
+    // Takes a single pass over the values, with NaN counted as missing
+    pub fn from_values(values: impl IntoIterator<Item = f64>) -> FieldStatistics {
+        let (mut count, mut missing_count) = (0, 0);
+        let (mut min, mut max) = (f64::INFINITY, f64::NEG_INFINITY);
+        // Welford's running mean and sum of squared differences hold up over large fields
+        let (mut mean, mut squares) = (0.0, 0.0);
+
+        for value in values {
+            if value.is_nan() {
+                missing_count += 1;
+                continue;
+            }
+
+            count += 1;
+            min = min.min(value);
+            max = max.max(value);
+            let delta = value - mean;
+            mean += delta / count as f64;
+            squares += delta * (value - mean);
+        }
+
+        if count == 0 {
+            return FieldStatistics {
+                count,
+                missing_count,
+                min: f64::NAN,
+                max: f64::NAN,
+                mean: f64::NAN,
+                std_dev: f64::NAN,
+            };
+        }
+
+        FieldStatistics {
+            count,
+            missing_count,
+            min,
+            max,
+            mean,
+            std_dev: (squares / count as f64).sqrt(),
+        }
+    }
+}
+
+// Values cropped to a region, laid out in rows of latitude like the grid they came from
+#[derive(Clone, Debug, PartialEq)]
+pub struct RegionData {
+    pub values: Vec<f64>,
+    // The number of rows and columns, as (latitudes, longitudes)
+    pub shape: (usize, usize),
+    pub latitudes: Vec<f64>,
+    pub longitudes: Vec<f64>,
+}
+
+impl RegionData {
+    // The value at column i and row j of the region
+    pub fn value_at(&self, i: usize, j: usize) -> Option<f64> {
+        self.row(j)?.get(i).copied()
+    }
+
+    pub fn row(&self, j: usize) -> Option<&[f64]> {
+        let (row_count, column_count) = self.shape;
+        if j >= row_count {
+            return None;
+        }
+        Some(&self.values[j * column_count..(j + 1) * column_count])
+    }
+}
+
+// How downsample combines the points of each block
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Downsampling {
+    // Keeps the first point of each block, as a strided view of the grid
+    Stride,
+    // Averages the points of each block that hold data
+    Mean,
+}
+
+// Strict parsing bails on the first inconsistency, for producers checking what they write. Lenient
+// parsing passes over what it can and keeps note of it, for consumers of messy real world feeds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseOptions {
+    pub strict: bool,
+    // How the longitudes handed back by the message are written
+    pub longitudes: LongitudeConvention,
+}
+
+impl ParseOptions {
+    pub fn strict() -> ParseOptions {
+        ParseOptions {
+            strict: true,
+            longitudes: LongitudeConvention::AsEncoded,
+        }
+    }
+
+    pub fn lenient() -> ParseOptions {
+        ParseOptions {
+            strict: false,
+            longitudes: LongitudeConvention::AsEncoded,
+        }
+    }
+
+    pub fn with_longitudes(self, longitudes: LongitudeConvention) -> ParseOptions {
+        ParseOptions { longitudes, ..self }
+    }
+}
+
+// Grids mostly give longitudes from 0 to 360 while most consumers expect -180 to 180. Whichever
+// is chosen applies to every longitude a message hands back, lookups take either.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LongitudeConvention {
+    AsEncoded,
+    ZeroTo360,
+    Minus180To180,
+}
+
+impl LongitudeConvention {
+    pub fn apply(&self, longitude: f64) -> f64 {
+        match self {
+            LongitudeConvention::AsEncoded => longitude,
+            LongitudeConvention::ZeroTo360 => longitude.rem_euclid(360.0),
+            LongitudeConvention::Minus180To180 => (longitude + 180.0).rem_euclid(360.0) - 180.0,
+        }
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> ParseOptions {
+        ParseOptions::strict()
+    }
+}
+
+// Something wrong with a message, and where it is
+#[derive(Clone, Debug, PartialEq)]
+pub struct Finding {
+    // From the start of the data the message was parsed from
+    pub offset: u64,
+    // The section at fault, when the problem lies with a single section
+    pub section: Option<u8>,
+    pub error: GribError,
+}
+
+impl Finding {
+    // Errors that don't say where they are belong to the message as a whole
+    fn locate(error: GribError, message_offset: u64) -> Finding {
+        let (offset, section) = match &error {
+            GribError::InvalidSection { number, offset, .. } => (*offset, Some(*number)),
+            GribError::UnexpectedEof { offset } | GribError::InvalidMessage { offset, .. } => (*offset, None),
+            GribError::MissingSection(number) => (message_offset, Some(*number)),
+            _ => (message_offset, None),
+        };
+
+        Finding { offset, section, error }
+    }
+}
+
+// Sections come in order, and a message holding several fields repeats from the local use,
+// grid definition or product definition section after each data section
+fn follows(previous: u8, number: u8) -> bool {
+    matches!(
+        (previous, number),
+        (0, 1) | (1, 2) | (1, 3) | (2, 3) | (3, 4) | (4, 5) | (5, 6) | (5, 7) | (6, 7) | (7, 2) | (7, 3) | (7, 4) | (7, 8)
+    )
+}
+
+// The grid definition gives the number of points along with the template's rows and columns, the
+// bitmap has a bit for each point and the data representation counts the points that hold a value
+fn check_point_counts(grid: &GridDefinitionSection, bitmap: &BitmapSection, packed_count: usize) -> Result<(), GribError> {
+    let grid_count = grid.data_point_count();
+
+    // Quasi regular grids list the number of points in each row, so only plain rows and columns are checked
+    if grid.optional_defining_number() == 0 {
+        if let Some(template) = grid.grid_definition_template() {
+            if template.grid_point_count() != grid_count {
+                return Err(GribError::PointCountMismatch {
+                    section: 3,
+                    expected: template.grid_point_count(),
+                    found: grid_count,
+                });
+            }
+        }
+    }
+
+    let expected = if bitmap.has_bitmap() {
+        let bits = bitmap.raw_bitmap_data().len() * 8;
+        if bits < grid_count {
+            return Err(GribError::PointCountMismatch {
+                section: 6,
+                expected: grid_count,
+                found: bits,
+            });
+        }
+        bitmap.mask(grid_count).count()
+    } else if bitmap.bitmap_indicator() != 255 {
+        // Predefined bitmaps are not known here, so there is nothing to check them against
+        return Ok(());
+    } else {
+        grid_count
+    };
+
+    if packed_count != expected {
+        return Err(GribError::PointCountMismatch {
+            section: 5,
+            expected,
+            found: packed_count,
+        });
+    }
+
+    Ok(())
+}
+
+// Lays out values in the order they were scanned as rows of latitude and columns of longitude
+#[cfg(feature = "ndarray")]
+fn grid_array(values: Vec<f64>, grid: &dyn GridDefinitionTemplate) -> Result<ndarray::Array2<f64>, GribError> {
+    let shape = (grid.latitude_count(), grid.longitude_count());
+    let scanning_mode = grid.scanning_mode();
+
+    let column_major = scanning_mode & 0x20 != 0;
+    let mut array = if column_major {
+        ndarray::Array2::from_shape_vec((shape.1, shape.0), values).map(|array| array.reversed_axes())
+    } else {
+        ndarray::Array2::from_shape_vec(shape, values)
+    }
+    .map_err(|e| GribError::InvalidData(e.to_string()))?;
+
+    // Every other row, or column when scanned by column, runs the opposite way
+    if scanning_mode & 0x10 != 0 {
+        let axis = if column_major { ndarray::Axis(1) } else { ndarray::Axis(0) };
+        for mut line in array.axis_iter_mut(axis).skip(1).step_by(2) {
+            let reversed = line.iter().rev().copied().collect::<Vec<f64>>();
+            line.assign(&ndarray::Array1::from(reversed));
+        }
+    }
+
+    Ok(array.as_standard_layout().into_owned())
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MessageMetadata {
     pub discipline: Discipline,
+    pub center: Center,
+    pub subcenter: u16,
     pub reference_date: DateTime<Utc>,
     pub forecast_date: DateTime<Utc>,
     pub variable_name: String,
     pub variable_abbreviation: String,
-    pub region: ((f64, f64), (f64, f64)),
+    // The box around the grid points, in the longitude convention of the message. The eastern
+    // edge is less than the western one for grids crossing the antimeridian of the convention.
+    pub bounds: LatLngBounds,
     pub location_grid: (usize, usize),
     pub location_resolution: (f64, f64),
     pub units: String,
@@ -19,113 +340,222 @@ pub struct MessageMetadata {
 
 pub struct Message<'a> {
     pub sections: Vec<Section<'a>>,
+    data: &'a [u8],
+    // Where the message starts in the data it was parsed from
+    offset: usize,
+    // Where each section starts, relative to the start of the message
+    section_offsets: Vec<usize>,
+    // Templates are only read when an accessor first needs them, then shared by the others
+    product_template: OnceLock<Result<HorizontalAnalysisForecastTemplate<'a>, GribError>>,
+    parameter: OnceLock<Result<Parameter, GribError>>,
+    // Decoded data is kept for later calls, along with the bitmap lookup table for point queries
+    values: OnceLock<Result<Vec<f64>, GribError>>,
+    bitmap_ranks: OnceLock<Vec<usize>>,
+    location_index: OnceLock<Result<LocationIndex, GribError>>,
+    problems: Vec<GribError>,
+    longitudes: LongitudeConvention,
 }
 
 impl<'a> Message<'a> {
-    pub fn parse(data: &'a [u8], offset: usize) -> Result<Message<'a>, &'static str> {
+    pub fn parse(data: &'a [u8], offset: usize) -> Result<Message<'a>, GribError> {
+        Message::parse_with_options(data, offset, ParseOptions::default())
+    }
+
+    // Lenient parsing only fails when there is no indicator section to start from, anything else
+    // wrong with the message is kept in its problems
+    pub fn parse_with_options(data: &'a [u8], offset: usize, options: ParseOptions) -> Result<Message<'a>, GribError> {
+        match detect_edition(&data[offset.min(data.len())..]) {
+            Some(2) | None => {}
+            Some(edition) => return Err(GribError::UnsupportedEdition(edition)),
+        }
+        trace_span!("parse_message", offset);
+
         let mut sections: Vec<Section<'a>> = Vec::new();
+        let mut section_offsets = Vec::new();
+        let mut problems = Vec::new();
+        let mut flag = |problem: GribError| {
+            if options.strict {
+                return Err(problem);
+            }
+            trace_event!(%problem, "skipped problem");
+            problems.push(problem);
+            Ok(())
+        };
+
+        // A message cut short by the end of the data
+        let total_length = read_u64_from_bytes(data, offset + 8);
+        if total_length.is_some_and(|length| length > (data.len() - offset) as u64) {
+            flag(GribError::UnexpectedEof { offset: offset as u64 })?;
+        }
 
         let mut current_offset = 0;
         loop {
-            if let Some(section) = sections.last() {
-                if let Section::End(_) = section {
-                    break;
+            if let Some(Section::End(_)) = sections.last() {
+                break;
+            }
+
+            // Running into the end of the data or the next message means the end section is missing
+            let section_offset = offset + current_offset;
+            if section_offset >= data.len() {
+                break;
+            }
+
+            let next_section = match Section::from_data(data, section_offset) {
+                Ok(section) => section,
+                Err(e) if sections.is_empty() => return Err(e),
+                Err(e) => {
+                    flag(e)?;
+
+                    // Sections with an unknown number can be stepped over, as long as their length fits
+                    match read_u32_from_bytes(data, section_offset).map(|length| length as usize) {
+                        Some(length) if length >= 5 && section_offset + length <= data.len() => {
+                            current_offset += length;
+                            continue;
+                        }
+                        _ => break,
+                    }
                 }
+            };
+
+            match (&next_section, sections.is_empty()) {
+                (Section::Indicator(_), true) => {}
+                (_, true) => return Err(GribError::MissingSection(0)),
+                (Section::Indicator(_), false) => break,
+                _ => {}
             }
 
-            let next_section = Section::from_data(data, offset + current_offset)?;
+            trace_event!(section = next_section.number(), length = next_section.len(), "read section");
+            section_offsets.push(current_offset);
             current_offset += next_section.len();
             sections.push(next_section);
         }
 
-        Ok(Message { sections })
+        // The local use and bitmap sections may be left out, every other section is required
+        if !matches!(sections.last(), Some(Section::End(_))) {
+            flag(GribError::MissingSection(8))?;
+        }
+        for number in [1, 3, 4, 5, 7] {
+            if !sections.iter().any(|s| s.number() == number) {
+                flag(GribError::MissingSection(number))?;
+            }
+        }
+
+        let total_length = total_length.unwrap_or(0);
+        if total_length != current_offset as u64 {
+            flag(GribError::InvalidMessage {
+                offset: offset as u64,
+                reason: format!(
+                    "The indicator gives a length of {} bytes but the sections add up to {}",
+                    total_length, current_offset
+                ),
+            })?;
+        }
+
+        let data = &data[offset..offset + current_offset];
+        let mut message = Message::new(sections, data, offset, section_offsets, problems);
+        message.longitudes = options.longitudes;
+        Ok(message)
     }
 
+    fn new(sections: Vec<Section<'a>>, data: &'a [u8], offset: usize, section_offsets: Vec<usize>, problems: Vec<GribError>) -> Message<'a> {
+        Message {
+            sections,
+            data,
+            offset,
+            section_offsets,
+            problems,
+            product_template: OnceLock::new(),
+            parameter: OnceLock::new(),
+            values: OnceLock::new(),
+            bitmap_ranks: OnceLock::new(),
+            location_index: OnceLock::new(),
+            longitudes: LongitudeConvention::AsEncoded,
+        }
+    }
+
+    // Stops at the first message that is not valid, parse_all_with_errors carries on past it
     pub fn parse_all(data: &'a [u8]) -> Vec<Message<'a>> {
-        let mut messages = Vec::new();
-        let mut offset: usize = 0;
+        MessageIterator::new(data).map_while(Result::ok).collect()
+    }
 
-        while offset < data.len() {
-            if let Ok(message) = Message::parse(data, offset) {
-                offset += message.len();
-                messages.push(message);
-            } else {
-                break;
-            }
-        }
+    // Finds the message boundaries first, then parses the messages across threads. Like parse_all,
+    // this stops at the first message that is not valid.
+    #[cfg(feature = "rayon")]
+    pub fn par_parse_all(data: &'a [u8]) -> Vec<Message<'a>> {
+        use rayon::prelude::*;
 
-        messages
+        crate::reader::scan(data)
+            .par_iter()
+            .map(|offsets| Message::parse(data, offsets.offset as usize))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map_while(Result::ok)
+            .collect()
+    }
+
+    // Unpacks the data of every message across threads, in the order of the messages
+    #[cfg(feature = "rayon")]
+    pub fn par_decode(messages: &[Message<'a>]) -> Vec<Result<Vec<f64>, GribError>> {
+        use rayon::prelude::*;
+
+        messages.par_iter().map(|message| message.data()).collect()
+    }
+
+    // Parses every readable message, skipping over corrupted data, along with the skipped byte ranges
+    pub fn parse_all_resynchronizing(data: &'a [u8]) -> (Vec<Message<'a>>, Vec<Range<usize>>) {
+        let mut iterator = MessageIterator::resynchronizing(data);
+        let messages = iterator.by_ref().filter_map(Result::ok).collect();
+        (messages, iterator.skipped)
+    }
+
+    // Parses every readable message like parse_all_resynchronizing, along with the offset and error
+    // of every message that had to be skipped
+    pub fn parse_all_with_errors(data: &'a [u8]) -> (Vec<Message<'a>>, Vec<(usize, GribError)>) {
+        let mut iterator = MessageIterator::resynchronizing(data);
+        let messages = iterator.by_ref().filter_map(Result::ok).collect();
+        (messages, iterator.errors)
     }
 
     pub fn variable_names(messages: Vec<Message<'a>>) -> Vec<Option<String>> {
         Message::parameters(messages)
             .iter()
-            .map(|p| {
-                match p {
-                    Some(p) => Some(p.name.clone()),
-                    None => None,
-                }
-            })
+            .map(|p| p.as_ref().map(|p| p.name.clone()))
             .collect()
     }
 
     pub fn variable_abbrevs(messages: Vec<Message<'a>>) -> Vec<Option<String>> {
         Message::parameters(messages)
             .iter()
-            .map(|p| {
-                match p {
-                    Some(p) => Some(p.abbrev.clone()),
-                    None => None,
-                }
-            })
+            .map(|p| p.as_ref().map(|p| p.abbrev.clone()))
             .collect()
     }
 
     pub fn units(messages: Vec<Message<'a>>) -> Vec<Option<String>> {
         Message::parameters(messages)
             .iter()
-            .map(|p| {
-                match p {
-                    Some(p) => Some(p.unit.clone()),
-                    None => None,
-                }
-            })
+            .map(|p| p.as_ref().map(|p| p.unit.clone()))
             .collect()
     }
 
     pub fn parameters(messages: Vec<Message<'a>>) -> Vec<Option<Parameter>> {
         messages
             .iter()
-            .map(|m| m.parameter())
-            .map(|r| {
-                match r {
-                    Ok(parameter) => Some(parameter),
-                    Err(_) => None,
-                }
-            })
+            .map(|m| m.parameter().ok())
             .collect()
     }
 
     pub fn forecast_dates(messages: Vec<Message<'a>>) -> Vec<Option<DateTime<Utc>>> {
         messages
             .iter()
-            .map(|m| m.forecast_date())
-            .map(|r| {
-                match r {
-                    Ok(date) => Some(date),
-                    Err(_) => None,
-                }
-            })
+            .map(|m| m.forecast_date().ok())
             .collect()
     }
 
+    #[allow(clippy::len_without_is_empty)]
     pub fn len(&self) -> usize {
         match self.sections.first() {
-            Some(section) => match &section {
-                Section::Indicator(indicator) => indicator.total_length() as usize,
-                _ => 0,
-            },
-            None => 0,
+            Some(Section::Indicator(indicator)) => indicator.total_length() as usize,
+            _ => 0,
         }
     }
 
@@ -133,87 +563,276 @@ impl<'a> Message<'a> {
         self.sections.len()
     }
 
-    pub fn discipline(&self) -> Result<Discipline, String> {
-        match self.sections.first().unwrap() {
-            Section::Indicator(indicator) => Ok(indicator.discipline()),
-            _ => Err("Indicator section not found when reading discipline".into()),
+    // The exact bytes the message was parsed from. For a field of a message
+    // with several fields, this is the whole containing message.
+    pub fn raw_bytes(&self) -> &'a [u8] {
+        self.data
+    }
+
+    // The exact bytes of each section, in the order they appear in the message
+    pub fn section_bytes(&self) -> Vec<&'a [u8]> {
+        self.sections
+            .iter()
+            .zip(self.section_offsets.iter())
+            .map(|(section, offset)| &self.data[*offset..*offset + section.len()])
+            .collect()
+    }
+
+    // Sections 2 to 7 may repeat within a message to hold several fields. Each field is
+    // made up of its own data section and the latest of the other sections before it.
+    // The other accessors describe the first field of such a message.
+    pub fn fields(&self) -> Vec<Message<'a>> {
+        let mut fields = Vec::new();
+        let mut latest: [Option<usize>; 9] = [None; 9];
+        let mut defined_bitmap: Option<usize> = None;
+
+        for (i, section) in self.sections.iter().enumerate() {
+            match section {
+                Section::Bitmap(bitmap) if bitmap.uses_previous_bitmap() => {
+                    latest[6] = defined_bitmap.or(Some(i));
+                }
+                Section::Bitmap(_) => {
+                    defined_bitmap = Some(i);
+                    latest[6] = Some(i);
+                }
+                Section::Data(_) => {
+                    latest[7] = Some(i);
+                    let indices = latest[..8]
+                        .iter()
+                        .flatten()
+                        .cloned()
+                        .chain(std::iter::once(self.sections.len() - 1))
+                        .collect::<Vec<usize>>();
+                    fields.push(self.field(&indices));
+                }
+                _ => latest[section.number() as usize] = Some(i),
+            }
+        }
+
+        fields
+    }
+
+    fn field(&self, indices: &[usize]) -> Message<'a> {
+        let section_offsets = indices.iter().map(|i| self.section_offsets[*i]).collect::<Vec<usize>>();
+        // These sections were already parsed successfully from the same data
+        let sections = section_offsets
+            .iter()
+            .map(|offset| Section::from_data(self.data, *offset).unwrap())
+            .collect();
+
+        let mut field = Message::new(sections, self.data, self.offset, section_offsets, self.problems.clone());
+        field.longitudes = self.longitudes;
+        field
+    }
+
+    // Where the message starts in the data it was parsed from
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    // What lenient parsing found wrong with the message and passed over
+    pub fn problems(&self) -> &[GribError] {
+        &self.problems
+    }
+
+    // Everything wrong with the structure of the message. Along with what a lenient parse passed
+    // over, such as section lengths, a total length that does not match or a missing end marker,
+    // this checks the order of the sections and that the number of packed values fits the grid.
+    pub fn validate(&self) -> Vec<Finding> {
+        let start = self.offset as u64;
+        let mut findings = self
+            .problems
+            .iter()
+            .map(|problem| Finding::locate(problem.clone(), start))
+            .collect::<Vec<_>>();
+
+        let mut previous: Option<u8> = None;
+        let mut grid = None;
+        let mut bitmap: Option<(BitmapSection<'a>, u64)> = None;
+        let mut defined_bitmap: Option<(BitmapSection<'a>, u64)> = None;
+        let mut packed_count = None;
+
+        for (section, section_offset) in self.sections.iter().zip(self.section_offsets.iter()) {
+            let number = section.number();
+            let offset = start + *section_offset as u64;
+            if previous.is_some_and(|previous| !follows(previous, number)) {
+                findings.push(Finding {
+                    offset,
+                    section: Some(number),
+                    error: GribError::InvalidSection {
+                        number,
+                        offset,
+                        reason: "out of order".into(),
+                    },
+                });
+            }
+            previous = Some(number);
+
+            match section {
+                Section::GridDefinition(section) => grid = Some((section, offset)),
+                Section::DataRepresentation(data_representation) => {
+                    packed_count = Some((data_representation.data_point_count(), offset));
+                    bitmap = None;
+                }
+                Section::Bitmap(section) if section.uses_previous_bitmap() => bitmap = defined_bitmap,
+                Section::Bitmap(section) => {
+                    bitmap = Some((*section, offset));
+                    defined_bitmap = bitmap;
+                }
+                Section::Data(_) => {
+                    let (Some((grid, grid_offset)), Some((packed_count, packed_offset))) = (grid, packed_count) else {
+                        continue;
+                    };
+
+                    let (bitmap, bitmap_offset) = bitmap.unwrap_or((BitmapSection::absent(), packed_offset));
+                    if let Err(error) = check_point_counts(grid, &bitmap, packed_count) {
+                        let (section, offset) = match error {
+                            GribError::PointCountMismatch { section: 3, .. } => (3, grid_offset),
+                            GribError::PointCountMismatch { section: 6, .. } => (6, bitmap_offset),
+                            _ => (5, packed_offset),
+                        };
+                        findings.push(Finding { offset, section: Some(section), error });
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        findings
+    }
+
+    // Validates every message in the data, including those that can only be read leniently,
+    // along with any data between messages that could not be read at all
+    pub fn validate_all(data: &'a [u8]) -> Vec<Finding> {
+        let mut iterator = MessageIterator::with_options(data, ParseOptions::lenient());
+        let mut findings = iterator
+            .by_ref()
+            .flatten()
+            .flat_map(|message| message.validate())
+            .collect::<Vec<_>>();
+
+        findings.extend(iterator.skipped().iter().map(|range| Finding {
+            offset: range.start as u64,
+            section: None,
+            error: GribError::InvalidMessage {
+                offset: range.start as u64,
+                reason: format!("{} bytes could not be read as a GRIB message", range.len()),
+            },
+        }));
+
+        findings.sort_by_key(|finding| finding.offset);
+        findings
+    }
+
+    pub fn discipline(&self) -> Result<Discipline, GribError> {
+        match self.sections.first() {
+            Some(Section::Indicator(indicator)) => Ok(indicator.discipline()),
+            _ => Err(GribError::MissingSection(0)),
         }.clone()
     }
 
-    pub fn parameter(&self) -> Result<Parameter, String> {
+    pub fn product_template(&self) -> Result<HorizontalAnalysisForecastTemplate<'a>, GribError> {
+        self.product_template
+            .get_or_init(|| self.read_product_template())
+            .clone()
+    }
+
+    fn read_product_template(&self) -> Result<HorizontalAnalysisForecastTemplate<'a>, GribError> {
         let discipline = self.discipline()?;
 
+        let identification = unwrap_or_return!(
+            self.sections.iter().find_map(|s| match s {
+                Section::Identification(identification) => Some(identification),
+                _ => None,
+            }),
+            GribError::MissingSection(1)
+        );
+
         let product_definition = unwrap_or_return!(
             self.sections.iter().find_map(|s| match s {
                 Section::ProductDefinition(product_definition) => Some(product_definition),
                 _ => None,
             }),
-            "Product definition section not found when reading variable data".into()
+            GribError::MissingSection(4)
         );
 
         let product_template = unwrap_or_return!(
-            match product_definition.product_definition_template(discipline.clone() as u8) {
+            match product_definition.product_definition_template(u8::from(discipline), identification.center_id(), identification.local_table_version()) {
                 ProductTemplate::HorizontalAnalysisForecast(template) => Some(template),
                 _ => None,
             },
-            "Only HorizontalAnalysisForecast templates are supported at this time".into()
+            GribError::UnsupportedTemplate {
+                kind: TemplateType::Product,
+                number: product_definition.product_definition_template_number(),
+            }
         );
 
-        let parameter = unwrap_or_return!(
-            product_template.parameter(),
-            "This Product and Parameter is currently not supported".into()
-        );
+        Ok(product_template)
+    }
+
+    pub fn parameter(&self) -> Result<Parameter, GribError> {
+        self.parameter
+            .get_or_init(|| {
+                let product_template = self.product_template()?;
+
+                let parameter = unwrap_or_return!(
+                    product_template.parameter(),
+                    GribError::UnknownParameter {
+                        discipline: u8::from(self.discipline()?),
+                        category: product_template.category_value(),
+                        number: product_template.parameter_value(),
+                    }
+                );
 
-        Ok(parameter)
+                Ok(parameter)
+            })
+            .clone()
     }
 
-    pub fn variable_name(&self) -> Result<String, String> {
+    pub fn variable_name(&self) -> Result<String, GribError> {
         let parameter = self.parameter()?;
         Ok(parameter.name)
     }
 
-    pub fn variable_abbrev(&self) -> Result<String, String> {
+    pub fn variable_abbrev(&self) -> Result<String, GribError> {
         let parameter = self.parameter()?;
         Ok(parameter.abbrev)
     }
 
-    pub fn reference_date(&self) -> Result<DateTime<Utc>, String> {
-        let reference_date = unwrap_or_return!(
+    pub fn center(&self) -> Result<(Center, u16), GribError> {
+        let center = unwrap_or_return!(
             self.sections.iter().find_map(|s| match s {
-                Section::Identification(identification) => Some(identification.reference_date()),
+                Section::Identification(identification) => Some((identification.center(), identification.subcenter_id())),
                 _ => None,
             }),
-            "Identification section not found when reading reference date".into()
+            GribError::MissingSection(1)
         );
-        Ok(reference_date)
+        Ok(center)
     }
 
-    pub fn forecast_date(&self) -> Result<DateTime<Utc>, String> {
-        let discipline = self.discipline()?;
-
-        let product_definition = unwrap_or_return!(
+    pub fn reference_date(&self) -> Result<DateTime<Utc>, GribError> {
+        let reference_date = unwrap_or_return!(
             self.sections.iter().find_map(|s| match s {
-                Section::ProductDefinition(product_definition) => Some(product_definition),
+                Section::Identification(identification) => Some(identification.reference_date()),
                 _ => None,
             }),
-            "Product definition section not found when reading variable data".into()
+            GribError::MissingSection(1)
         );
+        Ok(reference_date)
+    }
 
-        let product_template = unwrap_or_return!(
-            match product_definition.product_definition_template(discipline.clone() as u8) {
-                ProductTemplate::HorizontalAnalysisForecast(template) => Some(template),
-                _ => None,
-            },
-            "Only HorizontalAnalysisForecast templates are supported at this time".into()
-        );
+    pub fn forecast_date(&self) -> Result<DateTime<Utc>, GribError> {
+        let product_template = self.product_template()?;
 
         let reference_date = self.reference_date()?;
         Ok(product_template.forecast_datetime(reference_date))
     }
 
-    pub fn metadata(&self) -> Result<MessageMetadata, String> {
+    pub fn metadata(&self) -> Result<MessageMetadata, GribError> {
         let discipline = self.discipline()?;
 
+        let (center, subcenter) = self.center()?;
+
         let reference_date = self.reference_date()?;
 
         let grid_definition = unwrap_or_return!(
@@ -221,19 +840,35 @@ impl<'a> Message<'a> {
                 Section::GridDefinition(grid_definition) => Some(grid_definition),
                 _ => None,
             }),
-            "Grid definition section not found when reading variable data".into()
+            GribError::MissingSection(3)
         );
 
         let grid_template = unwrap_or_return!(
             grid_definition.grid_definition_template(),
-            "Only latitude longitude templates supported at this time".into()
+            GribError::UnsupportedTemplate {
+                kind: TemplateType::Grid,
+                number: grid_definition.grid_definition_template_number(),
+            }
         );
-        let region = (grid_template.start(), grid_template.end());
+        let bounds = grid_template.bounds();
+        let bounds = LatLngBounds {
+            east: self.longitudes.apply(bounds.east),
+            west: self.longitudes.apply(bounds.west),
+            ..bounds
+        };
         let location_grid = (grid_template.latitude_count(), grid_template.longitude_count());
         let location_resolution = (grid_template.latitude_resolution(), grid_template.longitude_resolution());
 
-        let parameter = self.parameter()?;
-    
+        // Parameters missing from the tables, such as local use codes, are still described by their numbers
+        let parameter = match self.parameter() {
+            Err(GribError::UnknownParameter { discipline, category, number }) => Parameter {
+                name: format!("unknown parameter {} of category {} in discipline {}", number, category, discipline),
+                unit: "unknown".into(),
+                abbrev: format!("var{}_{}_{}", discipline, category, number),
+            },
+            parameter => parameter?,
+        };
+
         let forecast_date = self.forecast_date()?;
 
         let data_representation = unwrap_or_return!(
@@ -241,18 +876,20 @@ impl<'a> Message<'a> {
                 Section::DataRepresentation(data_representation) => Some(data_representation),
                 _ => None,
             }),
-            "Product definition section not found when reading variable data".into()
+            GribError::MissingSection(5)
         );
         let data_template_number = data_representation.data_representation_template_number();
         let data_point_count = grid_definition.data_point_count();
 
         Ok(MessageMetadata {
             discipline,
+            center,
+            subcenter,
             reference_date,
             forecast_date,
             variable_name: parameter.name,
             variable_abbreviation: parameter.abbrev,
-            region,
+            bounds,
             location_grid,
             location_resolution,
             units: parameter.unit,
@@ -261,118 +898,927 @@ impl<'a> Message<'a> {
         })
     }
 
-    pub fn data(&self) -> Result<Vec<f64>, String> {
+    pub fn data(&self) -> Result<Vec<f64>, GribError> {
+        self.data_ref().map(|values| values.to_vec())
+    }
+
+    // The values with missing points given by the policy rather than NaN
+    pub fn data_with(&self, missing: MissingValue) -> Result<Vec<f64>, GribError> {
+        Ok(self.data_ref()?.iter().map(|value| missing.apply(*value)).collect())
+    }
+
+    // The values with None for missing points
+    pub fn data_options(&self) -> Result<Vec<Option<f64>>, GribError> {
+        Ok(self.data_ref()?.iter().map(|value| Some(*value).filter(|v| !v.is_nan())).collect())
+    }
+
+    // The values are unpacked on the first call and borrowed from the message after that
+    pub fn data_ref(&self) -> Result<&[f64], GribError> {
+        self.values
+            .get_or_init(|| self.unpack_data(|template, data| template.unpack_all(data)))
+            .as_ref()
+            .map(|values| values.as_slice())
+            .map_err(|e| e.clone())
+    }
+
+    // The values converted from the parameter's unit, using the default conversions of the units
+    // module. Other registries convert the data from parameter().unit themselves.
+    pub fn data_in_units(&self, unit: &str) -> Result<Vec<f64>, GribError> {
+        let mut values = self.data()?;
+        UnitRegistry::default().convert(&mut values, &self.parameter()?.unit, unit)?;
+        Ok(values)
+    }
+
+    // The same values as data, with large grids unpacked in chunks across threads
+    #[cfg(feature = "rayon")]
+    pub fn par_data(&self) -> Result<Vec<f64>, GribError> {
+        self.values
+            .get_or_init(|| self.unpack_data(|template, data| template.par_unpack_all(data)))
+            .clone()
+    }
+
+    // The packed values without placeholders for missing points, for sparse grids such as
+    // ocean only fields where expanding to the full grid would mostly hold NaN
+    pub fn masked_data(&self) -> Result<MaskedData<'_>, GribError> {
+        let (raw_packed_data, data_representation_template) = self.packed_data()?;
+        let mut values = data_representation_template.unpack_all(raw_packed_data)?;
+
+        let bitmap_section = self.bitmap_section();
+        let grid_point_count = self.sections.iter().find_map(|s| match s {
+            Section::GridDefinition(grid_definition) => Some(grid_definition.data_point_count()),
+            _ => None,
+        });
+
+        let point_count = match (grid_point_count, bitmap_section.has_bitmap()) {
+            (Some(count), _) => count,
+            (None, true) => usize::MAX,
+            (None, false) => values.len(),
+        };
+        let mask = bitmap_section.mask(point_count);
+        values.truncate(mask.count());
+
+        Ok(MaskedData { values, mask })
+    }
+
+    // The same values as data_iter with missing points given by the policy
+    pub fn data_iter_with(&self, missing: MissingValue) -> Result<impl Iterator<Item = f64> + '_, GribError> {
+        Ok(self.data_iter()?.map(move |value| missing.apply(value)))
+    }
+
+    // Unpacks the values as they are read, with NaN for points the bitmap marks as missing,
+    // so the whole grid never has to be held at once
+    pub fn data_iter(&self) -> Result<impl Iterator<Item = f64> + '_, GribError> {
+        let (raw_packed_data, data_representation_template) = self.packed_data()?;
+        let values = data_representation_template.unpack_iter(raw_packed_data)?;
+
+        let bitmap_section = self.bitmap_section();
+        let grid_point_count = self.sections.iter().find_map(|s| match s {
+            Section::GridDefinition(grid_definition) => Some(grid_definition.data_point_count()),
+            _ => None,
+        });
+
+        Ok(bitmap_section.map_iter(values).take(grid_point_count.unwrap_or(usize::MAX)))
+    }
+
+    // Computed as the values are unpacked rather than from a decoded copy of the grid, unless
+    // the data has already been decoded
+    pub fn statistics(&self) -> Result<FieldStatistics, GribError> {
+        if let Some(Ok(values)) = self.values.get() {
+            return Ok(FieldStatistics::from_values(values.iter().copied()));
+        }
+        Ok(FieldStatistics::from_values(self.data_iter()?))
+    }
+
+    // Decodes into a buffer holding at least one value per grid point, so the same buffer
+    // can be reused across messages. Returns the number of values written.
+    pub fn data_into(&self, out: &mut [f64]) -> Result<usize, GribError> {
+        self.unpack_data_into(out, f64::NAN, |template, data, out| template.unpack_into(data, out))
+    }
+
+    pub fn data_into_with(&self, out: &mut [f64], missing: MissingValue) -> Result<usize, GribError> {
+        let count = self.data_into(out)?;
+        out[..count].iter_mut().for_each(|value| *value = missing.apply(*value));
+        Ok(count)
+    }
+
+    pub fn data_into_f32(&self, out: &mut [f32]) -> Result<usize, GribError> {
+        self.unpack_data_into(out, f32::NAN, |template, data, out| template.unpack_into_f32(data, out))
+    }
+
+    // The values in single precision, without holding a double precision copy of the grid
+    // for templates that unpack in place
+    pub fn data_f32(&self) -> Result<Vec<f32>, GribError> {
+        let grid_point_count = self.sections.iter().find_map(|s| match s {
+            Section::GridDefinition(grid_definition) => Some(grid_definition.data_point_count()),
+            _ => None,
+        });
+        let packed_count = self.sections.iter().find_map(|s| match s {
+            Section::DataRepresentation(data_representation) => Some(data_representation.data_point_count()),
+            _ => None,
+        });
+
+        let mut values = vec![0.0; grid_point_count.unwrap_or(0).max(packed_count.unwrap_or(0))];
+        let count = self.data_into_f32(&mut values)?;
+        values.truncate(count);
+        Ok(values)
+    }
+
+    fn unpack_data<F>(&self, unpack: F) -> Result<Vec<f64>, GribError>
+    where
+        F: Fn(&dyn DataRepresentationTemplate<f64>, &[u8]) -> Result<Vec<f64>, GribError>,
+    {
+        let (raw_packed_data, data_representation_template) = self.packed_data()?;
+        trace_span!("unpack_data", packed_length = raw_packed_data.len());
+
+        let scaled_unpacked_data = unpack(data_representation_template.as_ref(), raw_packed_data)?;
+
+        let bitmap_section = self.bitmap_section();
+
+        let mut mapped_scaled_data = bitmap_section.map_data(scaled_unpacked_data);
+
+        // The bitmap is padded to a whole byte, so drop anything past the last grid point
+        let grid_point_count = self.sections.iter().find_map(|s| match s {
+            Section::GridDefinition(grid_definition) => Some(grid_definition.data_point_count()),
+            _ => None,
+        });
+        if let Some(grid_point_count) = grid_point_count {
+            mapped_scaled_data.truncate(grid_point_count);
+        }
+
+        trace_event!(values = mapped_scaled_data.len(), "unpacked data");
+        Ok(mapped_scaled_data)
+    }
+
+    fn unpack_data_into<T, F>(&self, out: &mut [T], missing: T, unpack: F) -> Result<usize, GribError>
+    where
+        T: Copy,
+        F: Fn(&dyn DataRepresentationTemplate<f64>, &[u8], &mut [T]) -> Result<usize, GribError>,
+    {
+        let (raw_packed_data, data_representation_template) = self.packed_data()?;
+        trace_span!("unpack_data_into", packed_length = raw_packed_data.len(), buffer_length = out.len());
+        let packed_count = unpack(data_representation_template.as_ref(), raw_packed_data, out)?;
+
+        let bitmap_section = self.bitmap_section();
+        let grid_point_count = self.sections.iter().find_map(|s| match s {
+            Section::GridDefinition(grid_definition) => Some(grid_definition.data_point_count()),
+            _ => None,
+        });
+
+        bitmap_section.expand_into(out, packed_count, grid_point_count.unwrap_or(usize::MAX), missing)
+    }
+
+    fn packed_data(&self) -> Result<(&[u8], DataTemplate<'a>), GribError> {
+        self.check_point_counts()?;
+
         let data_section = unwrap_or_return!(
             self.sections.iter().find_map(|s| match s {
                 Section::Data(data_section) => Some(data_section),
                 _ => None,
             }),
-            "Data section not found when reading message data".into()
+            GribError::MissingSection(7)
         );
 
-        let raw_packed_data = data_section.raw_bit_data();
-        println!("data sectionln: {}", raw_packed_data.len());
-
         let data_representation_section = unwrap_or_return!(
             self.sections.iter().find_map(|s| match s {
                 Section::DataRepresentation(data_representation_section) =>
                     Some(data_representation_section),
                 _ => None,
             }),
-            "Data representation section not found when reading message data".into()
+            GribError::MissingSection(5)
         );
 
         let data_representation_template = unwrap_or_return!(
             data_representation_section.data_representation_template(),
-            "Failed to unpack the data representation template".into()
+            GribError::UnsupportedTemplate {
+                kind: TemplateType::DataRepresentation,
+                number: data_representation_section.data_representation_template_number(),
+            }
         );
 
-        let scaled_unpacked_data = data_representation_template
-            .unpack_all(raw_packed_data)?;
+        Ok((data_section.raw_data_array(), data_representation_template))
+    }
+
+    // Values are only unpacked once the sections agree on how many there are, so they can not end
+    // up at the wrong grid points
+    pub fn check_point_counts(&self) -> Result<(), GribError> {
+        let grid = self.sections.iter().find_map(|s| match s {
+            Section::GridDefinition(grid_definition) => Some(grid_definition),
+            _ => None,
+        });
+        let packed_count = self.sections.iter().find_map(|s| match s {
+            Section::DataRepresentation(data_representation) => Some(data_representation.data_point_count()),
+            _ => None,
+        });
 
-        let bitmap_section = unwrap_or_return!(
+        match (grid, packed_count) {
+            (Some(grid), Some(packed_count)) => check_point_counts(grid, &self.bitmap_section(), packed_count),
+            _ => Ok(()),
+        }
+    }
+
+    // The bitmap section is optional, without one every grid point holds a value
+    fn bitmap_section(&self) -> BitmapSection<'a> {
+        self.sections
+            .iter()
+            .find_map(|s| match s {
+                Section::Bitmap(bitmap_section) => Some(*bitmap_section),
+                _ => None,
+            })
+            .unwrap_or_else(BitmapSection::absent)
+    }
+
+    pub(crate) fn grid_template(&self) -> Result<Box<dyn GridDefinitionTemplate + 'a>, GribError> {
+        let grid_definition = unwrap_or_return!(
             self.sections.iter().find_map(|s| match s {
-                Section::Bitmap(bitmap_section) => Some(bitmap_section),
+                Section::GridDefinition(grid_definition) => Some(grid_definition),
                 _ => None,
             }),
-            "Bitmap section not found when reading message data".into()
+            GribError::MissingSection(3)
         );
 
-        let mapped_scaled_data = bitmap_section.map_data(scaled_unpacked_data);
-        Ok(mapped_scaled_data)
+        let grid_template = unwrap_or_return!(
+            grid_definition.grid_definition_template(),
+            GribError::UnsupportedTemplate {
+                kind: TemplateType::Grid,
+                number: grid_definition.grid_definition_template_number(),
+            }
+        );
+
+        Ok(grid_template)
     }
 
-    pub fn data_locations(&self) -> Result<Vec<(f64, f64)>, String> {
+    // The coordinate reference system of the grid, with transforms to and from grid space
+    #[cfg(feature = "proj")]
+    pub fn crs(&self) -> Result<crate::crs::GridCrs<'a>, GribError> {
         let grid_definition = unwrap_or_return!(
             self.sections.iter().find_map(|s| match s {
                 Section::GridDefinition(grid_definition) => Some(grid_definition),
                 _ => None,
             }),
-            "Grid definition section not found when reading variable data".into()
+            GribError::MissingSection(3)
         );
 
-        let grid_template = unwrap_or_return!(
-            grid_definition.grid_definition_template(),
-            "Only latitude longitude templates supported at this time".into()
-        );
+        grid_definition.crs()
+    }
+
+    pub fn data_locations(&self) -> Result<Vec<(f64, f64)>, GribError> {
+        Ok(self.locations_iter()?.collect())
+    }
+
+    // The locations of the grid points in the same order as data(), without holding them all
+    pub fn locations_iter(&self) -> Result<impl Iterator<Item = (f64, f64)>, GribError> {
+        let longitudes = self.longitudes;
+        Ok(self
+            .grid_template()?
+            .locations_iter()
+            .map(move |(latitude, longitude)| (latitude, longitudes.apply(longitude))))
+    }
+
+    // The corners of the cell around every grid point in the order the values are stored, running
+    // counter clockwise from the south west. Cells are moved whole to the longitude convention so
+    // none is split across the antimeridian.
+    pub fn cell_polygons(&self) -> Result<Vec<[(f64, f64); 4]>, GribError> {
+        let cells = self.grid_template()?.cell_corners();
+        Ok(cells
+            .into_iter()
+            .map(|corners| {
+                let center = corners.iter().map(|(_, longitude)| longitude).sum::<f64>() / 4.0;
+                let shift = self.longitudes.apply(center) - center;
+                corners.map(|(latitude, longitude)| (latitude, longitude + shift))
+            })
+            .collect())
+    }
+
+    // The latitude of each row of the grid, or of every grid point for projected and rotated grids
+    pub fn latitudes(&self) -> Result<Vec<f64>, GribError> {
+        Ok(self.grid_template()?.latitudes())
+    }
+
+    // The longitude of each column of the grid, or of every grid point for projected and rotated grids
+    pub fn longitudes(&self) -> Result<Vec<f64>, GribError> {
+        Ok(self.grid_template()?.longitudes().into_iter().map(|longitude| self.longitudes.apply(longitude)).collect())
+    }
+
+    // The number of rows and columns of the grid, as (nj, ni)
+    pub fn shape(&self) -> Result<(usize, usize), GribError> {
+        let grid_template = self.grid_template()?;
+        Ok((grid_template.latitude_count(), grid_template.longitude_count()))
+    }
+
+    // The value at column i and row j, with NaN where there is no data. Before the data is
+    // unpacked only the packed values up to the point are read where the packing allows it.
+    pub fn value_at(&self, i: usize, j: usize) -> Result<f64, GribError> {
+        let index = self.grid_index(i, j)?;
+        let values = self.data_at_grid_indices(&[Some(index)])?;
+        Ok(values[0].unwrap_or(f64::NAN))
+    }
+
+    // The values of row j, running the same way as longitudes()
+    pub fn row(&self, j: usize) -> Result<Vec<f64>, GribError> {
+        let (_, column_count) = self.shape()?;
+        let indices = (0..column_count)
+            .map(|i| self.grid_index(i, j).map(Some))
+            .collect::<Result<Vec<_>, GribError>>()?;
+        let values = self.data_at_grid_indices(&indices)?;
+        Ok(values.into_iter().map(|value| value.unwrap_or(f64::NAN)).collect())
+    }
+
+    // Where the point at column i and row j is stored, following the scanning mode
+    fn grid_index(&self, i: usize, j: usize) -> Result<usize, GribError> {
+        let grid_template = self.grid_template()?;
+        let shape = (grid_template.latitude_count(), grid_template.longitude_count());
+        if i >= shape.1 || j >= shape.0 {
+            return Err(GribError::OutOfRange(format!(
+                "Grid point ({}, {}) is outside of the {} by {} grid",
+                i, j, shape.1, shape.0
+            )));
+        }
+
+        Ok(scanned_index(grid_template.scanning_mode(), shape, i, j))
+    }
+
+    // Every grid point as (latitude, longitude, value) in the order the values are stored, with
+    // NaN where the bitmap marks a point as missing. Neither the values nor the locations are
+    // held all at once.
+    pub fn data_with_locations(&self) -> Result<impl Iterator<Item = (f64, f64, f64)> + '_, GribError> {
+        let grid_template = self.grid_template()?;
+        let values = self.data_iter()?;
+
+        let locations: Box<dyn Iterator<Item = (f64, f64)>> = if grid_template.is_regular() {
+            let (latitudes, longitudes) = (grid_template.latitudes(), self.longitudes()?);
+            let shape = (latitudes.len(), longitudes.len());
+            let scanning_mode = grid_template.scanning_mode();
+            Box::new((0..shape.0 * shape.1).map(move |index| {
+                let (i, j) = scanned_position(scanning_mode, shape, index);
+                (latitudes[j], longitudes[i])
+            }))
+        } else {
+            Box::new(self.locations_iter()?)
+        };
+
+        Ok(locations.zip(values).map(|((latitude, longitude), value)| (latitude, longitude, value)))
+    }
+
+    // The values as rows of latitude and columns of longitude, shaped (nj, ni). Grids scanned a
+    // column at a time or in alternating directions are put back in order, so every row runs the
+    // same way as latitudes() and longitudes().
+    #[cfg(feature = "ndarray")]
+    pub fn data_array(&self) -> Result<ndarray::Array2<f64>, GribError> {
+        let grid_template = self.grid_template()?;
+        grid_array(self.data()?, grid_template.as_ref())
+    }
 
-        Ok(grid_template.locations())
+    // The latitude and longitude of every grid point, shaped like data_array()
+    #[cfg(feature = "ndarray")]
+    pub fn location_arrays(&self) -> Result<(ndarray::Array2<f64>, ndarray::Array2<f64>), GribError> {
+        let grid_template = self.grid_template()?;
+        let (latitudes, longitudes) = self.locations_iter()?.unzip::<f64, f64, Vec<f64>, Vec<f64>>();
+        Ok((grid_array(latitudes, grid_template.as_ref())?, grid_array(longitudes, grid_template.as_ref())?))
     }
 
-    pub fn data_at_location(&self, location: &(f64, f64)) -> Result<f64, String> {
+    // Built on the first point query and shared by the ones after it
+    pub fn location_index(&self) -> Result<&LocationIndex, GribError> {
+        self.location_index
+            .get_or_init(|| self.grid_template().map(|grid_template| LocationIndex::from_grid(grid_template.as_ref())))
+            .as_ref()
+            .map_err(|e| e.clone())
+    }
+
+    // Answers many points while decoding the packed values once, with None for locations
+    // outside of the grid or without data
+    pub fn data_at_locations(&self, locations: &[(f64, f64)]) -> Result<Vec<Option<f64>>, GribError> {
+        let location_index = self.location_index()?;
+        let grid_indices = locations
+            .iter()
+            .map(|location| location_index.nearest(location.0, location.1).ok())
+            .collect::<Vec<_>>();
+
+        self.data_at_grid_indices(&grid_indices)
+    }
+
+    // Weighs the grid points around the location. Points without data are left out and the
+    // weights of the rest scaled up to make up for them, with bicubic weights falling back to
+    // bilinear ones since they do not hold up with points missing.
+    pub fn data_at_location_interpolated(&self, location: &(f64, f64), method: InterpolationMethod) -> Result<f64, GribError> {
+        let location_index = self.location_index()?;
+        let neighbors = location_index.neighbors(location.0, location.1, method)?;
+        let values = self.data_at_grid_indices(&neighbors.iter().map(|(index, _)| Some(*index)).collect::<Vec<_>>())?;
+
+        if method == InterpolationMethod::Bicubic && values.iter().any(Option::is_none) {
+            return self.data_at_location_interpolated(location, InterpolationMethod::Bilinear);
+        }
+
+        match weighted_mean(&neighbors, &values) {
+            Some(value) => Ok(value),
+            None => Err(GribError::MissingData(location_index.nearest(location.0, location.1)?)),
+        }
+    }
+
+    // The values at the grid points, None for points without data
+    fn data_at_grid_indices(&self, grid_indices: &[Option<usize>]) -> Result<Vec<Option<f64>>, GribError> {
+        if let Some(values) = self.values.get() {
+            let values = values.as_ref().map_err(|e| e.clone())?;
+            return Ok(grid_indices
+                .iter()
+                .map(|index| index.and_then(|i| values.get(i).copied()).filter(|v| !v.is_nan()))
+                .collect());
+        }
+
+        let bitmap_section = self.bitmap_section();
+        let ranks = self.bitmap_ranks.get_or_init(|| bitmap_section.rank_table());
+        let data_indices = grid_indices
+            .iter()
+            .map(|index| index.and_then(|i| bitmap_section.data_index_with_ranks(i, ranks)))
+            .collect::<Vec<_>>();
+
+        // Only the span of packed values that covers every requested point is unpacked
+        let (first, last) = match (data_indices.iter().flatten().min(), data_indices.iter().flatten().max()) {
+            (Some(first), Some(last)) => (*first, *last),
+            _ => return Ok(vec![None; grid_indices.len()]),
+        };
+
+        let (raw_packed_data, data_representation_template) = self.packed_data()?;
+        let values = data_representation_template.unpack_range(raw_packed_data, first..last + 1)?;
+
+        Ok(data_indices
+            .iter()
+            .map(|index| index.and_then(|i| values.get(i - first).copied()).filter(|v| !v.is_nan()))
+            .collect())
+    }
+
+    pub fn data_at_location(&self, location: &(f64, f64)) -> Result<f64, GribError> {
+        let location_index = self.location_index()?.nearest(location.0, location.1)?;
+
+        match self.data_at_grid_indices(&[Some(location_index)])?[0] {
+            Some(value) => Ok(value),
+            None => Err(GribError::MissingData(location_index)),
+        }
+    }
+
+    // Every field of the message is unpacked and packed again with the target packing, leaving
+    // the other sections as they are
+    pub fn repack(&self, target: DataRepresentationTemplateKind) -> Result<Vec<u8>, GribError> {
+        let packed_fields = self
+            .fields()
+            .iter()
+            .map(|field| field.pack_field(target))
+            .collect::<Result<Vec<_>, GribError>>()?;
+
+        // Sections 5 and 7 are repeated together, once for every field
+        let mut fields = packed_fields.iter();
+        let mut field = None;
+        let mut message = Vec::with_capacity(self.len());
+        for section in &self.sections {
+            match section {
+                Section::DataRepresentation(_) => {
+                    field = fields.next();
+                    let (value_count, packed) = unwrap_or_return!(field, GribError::MissingSection(7));
+                    message.extend(DataRepresentationSectionBuilder::from_packed(*value_count as u32, packed).to_bytes()?);
+                }
+                Section::Data(_) => {
+                    let (_, packed) = unwrap_or_return!(field.take(), GribError::MissingSection(5));
+                    message.extend(DataSectionBuilder { data: packed.data.clone() }.to_bytes()?);
+                }
+                _ => message.extend_from_slice(section.data()),
+            }
+        }
+
+        let total_length = message.len() as u64;
+        message[8..16].copy_from_slice(&total_length.to_be_bytes());
+
+        Ok(message)
+    }
+
+    // The values of a single field packed with the target packing, along with how many were packed
+    fn pack_field(&self, target: DataRepresentationTemplateKind) -> Result<(usize, packing::PackedData), GribError> {
+        let data = self.data()?;
+
         let grid_definition = unwrap_or_return!(
             self.sections.iter().find_map(|s| match s {
                 Section::GridDefinition(grid_definition) => Some(grid_definition),
                 _ => None,
             }),
-            "Grid definition section not found when reading variable data".into()
+            GribError::MissingSection(3)
         );
 
         let grid_template = unwrap_or_return!(
             grid_definition.grid_definition_template(),
-            "Only latitude longitude templates supported at this time".into()
+            GribError::UnsupportedTemplate {
+                kind: TemplateType::Grid,
+                number: grid_definition.grid_definition_template_number(),
+            }
         );
 
-        let location_index = grid_template.index_for_location(location.0, location.1)?;
+        let has_bitmap = self.sections.iter().any(|s| match s {
+            Section::Bitmap(bitmap_section) => bitmap_section.has_bitmap(),
+            _ => false,
+        });
 
-        let data_section = unwrap_or_return!(
+        // The bitmap is copied as is, so only the present values are packed
+        let (values, shape) = if has_bitmap {
+            let values = data.into_iter().filter(|v| !v.is_nan()).collect::<Vec<f64>>();
+            let shape = (1, values.len());
+            (values, shape)
+        } else {
+            (data, (grid_template.latitude_count(), grid_template.longitude_count()))
+        };
+
+        let precision = self.packing_precision()?;
+        let packed = packing::pack(&values, shape, target, precision)?;
+        Ok((values.len(), packed))
+    }
+
+    // Crops to the latitude longitude box between the given (latitude, longitude) corners
+    pub fn crop(&self, start: (f64, f64), end: (f64, f64)) -> Result<Vec<u8>, GribError> {
+        let (grid_template, latitude_indices, longitude_indices) = self.region_indices(start, end)?;
+        if longitude_indices.windows(2).any(|w| w[1] != w[0] + 1) {
+            return Err(GribError::OutOfRange("Crop regions crossing the edge of the grid are not supported".into()));
+        }
+
+        // The crop is written a row at a time whatever order the grid is scanned in, so the
+        // rebuilt grid definition leaves the column and alternating row flags clear
+        let scanning_mode = grid_template.scanning_mode_flags();
+
+        let latitudes = grid_template.latitudes();
+        let longitudes = grid_template.longitudes();
+        let data = self.data()?;
+        let shape = (grid_template.latitude_count(), grid_template.longitude_count());
+        let values = latitude_indices
+            .iter()
+            .flat_map(|j| longitude_indices.iter().map(move |i| scanned_index(scanning_mode, shape, *i, *j)))
+            .map(|index| data[index])
+            .collect::<Vec<f64>>();
+
+        let first_latitude = latitudes[latitude_indices[0]];
+        let last_latitude = latitudes[latitude_indices[latitude_indices.len() - 1]];
+        let first_longitude = longitudes[longitude_indices[0]];
+        let last_longitude = longitudes[longitude_indices[longitude_indices.len() - 1]];
+
+        let mut grid_definition_builder = GridDefinitionSectionBuilder::new(LatLngGrid {
+            start: (first_latitude, first_longitude),
+            end: (last_latitude, last_longitude),
+            shape: (latitude_indices.len(), longitude_indices.len()),
+        });
+        grid_definition_builder.earth_shape = grid_template.earth_shape();
+
+        let (values, bitmap, shape) = if values.iter().any(|v| v.is_nan()) {
+            let bitmap = values.iter().map(|v| !v.is_nan()).collect::<Vec<bool>>();
+            let values = values.into_iter().filter(|v| !v.is_nan()).collect::<Vec<f64>>();
+            let shape = (1, values.len());
+            (values, Some(bitmap), shape)
+        } else {
+            (values, None, (latitude_indices.len(), longitude_indices.len()))
+        };
+
+        let data_representation_section = unwrap_or_return!(
             self.sections.iter().find_map(|s| match s {
-                Section::Data(data_section) => Some(data_section),
+                Section::DataRepresentation(data_representation_section) => Some(data_representation_section),
                 _ => None,
             }),
-            "Data section not found when reading message data".into()
+            GribError::MissingSection(5)
         );
+        let target = DataRepresentationTemplateKind::from_template_number(data_representation_section.data_representation_template_number())
+            .unwrap_or(DataRepresentationTemplateKind::SimplePacking);
 
-        let data_representation_section = unwrap_or_return!(
+        let precision = self.packing_precision()?;
+        let packed = packing::pack(&values, shape, target, precision)?;
+
+        let mut message = Vec::new();
+        for section in &self.sections {
+            match section {
+                Section::GridDefinition(_) => message.extend(grid_definition_builder.to_bytes()?),
+                Section::DataRepresentation(_) => {
+                    message.extend(DataRepresentationSectionBuilder::from_packed(values.len() as u32, &packed).to_bytes()?);
+                }
+                Section::Bitmap(_) => message.extend(BitmapSectionBuilder { bitmap: bitmap.clone() }.to_bytes()?),
+                Section::Data(_) => message.extend(DataSectionBuilder { data: packed.data.clone() }.to_bytes()?),
+                _ => message.extend_from_slice(section.data()),
+            }
+        }
+
+        let total_length = message.len() as u64;
+        message[8..16].copy_from_slice(&total_length.to_be_bytes());
+
+        Ok(message)
+    }
+
+    // The values within the box, cropped to the rows and columns it covers. Only the span of
+    // packed values covering those rows is unpacked where the packing allows it.
+    pub fn data_in_region(
+        &self,
+        min_latitude: f64,
+        min_longitude: f64,
+        max_latitude: f64,
+        max_longitude: f64,
+    ) -> Result<RegionData, GribError> {
+        let (grid_template, latitude_indices, mut longitude_indices) =
+            self.region_indices((min_latitude, min_longitude), (max_latitude, max_longitude))?;
+
+        // Boxes crossing the edge of the grid carry on eastwards from the first column past the gap
+        if let Some(gap) = longitude_indices.windows(2).position(|w| w[1] != w[0] + 1) {
+            longitude_indices.rotate_left(gap + 1);
+        }
+
+        let shape = (grid_template.latitude_count(), grid_template.longitude_count());
+        let scanning_mode = grid_template.scanning_mode();
+        let grid_indices = latitude_indices
+            .iter()
+            .flat_map(|j| longitude_indices.iter().map(move |i| Some(scanned_index(scanning_mode, shape, *i, *j))))
+            .collect::<Vec<_>>();
+        let values = self
+            .data_at_grid_indices(&grid_indices)?
+            .into_iter()
+            .map(|value| value.unwrap_or(f64::NAN))
+            .collect();
+
+        let latitudes = grid_template.latitudes();
+        let longitudes = grid_template.longitudes();
+        Ok(RegionData {
+            values,
+            shape: (latitude_indices.len(), longitude_indices.len()),
+            latitudes: latitude_indices.iter().map(|j| latitudes[*j]).collect(),
+            longitudes: longitude_indices.iter().map(|i| self.longitudes.apply(longitudes[*i])).collect(),
+        })
+    }
+
+    // A coarser copy of the grid with one point for each block of factor by factor points, for
+    // previews and tiles. Blocks along the last rows and columns may be smaller. Like latitudes()
+    // and longitudes(), locations are given per row and column for regular grids and per point
+    // for the others.
+    pub fn downsample(&self, factor: usize, method: Downsampling) -> Result<RegionData, GribError> {
+        if factor == 0 {
+            return Err(GribError::OutOfRange("The downsampling factor must be at least 1".into()));
+        }
+
+        let grid_template = self.grid_template()?;
+        let shape = (grid_template.latitude_count(), grid_template.longitude_count());
+        let scanning_mode = grid_template.scanning_mode();
+        let data = self.data_ref()?;
+        let coarse_shape = (shape.0.div_ceil(factor), shape.1.div_ceil(factor));
+
+        // The rows or columns of the grid making up the block at the index
+        let span = |index: usize, count: usize| index * factor..((index + 1) * factor).min(count);
+        let mean = |values: &mut dyn Iterator<Item = f64>| {
+            let (count, sum) = values.filter(|v| !v.is_nan()).fold((0, 0.0), |(count, sum), v| (count + 1, sum + v));
+            if count == 0 { f64::NAN } else { sum / count as f64 }
+        };
+        // Longitudes are averaged within 180 degrees of the first so blocks across the seam hold up
+        let mean_longitude = |longitudes: &mut dyn Iterator<Item = f64>| {
+            let longitudes = longitudes.collect::<Vec<_>>();
+            longitudes[0] + mean(&mut longitudes.iter().map(|longitude| longitude_difference(*longitude, longitudes[0])))
+        };
+
+        let blocks = (0..coarse_shape.0).flat_map(|j| (0..coarse_shape.1).map(move |i| (i, j))).collect::<Vec<_>>();
+        let block_indices = |(i, j): (usize, usize)| {
+            span(j, shape.0).flat_map(move |j| span(i, shape.1).map(move |i| scanned_index(scanning_mode, shape, i, j)))
+        };
+
+        let values = blocks
+            .iter()
+            .map(|(i, j)| match method {
+                Downsampling::Stride => data[scanned_index(scanning_mode, shape, i * factor, j * factor)],
+                Downsampling::Mean => mean(&mut block_indices((*i, *j)).map(|index| data[index])),
+            })
+            .collect();
+
+        let (latitudes, longitudes): (Vec<f64>, Vec<f64>) = if grid_template.is_regular() {
+            let (latitudes, longitudes) = (grid_template.latitudes(), grid_template.longitudes());
+            match method {
+                Downsampling::Stride => (
+                    (0..coarse_shape.0).map(|j| latitudes[j * factor]).collect(),
+                    (0..coarse_shape.1).map(|i| longitudes[i * factor]).collect(),
+                ),
+                Downsampling::Mean => (
+                    (0..coarse_shape.0).map(|j| mean(&mut span(j, shape.0).map(|j| latitudes[j]))).collect(),
+                    (0..coarse_shape.1).map(|i| mean_longitude(&mut span(i, shape.1).map(|i| longitudes[i]))).collect(),
+                ),
+            }
+        } else {
+            let locations = grid_template.locations();
+            let locations = blocks
+                .iter()
+                .map(|(i, j)| match method {
+                    Downsampling::Stride => locations[scanned_index(scanning_mode, shape, i * factor, j * factor)],
+                    Downsampling::Mean => (
+                        mean(&mut block_indices((*i, *j)).map(|index| locations[index].0)),
+                        mean_longitude(&mut block_indices((*i, *j)).map(|index| locations[index].1)),
+                    ),
+                })
+                .collect::<Vec<_>>();
+            locations.into_iter().unzip()
+        };
+
+        Ok(RegionData {
+            values,
+            shape: coarse_shape,
+            latitudes,
+            longitudes: longitudes.into_iter().map(|longitude| self.longitudes.apply(longitude)).collect(),
+        })
+    }
+
+    // The grid along with the rows and columns within the box between the corners
+    fn region_indices(
+        &self,
+        start: (f64, f64),
+        end: (f64, f64),
+    ) -> Result<(LatitudeLongitudeGridTemplate<'_>, Vec<usize>, Vec<usize>), GribError> {
+        let grid_definition = unwrap_or_return!(
             self.sections.iter().find_map(|s| match s {
-                Section::DataRepresentation(data_representation_section) =>
-                    Some(data_representation_section),
+                Section::GridDefinition(grid_definition) => Some(grid_definition),
                 _ => None,
             }),
-            "Data representation section not found when reading message data".into()
+            GribError::MissingSection(3)
         );
 
-        let data_representation_template = unwrap_or_return!(
-            data_representation_section.data_representation_template(),
-            "Failed to unpack the data representation template".into()
-        );
+        if grid_definition.grid_definition_template_number() != 0 {
+            return Err(GribError::UnsupportedTemplate {
+                kind: TemplateType::Grid,
+                number: grid_definition.grid_definition_template_number(),
+            });
+        }
+        let grid_template = LatitudeLongitudeGridTemplate::new(grid_definition.data());
 
-        let bitmap_section = unwrap_or_return!(
+        let (min_latitude, max_latitude) = (start.0.min(end.0), start.0.max(end.0));
+        let latitude_indices = grid_template
+            .latitudes()
+            .iter()
+            .enumerate()
+            .filter(|(_, lat)| **lat >= min_latitude - 1e-6 && **lat <= max_latitude + 1e-6)
+            .map(|(i, _)| i)
+            .collect::<Vec<usize>>();
+
+        // Compare longitudes on 0 to 360 so boxes can be given either way
+        let min_longitude = start.1.rem_euclid(360.0);
+        let max_longitude = end.1.rem_euclid(360.0);
+        let longitude_indices = grid_template
+            .longitudes()
+            .iter()
+            .map(|lng| lng.rem_euclid(360.0))
+            .enumerate()
+            .filter(|(_, lng)| if min_longitude <= max_longitude {
+                *lng >= min_longitude - 1e-6 && *lng <= max_longitude + 1e-6
+            } else {
+                *lng >= min_longitude - 1e-6 || *lng <= max_longitude + 1e-6
+            })
+            .map(|(i, _)| i)
+            .collect::<Vec<usize>>();
+
+        if latitude_indices.is_empty() || longitude_indices.is_empty() {
+            return Err(GribError::OutOfRange("The region does not overlap the grid".into()));
+        }
+
+        Ok((grid_template, latitude_indices, longitude_indices))
+    }
+
+    // Keeping the original scale factors preserves the precision of the source message
+    fn packing_precision(&self) -> Result<PackingPrecision, GribError> {
+        let data_representation_section = unwrap_or_return!(
             self.sections.iter().find_map(|s| match s {
-                Section::Bitmap(bitmap_section) => Some(bitmap_section),
+                Section::DataRepresentation(data_representation_section) => Some(data_representation_section),
                 _ => None,
             }),
-            "Bitmap section not found when reading message data".into()
+            GribError::MissingSection(5)
         );
 
-        let data_index = unwrap_or_return!(
-            bitmap_section.data_index(location_index), 
-            format!("No data available at index {}", location_index).into()
-        );
+        let raw_data_representation = data_representation_section.data();
+        let binary_scale_factor = read_i16_from_bytes(raw_data_representation, 15).unwrap_or(0);
+        let decimal_scale_factor = read_i16_from_bytes(raw_data_representation, 17).unwrap_or(0);
+        Ok(PackingPrecision::ScaleFactors(decimal_scale_factor, binary_scale_factor))
+    }
+}
+
+// Parses messages one at a time so large files can be streamed through without
+// holding every message, and so a bad message can be reported and skipped
+pub struct MessageIterator<'a> {
+    data: &'a [u8],
+    offset: usize,
+    finished: bool,
+    resynchronize: bool,
+    skipped: Vec<Range<usize>>,
+    errors: Vec<(usize, GribError)>,
+    options: ParseOptions,
+}
+
+impl<'a> MessageIterator<'a> {
+    pub fn new(data: &'a [u8]) -> MessageIterator<'a> {
+        MessageIterator {
+            data,
+            offset: 0,
+            finished: false,
+            resynchronize: false,
+            skipped: Vec::new(),
+            errors: Vec::new(),
+            options: ParseOptions::default(),
+        }
+    }
 
-        let raw_packed_data = data_section.raw_bit_data();
-        let data = data_representation_template.unpack_range(raw_packed_data, data_index..data_index+1)?;
+    // Lenient options also resynchronize, so messages that can not be read at all are skipped
+    pub fn with_options(data: &'a [u8], options: ParseOptions) -> MessageIterator<'a> {
+        MessageIterator {
+            resynchronize: !options.strict,
+            options,
+            ..MessageIterator::new(data)
+        }
+    }
 
-        Ok(data[0])
+    // Instead of reporting bad data, skips ahead to the next GRIB marker and carries on,
+    // keeping track of the byte ranges that were skipped
+    pub fn resynchronizing(data: &'a [u8]) -> MessageIterator<'a> {
+        MessageIterator {
+            resynchronize: true,
+            ..MessageIterator::new(data)
+        }
+    }
+
+    // The offset of the next message to be parsed
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    // The byte ranges passed over while resynchronizing
+    pub fn skipped(&self) -> &[Range<usize>] {
+        &self.skipped
+    }
+
+    // Why each skipped range could not be read, by the offset it starts at
+    pub fn errors(&self) -> &[(usize, GribError)] {
+        &self.errors
+    }
+
+    fn skip_to_next_marker(&mut self, offset: usize) {
+        let next = self.data[offset + 1..]
+            .windows(4)
+            .position(|w| w == b"GRIB")
+            .map(|position| offset + 1 + position)
+            .unwrap_or(self.data.len());
+
+        self.skipped.push(offset..next);
+        self.offset = next;
+        self.finished = false;
+    }
+
+    fn parse_next(&mut self) -> Result<Message<'a>, GribError> {
+        let offset = self.offset;
+        let remaining = &self.data[offset..];
+        if remaining.len() < 16 || &remaining[0..4] != b"GRIB" {
+            self.finished = true;
+            return Err(GribError::InvalidMessage {
+                offset: offset as u64,
+                reason: "No GRIB message found".into(),
+            });
+        }
+
+        if let Some(edition) = detect_edition(remaining).filter(|e| *e != 2) {
+            self.finished = true;
+            return Err(GribError::UnsupportedEdition(edition));
+        }
+
+        // Without a usable total length there is no way to find the next message
+        let total_length = read_u64_from_bytes(remaining, 8).unwrap_or(0);
+        if total_length < 20 || total_length > remaining.len() as u64 {
+            self.finished = true;
+            return Err(GribError::InvalidMessage {
+                offset: offset as u64,
+                reason: "The message is truncated".into(),
+            });
+        }
+
+        // The message fits in the data, so its length fits in a usize
+        let total_length = total_length as usize;
+
+        self.offset += total_length;
+
+        // Sections are bounded by the message so a bad one cannot run into the next message
+        let message_data = &self.data[..offset + total_length];
+        // Errors that do not point at a position in the data are located by the message
+        Message::parse_with_options(message_data, offset, self.options).map_err(|e| match e {
+            GribError::InvalidMessage { .. } | GribError::InvalidSection { .. } | GribError::UnexpectedEof { .. } => e,
+            e => GribError::InvalidMessage {
+                offset: offset as u64,
+                reason: e.to_string(),
+            },
+        })
+    }
+}
+
+impl<'a> Iterator for MessageIterator<'a> {
+    type Item = Result<Message<'a>, GribError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.finished || self.offset >= self.data.len() {
+                return None;
+            }
+
+            let offset = self.offset;
+            match self.parse_next() {
+                Ok(message) => return Some(Ok(message)),
+                Err(e) if !self.resynchronize => return Some(Err(e)),
+                Err(e) => {
+                    self.errors.push((offset, e));
+                    self.skip_to_next_marker(offset);
+                }
+            }
+        }
     }
 }
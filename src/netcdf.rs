@@ -0,0 +1,140 @@
+use crate::cube::{cf_units, hours_since, level_attributes, level_name, standard_name, Cube};
+use crate::error::GribError;
+use crate::message::Message;
+use crate::templates::product::tables::FixedSurfaceTypes;
+use netcdf3::{DataSet, FileWriter, InvalidDataSet, Version, NC_FILL_F32};
+use std::path::Path;
+
+// Writes the messages to a NetCDF-3 file following the CF conventions. Messages holding the same
+// parameter on the same type of surface become one variable over time and level, so a forecast
+// run of temperatures on pressure levels is a single (time, isobaric, latitude, longitude)
+// variable. Every message must be on the same grid. Regular latitude longitude grids get
+// latitude and longitude axes, other grids run over (y, x) with the location of every point
+// given by two dimensional latitude and longitude variables.
+pub fn write_netcdf<P: AsRef<Path>>(messages: &[Message], path: P) -> Result<(), GribError> {
+    let path = path.as_ref();
+    let (data_set, values) = define(&Cube::new(messages)?)?;
+
+    let write_error = |e| GribError::Io(format!("Failed to write {}: {:?}", path.display(), e));
+    let mut writer = FileWriter::open(path).map_err(write_error)?;
+    writer.set_def(&data_set, Version::Offset64Bit, 0).map_err(write_error)?;
+    for (name, values) in &values {
+        match values {
+            Values::F32(values) => writer.write_var_f32(name, values),
+            Values::F64(values) => writer.write_var_f64(name, values),
+        }
+        .map_err(write_error)?;
+    }
+    writer.close().map_err(write_error)
+}
+
+enum Values {
+    F32(Vec<f32>),
+    F64(Vec<f64>),
+}
+
+fn define(cube: &Cube) -> Result<(DataSet, Vec<(String, Values)>), GribError> {
+    let encoding = |e: InvalidDataSet| GribError::Encoding(format!("Invalid NetCDF definition: {}", e));
+    let mut data_set = DataSet::new();
+    let mut values = Vec::new();
+    data_set.add_global_attr_string("Conventions", "CF-1.8").map_err(encoding)?;
+    data_set.add_global_attr_string("source", "GRIB2").map_err(encoding)?;
+
+    // Times are given in hours since the earliest reference date
+    data_set.add_fixed_dim("time", cube.times.len()).map_err(encoding)?;
+    data_set.add_var_f64("time", &["time"]).map_err(encoding)?;
+    add_strings(&mut data_set, "time", &[("standard_name", "time"), ("axis", "T"), ("calendar", "proleptic_gregorian")])
+        .map_err(encoding)?;
+    data_set.add_var_attr_string("time", "units", hours_since(&cube.epoch)).map_err(encoding)?;
+    values.push(("time".to_string(), Values::F64(cube.hours())));
+
+    // A run of forecasts from one reference date also records it as a scalar coordinate
+    if cube.single_reference {
+        data_set.add_var_f64::<&str>("reference_time", &[]).map_err(encoding)?;
+        data_set.add_var_attr_string("reference_time", "standard_name", "forecast_reference_time").map_err(encoding)?;
+        data_set.add_var_attr_string("reference_time", "units", hours_since(&cube.epoch)).map_err(encoding)?;
+        values.push(("reference_time".to_string(), Values::F64(vec![0.0])));
+    }
+
+    for (surface, surface_levels) in &cube.levels {
+        let name = level_name(*surface);
+        data_set.add_fixed_dim(&name, surface_levels.len()).map_err(encoding)?;
+        data_set.add_var_f64(&name, &[&name]).map_err(encoding)?;
+        data_set
+            .add_var_attr_string(&name, "long_name", FixedSurfaceTypes::from(*surface).to_string())
+            .map_err(encoding)?;
+        add_strings(&mut data_set, &name, level_attributes(*surface)).map_err(encoding)?;
+        data_set.add_var_attr_string(&name, "axis", "Z").map_err(encoding)?;
+        values.push((name, Values::F64(surface_levels.clone())));
+    }
+
+    let (rows, columns) = cube.shape;
+    let (latitudes, longitudes) = cube.locations()?;
+    let horizontal = if cube.regular {
+        data_set.add_fixed_dim("latitude", rows).map_err(encoding)?;
+        data_set.add_fixed_dim("longitude", columns).map_err(encoding)?;
+        data_set.add_var_f64("latitude", &["latitude"]).map_err(encoding)?;
+        data_set.add_var_f64("longitude", &["longitude"]).map_err(encoding)?;
+        ["latitude", "longitude"]
+    } else {
+        data_set.add_fixed_dim("y", rows).map_err(encoding)?;
+        data_set.add_fixed_dim("x", columns).map_err(encoding)?;
+        data_set.add_var_f64("latitude", &["y", "x"]).map_err(encoding)?;
+        data_set.add_var_f64("longitude", &["y", "x"]).map_err(encoding)?;
+        ["y", "x"]
+    };
+    add_strings(&mut data_set, "latitude", &[("standard_name", "latitude"), ("units", "degrees_north")]).map_err(encoding)?;
+    add_strings(&mut data_set, "longitude", &[("standard_name", "longitude"), ("units", "degrees_east")]).map_err(encoding)?;
+    values.push(("latitude".to_string(), Values::F64(latitudes)));
+    values.push(("longitude".to_string(), Values::F64(longitudes)));
+
+    let mut coordinates = Vec::new();
+    if cube.single_reference {
+        coordinates.push("reference_time");
+    }
+    if !cube.regular {
+        coordinates.extend(["latitude", "longitude"]);
+    }
+
+    for variable in &cube.variables {
+        let name = &variable.name;
+        let level_count = cube.level_count(variable);
+        let mut dimensions = vec!["time".to_string()];
+        dimensions.extend(level_count.map(|_| level_name(variable.surface)));
+        dimensions.extend(horizontal.iter().map(|dimension| dimension.to_string()));
+        data_set.add_var_f32(name, &dimensions).map_err(encoding)?;
+
+        let parameter = &variable.parameter;
+        data_set.add_var_attr_string(name, "long_name", &parameter.name).map_err(encoding)?;
+        data_set.add_var_attr_string(name, "units", cf_units(&parameter.unit)).map_err(encoding)?;
+        if let Some(standard_name) = standard_name(&parameter.abbrev) {
+            data_set.add_var_attr_string(name, "standard_name", standard_name).map_err(encoding)?;
+        }
+        if !coordinates.is_empty() {
+            data_set.add_var_attr_string(name, "coordinates", coordinates.join(" ")).map_err(encoding)?;
+        }
+        data_set.add_var_attr_f32(name, "_FillValue", vec![NC_FILL_F32]).map_err(encoding)?;
+
+        // Times and levels without a message are left as fill values
+        let point_count = rows * columns;
+        let level_count = level_count.unwrap_or(1);
+        let mut data = vec![NC_FILL_F32; cube.times.len() * level_count * point_count];
+        for ((time_index, level_index), message) in &variable.fields {
+            let start = (time_index * level_count + level_index) * point_count;
+            for (value, field) in data[start..start + point_count].iter_mut().zip(cube.plane(message)?) {
+                if !field.is_nan() {
+                    *value = field as f32;
+                }
+            }
+        }
+        values.push((name.clone(), Values::F32(data)));
+    }
+
+    Ok((data_set, values))
+}
+
+fn add_strings(data_set: &mut DataSet, name: &str, attributes: &[(&str, &str)]) -> Result<(), InvalidDataSet> {
+    attributes
+        .iter()
+        .try_for_each(|(attribute, value)| data_set.add_var_attr_string(name, attribute, value))
+}
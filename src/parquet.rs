@@ -0,0 +1,181 @@
+use crate::error::GribError;
+use crate::idx::{forecast_description, level_description, unknown_variable};
+use crate::message::Message;
+use parquet::basic::Compression;
+use parquet::data_type::{ByteArray, ByteArrayType, DataType, DoubleType, Int32Type, Int64Type};
+use parquet::errors::ParquetError;
+use parquet::file::properties::WriterProperties;
+use parquet::file::writer::{SerializedColumnWriter, SerializedFileWriter};
+use parquet::schema::parser::parse_message_type;
+use std::io::Write;
+use std::sync::Arc;
+
+const POINT_SCHEMA: &str = "
+message grib_points {
+    REQUIRED INT32 message;
+    REQUIRED BYTE_ARRAY parameter (UTF8);
+    REQUIRED BYTE_ARRAY level (UTF8);
+    REQUIRED INT64 reference_time (TIMESTAMP(MILLIS, true));
+    REQUIRED INT64 valid_time (TIMESTAMP(MILLIS, true));
+    REQUIRED DOUBLE latitude;
+    REQUIRED DOUBLE longitude;
+    OPTIONAL DOUBLE value;
+}
+";
+
+const INVENTORY_SCHEMA: &str = "
+message grib_inventory {
+    REQUIRED INT32 message;
+    REQUIRED INT64 offset;
+    REQUIRED INT64 length;
+    REQUIRED INT32 discipline;
+    REQUIRED BYTE_ARRAY parameter (UTF8);
+    OPTIONAL BYTE_ARRAY name (UTF8);
+    OPTIONAL BYTE_ARRAY unit (UTF8);
+    REQUIRED BYTE_ARRAY level (UTF8);
+    REQUIRED BYTE_ARRAY forecast (UTF8);
+    REQUIRED INT64 reference_time (TIMESTAMP(MILLIS, true));
+    OPTIONAL INT64 valid_time (TIMESTAMP(MILLIS, true));
+    REQUIRED INT64 point_count;
+}
+";
+
+// The values of one column, with None for nulls
+enum Column {
+    Int32(Vec<Option<i32>>),
+    Int64(Vec<Option<i64>>),
+    Double(Vec<Option<f64>>),
+    Text(Vec<Option<ByteArray>>),
+}
+
+// Writes every grid point of the messages as a row, in a row group for each message. Points the
+// bitmap marks as missing have a null value. Messages are numbered from 1 in the order given,
+// like the inventory.
+pub fn write_points_parquet<W: Write + Send>(messages: &[Message], writer: W) -> Result<(), GribError> {
+    let mut writer = file_writer(POINT_SCHEMA, writer)?;
+
+    for (number, message) in messages.iter().enumerate() {
+        let (parameter, level, _) = descriptions(message);
+        let reference_time = message.reference_date()?.timestamp_millis();
+        let valid_time = message.forecast_date()?.timestamp_millis();
+
+        let (mut latitudes, mut longitudes, mut values) = (Vec::new(), Vec::new(), Vec::new());
+        for (latitude, longitude, value) in message.data_with_locations()? {
+            latitudes.push(Some(latitude));
+            longitudes.push(Some(longitude));
+            values.push(if value.is_nan() { None } else { Some(value) });
+        }
+
+        let count = values.len();
+        write_row_group(
+            &mut writer,
+            vec![
+                Column::Int32(vec![Some(number as i32 + 1); count]),
+                Column::Text(vec![Some(ByteArray::from(parameter.as_str())); count]),
+                Column::Text(vec![Some(ByteArray::from(level.as_str())); count]),
+                Column::Int64(vec![Some(reference_time); count]),
+                Column::Int64(vec![Some(valid_time); count]),
+                Column::Double(latitudes),
+                Column::Double(longitudes),
+                Column::Double(values),
+            ],
+        )?;
+    }
+
+    writer.close().map(|_| ()).map_err(parquet_error)
+}
+
+// Writes a row for each message with the fields of its wgrib2 style inventory line, along with
+// where it is in the data and how many grid points it holds
+pub fn write_inventory_parquet<W: Write + Send>(messages: &[Message], writer: W) -> Result<(), GribError> {
+    let mut writer = file_writer(INVENTORY_SCHEMA, writer)?;
+
+    let (mut numbers, mut offsets, mut lengths, mut disciplines) = (vec![], vec![], vec![], vec![]);
+    let (mut parameters, mut names, mut units, mut levels, mut forecasts) = (vec![], vec![], vec![], vec![], vec![]);
+    let (mut reference_times, mut valid_times, mut point_counts) = (vec![], vec![], vec![]);
+    for (number, message) in messages.iter().enumerate() {
+        let (parameter, level, forecast) = descriptions(message);
+        let known = message.parameter().ok();
+        let text = |value: &str| Some(ByteArray::from(value));
+
+        numbers.push(Some(number as i32 + 1));
+        offsets.push(Some(message.offset() as i64));
+        lengths.push(Some(message.len() as i64));
+        disciplines.push(Some(message.discipline().map(u8::from).unwrap_or(255) as i32));
+        parameters.push(text(&parameter));
+        names.push(known.as_ref().and_then(|known| text(&known.name)));
+        units.push(known.as_ref().and_then(|known| text(&known.unit)));
+        levels.push(text(&level));
+        forecasts.push(text(&forecast));
+        reference_times.push(Some(message.reference_date()?.timestamp_millis()));
+        valid_times.push(message.forecast_date().ok().map(|date| date.timestamp_millis()));
+        point_counts.push(Some(message.shape().map(|(rows, columns)| (rows * columns) as i64)?));
+    }
+
+    write_row_group(
+        &mut writer,
+        vec![
+            Column::Int32(numbers),
+            Column::Int64(offsets),
+            Column::Int64(lengths),
+            Column::Int32(disciplines),
+            Column::Text(parameters),
+            Column::Text(names),
+            Column::Text(units),
+            Column::Text(levels),
+            Column::Text(forecasts),
+            Column::Int64(reference_times),
+            Column::Int64(valid_times),
+            Column::Int64(point_counts),
+        ],
+    )?;
+    writer.close().map(|_| ()).map_err(parquet_error)
+}
+
+// The variable, level and forecast fields of the inventory line of the message
+fn descriptions(message: &Message) -> (String, String, String) {
+    let variable = message.variable_abbrev().unwrap_or_else(|_| unknown_variable(message));
+    match message.product_template() {
+        Ok(template) => (variable, level_description(&template), forecast_description(&template)),
+        Err(_) => (variable, "unknown level".into(), "unknown".into()),
+    }
+}
+
+fn file_writer<W: Write + Send>(schema: &str, writer: W) -> Result<SerializedFileWriter<W>, GribError> {
+    let schema = Arc::new(parse_message_type(schema).map_err(parquet_error)?);
+    let properties = Arc::new(WriterProperties::builder().set_compression(Compression::SNAPPY).build());
+    SerializedFileWriter::new(writer, schema, properties).map_err(parquet_error)
+}
+
+fn write_row_group<W: Write + Send>(writer: &mut SerializedFileWriter<W>, columns: Vec<Column>) -> Result<(), GribError> {
+    let mut row_group = writer.next_row_group().map_err(parquet_error)?;
+    for column in columns {
+        let mut column_writer = unwrap_or_return!(
+            row_group.next_column().map_err(parquet_error)?,
+            GribError::Encoding("The columns do not match the Parquet schema".into())
+        );
+        match column {
+            Column::Int32(values) => write_column::<Int32Type>(&mut column_writer, values),
+            Column::Int64(values) => write_column::<Int64Type>(&mut column_writer, values),
+            Column::Double(values) => write_column::<DoubleType>(&mut column_writer, values),
+            Column::Text(values) => write_column::<ByteArrayType>(&mut column_writer, values),
+        }
+        .map_err(parquet_error)?;
+        column_writer.close().map_err(parquet_error)?;
+    }
+    row_group.close().map(|_| ()).map_err(parquet_error)
+}
+
+// Required columns are written without definition levels, optional ones mark where the nulls are
+fn write_column<T: DataType>(writer: &mut SerializedColumnWriter, values: Vec<Option<T::T>>) -> Result<(), ParquetError> {
+    let writer = writer.typed::<T>();
+    let levels = values.iter().map(|value| value.is_some() as i16).collect::<Vec<_>>();
+    let present = values.into_iter().flatten().collect::<Vec<_>>();
+    let optional = writer.get_descriptor().max_def_level() > 0;
+    writer.write_batch(&present, if optional { Some(&levels) } else { None }, None)?;
+    Ok(())
+}
+
+fn parquet_error(error: ParquetError) -> GribError {
+    GribError::Io(format!("Failed to write Parquet: {}", error))
+}
@@ -0,0 +1,172 @@
+use crate::error::GribError;
+use crate::message::Message;
+
+// The radius of the sphere cell areas are measured on, in metres
+const EARTH_RADIUS: f64 = 6_371_008.8;
+
+// An area given by rings of (latitude, longitude) vertices, such as a watershed or a marine
+// zone. Rings close by themselves, and points inside an interior ring are outside the polygon.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polygon {
+    pub exterior: Vec<(f64, f64)>,
+    pub interiors: Vec<Vec<(f64, f64)>>,
+}
+
+impl Polygon {
+    pub fn new(exterior: Vec<(f64, f64)>) -> Polygon {
+        Polygon {
+            exterior,
+            interiors: Vec::new(),
+        }
+    }
+
+    pub fn with_interior(mut self, interior: Vec<(f64, f64)>) -> Polygon {
+        self.interiors.push(interior);
+        self
+    }
+
+    // Longitudes are compared within 180 degrees of the first vertex, so locations and rings
+    // may be given on either -180 to 180 or 0 to 360
+    pub fn contains(&self, latitude: f64, longitude: f64) -> bool {
+        let reference = match self.exterior.first() {
+            Some((_, longitude)) => *longitude,
+            None => return false,
+        };
+
+        ring_contains(&self.exterior, reference, latitude, longitude)
+            && !self.interiors.iter().any(|ring| ring_contains(ring, reference, latitude, longitude))
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl From<&geo_types::Polygon<f64>> for Polygon {
+    // geo types coordinates are (x, y), which is (longitude, latitude)
+    fn from(polygon: &geo_types::Polygon<f64>) -> Polygon {
+        let ring = |line: &geo_types::LineString<f64>| line.coords().map(|c| (c.y, c.x)).collect::<Vec<_>>();
+        Polygon {
+            exterior: ring(polygon.exterior()),
+            interiors: polygon.interiors().iter().map(ring).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl From<geo_types::Polygon<f64>> for Polygon {
+    fn from(polygon: geo_types::Polygon<f64>) -> Polygon {
+        Polygon::from(&polygon)
+    }
+}
+
+// The area weighted statistics of the grid points inside a polygon that hold data
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AreaStatistics {
+    pub count: usize,
+    // The total area of the cells around the points, in square metres
+    pub area: f64,
+    pub mean: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+// The indices of the grid points inside the polygon, in the order the values are stored
+pub fn points_in_polygon(message: &Message, polygon: &Polygon) -> Result<Vec<usize>, GribError> {
+    Ok(message
+        .locations_iter()?
+        .enumerate()
+        .filter(|(_, (latitude, longitude))| polygon.contains(*latitude, *longitude))
+        .map(|(index, _)| index)
+        .collect())
+}
+
+// Weighs every point inside the polygon by the area of its cell, so rows of a latitude longitude
+// grid count for less towards the poles. None when no point inside holds data.
+pub fn area_statistics(message: &Message, polygon: &Polygon) -> Result<Option<AreaStatistics>, GribError> {
+    let cells = message.cell_polygons()?;
+    let inside = message
+        .data_with_locations()?
+        .zip(cells.iter())
+        .filter(|((latitude, longitude, value), _)| !value.is_nan() && polygon.contains(*latitude, *longitude))
+        .map(|((_, _, value), corners)| (value, cell_area(corners)));
+
+    let mut statistics: Option<AreaStatistics> = None;
+    let mut weighted_total = 0.0;
+    for (value, area) in inside {
+        weighted_total += value * area;
+        statistics = Some(match statistics {
+            None => AreaStatistics {
+                count: 1,
+                area,
+                mean: value,
+                min: value,
+                max: value,
+            },
+            Some(statistics) => AreaStatistics {
+                count: statistics.count + 1,
+                area: statistics.area + area,
+                mean: 0.0,
+                min: statistics.min.min(value),
+                max: statistics.max.max(value),
+            },
+        });
+    }
+
+    Ok(statistics.map(|statistics| AreaStatistics {
+        mean: if statistics.area > 0.0 { weighted_total / statistics.area } else { statistics.mean },
+        ..statistics
+    }))
+}
+
+// Ray casting with the longitudes brought within 180 degrees of the reference
+fn ring_contains(ring: &[(f64, f64)], reference: f64, latitude: f64, longitude: f64) -> bool {
+    let unwrap = |longitude: f64| reference + (longitude - reference + 180.0).rem_euclid(360.0) - 180.0;
+    let longitude = unwrap(longitude);
+
+    let mut inside = false;
+    for (k, vertex) in ring.iter().enumerate() {
+        let previous = ring[(k + ring.len() - 1) % ring.len()];
+        let (latitude_a, longitude_a) = (vertex.0, unwrap(vertex.1));
+        let (latitude_b, longitude_b) = (previous.0, unwrap(previous.1));
+
+        if (latitude_a > latitude) != (latitude_b > latitude) {
+            let crossing = longitude_a + (latitude - latitude_a) / (latitude_b - latitude_a) * (longitude_b - longitude_a);
+            if longitude < crossing {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+// The area of a cell on the sphere, exact for cells bounded by meridians and parallels
+fn cell_area(corners: &[(f64, f64); 4]) -> f64 {
+    let total = (0..4)
+        .map(|k| {
+            let (latitude_a, longitude_a) = corners[k];
+            let (latitude_b, longitude_b) = corners[(k + 1) % 4];
+            (longitude_b - longitude_a).to_radians() * (2.0 + latitude_a.to_radians().sin() + latitude_b.to_radians().sin())
+        })
+        .sum::<f64>();
+    (total * EARTH_RADIUS * EARTH_RADIUS / 2.0).abs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cell_area, Polygon};
+
+    #[test]
+    fn contain_points_and_measure_cells() {
+        let polygon = Polygon::new(vec![(40.0, -72.0), (40.0, -70.0), (42.0, -70.0), (42.0, -72.0)])
+            .with_interior(vec![(40.8, -71.2), (40.8, -70.8), (41.2, -70.8), (41.2, -71.2)]);
+        assert!(polygon.contains(40.5, -71.5));
+        assert!(polygon.contains(40.5, 288.5));
+        assert!(!polygon.contains(41.0, -71.0));
+        assert!(!polygon.contains(43.0, -71.0));
+        assert!(!Polygon::new(Vec::new()).contains(0.0, 0.0));
+
+        // A degree square shrinks with the cosine of its latitude
+        let equator = cell_area(&[(-0.5, -0.5), (-0.5, 0.5), (0.5, 0.5), (0.5, -0.5)]);
+        let sixty = cell_area(&[(59.5, -0.5), (59.5, 0.5), (60.5, 0.5), (60.5, -0.5)]);
+        assert!((equator / 1e6 - 12_364.0).abs() < 10.0);
+        assert!((sixty / equator - 0.5).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,451 @@
+use crate::error::GribError;
+use crate::sections::indicator::Discipline;
+use crate::utils::{read_u32_from_bytes, read_u64_from_bytes};
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+use std::vec::Vec;
+
+// Where a section sits within the underlying reader
+#[derive(Clone, Debug, PartialEq)]
+pub struct SectionHeader {
+    pub number: u8,
+    pub offset: u64,
+    pub length: u64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MessageHeader {
+    pub offset: u64,
+    pub length: u64,
+    pub discipline: Discipline,
+    pub sections: Vec<SectionHeader>,
+}
+
+impl MessageHeader {
+    pub fn section(&self, number: u8) -> Option<&SectionHeader> {
+        self.sections.iter().find(|s| s.number == number)
+    }
+}
+
+// A source that can return the bytes at any offset, such as a file or a remote object
+pub trait RangeRead {
+    // Returns fewer bytes than requested only when the range runs past the end of the source
+    fn read_range(&mut self, offset: u64, length: u64) -> Result<Vec<u8>, GribError>;
+}
+
+impl<R: Read + Seek> RangeRead for R {
+    fn read_range(&mut self, offset: u64, length: u64) -> Result<Vec<u8>, GribError> {
+        self.seek(SeekFrom::Start(offset))
+            .map_err(|e| GribError::Io(format!("Failed to seek to offset {}: {}", offset, e)))?;
+
+        let mut data = Vec::with_capacity(memory_length(length)?);
+        self.take(length)
+            .read_to_end(&mut data)
+            .map_err(|e| GribError::Io(format!("Failed to read message data: {}", e)))?;
+        Ok(data)
+    }
+}
+
+// Buffers a forward only stream, such as a decompressed file, so it can be read by range.
+// The stream is only read as far as the ranges requested so far.
+pub struct BufferedRangeReader<R: Read> {
+    reader: R,
+    buffer: Vec<u8>,
+    finished: bool,
+}
+
+impl<R: Read> BufferedRangeReader<R> {
+    pub fn new(reader: R) -> BufferedRangeReader<R> {
+        BufferedRangeReader {
+            reader,
+            buffer: Vec::new(),
+            finished: false,
+        }
+    }
+}
+
+impl<R: Read> RangeRead for BufferedRangeReader<R> {
+    fn read_range(&mut self, offset: u64, length: u64) -> Result<Vec<u8>, GribError> {
+        let end = memory_length(offset.saturating_add(length))?;
+        if !self.finished && self.buffer.len() < end {
+            let wanted = (end - self.buffer.len()) as u64;
+            let read = self
+                .reader
+                .by_ref()
+                .take(wanted)
+                .read_to_end(&mut self.buffer)
+                .map_err(|e| GribError::Io(format!("Failed to read message data: {}", e)))?;
+            self.finished = (read as u64) < wanted;
+        }
+
+        let start = offset.min(self.buffer.len() as u64) as usize;
+        let end = end.min(self.buffer.len());
+        Ok(self.buffer[start..end].to_vec())
+    }
+}
+
+// Reads messages one after another from a stream that cannot seek, such as stdin or a socket.
+// Only the current message is held, its length comes from the indicator section.
+pub struct MessageStream<R: Read> {
+    reader: R,
+    offset: u64,
+}
+
+impl<R: Read> MessageStream<R> {
+    pub fn new(reader: R) -> MessageStream<R> {
+        MessageStream { reader, offset: 0 }
+    }
+
+    // The number of bytes read from the stream so far
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    // The next complete message, ready for Message::parse
+    pub fn next_message(&mut self) -> Result<Option<Vec<u8>>, GribError> {
+        let mut message = Vec::with_capacity(16);
+        self.read_to(&mut message, 16)?;
+        if message.is_empty() {
+            return Ok(None);
+        } else if message.len() < 16 {
+            return Err(GribError::UnexpectedEof { offset: self.offset });
+        }
+
+        let length = message_length(&message, self.offset)?;
+        self.read_to(&mut message, memory_length(length)?)?;
+        if (message.len() as u64) < length {
+            return Err(GribError::InvalidMessage {
+                offset: self.offset,
+                reason: "The message is truncated".into(),
+            });
+        }
+
+        self.offset += length;
+        Ok(Some(message))
+    }
+
+    fn read_to(&mut self, buffer: &mut Vec<u8>, length: usize) -> Result<(), GribError> {
+        let wanted = (length - buffer.len()) as u64;
+        self.reader
+            .by_ref()
+            .take(wanted)
+            .read_to_end(buffer)
+            .map(|_| ())
+            .map_err(|e| GribError::Io(format!("Failed to read message data: {}", e)))
+    }
+}
+
+impl<R: Read> Iterator for MessageStream<R> {
+    type Item = Result<Vec<u8>, GribError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_message().transpose()
+    }
+}
+
+// A file opened by GribReader::open, decompressed as it is read if needed
+pub enum FileSource {
+    Plain(File),
+    Compressed(BufferedRangeReader<Box<dyn Read>>),
+}
+
+impl RangeRead for FileSource {
+    fn read_range(&mut self, offset: u64, length: u64) -> Result<Vec<u8>, GribError> {
+        match self {
+            FileSource::Plain(file) => file.read_range(offset, length),
+            FileSource::Compressed(reader) => reader.read_range(offset, length),
+        }
+    }
+}
+
+impl GribReader<FileSource> {
+    // Opens a GRIB file, which may be gzip or bzip2 compressed when those features are enabled
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<GribReader<FileSource>, GribError> {
+        let path = path.as_ref();
+        let mut file = File::open(path).map_err(|e| GribError::Io(format!("Failed to open {}: {}", path.display(), e)))?;
+
+        let magic = file.read_range(0, 4)?;
+        file.seek(SeekFrom::Start(0))
+            .map_err(|e| GribError::Io(format!("Failed to seek in {}: {}", path.display(), e)))?;
+
+        let source = if magic.starts_with(&[0x1F, 0x8B]) {
+            FileSource::Compressed(BufferedRangeReader::new(gzip_decoder(file)?))
+        } else if magic.starts_with(b"BZh") {
+            FileSource::Compressed(BufferedRangeReader::new(bzip2_decoder(file)?))
+        } else {
+            FileSource::Plain(file)
+        };
+
+        Ok(GribReader::new(source))
+    }
+}
+
+#[cfg(feature = "gzip")]
+fn gzip_decoder(file: File) -> Result<Box<dyn Read>, GribError> {
+    Ok(Box::new(flate2::read::MultiGzDecoder::new(std::io::BufReader::new(file))))
+}
+
+#[cfg(not(feature = "gzip"))]
+fn gzip_decoder(_file: File) -> Result<Box<dyn Read>, GribError> {
+    Err(GribError::Io("The file is gzip compressed, which requires the gzip feature".into()))
+}
+
+#[cfg(feature = "bzip2")]
+fn bzip2_decoder(file: File) -> Result<Box<dyn Read>, GribError> {
+    Ok(Box::new(bzip2::read::MultiBzDecoder::new(std::io::BufReader::new(file))))
+}
+
+#[cfg(not(feature = "bzip2"))]
+fn bzip2_decoder(_file: File) -> Result<Box<dyn Read>, GribError> {
+    Err(GribError::Io("The file is bzip2 compressed, which requires the bzip2 feature".into()))
+}
+
+// Reads messages from a file or other range readable source without loading it into memory.
+// Only the section headers are read while scanning, the section contents are read on request.
+pub struct GribReader<R: RangeRead> {
+    reader: R,
+    position: u64,
+}
+
+impl<R: RangeRead> GribReader<R> {
+    pub fn new(reader: R) -> GribReader<R> {
+        GribReader { reader, position: 0 }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    // Scans every message from the start of the reader
+    pub fn headers(&mut self) -> Result<Vec<MessageHeader>, GribError> {
+        self.position = 0;
+
+        let mut headers = Vec::new();
+        while let Some(header) = self.next_header()? {
+            headers.push(header);
+        }
+
+        Ok(headers)
+    }
+
+    // Scans the message at the current position and moves on to the start of the next one
+    pub fn next_header(&mut self) -> Result<Option<MessageHeader>, GribError> {
+        let offset = self.position;
+
+        let indicator = self.reader.read_range(offset, 16)?;
+        if indicator.is_empty() {
+            return Ok(None);
+        } else if indicator.len() < 16 {
+            return Err(GribError::UnexpectedEof { offset });
+        }
+
+        let length = message_length(&indicator, offset)?;
+        let mut sections = vec![SectionHeader {
+            number: 0,
+            offset,
+            length: 16,
+        }];
+
+        let end = offset + length;
+        let mut section_offset = offset + 16;
+        while section_offset < end {
+            let section_start = self.read_range(section_offset, (end - section_offset).min(5))?;
+
+            if section_start.starts_with(b"7777") {
+                sections.push(SectionHeader {
+                    number: 8,
+                    offset: section_offset,
+                    length: 4,
+                });
+                break;
+            }
+
+            if section_start.len() < 5 {
+                return Err(GribError::UnexpectedEof { offset: section_offset });
+            }
+
+            let section = section_header(&section_start, section_start[4], section_offset, end)?;
+            section_offset += section.length;
+            sections.push(section);
+        }
+
+        if sections.last().map(|s| s.number) != Some(8) {
+            return Err(GribError::InvalidMessage {
+                offset,
+                reason: GribError::MissingSection(8).to_string(),
+            });
+        }
+
+        self.position = end;
+
+        Ok(Some(MessageHeader {
+            offset,
+            length,
+            discipline: indicator[6].into(),
+            sections,
+        }))
+    }
+
+    // The complete message, ready for Message::parse
+    pub fn read_message(&mut self, header: &MessageHeader) -> Result<Vec<u8>, GribError> {
+        self.read_range(header.offset, header.length)
+    }
+
+    pub fn read_section(&mut self, section: &SectionHeader) -> Result<Vec<u8>, GribError> {
+        self.read_range(section.offset, section.length)
+    }
+
+    // The message with an empty data section, which is enough to read its metadata
+    // without loading the packed values
+    pub fn read_message_metadata(&mut self, header: &MessageHeader) -> Result<Vec<u8>, GribError> {
+        let mut message = Vec::new();
+        for section in &header.sections {
+            if section.number == 7 {
+                message.extend_from_slice(&5u32.to_be_bytes());
+                message.push(7);
+            } else {
+                message.extend(self.read_section(section)?);
+            }
+        }
+
+        set_message_length(&mut message);
+        Ok(message)
+    }
+
+    // Reads exactly the given range
+    fn read_range(&mut self, offset: u64, length: u64) -> Result<Vec<u8>, GribError> {
+        let data = self.reader.read_range(offset, length)?;
+        if (data.len() as u64) < length {
+            return Err(GribError::UnexpectedEof { offset });
+        }
+
+        Ok(data)
+    }
+}
+
+// The positions of a message and its sections, as found by scan
+pub type MessageOffsets = MessageHeader;
+
+// Walks the indicator and section headers of the messages in memory without parsing any templates,
+// stopping at the first message that is not valid
+pub fn scan(data: &[u8]) -> Vec<MessageOffsets> {
+    let mut messages = Vec::new();
+    let mut offset = 0;
+    while offset < data.len() {
+        match scan_message(data, offset as u64) {
+            Ok(message) => {
+                offset += message.length as usize;
+                messages.push(message);
+            }
+            Err(_) => break,
+        }
+    }
+
+    messages
+}
+
+fn scan_message(data: &[u8], offset: u64) -> Result<MessageOffsets, GribError> {
+    let start = offset as usize;
+    if data.len() < start + 16 {
+        return Err(GribError::UnexpectedEof { offset });
+    }
+
+    let length = message_length(&data[start..start + 16], offset)?;
+    let end = offset + length;
+    if end > data.len() as u64 {
+        return Err(GribError::InvalidMessage {
+            offset,
+            reason: "The message is truncated".into(),
+        });
+    }
+
+    let mut sections = vec![SectionHeader {
+        number: 0,
+        offset,
+        length: 16,
+    }];
+
+    let mut section_offset = offset + 16;
+    while section_offset < end {
+        let section_start = &data[section_offset as usize..end.min(section_offset + 5) as usize];
+        if section_start.starts_with(b"7777") {
+            sections.push(SectionHeader {
+                number: 8,
+                offset: section_offset,
+                length: 4,
+            });
+            break;
+        }
+
+        if section_start.len() < 5 {
+            return Err(GribError::UnexpectedEof { offset: section_offset });
+        }
+
+        let section = section_header(section_start, section_start[4], section_offset, end)?;
+        section_offset += section.length;
+        sections.push(section);
+    }
+
+    if sections.last().map(|s| s.number) != Some(8) {
+        return Err(GribError::InvalidMessage {
+            offset,
+            reason: GribError::MissingSection(8).to_string(),
+        });
+    }
+
+    Ok(MessageHeader {
+        offset,
+        length,
+        discipline: data[start + 6].into(),
+        sections,
+    })
+}
+
+pub(crate) fn message_length(indicator: &[u8], offset: u64) -> Result<u64, GribError> {
+    if &indicator[0..4] != b"GRIB" {
+        return Err(GribError::InvalidMessage {
+            offset,
+            reason: "No GRIB message found".into(),
+        });
+    }
+
+    if indicator[7] != 2 {
+        return Err(GribError::UnsupportedEdition(indicator[7]));
+    }
+
+    // The total length is 64 bits, so offsets past it must not wrap around
+    let length = read_u64_from_bytes(indicator, 8).unwrap_or(0);
+    if length < 20 || offset.checked_add(length).is_none() {
+        return Err(GribError::InvalidMessage {
+            offset,
+            reason: "Invalid message length".into(),
+        });
+    }
+
+    Ok(length)
+}
+
+// Messages over 4 GiB can be scanned anywhere, but only held in memory on 64 bit platforms
+pub(crate) fn memory_length(length: u64) -> Result<usize, GribError> {
+    usize::try_from(length).map_err(|_| GribError::OutOfRange(format!("{} bytes is too large to hold in memory on this platform", length)))
+}
+
+pub(crate) fn section_header(section_start: &[u8], number: u8, offset: u64, message_end: u64) -> Result<SectionHeader, GribError> {
+    let length = read_u32_from_bytes(section_start, 0).unwrap_or(0) as u64;
+    if length < 5 || length > message_end - offset {
+        return Err(GribError::InvalidSection {
+            number,
+            offset,
+            reason: "length".into(),
+        });
+    }
+
+    Ok(SectionHeader { number, offset, length })
+}
+
+pub(crate) fn set_message_length(message: &mut [u8]) {
+    let total_length = message.len() as u64;
+    message[8..16].copy_from_slice(&total_length.to_be_bytes());
+}
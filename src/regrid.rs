@@ -0,0 +1,54 @@
+use crate::encoding::LatLngGrid;
+use crate::error::GribError;
+use crate::location_index::{weighted_mean, InterpolationMethod};
+use crate::message::{Message, RegionData};
+
+// Resamples the message onto a regular latitude longitude grid, so fields from different models
+// can be compared point for point. Target points outside the source grid, or without data around
+// them, are NaN.
+pub fn regrid(message: &Message, target: &LatLngGrid, method: InterpolationMethod) -> Result<RegionData, GribError> {
+    let values = message.data_ref()?;
+    let location_index = message.location_index()?;
+
+    let latitudes = axis(target.start.0, target.end.0, target.latitude_count());
+    let longitudes = axis(target.start.1, target.end.1, target.longitude_count());
+
+    let value = |latitude: f64, longitude: f64| -> Option<f64> {
+        let values_at = |neighbors: &[(usize, f64)]| {
+            neighbors
+                .iter()
+                .map(|(index, _)| values.get(*index).copied().filter(|v| !v.is_nan()))
+                .collect::<Vec<_>>()
+        };
+
+        let neighbors = location_index.neighbors(latitude, longitude, method).ok()?;
+        let neighbor_values = values_at(&neighbors);
+        if method == InterpolationMethod::Bicubic && neighbor_values.iter().any(Option::is_none) {
+            let neighbors = location_index.neighbors(latitude, longitude, InterpolationMethod::Bilinear).ok()?;
+            return weighted_mean(&neighbors, &values_at(&neighbors));
+        }
+        weighted_mean(&neighbors, &neighbor_values)
+    };
+
+    let values = latitudes
+        .iter()
+        .flat_map(|latitude| longitudes.iter().map(move |longitude| (*latitude, *longitude)))
+        .map(|(latitude, longitude)| value(latitude, longitude).unwrap_or(f64::NAN))
+        .collect();
+
+    Ok(RegionData {
+        values,
+        shape: target.shape,
+        latitudes,
+        longitudes,
+    })
+}
+
+// Evenly spaced from start to end, inclusive of both
+fn axis(start: f64, end: f64, count: usize) -> Vec<f64> {
+    if count < 2 {
+        return vec![start; count];
+    }
+    let step = (end - start) / (count - 1) as f64;
+    (0..count).map(|i| start + step * i as f64).collect()
+}
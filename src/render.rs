@@ -0,0 +1,123 @@
+use crate::error::GribError;
+use crate::message::Message;
+use crate::templates::grid::scanned_index;
+use std::path::Path;
+
+// Viridis sampled at every eighth of the way, colors between are blended linearly
+const VIRIDIS: [[u8; 3]; 9] = [
+    [68, 1, 84],
+    [71, 44, 122],
+    [59, 81, 139],
+    [44, 113, 142],
+    [33, 144, 141],
+    [39, 173, 129],
+    [92, 200, 99],
+    [170, 220, 50],
+    [253, 231, 37],
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Colormap {
+    // Black at the bottom of the scale to white at the top
+    Grayscale,
+    Viridis,
+}
+
+impl Colormap {
+    // The color at a fraction of the way up the scale
+    pub fn color(&self, fraction: f64) -> [u8; 3] {
+        let fraction = fraction.clamp(0.0, 1.0);
+        match self {
+            Colormap::Grayscale => {
+                let level = (fraction * 255.0).round() as u8;
+                [level, level, level]
+            }
+            Colormap::Viridis => {
+                let position = fraction * (VIRIDIS.len() - 1) as f64;
+                let lower = (position.floor() as usize).min(VIRIDIS.len() - 2);
+                let weight = position - lower as f64;
+                let blend = |k: usize| {
+                    (VIRIDIS[lower][k] as f64 * (1.0 - weight) + VIRIDIS[lower + 1][k] as f64 * weight).round() as u8
+                };
+                [blend(0), blend(1), blend(2)]
+            }
+        }
+    }
+}
+
+// The values at the bottom and the top of the colormap, values past either end take its color
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColorScale {
+    // From the smallest to the largest value of the message
+    DataRange,
+    Fixed { min: f64, max: f64 },
+}
+
+// Renders the message as an 8 bit RGBA PNG with a pixel for every grid point, the first row to
+// the north and the first column to the west as the scanning mode gives them. Missing values are
+// left transparent.
+pub fn render_png(message: &Message, colormap: Colormap, scale: ColorScale) -> Result<Vec<u8>, GribError> {
+    let data = message.data()?;
+    let (min, max) = match scale {
+        ColorScale::DataRange => data
+            .iter()
+            .filter(|value| !value.is_nan())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| (min.min(*value), max.max(*value))),
+        ColorScale::Fixed { min, max } if min < max => (min, max),
+        ColorScale::Fixed { .. } => {
+            return Err(GribError::InvalidData("The top of the color scale must be above its bottom".into()))
+        }
+    };
+
+    // Bit 1 of the scanning mode is set when points run westwards, bit 2 when rows run northwards
+    let (rows, columns) = message.shape()?;
+    let scanning_mode = message.grid_template()?.scanning_mode();
+    let column = |c: usize| if scanning_mode & 0x80 != 0 { columns - 1 - c } else { c };
+    let row = |r: usize| if scanning_mode & 0x40 != 0 { rows - 1 - r } else { r };
+
+    let mut pixels = Vec::with_capacity(rows * columns * 4);
+    for pixel in 0..rows * columns {
+        let index = scanned_index(scanning_mode, (rows, columns), column(pixel % columns), row(pixel / columns));
+        match data.get(index) {
+            Some(value) if !value.is_nan() => {
+                // A field holding one value is drawn at the bottom of the scale
+                let fraction = if max > min { (value - min) / (max - min) } else { 0.0 };
+                pixels.extend(colormap.color(fraction));
+                pixels.push(255);
+            }
+            _ => pixels.extend([0, 0, 0, 0]),
+        }
+    }
+
+    let png_error = |e: png::EncodingError| GribError::Encoding(format!("Failed to encode the PNG: {}", e));
+    let mut output = Vec::new();
+    {
+        let mut encoder = png::Encoder::new(&mut output, columns as u32, rows as u32);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(png_error)?;
+        writer.write_image_data(&pixels).map_err(png_error)?;
+    }
+
+    Ok(output)
+}
+
+pub fn write_png<P: AsRef<Path>>(message: &Message, path: P, colormap: Colormap, scale: ColorScale) -> Result<(), GribError> {
+    let path = path.as_ref();
+    let png = render_png(message, colormap, scale)?;
+    std::fs::write(path, png).map_err(|e| GribError::Io(format!("Failed to write {}: {}", path.display(), e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Colormap;
+
+    #[test]
+    fn colormap_ends() {
+        assert_eq!(Colormap::Grayscale.color(0.0), [0, 0, 0]);
+        assert_eq!(Colormap::Grayscale.color(1.5), [255, 255, 255]);
+        assert_eq!(Colormap::Viridis.color(0.0), [68, 1, 84]);
+        assert_eq!(Colormap::Viridis.color(1.0), [253, 231, 37]);
+        assert_eq!(Colormap::Viridis.color(0.0625), [70, 23, 103]);
+    }
+}
@@ -0,0 +1,102 @@
+use crate::error::GribError;
+use crate::message::{Message, MessageIterator};
+use crate::sections::section::Section;
+use crate::templates::template::TemplateType;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Range;
+use std::path::Path;
+
+// A summary of what a file holds and what could not be read from it, for working out why a
+// file will not load
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GribReport {
+    pub message_count: usize,
+    // The number of messages for each discipline and originating center
+    pub disciplines: BTreeMap<u8, usize>,
+    pub centers: BTreeMap<u16, usize>,
+    // Every template number seen, including those that can not be read
+    pub grid_templates: BTreeSet<u16>,
+    pub product_templates: BTreeSet<u16>,
+    pub data_representation_templates: BTreeSet<u16>,
+    // The offset of each message using a template that can not be read, with the template
+    pub unsupported_templates: Vec<(usize, GribError)>,
+    // The byte ranges that could not be read as messages at all, with why
+    pub corrupt_regions: Vec<(Range<usize>, GribError)>,
+}
+
+impl GribReport {
+    pub fn from_data(data: &[u8]) -> GribReport {
+        let mut report = GribReport::default();
+
+        let mut iterator = MessageIterator::resynchronizing(data);
+        for message in iterator.by_ref().flatten() {
+            report.add_message(&message);
+        }
+
+        report.corrupt_regions = iterator
+            .skipped()
+            .iter()
+            .cloned()
+            .zip(iterator.errors().iter().map(|(_, error)| error.clone()))
+            .collect();
+
+        report
+    }
+
+    // Reads the whole file, so corrupt regions are found wherever they are
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<GribReport, GribError> {
+        let path = path.as_ref();
+        let data = std::fs::read(path).map_err(|e| GribError::Io(format!("Failed to read {}: {}", path.display(), e)))?;
+        Ok(GribReport::from_data(&data))
+    }
+
+    pub fn is_clean(&self) -> bool {
+        self.unsupported_templates.is_empty() && self.corrupt_regions.is_empty()
+    }
+
+    fn add_message(&mut self, message: &Message) {
+        self.message_count += 1;
+
+        // Fields of the same message sharing a template are only reported once
+        let mut unsupported = Vec::new();
+        for section in &message.sections {
+            match section {
+                Section::Indicator(indicator) => {
+                    *self.disciplines.entry(u8::from(indicator.discipline())).or_insert(0) += 1;
+                }
+                Section::Identification(identification) => {
+                    *self.centers.entry(identification.center_id()).or_insert(0) += 1;
+                }
+                Section::GridDefinition(grid) => {
+                    let number = grid.grid_definition_template_number();
+                    self.grid_templates.insert(number);
+                    if grid.template_length().is_none() {
+                        unsupported.push(GribError::UnsupportedTemplate { kind: TemplateType::Grid, number });
+                    }
+                }
+                Section::ProductDefinition(product) => {
+                    let number = product.product_definition_template_number();
+                    self.product_templates.insert(number);
+                    if product.template_length().is_none() {
+                        unsupported.push(GribError::UnsupportedTemplate { kind: TemplateType::Product, number });
+                    }
+                }
+                Section::DataRepresentation(data_representation) => {
+                    let number = data_representation.data_representation_template_number();
+                    self.data_representation_templates.insert(number);
+                    if data_representation.template_length().is_none() {
+                        unsupported.push(GribError::UnsupportedTemplate { kind: TemplateType::DataRepresentation, number });
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        for error in unsupported {
+            let entry = (message.offset(), error);
+            if !self.unsupported_templates.contains(&entry) {
+                self.unsupported_templates.push(entry);
+            }
+        }
+    }
+}
@@ -1,97 +1,235 @@
+use crate::error::GribError;
 use std::vec::Vec;
-use std::iter::Iterator;
-use crate::utils::bit_array_from_bytes;
-use crate::utils::read_u32_from_bytes;
-use crate::utils::positive_bit_count;
-use crate::utils::byte_to_bits;
+use crate::utils::{read_u32_from_bytes, BitReader};
 use super::grib_section::GribSection;
 
+// A bitmap section with the indicator set to 255, meaning the bitmap does not apply
+static NO_BITMAP: [u8; 6] = [0, 0, 0, 6, 6, 255];
+
+#[derive(Clone, Copy)]
 pub struct BitmapSection<'a> {
     data: &'a[u8],
 }
 
 impl<'a> BitmapSection<'a> {
-    pub fn from_data(data: &[u8]) -> BitmapSection {
+    pub fn from_data(data: &'a [u8]) -> BitmapSection<'a> {
         BitmapSection {
-            data: &data,
+            data,
         }
     }
 
+    // Stands in for the bitmap section of a message that leaves it out, every point holds a value
+    pub fn absent() -> BitmapSection<'a> {
+        BitmapSection::from_data(&NO_BITMAP)
+    }
+
+    pub fn bitmap_indicator(&self) -> u8 {
+        self.data[5]
+    }
+
     pub fn has_bitmap(&self) -> bool {
         self.data[5] == 0
     }
 
-    pub fn raw_bitmap_data(&self) -> &[u8] {
+    // Later fields of a message can reuse the bitmap defined for an earlier field
+    pub fn uses_previous_bitmap(&self) -> bool {
+        self.data[5] == 254
+    }
+
+    pub fn raw_bitmap_data(&self) -> &'a [u8] {
         &self.data[6..]
     }
 
     pub fn bitmap(&self) -> Vec<u8> {
-        bit_array_from_bytes(self.raw_bitmap_data())
+        let mut reader = BitReader::new(self.raw_bitmap_data());
+        let mut bitmap = Vec::with_capacity(reader.remaining_bits());
+        while let Ok(bit) = reader.read_bit() {
+            bitmap.push(bit as u8);
+        }
+
+        bitmap
     }
 
     pub fn map_data(&self, unmapped_data: Vec<f64>) -> Vec<f64> {
-        let mut nan_count: usize = 0;
-
-        let bitmask = self.bitmap();
-        let mut data = Vec::new();
-        data.resize(bitmask.len(), 0.0);
-
-        for (i, mask) in bitmask.iter().enumerate() {
-            data[i] = match mask {
-                1 => unmapped_data[i - nan_count],
-                _ => {
-                    nan_count += 1;
-                    std::f64::NAN
-                }
-            };
+        if !self.has_bitmap() {
+            return unmapped_data;
+        }
+
+        let mut reader = BitReader::new(self.raw_bitmap_data());
+        let mut values = unmapped_data.into_iter();
+        let mut data = Vec::with_capacity(reader.remaining_bits());
+        while let Ok(present) = reader.read_bit() {
+            data.push(match present {
+                true => values.next().unwrap_or(f64::NAN),
+                false => f64::NAN,
+            });
         }
 
         data
     }
 
+    // Which of the first point_count grid points hold a value, borrowed from the bitmap
+    pub fn mask(&self, point_count: usize) -> Bitmask<'a> {
+        match self.has_bitmap() {
+            true => Bitmask {
+                bits: Some(self.raw_bitmap_data()),
+                len: point_count.min(self.raw_bitmap_data().len() * 8),
+            },
+            false => Bitmask {
+                bits: None,
+                len: point_count,
+            },
+        }
+    }
+
+    // Spreads the packed values across the grid points as they are read
+    pub fn map_iter<I: Iterator<Item = f64>>(&self, values: I) -> BitmapIterator<'a, I> {
+        BitmapIterator {
+            bitmap: match self.has_bitmap() {
+                true => Some(BitReader::new(self.raw_bitmap_data())),
+                false => None,
+            },
+            values,
+        }
+    }
+
+    // Spreads the packed values at the start of out across the grid points in place, returning
+    // the number of grid points. Working backwards means no value is overwritten before it moves.
+    pub fn expand_into<T: Copy>(&self, out: &mut [T], packed_count: usize, point_count: usize, missing: T) -> Result<usize, GribError> {
+        if !self.has_bitmap() {
+            return Ok(packed_count.min(point_count));
+        }
+
+        let bitmap = self.raw_bitmap_data();
+        let point_count = point_count.min(bitmap.len() * 8);
+        if out.len() < point_count {
+            return Err(GribError::BufferTooSmall { length: out.len(), required: point_count });
+        }
+
+        let is_set = |i: usize| bitmap[i / 8] & (0x80 >> (i % 8)) != 0;
+        let mut rank = (0..point_count).filter(|i| is_set(*i)).count();
+        for i in (0..point_count).rev() {
+            out[i] = if is_set(i) {
+                rank -= 1;
+                if rank < packed_count { out[rank] } else { missing }
+            } else {
+                missing
+            };
+        }
+
+        Ok(point_count)
+    }
+
+    // The number of set bits before each byte of the bitmap, so repeated lookups
+    // do not need to count through the bitmap each time
+    pub fn rank_table(&self) -> Vec<usize> {
+        let raw_bitmap = self.raw_bitmap_data();
+        let mut ranks = Vec::with_capacity(raw_bitmap.len() + 1);
+        let mut rank = 0;
+        ranks.push(rank);
+        for byte in raw_bitmap {
+            rank += byte.count_ones() as usize;
+            ranks.push(rank);
+        }
+
+        ranks
+    }
+
+    pub fn data_index_with_ranks(&self, index: usize, ranks: &[usize]) -> Option<usize> {
+        if !self.has_bitmap() {
+            return Some(index);
+        }
+
+        let byte = *self.raw_bitmap_data().get(index / 8)?;
+        let bit = index % 8;
+        if byte & (0x80 >> bit) == 0 {
+            return None;
+        }
+
+        let set_before = ((byte as u16) >> (8 - bit)).count_ones() as usize;
+        Some(ranks.get(index / 8)? + set_before)
+    }
+
     pub fn data_index(&self, index: usize) -> Option<usize> {
         if !self.has_bitmap() {
             return Some(index);
         }
 
-        // 110101011 01101101
-        // 012345678
-        // 5 - 2 = 3
-        let bitmask = self.bitmap();
-        if bitmask.len() <= index {
-            return None
-        } else if bitmask[index] == 0 {
-            return None
+        let raw_bitmap = self.raw_bitmap_data();
+        let mut reader = BitReader::new(raw_bitmap);
+        reader.seek(index);
+        if !reader.read_bit().ok()? {
+            return None;
+        }
+
+        // Whole bytes before the index can be counted at once
+        let full_bytes = index / 8;
+        let set_bits = raw_bitmap[..full_bytes].iter().map(|b| b.count_ones() as usize).sum::<usize>();
+
+        let mut reader = BitReader::new(&raw_bitmap[full_bytes..]);
+        let partial = reader.read(index % 8).ok()?.count_ones() as usize;
+
+        Some(set_bits + partial)
+    }
+}
+
+// Marks which grid points hold a value. Without a bitmap every point holds one.
+#[derive(Clone, Copy, Debug)]
+pub struct Bitmask<'b> {
+    bits: Option<&'b [u8]>,
+    len: usize,
+}
+
+impl<'b> Bitmask<'b> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn is_set(&self, index: usize) -> bool {
+        match self.bits {
+            _ if index >= self.len => false,
+            Some(bits) => bits[index / 8] & (0x80 >> (index % 8)) != 0,
+            None => true,
         }
+    }
 
-        let mut nan_count: usize = 0;
-        for i in (0..index).rev() {
-            if bitmask[i] == 0 {
-                nan_count += 1;
-            }
+    // The number of grid points that hold a value
+    pub fn count(&self) -> usize {
+        match self.bits {
+            Some(_) => (0..self.len).filter(|i| self.is_set(*i)).count(),
+            None => self.len,
         }
+    }
 
-        Some(index - nan_count)
+    pub fn iter(&self) -> impl Iterator<Item = bool> + 'b {
+        let mask = *self;
+        (0..mask.len).map(move |i| mask.is_set(i))
+    }
+}
 
-        // EXPERIMENTAL
-        // let raw_bitmap = self.raw_bitmap_data();
-        // let mut index_counter: usize = 0;
+pub struct BitmapIterator<'b, I: Iterator<Item = f64>> {
+    bitmap: Option<BitReader<'b>>,
+    values: I,
+}
 
-        // for i in 0..raw_bitmap.len() {
-        //     let valid_count = positive_bit_count(&raw_bitmap[i]);
-        //     if index > i * 8 {
-        //         // println!("{}: {}", index_counter, valid_count);
-        //         index_counter += valid_count as usize;
-        //         continue;
-        //     }
+impl<'b, I: Iterator<Item = f64>> Iterator for BitmapIterator<'b, I> {
+    type Item = f64;
 
-        //     let bits = byte_to_bits(&raw_bitmap[i]);
-        //     let stop_index = (i*8) - index;
-        //     index_counter += raw_bitmap[0..stop_index].iter().sum::<u8>() as usize;
-        //     break;
-        // }
+    fn next(&mut self) -> Option<f64> {
+        let bitmap = match &mut self.bitmap {
+            Some(bitmap) => bitmap,
+            None => return self.values.next(),
+        };
 
-        // Some(index_counter.into())
+        match bitmap.read_bit() {
+            Ok(true) => Some(self.values.next().unwrap_or(f64::NAN)),
+            Ok(false) => Some(f64::NAN),
+            Err(_) => None,
+        }
     }
 }
 
@@ -103,4 +241,42 @@ impl <'a> GribSection for BitmapSection<'a> {
     fn number(&self) -> u8 {
         self.data[4]
     }
-}
\ No newline at end of file
+
+    fn data(&self) -> &[u8] {
+        self.data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BitmapSection;
+
+    #[test]
+    fn map_data_with_bitmap() {
+        let raw: [u8; 8] = [0, 0, 0, 8, 6, 0, 0b1101_0110, 0b1000_0000];
+        let bitmap = BitmapSection::from_data(&raw);
+        assert_eq!(bitmap.bitmap()[0..9], [1, 1, 0, 1, 0, 1, 1, 0, 1]);
+
+        let data = bitmap.map_data(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(data.len(), 16);
+        assert_eq!(data[8], 6.0);
+        assert!(data[2].is_nan() && data[9].is_nan());
+
+        assert_eq!(bitmap.data_index(0), Some(0));
+        assert_eq!(bitmap.data_index(2), None);
+        assert_eq!(bitmap.data_index(6), Some(4));
+        assert_eq!(bitmap.data_index(8), Some(5));
+        assert_eq!(bitmap.data_index(16), None);
+
+        let mask = bitmap.mask(12);
+        assert_eq!(mask.count(), 6);
+        assert!(mask.is_set(8) && !mask.is_set(9) && !mask.is_set(12));
+        assert_eq!(mask.iter().filter(|b| *b).count(), 6);
+
+        let ranks = bitmap.rank_table();
+        assert_eq!(ranks, vec![0, 5, 6]);
+        for index in 0..17 {
+            assert_eq!(bitmap.data_index_with_ranks(index, &ranks), bitmap.data_index(index));
+        }
+    }
+}
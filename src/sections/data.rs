@@ -1,4 +1,4 @@
-use crate::utils::{bit_array_from_bytes, read_u32_from_bytes};
+use crate::utils::read_u32_from_bytes;
 use super::grib_section::GribSection;
 
 pub struct DataSection<'a> {
@@ -6,9 +6,9 @@ pub struct DataSection<'a> {
 }
 
 impl<'a> DataSection<'a> {
-    pub fn from_data(data: &[u8]) -> DataSection {
+    pub fn from_data(data: &'a [u8]) -> DataSection<'a> {
         DataSection {
-            data: &data,
+            data,
         }
     }
 
@@ -17,7 +17,10 @@ impl<'a> DataSection<'a> {
     }
 
     pub fn raw_bit_data(&self) -> Vec<u8> {
-        bit_array_from_bytes(self.raw_data_array())
+        self.raw_data_array()
+            .iter()
+            .flat_map(|b| (0..8).rev().map(move |i| (b >> i) & 1))
+            .collect()
     }
 }
 
@@ -29,4 +32,8 @@ impl <'a> GribSection for DataSection<'a> {
     fn number(&self) -> u8 {
         self.data[4]
     }
+
+    fn data(&self) -> &[u8] {
+        self.data
+    }
 }
\ No newline at end of file
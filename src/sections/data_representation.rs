@@ -1,5 +1,5 @@
 use crate::utils::{read_u16_from_bytes, read_u32_from_bytes};
-use crate::templates::data_representation::{DataRepresentationTemplate, JPEGDataRepresentationTemplate, SimpleGridPointDataRepresentationTemplate};
+use crate::templates::data_representation::{ComplexSpatialDifferencingDataRepresentationTemplate, DataRepresentationTemplate, JPEGDataRepresentationTemplate, SimpleGridPointDataRepresentationTemplate};
 use super::grib_section::GribSection;
 
 pub struct DataRepresentationSection<'a> {
@@ -7,9 +7,9 @@ pub struct DataRepresentationSection<'a> {
 }
 
 impl<'a> DataRepresentationSection<'a> {
-    pub fn from_data(data: &[u8]) -> DataRepresentationSection {
+    pub fn from_data(data: &'a [u8]) -> DataRepresentationSection<'a> {
         DataRepresentationSection {
-            data: &data,
+            data,
         }
     }
     
@@ -21,11 +21,26 @@ impl<'a> DataRepresentationSection<'a> {
         read_u16_from_bytes(self.data, 9).unwrap_or(0)
     }
 
+    // The length the section needs to hold its template, for the templates that can be read
+    pub fn template_length(&self) -> Option<usize> {
+        match self.data_representation_template_number() {
+            0 => Some(SimpleGridPointDataRepresentationTemplate::LENGTH),
+            3 => Some(ComplexSpatialDifferencingDataRepresentationTemplate::LENGTH),
+            40 => Some(JPEGDataRepresentationTemplate::LENGTH),
+            #[cfg(feature = "png")]
+            41 => Some(crate::templates::data_representation::PNGDataRepresentationTemplate::LENGTH),
+            _ => None,
+        }
+    }
+
     pub fn data_representation_template(&self) -> Option<Box<dyn DataRepresentationTemplate<f64> + 'a>> {
         let template_number = self.data_representation_template_number();
         match template_number {
             0 => Some(Box::new(SimpleGridPointDataRepresentationTemplate::new(self.data))),
+            3 => Some(Box::new(ComplexSpatialDifferencingDataRepresentationTemplate::new(self.data))),
             40 => Some(Box::new(JPEGDataRepresentationTemplate::new(self.data))),
+            #[cfg(feature = "png")]
+            41 => Some(Box::new(crate::templates::data_representation::PNGDataRepresentationTemplate::new(self.data))),
             _ => None,
         }
     }
@@ -39,4 +54,8 @@ impl <'a> GribSection for DataRepresentationSection<'a> {
     fn number(&self) -> u8 {
         self.data[4]
     }
+
+    fn data(&self) -> &[u8] {
+        self.data
+    }
 }
\ No newline at end of file
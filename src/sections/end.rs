@@ -13,9 +13,9 @@ pub struct EndSection<'a> {
 }
 
 impl<'a> EndSection<'a> {
-    pub fn from_data(data: &[u8]) -> EndSection {
+    pub fn from_data(data: &'a [u8]) -> EndSection<'a> {
         EndSection {
-            data: &data,
+            data,
         }
     }
 
@@ -36,4 +36,8 @@ impl <'a> GribSection for EndSection<'a> {
     fn number(&self) -> u8 {
         8
     }
+
+    fn data(&self) -> &[u8] {
+        self.data
+    }
 }
\ No newline at end of file
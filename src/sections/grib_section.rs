@@ -1,5 +1,7 @@
 
+#[allow(clippy::len_without_is_empty)]
 pub trait GribSection {
 	fn len(&self) -> usize;
 	fn number(&self) -> u8;
+	fn data(&self) -> &[u8];
 }
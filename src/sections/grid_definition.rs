@@ -1,20 +1,23 @@
 use crate::utils::{read_u16_from_bytes, read_u32_from_bytes};
 use gribberish_macros::{DisplayDescription, FromValue};
-use crate::templates::grid::{GridDefinitionTemplate, LatitudeLongitudeGridTemplate};
+use crate::templates::grid::{GridDefinitionTemplate, LambertConformalTemplate, LatitudeLongitudeGridTemplate, RotatedLatitudeLongitudeGridTemplate};
 use super::grib_section::GribSection;
 
-#[repr(u8)]
+#[repr(u16)]
 #[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GridSource {
     #[description = "specified in code"]
     Code = 0,
     Predetermined = 1,
     #[description = "not applicable"]
     NotApplicable = 255,
+    Unknown(u8) = 256,
 }
 
-#[repr(u8)]
+#[repr(u16)]
 #[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NumberListInterpretation {
     #[description = "no appended list"]
     None = 0, 
@@ -25,6 +28,7 @@ pub enum NumberListInterpretation {
     #[description = "Numbers define the actual latitudes for each row in the grid. The list of numbers are integer values of the valid latitudes in microdegrees (scale by 106) or in unit equal to the ratio of the basic angle and the subdivisions number for each row, in the same order as specified in the 'scanning mode flag' (bit no. 2)"]
     ActualLatitudes = 3, 
     Missing = 255,
+    Unknown(u8) = 256,
 }
 
 pub struct GridDefinitionSection<'a>{
@@ -32,9 +36,9 @@ pub struct GridDefinitionSection<'a>{
 }
 
 impl<'a> GridDefinitionSection<'a> {
-    pub fn from_data(data: &[u8]) -> GridDefinitionSection {
+    pub fn from_data(data: &'a [u8]) -> GridDefinitionSection<'a> {
         GridDefinitionSection {
-            data: &data,
+            data,
         }
     }
 
@@ -58,13 +62,30 @@ impl<'a> GridDefinitionSection<'a> {
         read_u16_from_bytes(self.data, 12).unwrap_or(0)
     }
 
+    // The length the section needs to hold its template, for the templates that can be read
+    pub fn template_length(&self) -> Option<usize> {
+        match self.grid_definition_template_number() {
+            0 => Some(LatitudeLongitudeGridTemplate::LENGTH),
+            1 => Some(RotatedLatitudeLongitudeGridTemplate::LENGTH),
+            30 => Some(LambertConformalTemplate::LENGTH),
+            _ => None,
+        }
+    }
+
     pub fn grid_definition_template(&self) -> Option<Box<dyn GridDefinitionTemplate + 'a>> {
         let template_number = self.grid_definition_template_number();
         match template_number {
             0 => Some(Box::new(LatitudeLongitudeGridTemplate::new(self.data))),
+            1 => Some(Box::new(RotatedLatitudeLongitudeGridTemplate::new(self.data))),
+            30 => Some(Box::new(LambertConformalTemplate::new(self.data))),
             _ => None,
         }
     }
+
+    #[cfg(feature = "proj")]
+    pub fn crs(&self) -> Result<crate::crs::GridCrs<'a>, crate::error::GribError> {
+        crate::crs::GridCrs::new(self.data, self.grid_definition_template_number())
+    }
 }
 
 impl <'a> GribSection for GridDefinitionSection<'a> {
@@ -75,4 +96,8 @@ impl <'a> GribSection for GridDefinitionSection<'a> {
     fn number(&self) -> u8 {
         self.data[4]
     }
+
+    fn data(&self) -> &[u8] {
+        self.data
+    }
 }
\ No newline at end of file
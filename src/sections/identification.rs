@@ -2,9 +2,94 @@ use gribberish_macros::{DisplayDescription, FromValue};
 use crate::utils::{read_u32_from_bytes, read_u16_from_bytes};
 use super::grib_section::GribSection;
 use chrono::prelude::*;
+use std::convert::TryFrom;
 
-#[repr(u8)]
-#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue)]
+#[repr(u16)]
+#[derive(Eq, Clone, Copy, PartialEq, Debug, DisplayDescription, FromValue)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Center {
+    #[description = "US National Weather Service - NCEP"]
+    NCEP = 7,
+    #[description = "US National Weather Service - NWSTG"]
+    NWSTG = 8,
+    #[description = "US National Weather Service - other"]
+    NWSOther = 9,
+    #[description = "Japan Meteorological Agency - Tokyo"]
+    JMA = 34,
+    #[description = "Brazilian Space Agency - INPE/CPTEC"]
+    CPTEC = 46,
+    #[description = "US National Hurricane Center - Miami"]
+    NHC = 52,
+    #[description = "Canadian Meteorological Centre - Montreal"]
+    CMC = 54,
+    #[description = "US Air Force - Air Force Global Weather Center"]
+    USAF = 57,
+    #[description = "US Navy - Fleet Numerical Oceanography Center"]
+    FNMOC = 58,
+    #[description = "NOAA Forecast Systems Laboratory - Boulder"]
+    FSL = 59,
+    #[description = "National Center for Atmospheric Research - Boulder"]
+    NCAR = 60,
+    #[description = "UK Meteorological Office - Exeter"]
+    UKMO = 74,
+    #[description = "Deutscher Wetterdienst - Offenbach"]
+    DWD = 78,
+    #[description = "Italian Meteorological Service - Rome"]
+    CNMC = 80,
+    #[description = "Swedish Meteorological and Hydrological Institute - Norrkoping"]
+    SMHI = 82,
+    #[description = "Meteo-France - Toulouse (RSMC)"]
+    MeteoFranceToulouse = 84,
+    #[description = "Meteo-France - Toulouse"]
+    MeteoFrance = 85,
+    #[description = "Finnish Meteorological Institute - Helsinki"]
+    FMI = 86,
+    #[description = "Norwegian Meteorological Institute - Oslo"]
+    MetNorway = 88,
+    #[description = "Hellenic National Meteorological Service - Athens"]
+    HNMS = 96,
+    #[description = "European Space Agency"]
+    ESA = 97,
+    #[description = "European Centre for Medium-Range Weather Forecasts"]
+    ECMWF = 98,
+    #[description = "Royal Netherlands Meteorological Institute - De Bilt"]
+    KNMI = 99,
+    #[description = "US NOAA/NESDIS"]
+    NESDIS = 160,
+    #[description = "US NOAA Office of Oceanic and Atmospheric Research"]
+    OAR = 161,
+    #[description = "US National Aeronautics and Space Administration"]
+    NASA = 173,
+    #[description = "Spanish State Meteorological Agency - Madrid"]
+    AEMET = 214,
+    #[description = "MeteoSwiss - Zurich"]
+    MeteoSwiss = 215,
+    #[description = "Met Eireann - Dublin"]
+    MetEireann = 233,
+    #[description = "Max Planck Institute for Meteorology - Hamburg"]
+    MPI = 252,
+    #[description = "EUMETSAT Operations Centre - Darmstadt"]
+    EUMETSAT = 254,
+    Missing = 255,
+    Unknown(u8) = 256,
+}
+
+impl Center {
+    pub fn from_id(id: u16) -> Center {
+        match u8::try_from(id) {
+            Ok(id) => id.into(),
+            Err(_) => Center::Missing,
+        }
+    }
+
+    pub fn id(&self) -> u16 {
+        u8::from(*self).into()
+    }
+}
+
+#[repr(u16)]
+#[derive(Eq, Clone, Copy, PartialEq, Debug, DisplayDescription, FromValue)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ReferenceDataSignificance {
     Analysis = 0,
     #[description = "start of forecast"]
@@ -14,10 +99,12 @@ pub enum ReferenceDataSignificance {
     #[description = "observation time"]
     ObservationTime = 3,
     Missing = 255,
+    Unknown(u8) = 256,
 }
 
-#[repr(u8)]
-#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue)]
+#[repr(u16)]
+#[derive(Eq, Clone, Copy, PartialEq, Debug, DisplayDescription, FromValue)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ProductionStatus {
     Operational = 0,
     #[description = "operational test"]
@@ -37,10 +124,12 @@ pub enum ProductionStatus {
     #[description = "UERRA test"]
     UERRATest = 9,
     Missing = 255,
+    Unknown(u8) = 256,
 }
 
-#[repr(u8)]
-#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue)]
+#[repr(u16)]
+#[derive(Eq, Clone, Copy, PartialEq, Debug, DisplayDescription, FromValue)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GribDataType {
     Analysis = 0,
     Forecast = 1,
@@ -60,6 +149,7 @@ pub enum GribDataType {
     EventProbability = 8,
     Experimental = 192,
     Missing = 255,
+    Unknown(u8) = 256,
 }
 
 pub struct IdentificationSection<'a>{
@@ -67,11 +157,31 @@ pub struct IdentificationSection<'a>{
 }
 
 impl<'a> IdentificationSection<'a> {
-    pub fn from_data(data: &[u8]) -> IdentificationSection {
+    pub fn from_data(data: &'a [u8]) -> IdentificationSection<'a> {
         IdentificationSection {
-            data: &data,
+            data,
         }
     }
+
+    pub fn center_id(&self) -> u16 {
+        read_u16_from_bytes(self.data, 5).unwrap_or(0)
+    }
+
+    pub fn center(&self) -> Center {
+        Center::from_id(self.center_id())
+    }
+
+    pub fn subcenter_id(&self) -> u16 {
+        read_u16_from_bytes(self.data, 7).unwrap_or(0)
+    }
+
+    pub fn master_table_version(&self) -> u8 {
+        self.data[9]
+    }
+
+    pub fn local_table_version(&self) -> u8 {
+        self.data[10]
+    }
  
     pub fn reference_date_significance(&self) -> ReferenceDataSignificance {
         self.data[11].into()
@@ -85,7 +195,7 @@ impl<'a> IdentificationSection<'a> {
         let minute = self.data[17] as u32;
         let second = self.data[18] as u32;
 
-        Utc.ymd(year, month, day).and_hms(hour, minute, second)
+        Utc.with_ymd_and_hms(year, month, day, hour, minute, second).unwrap()
     }
 
     pub fn production_status(&self) -> ProductionStatus {
@@ -105,4 +215,8 @@ impl <'a> GribSection for IdentificationSection<'a> {
     fn number(&self) -> u8 {
         self.data[4]
     }
+
+    fn data(&self) -> &[u8] {
+        self.data
+    }
 }
\ No newline at end of file
@@ -10,15 +10,28 @@ fn validate_indicator_section(data: &[u8]) -> bool {
 	}
 }
 
-#[repr(u8)]
+#[repr(u16)]
 #[derive(Eq, Clone, PartialEq, Debug, DisplayDescription, FromValue)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Discipline {
 	Meteorological = 0,
 	Hydrological = 1,
 	LandSurface = 2,
 	Space = 3,
+	SpaceWeather = 4,
 	Oceanographic = 10,
 	Missing = 255,
+	Unknown(u8) = 256,
+}
+
+// The edition byte sits at the same place in every edition, so it can be read
+// before knowing how the rest of the message is laid out
+pub fn detect_edition(data: &[u8]) -> Option<u8> {
+	if data.len() < 8 || !validate_indicator_section(&data[0..4]) {
+		return None;
+	}
+
+	Some(data[7])
 }
 
 pub struct IndicatorSection<'a>{
@@ -27,9 +40,9 @@ pub struct IndicatorSection<'a>{
 
 impl<'a> IndicatorSection<'a> {
 
-	pub fn from_data(data: &[u8]) -> IndicatorSection {
+	pub fn from_data(data: &'a [u8]) -> IndicatorSection<'a> {
 		IndicatorSection {
-            data: &data,
+            data,
 		}
 	}
 
@@ -50,7 +63,7 @@ impl<'a> IndicatorSection<'a> {
 	}
 
 	pub fn total_length(&self) -> u64 {
-		read_u64_from_bytes(self.data, 8).unwrap_or(0) as u64
+		read_u64_from_bytes(self.data, 8).unwrap_or(0)
 	}
 }
 
@@ -62,11 +75,17 @@ impl <'a> GribSection for IndicatorSection<'a> {
     fn number(&self) -> u8 {
         0
     }
+
+    fn data(&self) -> &[u8] {
+        self.data
+    }
 }
 
+#[cfg(test)]
 mod tests {
 	use super::IndicatorSection;
 	use super::Discipline;
+	use super::detect_edition;
 
     #[test]
     fn read_indicator() {
@@ -74,5 +93,23 @@ mod tests {
 		let indicator = IndicatorSection::from_data(&raw);
         assert!(indicator.valid());
 		assert!(indicator.discipline() == Discipline::Meteorological);
+		assert_eq!(detect_edition(&raw), Some(2));
+	}
+
+	#[test]
+	fn detect_grib1_edition() {
+		let raw: [u8; 8] = [0x47, 0x52, 0x49, 0x42, 0x00, 0x00, 0x40, 0x01];
+		assert_eq!(detect_edition(&raw), Some(1));
+		assert_eq!(detect_edition(b"7777GRIB"), None);
+	}
+
+	#[test]
+	fn keep_unknown_disciplines() {
+		assert_eq!(Discipline::from(10), Discipline::Oceanographic);
+		assert_eq!(Discipline::from(20), Discipline::Unknown(20));
+		assert_eq!(u8::from(Discipline::Unknown(20)), 20);
+		assert_eq!(u8::from(Discipline::Missing), 255);
+		assert_eq!(Discipline::from_value(20), None);
+		assert_eq!(Discipline::Unknown(20).to_string(), "unknown (20)");
 	}
 }
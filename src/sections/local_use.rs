@@ -6,9 +6,9 @@ pub struct LocalUseSection<'a> {
 }
 
 impl<'a> LocalUseSection<'a> {
-    pub fn from_data(data: &[u8]) -> LocalUseSection {
+    pub fn from_data(data: &'a [u8]) -> LocalUseSection<'a> {
         LocalUseSection {
-            data: &data,
+            data,
         }
     }
 }
@@ -21,4 +21,8 @@ impl <'a> GribSection for LocalUseSection<'a> {
     fn number(&self) -> u8 {
         self.data[4]
     }
+
+    fn data(&self) -> &[u8] {
+        self.data
+    }
 }
\ No newline at end of file
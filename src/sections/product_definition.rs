@@ -1,5 +1,5 @@
 use crate::utils::{read_u16_from_bytes, read_u32_from_bytes};
-use crate::templates::product::ProductTemplate;
+use crate::templates::product::{HorizontalAnalysisForecastTemplate, ProductTemplate};
 use super::grib_section::GribSection;
 
 pub struct ProductDefinitionSection<'a> {
@@ -7,9 +7,9 @@ pub struct ProductDefinitionSection<'a> {
 }
 
 impl<'a> ProductDefinitionSection<'a> {
-    pub fn from_data(data: &[u8]) -> ProductDefinitionSection {
+    pub fn from_data(data: &'a [u8]) -> ProductDefinitionSection<'a> {
         ProductDefinitionSection {
-            data: &data,
+            data,
         }
     }
 
@@ -21,8 +21,16 @@ impl<'a> ProductDefinitionSection<'a> {
         read_u16_from_bytes(self.data, 7).unwrap_or(0)
     }
 
-    pub fn product_definition_template(&self, discipline: u8) -> ProductTemplate<'a> {
-        ProductTemplate::from_template_number(self.product_definition_template_number(), &self.data, discipline)
+    // The length the section needs to hold its template, for the templates that can be read
+    pub fn template_length(&self) -> Option<usize> {
+        match self.product_definition_template_number() {
+            number @ (0 | 1 | 8 | 11 | 40) => Some(HorizontalAnalysisForecastTemplate::length(number)),
+            _ => None,
+        }
+    }
+
+    pub fn product_definition_template(&self, discipline: u8, center: u16, local_table_version: u8) -> ProductTemplate<'a> {
+        ProductTemplate::from_template_number(self.product_definition_template_number(), self.data, discipline, center, local_table_version)
     }
 }
 
@@ -34,4 +42,8 @@ impl <'a> GribSection for ProductDefinitionSection<'a> {
     fn number(&self) -> u8 {
         self.data[4]
     }
+
+    fn data(&self) -> &[u8] {
+        self.data
+    }
 }
\ No newline at end of file
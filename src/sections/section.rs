@@ -1,3 +1,4 @@
+use crate::error::GribError;
 use crate::utils::read_u32_from_bytes;
 use super::indicator::IndicatorSection;
 use super::identification::IdentificationSection;
@@ -23,26 +24,72 @@ pub enum Section<'a> {
 }
 
 impl<'a> Section<'a> {
-    pub fn from_data(data: &'a[u8], offset: usize) -> Result<Section<'a>, &'static str> {
+    pub fn from_data(data: &'a[u8], offset: usize) -> Result<Section<'a>, GribError> {
+        // The end section is only the 7777 marker, every other section starts with its length and number
+        if offset + 4 > data.len() || (offset + 5 > data.len() && !EndSection::is_end_section(data, offset)) {
+            return Err(GribError::UnexpectedEof { offset: offset as u64 });
+        }
+
         let section_len = section_length(data, offset);
         let section_num = section_number(data, offset);
 
+        let invalid = |reason: &str| GribError::InvalidSection {
+            number: section_num,
+            offset: offset as u64,
+            reason: reason.into(),
+        };
+
+        if (section_num != 8 && section_len < 5) || offset + section_len > data.len() {
+            return Err(invalid("length"));
+        }
+
         let section_data = &data[offset..offset+section_len];
 
-        match section_num { 
-            0 => Ok(Section::Indicator(IndicatorSection::from_data(section_data))),
-            1 => Ok(Section::Identification(IdentificationSection::from_data(section_data))),
-            2 => Ok(Section::LocalUse(LocalUseSection::from_data(section_data))),
-            3 => Ok(Section::GridDefinition(GridDefinitionSection::from_data(section_data))),
-            4 => Ok(Section::ProductDefinition(ProductDefinitionSection::from_data(section_data))),
-            5 => Ok(Section::DataRepresentation(DataRepresentationSection::from_data(section_data))),
-            6 => Ok(Section::Bitmap(BitmapSection::from_data(section_data))),
-            7 => Ok(Section::Data(DataSection::from_data(section_data))),
-            8 => Ok(Section::End(EndSection::from_data(section_data))),
-            _ => Err("Invalid section number")
+        let section = match section_num { 
+            0 => Section::Indicator(IndicatorSection::from_data(section_data)),
+            1 => Section::Identification(IdentificationSection::from_data(section_data)),
+            2 => Section::LocalUse(LocalUseSection::from_data(section_data)),
+            3 => Section::GridDefinition(GridDefinitionSection::from_data(section_data)),
+            4 => Section::ProductDefinition(ProductDefinitionSection::from_data(section_data)),
+            5 => Section::DataRepresentation(DataRepresentationSection::from_data(section_data)),
+            6 => Section::Bitmap(BitmapSection::from_data(section_data)),
+            7 => Section::Data(DataSection::from_data(section_data)),
+            8 => Section::End(EndSection::from_data(section_data)),
+            _ => return Err(invalid("number")),
+        };
+
+        // The accessors read their fields without checking, so a section that ends part way
+        // through its fields or its template is rejected here
+        if section_len < section.required_length() {
+            return Err(GribError::UnexpectedEof { offset: offset as u64 });
+        }
+
+        Ok(section)
+    }
+
+    // The fixed part of the section, or all of its template for the templates that can be read
+    fn required_length(&self) -> usize {
+        let template_length = match self {
+            Section::GridDefinition(grid_definition) => grid_definition.template_length(),
+            Section::ProductDefinition(product_definition) => product_definition.template_length(),
+            Section::DataRepresentation(data_representation) => data_representation.template_length(),
+            _ => None,
+        };
+
+        match self {
+            Section::Indicator(_) => 16,
+            Section::Identification(_) => 21,
+            Section::GridDefinition(_) => 14,
+            Section::ProductDefinition(_) => 9,
+            Section::DataRepresentation(_) => 11,
+            Section::Bitmap(_) => 6,
+            Section::End(_) => 4,
+            _ => 5,
         }
+        .max(template_length.unwrap_or(0))
     }
 
+    #[allow(clippy::len_without_is_empty)]
     pub fn len(&self) -> usize {
         match self {
             Section::Indicator(indicator) => indicator.len(),
@@ -70,6 +117,20 @@ impl<'a> Section<'a> {
             Section::End(end) => end.number(),
         }
     }
+
+    pub fn data(&self) -> &[u8] {
+        match self {
+            Section::Indicator(indicator) => indicator.data(),
+            Section::Identification(identification) => identification.data(),
+            Section::LocalUse(local_use) => local_use.data(),
+            Section::GridDefinition(grid_definition) => grid_definition.data(),
+            Section::ProductDefinition(product_definition) => product_definition.data(),
+            Section::DataRepresentation(data_representation) => data_representation.data(),
+            Section::Bitmap(bitmap) => bitmap.data(),
+            Section::Data(data) => data.data(),
+            Section::End(end) => end.data(),
+        }
+    }
 }
 
 // TODO: IMPL TRY FROMS FOR INNER TYPES HERE 
@@ -90,6 +151,6 @@ fn section_number(data: &[u8], offset: usize) -> u8 {
     } else if EndSection::is_end_section(data, offset) {
         8
     } else {
-        data[offset + 4]
+        *data.get(offset + 4).unwrap_or(&255)
     }
 }
\ No newline at end of file
@@ -0,0 +1,90 @@
+use crate::error::GribError;
+use crate::location_index::InterpolationMethod;
+use crate::message::Message;
+use chrono::{DateTime, Utc};
+use gribberish_types::Parameter;
+use std::collections::BTreeMap;
+
+// The value at the location in each message, ordered by the time each is valid at, as for a
+// forecast at a single point. The messages must hold the same parameter. Times without data
+// around the location are NaN, messages at the same time keep the order they were given in.
+pub fn time_series(
+    messages: &[Message],
+    location: &(f64, f64),
+    method: InterpolationMethod,
+) -> Result<Vec<(DateTime<Utc>, f64)>, GribError> {
+    let parameter = match messages.first() {
+        Some(message) => message.parameter()?,
+        None => return Ok(Vec::new()),
+    };
+
+    let mut series = messages
+        .iter()
+        .map(|message| {
+            check_parameter(message, &parameter)?;
+            Ok((message.forecast_date()?, value_at(message, location, method)?))
+        })
+        .collect::<Result<Vec<_>, GribError>>()?;
+
+    series.sort_by_key(|(date, _)| *date);
+    Ok(series)
+}
+
+// The value at each isobaric level in hPa, from the highest pressure up as in a sounding. The
+// messages must hold the same parameter valid at the same time, such as one group from
+// group_by_forecast_date, and those on other kinds of surface are left out.
+pub fn vertical_profile(
+    messages: &[&Message],
+    location: &(f64, f64),
+    method: InterpolationMethod,
+) -> Result<Vec<(f64, f64)>, GribError> {
+    let (parameter, forecast_date) = match messages.first() {
+        Some(message) => (message.parameter()?, message.forecast_date()?),
+        None => return Ok(Vec::new()),
+    };
+
+    let mut profile = Vec::new();
+    for message in messages {
+        check_parameter(message, &parameter)?;
+        if message.forecast_date()? != forecast_date {
+            return Err(GribError::InvalidData("The messages of a profile are not valid at the same time".into()));
+        }
+
+        if let Some(pressure) = message.product_template()?.pressure_hpa() {
+            profile.push((pressure, value_at(message, location, method)?));
+        }
+    }
+
+    profile.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+    Ok(profile)
+}
+
+// The messages valid at each time, keeping the order they were given in
+pub fn group_by_forecast_date<'m, 'a>(
+    messages: &'m [Message<'a>],
+) -> Result<BTreeMap<DateTime<Utc>, Vec<&'m Message<'a>>>, GribError> {
+    let mut groups: BTreeMap<DateTime<Utc>, Vec<&'m Message<'a>>> = BTreeMap::new();
+    for message in messages {
+        groups.entry(message.forecast_date()?).or_default().push(message);
+    }
+    Ok(groups)
+}
+
+fn check_parameter(message: &Message, parameter: &Parameter) -> Result<(), GribError> {
+    let found = message.parameter()?;
+    if &found != parameter {
+        return Err(GribError::InvalidData(format!(
+            "Expected every message to hold {} but found {}",
+            parameter.abbrev, found.abbrev
+        )));
+    }
+    Ok(())
+}
+
+// Points without data around them are NaN
+fn value_at(message: &Message, location: &(f64, f64), method: InterpolationMethod) -> Result<f64, GribError> {
+    match message.data_at_location_interpolated(location, method) {
+        Err(GribError::MissingData(_)) => Ok(f64::NAN),
+        value => value,
+    }
+}
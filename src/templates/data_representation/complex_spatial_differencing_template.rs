@@ -0,0 +1,262 @@
+use crate::error::GribError;
+use crate::{templates::template::{Template, TemplateType}, utils::grib_power};
+use super::data_representation_template::DataRepresentationTemplate;
+use super::tables::{GroupSplittingMethods, MissingValueManagement, OriginalFieldValue, SpatialDifferencingOrder};
+use crate::utils::{read_f32_from_bytes, read_i16_from_bytes, read_u32_from_bytes, BitReader};
+use std::ops::Range;
+
+pub struct ComplexSpatialDifferencingDataRepresentationTemplate<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Template for ComplexSpatialDifferencingDataRepresentationTemplate<'a> {
+    fn data(&self) -> &[u8] {
+        self.data
+    }
+
+    fn template_number(&self) -> u16 {
+        3
+    }
+
+    fn template_type(&self) -> TemplateType {
+        TemplateType::DataRepresentation
+    }
+
+    fn template_name(&self) -> &str {
+        "grid point data - complex packing and spatial differencing"
+    }
+}
+
+impl<'a> ComplexSpatialDifferencingDataRepresentationTemplate<'a> {
+    // The length of a section holding template 5.3
+    pub const LENGTH: usize = 49;
+
+    pub fn new(data: &'a [u8]) -> ComplexSpatialDifferencingDataRepresentationTemplate<'a> {
+        ComplexSpatialDifferencingDataRepresentationTemplate { data }
+    }
+
+    pub fn data_point_count(&self) -> usize {
+        read_u32_from_bytes(self.data, 5).unwrap_or(0) as usize
+    }
+
+    pub fn reference_value(&self) -> f32 {
+        read_f32_from_bytes(self.data, 11).unwrap_or(0.0)
+    }
+
+    pub fn binary_scale_factor(&self) -> i16 {
+        read_i16_from_bytes(self.data, 15).unwrap_or(0)
+    }
+
+    pub fn decimal_scale_factor(&self) -> i16 {
+        read_i16_from_bytes(self.data, 17).unwrap_or(0)
+    }
+
+    pub fn group_reference_bit_count(&self) -> u8 {
+        self.data[19]
+    }
+
+    pub fn original_field_value(&self) -> OriginalFieldValue {
+        self.data[20].into()
+    }
+
+    pub fn group_splitting_method(&self) -> GroupSplittingMethods {
+        self.data[21].into()
+    }
+
+    pub fn missing_value_management(&self) -> MissingValueManagement {
+        self.data[22].into()
+    }
+
+    pub fn group_count(&self) -> usize {
+        read_u32_from_bytes(self.data, 31).unwrap_or(0) as usize
+    }
+
+    pub fn group_width_reference(&self) -> u8 {
+        self.data[35]
+    }
+
+    pub fn group_width_bit_count(&self) -> u8 {
+        self.data[36]
+    }
+
+    pub fn group_length_reference(&self) -> u32 {
+        read_u32_from_bytes(self.data, 37).unwrap_or(0)
+    }
+
+    pub fn group_length_increment(&self) -> u8 {
+        self.data[41]
+    }
+
+    pub fn last_group_length(&self) -> u32 {
+        read_u32_from_bytes(self.data, 42).unwrap_or(0)
+    }
+
+    pub fn group_length_bit_count(&self) -> u8 {
+        self.data[46]
+    }
+
+    pub fn spatial_differencing_order(&self) -> SpatialDifferencingOrder {
+        self.data[47].into()
+    }
+
+    pub fn extra_descriptor_octet_count(&self) -> u8 {
+        self.data[48]
+    }
+
+    fn unpack_values<F>(&self, data: &[u8], read_groups: F) -> Result<Vec<f64>, GribError>
+    where
+        F: Fn(&[u8], &[PackedGroup]) -> Result<Vec<i64>, GribError>,
+    {
+        let value_count = self.data_point_count();
+        let group_count = self.group_count();
+
+        let bscale = grib_power(self.binary_scale_factor().into(), 2);
+        let dscale = grib_power(-(self.decimal_scale_factor() as i32), 10);
+        let reference_value: f64 = self.reference_value().into();
+
+        // With no groups every value is the reference value
+        if group_count == 0 {
+            return Ok(vec![reference_value * dscale; value_count]);
+        }
+
+        if self.missing_value_management() != MissingValueManagement::NoMissingValues {
+            return Err(GribError::InvalidData("Missing values within complex packed data are not supported".into()));
+        }
+
+        let order = match self.spatial_differencing_order() {
+            SpatialDifferencingOrder::First => 1,
+            SpatialDifferencingOrder::Second => 2,
+            SpatialDifferencingOrder::Unknown(order) => {
+                return Err(GribError::InvalidData(format!("Spatial differencing order {} is not supported", order)))
+            }
+        };
+
+        let mut reader = BitReader::new(data);
+
+        let descriptor_bits = self.extra_descriptor_octet_count() as usize * 8;
+        let first_values = (0..order)
+            .map(|_| reader.read_signed(descriptor_bits))
+            .collect::<Result<Vec<i64>, GribError>>()?;
+        let minimum_difference = reader.read_signed(descriptor_bits)?;
+
+        let reference_bits = self.group_reference_bit_count() as usize;
+        let references = (0..group_count)
+            .map(|_| reader.read(reference_bits))
+            .collect::<Result<Vec<u64>, GribError>>()?;
+        reader.align();
+
+        let width_bits = self.group_width_bit_count() as usize;
+        let width_reference = self.group_width_reference() as u64;
+        let widths = (0..group_count)
+            .map(|_| reader.read(width_bits).map(|w| w + width_reference))
+            .collect::<Result<Vec<u64>, GribError>>()?;
+        reader.align();
+
+        let length_bits = self.group_length_bit_count() as usize;
+        let length_reference = self.group_length_reference() as u64;
+        let length_increment = self.group_length_increment() as u64;
+        let mut lengths = (0..group_count)
+            .map(|_| reader.read(length_bits).map(|l| length_reference + l * length_increment))
+            .collect::<Result<Vec<u64>, GribError>>()?;
+        reader.align();
+        lengths[group_count - 1] = self.last_group_length() as u64;
+
+        // The position of each group follows from the widths and lengths of the groups before it
+        let mut offset = reader.bit_offset();
+        let mut groups = Vec::with_capacity(group_count);
+        for ((reference, width), length) in references.iter().zip(widths.iter()).zip(lengths.iter()) {
+            groups.push(PackedGroup {
+                offset,
+                reference: *reference,
+                width: *width as usize,
+                length: *length as usize,
+            });
+            offset += (*width * *length) as usize;
+        }
+
+        let mut values = read_groups(data, &groups)?;
+
+        if values.len() != value_count {
+            return Err(GribError::InvalidData(format!("Expected {} values but the groups held {}", value_count, values.len())));
+        }
+
+        // Undo the spatial differencing, the first values are given explicitly
+        for (i, first_value) in first_values.iter().enumerate().take(value_count) {
+            values[i] = *first_value;
+        }
+
+        for i in order..value_count {
+            values[i] = match order {
+                1 => values[i] + minimum_difference + values[i - 1],
+                _ => values[i] + minimum_difference + 2 * values[i - 1] - values[i - 2],
+            };
+        }
+
+        Ok(values
+            .iter()
+            .map(|v| (*v as f64 * bscale + reference_value) * dscale)
+            .collect())
+    }
+}
+
+impl<'a> DataRepresentationTemplate<f64> for ComplexSpatialDifferencingDataRepresentationTemplate<'a> {
+    fn bit_count_per_datapoint(&self) -> usize {
+        self.group_reference_bit_count() as usize
+    }
+
+    fn unpack_range(&self, data: &[u8], range: Range<usize>) -> Result<Vec<f64>, GribError> {
+        let values = self.unpack_all(data)?;
+        if range.end > values.len() {
+            return Err(GribError::OutOfRange("Requested range is out of bounds".into()));
+        }
+
+        Ok(values[range].to_vec())
+    }
+
+    fn unpack_all(&self, data: &[u8]) -> Result<Vec<f64>, GribError> {
+        self.unpack_values(data, |data, groups| {
+            let mut values = Vec::with_capacity(groups.iter().map(|g| g.length).sum());
+            for group in groups {
+                group.read_into(data, &mut values)?;
+            }
+            Ok(values)
+        })
+    }
+
+    // The groups are unpacked across threads, only undoing the spatial differencing is sequential
+    #[cfg(feature = "rayon")]
+    fn par_unpack_all(&self, data: &[u8]) -> Result<Vec<f64>, GribError> {
+        use rayon::prelude::*;
+
+        self.unpack_values(data, |data, groups| {
+            let groups = groups
+                .par_iter()
+                .map(|group| {
+                    let mut values = Vec::with_capacity(group.length);
+                    group.read_into(data, &mut values)?;
+                    Ok(values)
+                })
+                .collect::<Result<Vec<Vec<i64>>, GribError>>()?;
+            Ok(groups.concat())
+        })
+    }
+}
+
+// A group of packed values sharing a reference and bit width
+struct PackedGroup {
+    offset: usize,
+    reference: u64,
+    width: usize,
+    length: usize,
+}
+
+impl PackedGroup {
+    fn read_into(&self, data: &[u8], values: &mut Vec<i64>) -> Result<(), GribError> {
+        let mut reader = BitReader::new(data);
+        reader.seek(self.offset);
+        for _ in 0..self.length {
+            values.push((reader.read(self.width)? + self.reference) as i64);
+        }
+        Ok(())
+    }
+}
@@ -1,7 +1,65 @@
+use crate::error::GribError;
 use std::ops::Range;
 
+pub(crate) fn check_buffer_length(length: usize, required: usize) -> Result<(), GribError> {
+    if length < required {
+        return Err(GribError::BufferTooSmall { length, required });
+    }
+
+    Ok(())
+}
+
 pub trait DataRepresentationTemplate<T> {
     fn bit_count_per_datapoint(&self) -> usize;
-    fn unpack_range(&self, bits: Vec<u8>, range: Range<usize>) -> Result<Vec<T>, String>;
-    fn unpack_all(&self, bits: Vec<u8>) -> Result<Vec<T>, String>;
+    // The packed bytes of the data section, without the section header
+    fn unpack_range(&self, data: &[u8], range: Range<usize>) -> Result<Vec<T>, GribError>;
+    fn unpack_all(&self, data: &[u8]) -> Result<Vec<T>, GribError>;
+
+    // Unpacks values as they are read. Templates that can not unpack incrementally unpack
+    // everything up front, and a packed stream that ends early ends the iterator.
+    fn unpack_iter<'b>(&self, data: &'b [u8]) -> Result<Box<dyn Iterator<Item = T> + 'b>, GribError>
+    where
+        T: 'b,
+    {
+        Ok(Box::new(self.unpack_all(data)?.into_iter()))
+    }
+
+    // Writes the unpacked values to the start of out, returning how many were written.
+    // Templates that can unpack in place override this to avoid allocating.
+    fn unpack_into(&self, data: &[u8], out: &mut [T]) -> Result<usize, GribError>
+    where
+        T: Copy,
+    {
+        let values = self.unpack_all(data)?;
+        check_buffer_length(out.len(), values.len())?;
+        out[..values.len()].copy_from_slice(&values);
+        Ok(values.len())
+    }
+
+    // Single precision values, which hold the packed precision of nearly every message in half the memory
+    fn unpack_all_f32(&self, data: &[u8]) -> Result<Vec<f32>, GribError>
+    where
+        T: Copy + Into<f64>,
+    {
+        let values = self.unpack_all(data)?;
+        Ok(values.into_iter().map(|v| v.into() as f32).collect())
+    }
+
+    fn unpack_into_f32(&self, data: &[u8], out: &mut [f32]) -> Result<usize, GribError>
+    where
+        T: Copy + Into<f64>,
+    {
+        let values = self.unpack_all(data)?;
+        check_buffer_length(out.len(), values.len())?;
+        for (o, v) in out.iter_mut().zip(values.iter()) {
+            *o = (*v).into() as f32;
+        }
+        Ok(values.len())
+    }
+
+    // Unpacks the same values as unpack_all across threads, for templates that can split the packed data
+    #[cfg(feature = "rayon")]
+    fn par_unpack_all(&self, data: &[u8]) -> Result<Vec<T>, GribError> {
+        self.unpack_all(data)
+    }
 }
\ No newline at end of file
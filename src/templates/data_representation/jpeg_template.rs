@@ -1,11 +1,9 @@
+use crate::error::GribError;
 use crate::{templates::template::{Template, TemplateType}, utils::{grib_power, extract_jpeg_data}};
 use super::data_representation_template::DataRepresentationTemplate;
 use super::tables::{CompressionType, OriginalFieldValue};
-use crate::unwrap_or_return;
-use crate::utils::{from_bits, read_f32_from_bytes, read_i16_from_bytes, bits_to_bytes};
-use openjpeg_sys::opj_stream_set_user_data;
-use std::{convert::TryInto, ops::Range, ptr::null_mut};
-use std::io::BufReader;
+use crate::utils::{read_f32_from_bytes, read_i16_from_bytes, read_u32_from_bytes};
+use std::ops::Range;
 
 pub struct JPEGDataRepresentationTemplate<'a> {
     data: &'a [u8],
@@ -30,10 +28,17 @@ impl<'a> Template for JPEGDataRepresentationTemplate<'a> {
 }
 
 impl<'a> JPEGDataRepresentationTemplate<'a> {
-    pub fn new(data: &'a [u8]) -> JPEGDataRepresentationTemplate {
+    // The length of a section holding template 5.40
+    pub const LENGTH: usize = 23;
+
+    pub fn new(data: &'a [u8]) -> JPEGDataRepresentationTemplate<'a> {
         JPEGDataRepresentationTemplate { data }
     }
 
+    pub fn data_point_count(&self) -> usize {
+        read_u32_from_bytes(self.data, 5).unwrap_or(0) as usize
+    }
+
     pub fn reference_value(&self) -> f32 {
         read_f32_from_bytes(self.data, 11).unwrap_or(0.0)
     }
@@ -68,15 +73,12 @@ impl<'a> DataRepresentationTemplate<f64> for JPEGDataRepresentationTemplate<'a>
 		self.bit_count() as usize
     }
 	
-	fn unpack_range(&self, bits: Vec<u8>, range: Range<usize>) -> Result<Vec<f64>, String> {
-
-        let bytes = bits_to_bytes(bits).unwrap();
-
+	fn unpack_range(&self, data: &[u8], range: Range<usize>) -> Result<Vec<f64>, GribError> {
         let bscale = grib_power(self.binary_scale_factor().into(), 2);
         let dscale = grib_power(-(self.decimal_scale_factor() as i32), 10);
         let reference_value: f64 = self.reference_value().into();
 
-        let output_value: Vec<f64> = extract_jpeg_data(&bytes)?
+        let output_value: Vec<f64> = extract_jpeg_data(data)?
             [range]
             .iter()
             .map(|d| {
@@ -87,8 +89,16 @@ impl<'a> DataRepresentationTemplate<f64> for JPEGDataRepresentationTemplate<'a>
         Ok(output_value)
 	}
 
-    fn unpack_all(&self, bits: Vec<u8>) -> Result<Vec<f64>, String> {
-		let bit_count = bits.len();
-		self.unpack_range(bits, 0..bit_count)
+    fn unpack_all(&self, data: &[u8]) -> Result<Vec<f64>, GribError> {
+        let value_count = self.data_point_count();
+
+        // A bit count of zero means a constant field with no image data
+        if self.bit_count() == 0 {
+            let dscale = grib_power(-(self.decimal_scale_factor() as i32), 10);
+            let value = self.reference_value() as f64 * dscale;
+            return Ok(vec![value; value_count]);
+        }
+
+		self.unpack_range(data, 0..value_count)
     }
 }
@@ -2,7 +2,13 @@ pub mod tables;
 pub mod data_representation_template;
 pub mod simple_grid_point_template;
 pub mod jpeg_template;
+pub mod complex_spatial_differencing_template;
+#[cfg(feature = "png")]
+pub mod png_template;
 
 pub use data_representation_template::DataRepresentationTemplate;
 pub use simple_grid_point_template::SimpleGridPointDataRepresentationTemplate;
-pub use jpeg_template::JPEGDataRepresentationTemplate;
\ No newline at end of file
+pub use jpeg_template::JPEGDataRepresentationTemplate;
+pub use complex_spatial_differencing_template::ComplexSpatialDifferencingDataRepresentationTemplate;
+#[cfg(feature = "png")]
+pub use png_template::PNGDataRepresentationTemplate;
@@ -0,0 +1,96 @@
+use crate::error::GribError;
+use crate::{templates::template::{Template, TemplateType}, utils::{grib_power, extract_png_data}};
+use super::data_representation_template::DataRepresentationTemplate;
+use super::tables::OriginalFieldValue;
+use crate::utils::{read_f32_from_bytes, read_i16_from_bytes, read_u32_from_bytes};
+use std::ops::Range;
+
+pub struct PNGDataRepresentationTemplate<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Template for PNGDataRepresentationTemplate<'a> {
+    fn data(&self) -> &[u8] {
+        self.data
+    }
+
+    fn template_number(&self) -> u16 {
+        41
+    }
+
+    fn template_type(&self) -> TemplateType {
+        TemplateType::DataRepresentation
+    }
+
+    fn template_name(&self) -> &str {
+        "grid point data - png compression"
+    }
+}
+
+impl<'a> PNGDataRepresentationTemplate<'a> {
+    // The length of a section holding template 5.41
+    pub const LENGTH: usize = 21;
+
+    pub fn new(data: &'a [u8]) -> PNGDataRepresentationTemplate<'a> {
+        PNGDataRepresentationTemplate { data }
+    }
+
+    pub fn data_point_count(&self) -> usize {
+        read_u32_from_bytes(self.data, 5).unwrap_or(0) as usize
+    }
+
+    pub fn reference_value(&self) -> f32 {
+        read_f32_from_bytes(self.data, 11).unwrap_or(0.0)
+    }
+
+    pub fn binary_scale_factor(&self) -> i16 {
+        read_i16_from_bytes(self.data, 15).unwrap_or(0)
+    }
+
+    pub fn decimal_scale_factor(&self) -> i16 {
+        read_i16_from_bytes(self.data, 17).unwrap_or(0)
+    }
+
+    pub fn bit_count(&self) -> u8 {
+        self.data[19]
+    }
+
+    pub fn original_field_value(&self) -> OriginalFieldValue {
+        self.data[20].into()
+    }
+}
+
+impl<'a> DataRepresentationTemplate<f64> for PNGDataRepresentationTemplate<'a> {
+    fn bit_count_per_datapoint(&self) -> usize {
+        self.bit_count() as usize
+    }
+
+    fn unpack_range(&self, data: &[u8], range: Range<usize>) -> Result<Vec<f64>, GribError> {
+        let bscale = grib_power(self.binary_scale_factor().into(), 2);
+        let dscale = grib_power(-(self.decimal_scale_factor() as i32), 10);
+        let reference_value: f64 = self.reference_value().into();
+
+        let values = extract_png_data(data)?;
+        if range.end > values.len() {
+            return Err(GribError::OutOfRange("Requested range is out of bounds".into()));
+        }
+
+        Ok(values[range]
+            .iter()
+            .map(|d| ((*d as f64) * bscale + reference_value) * dscale)
+            .collect())
+    }
+
+    fn unpack_all(&self, data: &[u8]) -> Result<Vec<f64>, GribError> {
+        let value_count = self.data_point_count();
+
+        // A bit count of zero means a constant field with no image data
+        if self.bit_count() == 0 {
+            let dscale = grib_power(-(self.decimal_scale_factor() as i32), 10);
+            let value = self.reference_value() as f64 * dscale;
+            return Ok(vec![value; value_count]);
+        }
+
+        self.unpack_range(data, 0..value_count)
+    }
+}
@@ -1,10 +1,27 @@
+use crate::error::GribError;
 use crate::{templates::template::{Template, TemplateType}, utils::grib_power};
-use super::data_representation_template::DataRepresentationTemplate;
+use super::data_representation_template::{check_buffer_length, DataRepresentationTemplate};
 use super::tables::{OriginalFieldValue};
-use crate::unwrap_or_return;
-use crate::utils::{from_bits, read_f32_from_bytes, read_i16_from_bytes};
+use crate::utils::{read_f32_from_bytes, read_i16_from_bytes, read_u32_from_bytes, BitReader};
 use std::ops::Range;
 
+// Packed values scale as (raw * 2^E + R) * 10^-D, which folds into one multiply and one add
+#[derive(Clone, Copy)]
+struct Scaling {
+    factor: f64,
+    offset: f64,
+}
+
+impl Scaling {
+    fn apply(self, raw_value: u64) -> f64 {
+        raw_value as f64 * self.factor + self.offset
+    }
+}
+
+// The number of values each thread unpacks at a time
+#[cfg(feature = "rayon")]
+const PARALLEL_CHUNK_SIZE: usize = 1 << 16;
+
 pub struct SimpleGridPointDataRepresentationTemplate<'a> {
     data: &'a [u8],
 }
@@ -28,10 +45,17 @@ impl<'a> Template for SimpleGridPointDataRepresentationTemplate<'a> {
 }
 
 impl<'a> SimpleGridPointDataRepresentationTemplate<'a> {
-    pub fn new(data: &'a [u8]) -> SimpleGridPointDataRepresentationTemplate {
+    // The length of a section holding template 5.0
+    pub const LENGTH: usize = 21;
+
+    pub fn new(data: &'a [u8]) -> SimpleGridPointDataRepresentationTemplate<'a> {
         SimpleGridPointDataRepresentationTemplate { data }
     }
 
+    pub fn data_point_count(&self) -> usize {
+        read_u32_from_bytes(self.data, 5).unwrap_or(0) as usize
+    }
+
     pub fn reference_value(&self) -> f32 {
         read_f32_from_bytes(self.data, 11).unwrap_or(0.0)
     }
@@ -51,6 +75,48 @@ impl<'a> SimpleGridPointDataRepresentationTemplate<'a> {
     pub fn original_field_value(&self) -> OriginalFieldValue {
         self.data[20].into()
     }
+
+    fn scaling(&self) -> Scaling {
+        let bscale = grib_power(self.binary_scale_factor().into(), 2);
+        let dscale = grib_power(-(self.decimal_scale_factor() as i32), 10);
+        let reference_value: f64 = self.reference_value().into();
+
+        Scaling {
+            factor: bscale * dscale,
+            offset: reference_value * dscale,
+        }
+    }
+
+    // Unpacks every value straight into the buffer, without allocating
+    fn unpack_values_into<T>(&self, data: &[u8], out: &mut [T], convert: impl Fn(f64) -> T) -> Result<usize, GribError> {
+        let value_count = self.data_point_count();
+        check_buffer_length(out.len(), value_count)?;
+
+        // A bit count of zero means a constant field equal to the reference value
+        if self.bit_count() == 0 {
+            let value = self.scaling().offset;
+            out[..value_count].iter_mut().for_each(|o| *o = convert(value));
+            return Ok(value_count);
+        }
+
+        self.unpack_range_into(data, 0, &mut out[..value_count], convert)?;
+        Ok(value_count)
+    }
+
+    // Fills out with the values starting at the given index
+    fn unpack_range_into<T>(&self, data: &[u8], start: usize, out: &mut [T], convert: impl Fn(f64) -> T) -> Result<(), GribError> {
+        let bits_per_val: usize = self.bit_count().into();
+        let scaling = self.scaling();
+
+        let mut reader = BitReader::new(data);
+        reader.seek(start * bits_per_val);
+
+        for o in out.iter_mut() {
+            *o = convert(scaling.apply(reader.read(bits_per_val)?));
+        }
+
+        Ok(())
+    }
 }
 
 impl<'a> DataRepresentationTemplate<f64> for SimpleGridPointDataRepresentationTemplate<'a> {
@@ -58,55 +124,80 @@ impl<'a> DataRepresentationTemplate<f64> for SimpleGridPointDataRepresentationTe
 		self.bit_count() as usize
     }
 	
-	fn unpack_range(&self, bits: Vec<u8>, range: Range<usize>) -> Result<Vec<f64>, String> {
-        let mut v = Vec::new();
+	fn unpack_range(&self, data: &[u8], range: Range<usize>) -> Result<Vec<f64>, GribError> {
+        // A bit count of zero means a constant field equal to the reference value
+        if self.bit_count() == 0 {
+            return Ok(vec![self.scaling().offset; range.len()]);
+        }
+
+        let mut v = vec![0.0; range.len()];
+        self.unpack_range_into(data, range.start, &mut v, |value| value)?;
+        Ok(v)
+	}
 
+    fn unpack_iter<'b>(&self, data: &'b [u8]) -> Result<Box<dyn Iterator<Item = f64> + 'b>, GribError>
+    where
+        f64: 'b,
+    {
+        let value_count = self.data_point_count();
         let bits_per_val: usize = self.bit_count().into();
+        let scaling = self.scaling();
+
         if bits_per_val == 0 {
-            return Err("Invalid bits per value size of 0".into());
+            return Ok(Box::new(std::iter::repeat_n(scaling.offset, value_count)));
         }
 
-        let bit_start_index: usize = 32 - bits_per_val;
+        let mut reader = BitReader::new(data);
+        Ok(Box::new(
+            (0..value_count)
+                .map_while(move |_| reader.read(bits_per_val).ok())
+                .map(move |raw_value| scaling.apply(raw_value)),
+        ))
+    }
 
-        let mut raw_value: f64 = 0.0;
-		let mut val_bits: [u8; 32] = [0; 32];
-		
-		let start_index = range.start * bits_per_val;
-		let end_index = range.end * bits_per_val;
+    fn unpack_into(&self, data: &[u8], out: &mut [f64]) -> Result<usize, GribError> {
+        self.unpack_values_into(data, out, |value| value)
+    }
 
-        let bscale = grib_power(self.binary_scale_factor().into(), 2);
-        let dscale = grib_power(-(self.decimal_scale_factor() as i32), 10);
-        let reference_value: f64 = self.reference_value().into();
+    fn unpack_all_f32(&self, data: &[u8]) -> Result<Vec<f32>, GribError> {
+        let mut values = vec![0.0; self.data_point_count()];
+        self.unpack_values_into(data, &mut values, |value| value as f32)?;
+        Ok(values)
+    }
+
+    fn unpack_into_f32(&self, data: &[u8], out: &mut [f32]) -> Result<usize, GribError> {
+        self.unpack_values_into(data, out, |value| value as f32)
+    }
+
+    fn unpack_all(&self, data: &[u8]) -> Result<Vec<f64>, GribError> {
+        let value_count = self.data_point_count();
 
-        for i in (start_index..end_index).step_by(bits_per_val) {
-            val_bits = [0; 32];
-
-            let mut i_end_index = i + bits_per_val;
-            if i >= bits.len() {
-                continue;
-            } else if i_end_index >= bits.len() {
-                i_end_index = bits.len() - 1;
-            }
-
-            let relevent_bits = &bits[i..i_end_index];
-            for (j, bit) in relevent_bits.iter().enumerate() {
-                val_bits[j + bit_start_index] = *bit;
-            }
-
-            raw_value = unwrap_or_return!(
-                from_bits::<u32>(&val_bits),
-                "failed to convert value to u32".into()
-            )
-            .into();
-            let val = (raw_value * bscale + reference_value) * dscale;
-            v.push(val);
+        // A bit count of zero means a constant field equal to the reference value
+        if self.bit_count() == 0 {
+            return Ok(vec![self.scaling().offset; value_count]);
         }
 
-        Ok(v)
-	}
+		self.unpack_range(data, 0..value_count)
+    }
+
+    #[cfg(feature = "rayon")]
+    fn par_unpack_all(&self, data: &[u8]) -> Result<Vec<f64>, GribError> {
+        use rayon::prelude::*;
+
+        let value_count = self.data_point_count();
+        if self.bit_count() == 0 || value_count <= PARALLEL_CHUNK_SIZE {
+            return self.unpack_all(data);
+        }
+
+        // Every value has the same width, so each chunk can seek straight to its first value
+        let chunks = (0..value_count.div_ceil(PARALLEL_CHUNK_SIZE))
+            .into_par_iter()
+            .map(|chunk| {
+                let start = chunk * PARALLEL_CHUNK_SIZE;
+                self.unpack_range(data, start..(start + PARALLEL_CHUNK_SIZE).min(value_count))
+            })
+            .collect::<Result<Vec<Vec<f64>>, GribError>>()?;
 
-    fn unpack_all(&self, bits: Vec<u8>) -> Result<Vec<f64>, String> {
-		let bit_count = bits.len();
-		self.unpack_range(bits, 0..bit_count)
+        Ok(chunks.concat())
     }
 }
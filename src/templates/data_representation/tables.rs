@@ -2,36 +2,45 @@ use gribberish_macros::{DisplayDescription, FromValue};
 
 #[repr(u8)]
 #[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OriginalFieldValue {
     FloatingPoint = 0,
     Integer = 1,
+    Unknown(u8),
 }
 
 #[repr(u8)]
 #[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MatrixCoordinateValueFunctions {
     ExplicitCoordinateValueSet = 0,
     LinearCoordinates = 1,
     GeometricCoordinates = 11,
+    Unknown(u8),
 }
 
 #[repr(u8)]
 #[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MatrixCoordinateParameters {
     DirectionDegreesTrue = 1,
     Frequency = 2,
     RadialNumber = 3,
+    Unknown(u8),
 }
 
 #[repr(u8)]
 #[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GroupSplittingMethods {
     RowByRow = 0,
     GeneralGroup = 1,
+    Unknown(u8),
 }
 
 #[repr(u8)]
 #[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MissingValueManagement {
     #[description = "no explicit missing values included with the data values"]
     NoMissingValues = 0,
@@ -39,19 +48,23 @@ pub enum MissingValueManagement {
     IncludesMissingPrimary = 1,
     #[description = "primary and secondary missing values included within the data values"]
     IncludesMissingPrimarySecondary = 2,
+    Unknown(u8),
 }
 
 #[repr(u8)]
 #[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SpatialDifferencingOrder {
     #[description = "first order spatial differencing"]
     First = 1,
     #[description = "second order spatial differencing"]
     Second = 2,
+    Unknown(u8),
 }
 
 #[repr(u8)]
 #[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FloatingPointPrecision {
     #[description = "IEEE 32 bit"]
     IEEE32Bit = 1,
@@ -59,11 +72,14 @@ pub enum FloatingPointPrecision {
     IEEE64Bit = 2,
     #[description = "IEEE 128 bit"]
     IEEE128Bit = 3,
+    Unknown(u8),
 }
 
 #[repr(u8)]
 #[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CompressionType {
     Lossless = 0,
     Lossy = 1,
+    Unknown(u8),
 }
\ No newline at end of file
@@ -1,5 +1,7 @@
+use crate::error::GribError;
 use super::template::{Template, TemplateType};
-use crate::utils::{bit_array_from_bytes, read_signed_from_bytes, read_u32_from_bytes};
+use crate::utils::{read_f32_from_bytes, read_signed_from_bytes, read_u32_from_bytes};
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
 use gribberish_macros::{DisplayDescription, FromValue};
 use std::iter::Iterator;
 use std::vec::Vec;
@@ -16,12 +18,93 @@ pub trait GridDefinitionTemplate {
     fn latitudes(&self) -> Vec<f64>;
     fn longitudes(&self) -> Vec<f64>;
     fn locations(&self) -> Vec<(f64, f64)>;
-    fn location_for_index(&self, index: usize) -> Result<(f64, f64), &'static str>;
-    fn index_for_location(&self, latitude: f64, longitude: f64) -> Result<usize, &'static str>;
+    // Whether every row shares a latitude and every column a longitude, both evenly spaced
+    fn is_regular(&self) -> bool {
+        false
+    }
+    // Grids with separable coordinates build each location from the axes as it is read,
+    // curvilinear grids fall back to the eager locations
+    fn locations_iter(&self) -> Box<dyn Iterator<Item = (f64, f64)>> {
+        Box::new(self.locations().into_iter())
+    }
+    fn location_for_index(&self, index: usize) -> Result<(f64, f64), GribError>;
+    fn index_for_location(&self, latitude: f64, longitude: f64) -> Result<usize, GribError>;
+    // The scanning mode flags of code table 3.4, the default scans each row west to east
+    fn scanning_mode(&self) -> u8 {
+        0
+    }
+    // Whether vector components such as winds are resolved along the grid's x and y axes rather
+    // than towards east and north, from the resolution and component flags of code table 3.3
+    fn is_grid_relative(&self) -> bool {
+        false
+    }
+    // The angle in radians, counter clockwise, from east to the grid's x axis at every grid point
+    // in the order the values are stored. Zero for grids whose axes follow the parallels.
+    fn grid_angles(&self) -> Vec<f64> {
+        vec![0.0; self.grid_point_count()]
+    }
+    // The corners of the cell around every grid point in the order the values are stored, halfway
+    // to the neighboring points. Corners run counter clockwise from the south west, with their
+    // longitudes kept within 180 degrees of the point's so no cell wraps across the grid.
+    fn cell_corners(&self) -> Vec<[(f64, f64); 4]>;
+    // The box around the grid points. Longitudes are measured eastwards from the first point,
+    // so grids crossing the antimeridian have an eastern edge past 360.
+    fn bounds(&self) -> LatLngBounds {
+        let locations = self.locations();
+        let first = locations.first().map(|(_, longitude)| *longitude).unwrap_or(0.0);
+        locations.iter().fold(
+            LatLngBounds {
+                north: f64::NEG_INFINITY,
+                south: f64::INFINITY,
+                east: f64::NEG_INFINITY,
+                west: f64::INFINITY,
+            },
+            |bounds, (latitude, longitude)| {
+                let longitude = first + longitude_difference(*longitude, first);
+                LatLngBounds {
+                    north: bounds.north.max(*latitude),
+                    south: bounds.south.min(*latitude),
+                    east: bounds.east.max(longitude),
+                    west: bounds.west.min(longitude),
+                }
+            },
+        )
+    }
+}
+
+// The northern and southern latitudes and the western and eastern longitudes of a box
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LatLngBounds {
+    pub north: f64,
+    pub south: f64,
+    pub east: f64,
+    pub west: f64,
+}
+
+// Bit 5 of the resolution and component flags, set when vector components follow the grid axes
+const GRID_RELATIVE_FLAG: u8 = 0x08;
+
+// Where the point at column i and row j of a (rows, columns) grid is stored. Points are scanned
+// row by row unless the mode scans column by column, and every other line runs the opposite
+// way when the mode alternates.
+pub(crate) fn scanned_index(scanning_mode: u8, shape: (usize, usize), i: usize, j: usize) -> usize {
+    let (line, position, line_length) = if scanning_mode & 0x20 != 0 { (i, j, shape.0) } else { (j, i, shape.1) };
+    let position = if scanning_mode & 0x10 != 0 && line % 2 == 1 { line_length - 1 - position } else { position };
+    line * line_length + position
+}
+
+// The column and row of the point stored at the index, the reverse of scanned_index
+pub(crate) fn scanned_position(scanning_mode: u8, shape: (usize, usize), index: usize) -> (usize, usize) {
+    let line_length = if scanning_mode & 0x20 != 0 { shape.0 } else { shape.1 };
+    let (line, position) = (index / line_length, index % line_length);
+    let position = if scanning_mode & 0x10 != 0 && line % 2 == 1 { line_length - 1 - position } else { position };
+    if scanning_mode & 0x20 != 0 { (line, position) } else { (position, line) }
 }
 
-#[repr(u8)]
-#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue)]
+#[repr(u16)]
+#[derive(Eq, Clone, Copy, PartialEq, Debug, DisplayDescription, FromValue)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EarthShape {
     #[description = "Earth assumed spherical with radius = 6,367,470.0 m"]
     Spherical = 0,
@@ -42,6 +125,7 @@ pub enum EarthShape {
     #[description = "Earth model assumed spherical with radius 6371200 m, but the horizontal datum of the resulting latitude/longitude field is the WGS84 reference frame"]
     OblateWGS84 = 8,
     Missing = 255,
+    Unknown(u8) = 256,
 }
 
 pub struct LatitudeLongitudeGridTemplate<'a> {
@@ -67,7 +151,10 @@ impl<'a> Template for LatitudeLongitudeGridTemplate<'a> {
 }
 
 impl<'a> LatitudeLongitudeGridTemplate<'a> {
-    pub fn new(data: &'a [u8]) -> LatitudeLongitudeGridTemplate {
+    // The length of a section holding template 3.0
+    pub const LENGTH: usize = 72;
+
+    pub fn new(data: &'a [u8]) -> LatitudeLongitudeGridTemplate<'a> {
         LatitudeLongitudeGridTemplate { data }
     }
 
@@ -126,7 +213,7 @@ impl<'a> LatitudeLongitudeGridTemplate<'a> {
     }
 
     pub fn resolution_component_flags(&self) -> Vec<u8> {
-        bit_array_from_bytes(&self.data[54..55])
+        (0..8).rev().map(|i| (self.data[54] >> i) & 1).collect()
     }
 
     pub fn end_latitude(&self) -> f64 {
@@ -141,20 +228,21 @@ impl<'a> LatitudeLongitudeGridTemplate<'a> {
 
     pub fn i_direction_increment(&self) -> f64 {
         let value = read_u32_from_bytes(self.data, 63).unwrap_or(0) as f64;
+        value * (10f64.powf(-6.0))
+    }
+
+    // Latitudes step along j, so the increment is negative when they run north to south
+    pub fn j_direction_increment(&self) -> f64 {
+        let value = read_u32_from_bytes(self.data, 67).unwrap_or(0) as f64;
         let value = value * (10f64.powf(-6.0));
 
         if self.is_descending_latitude() {
-            value * -1.0
+            -value
         } else {
             value
         }
     }
 
-    pub fn j_direction_increment(&self) -> f64 {
-        let value = read_u32_from_bytes(self.data, 67).unwrap_or(0) as f64;
-        value * (10f64.powf(-6.0))
-    }
-
     pub fn scanning_mode_flags(&self) -> u8 {
         self.data[71]
     }
@@ -216,47 +304,112 @@ impl<'a> GridDefinitionTemplate for LatitudeLongitudeGridTemplate<'a> {
     }
 
     fn locations(&self) -> Vec<(f64, f64)> {
-        let latitudes = self.latitudes();
-        let longitudes = self.longitudes();
-
-        let mut locations = Vec::with_capacity(latitudes.len() * longitudes.len());
-        for lat_i in 0..latitudes.len() {
-            for lon_i in 0..longitudes.len() {
-                locations.push((latitudes[lat_i], longitudes[lon_i]));
-            }
-        }
+        self.locations_iter().collect()
+    }
+
+    fn is_regular(&self) -> bool {
+        true
+    }
 
-        return locations;
+    fn scanning_mode(&self) -> u8 {
+        self.scanning_mode_flags()
     }
 
-    fn index_for_location(&self, latitude: f64, longitude: f64) -> Result<usize, &'static str> {
+    // The first point is a corner, stepped from southwards unless the scanning mode runs the j
+    // direction north and westwards when it runs the i direction west. The edges are counted
+    // out with the increments, or taken from the last point when the increments are not given.
+    fn bounds(&self) -> LatLngBounds {
+        let (flags, scanning_mode) = (self.data[54], self.scanning_mode_flags());
+        let (first_latitude, first_longitude) = (self.start_latitude(), self.start_longitude());
+        let westwards = scanning_mode & 0x80 != 0;
+
+        let latitude_span = if flags & 0x10 != 0 {
+            self.j_direction_increment().abs() * self.latitude_count().saturating_sub(1) as f64
+        } else {
+            (self.end_latitude() - first_latitude).abs()
+        };
+        let longitude_span = if flags & 0x20 != 0 {
+            self.i_direction_increment() * self.longitude_count().saturating_sub(1) as f64
+        } else if westwards {
+            (first_longitude - self.end_longitude()).rem_euclid(360.0)
+        } else {
+            (self.end_longitude() - first_longitude).rem_euclid(360.0)
+        };
+
+        let (south, north) = if scanning_mode & 0x40 != 0 {
+            (first_latitude, first_latitude + latitude_span)
+        } else {
+            (first_latitude - latitude_span, first_latitude)
+        };
+        let (west, east) = if westwards {
+            (first_longitude - longitude_span, first_longitude)
+        } else {
+            (first_longitude, first_longitude + longitude_span)
+        };
+        LatLngBounds { north, south, east, west }
+    }
+
+    fn cell_corners(&self) -> Vec<[(f64, f64); 4]> {
+        let (latitudes, longitudes) = (self.latitudes(), self.longitudes());
+        let shape = (latitudes.len(), longitudes.len());
+        let (half_height, half_width) = (self.latitude_resolution().abs() / 2.0, self.longitude_resolution().abs() / 2.0);
+        let scanning_mode = self.scanning_mode_flags();
+
+        (0..shape.0 * shape.1)
+            .map(|index| {
+                let (i, j) = scanned_position(scanning_mode, shape, index);
+                let (latitude, longitude) = (latitudes[j], longitudes[i]);
+                let (south, north) = ((latitude - half_height).max(-90.0), (latitude + half_height).min(90.0));
+                let (west, east) = (longitude - half_width, longitude + half_width);
+                [(south, west), (south, east), (north, east), (north, west)]
+            })
+            .collect()
+    }
+
+    fn locations_iter(&self) -> Box<dyn Iterator<Item = (f64, f64)>> {
+        let (latitudes, longitudes) = (self.latitudes(), self.longitudes());
+        let shape = (latitudes.len(), longitudes.len());
+        let scanning_mode = self.scanning_mode_flags();
+
+        Box::new((0..shape.0 * shape.1).map(move |index| {
+            let (i, j) = scanned_position(scanning_mode, shape, index);
+            (latitudes[j], longitudes[i])
+        }))
+    }
+
+    fn index_for_location(&self, latitude: f64, longitude: f64) -> Result<usize, GribError> {
         let descending = self.is_descending_latitude();
-        if !descending && (latitude < self.start_latitude() || latitude > self.end_latitude()) {
-            return Err("Latitude is out of range");
-        } else if descending && (latitude > self.start_latitude() || latitude < self.end_latitude())
+        if (!descending && (latitude < self.start_latitude() || latitude > self.end_latitude()))
+            || (descending && (latitude > self.start_latitude() || latitude < self.end_latitude()))
         {
-            return Err("Latitude is out of range");
-        } else if longitude < self.start_longitude() || longitude > self.end_longitude() {
-            return Err("Longitude is out of range");
+            return Err(GribError::OutOfRange("Latitude is out of range".into()));
         }
 
-        let lat_difference = (latitude - self.start_latitude()).abs();
-        let lat_index = (lat_difference / self.latitude_resolution().abs()) as usize;
+        // Longitudes may be given on either -180 to 180 or 0 to 360, so they are measured
+        // eastwards from the first column
+        let lon_difference = (longitude - self.start_longitude()).rem_euclid(360.0);
+        let lon_span = self.end_longitude() - self.start_longitude();
+        let lon_span = if lon_span < 0.0 { lon_span + 360.0 } else { lon_span };
+        if lon_difference > lon_span + 1e-6 {
+            return Err(GribError::OutOfRange("Longitude is out of range".into()));
+        }
 
-        let lon_difference = (longitude - self.start_longitude()).abs();
-        let lon_index = (lon_difference / self.longitude_resolution().abs()) as usize;
+        // The nearest grid point, so locations read back from the grid find their own point
+        let lat_difference = (latitude - self.start_latitude()).abs();
+        let lat_index = (lat_difference / self.latitude_resolution().abs()).round() as usize;
+        let lon_index = (lon_difference / self.longitude_resolution().abs()).round() as usize;
 
-        let index = lat_index * self.longitude_count() as usize + lon_index;
-        Ok(index)
+        let shape = (self.latitude_count(), self.longitude_count());
+        Ok(scanned_index(self.scanning_mode_flags(), shape, lon_index, lat_index))
     }
 
-    fn location_for_index(&self, index: usize) -> Result<(f64, f64), &'static str> {
+    fn location_for_index(&self, index: usize) -> Result<(f64, f64), GribError> {
         if index >= self.grid_point_count() {
-            return Err("Index out of range");
+            return Err(GribError::OutOfRange("Index out of range".into()));
         }
 
-        let lat_index = index / self.latitude_resolution() as usize;
-        let lon_index = index % self.longitude_resolution() as usize;
+        let shape = (self.latitude_count(), self.longitude_count());
+        let (lon_index, lat_index) = scanned_position(self.scanning_mode_flags(), shape, index);
 
         let latitude = self.start_latitude() + self.latitude_resolution() * lat_index as f64;
         let longitude = self.start_longitude() + self.longitude_resolution() * lon_index as f64;
@@ -264,3 +417,590 @@ impl<'a> GridDefinitionTemplate for LatitudeLongitudeGridTemplate<'a> {
         Ok((latitude, longitude))
     }
 }
+
+// The radius of the sphere projections are computed on, oblate shapes are taken as the sphere of
+// their mean radius
+pub(crate) fn earth_radius(data: &[u8]) -> f64 {
+    let scaled = |offset: usize| scaled_earth_value(data, offset);
+    let mean = |major: f64, minor: f64| (2.0 * major + minor) / 3.0;
+
+    match EarthShape::from(data[14]) {
+        EarthShape::Spherical => 6_367_470.0,
+        EarthShape::SpecifiedRadiusSpherical => scaled(15),
+        EarthShape::OblateIAU => mean(6_378_160.0, 6_356_775.0),
+        EarthShape::OblateKM => mean(scaled(20) * 1000.0, scaled(25) * 1000.0),
+        EarthShape::OblateIAGGRS80 | EarthShape::WGS84 => mean(6_378_137.0, 6_356_752.314),
+        EarthShape::OblateM => mean(scaled(20), scaled(25)),
+        EarthShape::OblateWGS84 => 6_371_200.0,
+        _ => 6_371_229.0,
+    }
+}
+
+// A radius or axis of the earth, as a scale factor followed by the scaled value
+pub(crate) fn scaled_earth_value(data: &[u8], offset: usize) -> f64 {
+    read_u32_from_bytes(data, offset + 1).unwrap_or(0) as f64 / 10f64.powi(data[offset] as i32)
+}
+
+// The difference between two longitudes, wrapped to -180 to 180
+pub(crate) fn longitude_difference(a: f64, b: f64) -> f64 {
+    (a - b + 180.0).rem_euclid(360.0) - 180.0
+}
+
+// Moves the corner longitudes to within 180 degrees of the cell's center
+fn unwrap_corners(center_longitude: f64, corners: [(f64, f64); 4]) -> [(f64, f64); 4] {
+    corners.map(|(latitude, longitude)| (latitude, center_longitude + longitude_difference(longitude, center_longitude)))
+}
+
+pub struct LambertConformalTemplate<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Template for LambertConformalTemplate<'a> {
+    fn template_type(&self) -> TemplateType {
+        TemplateType::Grid
+    }
+
+    fn template_number(&self) -> u16 {
+        30
+    }
+
+    fn data(&self) -> &[u8] {
+        self.data
+    }
+
+    fn template_name(&self) -> &str {
+        "Lambert Conformal"
+    }
+}
+
+impl<'a> LambertConformalTemplate<'a> {
+    // The length of a section holding template 3.30
+    pub const LENGTH: usize = 81;
+
+    pub fn new(data: &'a [u8]) -> LambertConformalTemplate<'a> {
+        LambertConformalTemplate { data }
+    }
+
+    pub fn earth_shape(&self) -> EarthShape {
+        self.data[14].into()
+    }
+
+    pub fn x_point_count(&self) -> u32 {
+        read_u32_from_bytes(self.data, 30).unwrap_or(0)
+    }
+
+    pub fn y_point_count(&self) -> u32 {
+        read_u32_from_bytes(self.data, 34).unwrap_or(0)
+    }
+
+    pub fn first_latitude(&self) -> f64 {
+        let value = read_signed_from_bytes(self.data, 38).unwrap_or(0) as f64;
+        value * (10f64.powf(-6.0))
+    }
+
+    pub fn first_longitude(&self) -> f64 {
+        let value = read_u32_from_bytes(self.data, 42).unwrap_or(0) as f64;
+        value * (10f64.powf(-6.0))
+    }
+
+    pub fn resolution_component_flags(&self) -> Vec<u8> {
+        (0..8).rev().map(|i| (self.data[46] >> i) & 1).collect()
+    }
+
+    // The latitude where the increments are true
+    pub fn increment_latitude(&self) -> f64 {
+        let value = read_signed_from_bytes(self.data, 47).unwrap_or(0) as f64;
+        value * (10f64.powf(-6.0))
+    }
+
+    // The meridian parallel to the y axis
+    pub fn orientation_longitude(&self) -> f64 {
+        let value = read_u32_from_bytes(self.data, 51).unwrap_or(0) as f64;
+        value * (10f64.powf(-6.0))
+    }
+
+    // In metres
+    pub fn x_direction_increment(&self) -> f64 {
+        let value = read_u32_from_bytes(self.data, 55).unwrap_or(0) as f64;
+        value * (10f64.powf(-3.0))
+    }
+
+    // In metres
+    pub fn y_direction_increment(&self) -> f64 {
+        let value = read_u32_from_bytes(self.data, 59).unwrap_or(0) as f64;
+        value * (10f64.powf(-3.0))
+    }
+
+    pub fn projection_center_flags(&self) -> u8 {
+        self.data[63]
+    }
+
+    pub fn scanning_mode_flags(&self) -> u8 {
+        self.data[64]
+    }
+
+    pub fn first_standard_parallel(&self) -> f64 {
+        let value = read_signed_from_bytes(self.data, 65).unwrap_or(0) as f64;
+        value * (10f64.powf(-6.0))
+    }
+
+    pub fn second_standard_parallel(&self) -> f64 {
+        let value = read_signed_from_bytes(self.data, 69).unwrap_or(0) as f64;
+        value * (10f64.powf(-6.0))
+    }
+
+    pub fn south_pole_latitude(&self) -> f64 {
+        let value = read_signed_from_bytes(self.data, 73).unwrap_or(0) as f64;
+        value * (10f64.powf(-6.0))
+    }
+
+    pub fn south_pole_longitude(&self) -> f64 {
+        let value = read_u32_from_bytes(self.data, 77).unwrap_or(0) as f64;
+        value * (10f64.powf(-6.0))
+    }
+
+    // How far the cone is unrolled, the ratio of the angle between meridians on the projection to
+    // the angle between them on the earth
+    pub fn cone_constant(&self) -> f64 {
+        self.projection().cone_constant
+    }
+
+    // The position of a location on the projection in metres, with the pole at the origin
+    pub fn project(&self, latitude: f64, longitude: f64) -> (f64, f64) {
+        self.projection().project(latitude, longitude)
+    }
+
+    // The location of a position on the projection, the reverse of project
+    pub fn unproject(&self, x: f64, y: f64) -> (f64, f64) {
+        self.projection().unproject(x, y)
+    }
+
+    fn projection(&self) -> LambertProjection {
+        let first = self.first_standard_parallel().to_radians();
+        let second = self.second_standard_parallel().to_radians();
+        let cone_constant = if (first - second).abs() < 1e-9 {
+            first.sin()
+        } else {
+            (first.cos() / second.cos()).ln() / ((FRAC_PI_4 + second / 2.0).tan() / (FRAC_PI_4 + first / 2.0).tan()).ln()
+        };
+
+        LambertProjection {
+            cone_constant,
+            scale: earth_radius(self.data) * first.cos() * (FRAC_PI_4 + first / 2.0).tan().powf(cone_constant) / cone_constant,
+            orientation_longitude: self.orientation_longitude(),
+        }
+    }
+
+    // The projected position of the first point and the step to the next column and row, in the
+    // directions the scanning mode runs
+    pub(crate) fn grid_steps(&self) -> ((f64, f64), (f64, f64)) {
+        let scanning_mode = self.scanning_mode_flags();
+        let x_step = if scanning_mode & 0x80 != 0 { -self.x_direction_increment() } else { self.x_direction_increment() };
+        let y_step = if scanning_mode & 0x40 != 0 { self.y_direction_increment() } else { -self.y_direction_increment() };
+        (self.project(self.first_latitude(), self.first_longitude()), (x_step, y_step))
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        (self.y_point_count() as usize, self.x_point_count() as usize)
+    }
+}
+
+// Spherical Lambert conformal conic projection, kept apart from the template so iterators over
+// the grid can own it
+#[derive(Clone, Copy)]
+struct LambertProjection {
+    cone_constant: f64,
+    // The earth radius times the projection's F constant
+    scale: f64,
+    orientation_longitude: f64,
+}
+
+impl LambertProjection {
+    fn project(&self, latitude: f64, longitude: f64) -> (f64, f64) {
+        let n = self.cone_constant;
+        let rho = self.scale / (FRAC_PI_4 + latitude.to_radians() / 2.0).tan().powf(n);
+        let theta = n * longitude_difference(longitude, self.orientation_longitude).to_radians();
+        (rho * theta.sin(), -rho * theta.cos())
+    }
+
+    // Longitudes are given from 0 to 360
+    fn unproject(&self, x: f64, y: f64) -> (f64, f64) {
+        let n = self.cone_constant;
+        let rho = n.signum() * x.hypot(y);
+        let theta = if n < 0.0 { (-x).atan2(y) } else { x.atan2(-y) };
+        let latitude = 2.0 * (self.scale / rho).powf(1.0 / n).atan() - FRAC_PI_2;
+        let longitude = (self.orientation_longitude + (theta / n).to_degrees()).rem_euclid(360.0);
+        (latitude.to_degrees(), longitude)
+    }
+}
+
+impl<'a> GridDefinitionTemplate for LambertConformalTemplate<'a> {
+    fn grid_point_count(&self) -> usize {
+        (self.x_point_count() * self.y_point_count()) as usize
+    }
+
+    fn start(&self) -> (f64, f64) {
+        (self.first_latitude(), self.first_longitude())
+    }
+
+    // The location at the middle of the projected grid
+    fn origin(&self) -> (f64, f64) {
+        let ((x, y), (x_step, y_step)) = self.grid_steps();
+        let (rows, columns) = self.shape();
+        let middle = |count: usize| count.saturating_sub(1) as f64 / 2.0;
+        self.unproject(x + middle(columns) * x_step, y + middle(rows) * y_step)
+    }
+
+    // The location of the last point stored
+    fn end(&self) -> (f64, f64) {
+        self.location_for_index(self.grid_point_count().saturating_sub(1)).unwrap_or_else(|_| self.start())
+    }
+
+    fn latitude_count(&self) -> usize {
+        self.y_point_count() as usize
+    }
+
+    fn longitude_count(&self) -> usize {
+        self.x_point_count() as usize
+    }
+
+    // Projected grids are evenly spaced in metres rather than degrees
+    fn latitude_resolution(&self) -> f64 {
+        self.y_direction_increment()
+    }
+
+    fn longitude_resolution(&self) -> f64 {
+        self.x_direction_increment()
+    }
+
+    fn latitudes(&self) -> Vec<f64> {
+        self.locations_iter().map(|(latitude, _)| latitude).collect()
+    }
+
+    fn longitudes(&self) -> Vec<f64> {
+        self.locations_iter().map(|(_, longitude)| longitude).collect()
+    }
+
+    fn locations(&self) -> Vec<(f64, f64)> {
+        self.locations_iter().collect()
+    }
+
+    fn scanning_mode(&self) -> u8 {
+        self.scanning_mode_flags()
+    }
+
+    fn is_grid_relative(&self) -> bool {
+        self.data[46] & GRID_RELATIVE_FLAG != 0
+    }
+
+    // Halfway along both projected axes, so the corners are shared with the neighboring cells
+    fn cell_corners(&self) -> Vec<[(f64, f64); 4]> {
+        let projection = self.projection();
+        let ((x, y), (x_step, y_step)) = self.grid_steps();
+        let (half_width, half_height) = (x_step.abs() / 2.0, y_step.abs() / 2.0);
+        let (shape, scanning_mode) = (self.shape(), self.scanning_mode_flags());
+
+        (0..self.grid_point_count())
+            .map(|index| {
+                let (i, j) = scanned_position(scanning_mode, shape, index);
+                let (center_x, center_y) = (x + i as f64 * x_step, y + j as f64 * y_step);
+                let corners = [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]
+                    .map(|(dx, dy)| projection.unproject(center_x + dx * half_width, center_y + dy * half_height));
+                unwrap_corners(projection.unproject(center_x, center_y).1, corners)
+            })
+            .collect()
+    }
+
+    // The y axis runs along the orientation meridian and turns with the cone away from it
+    fn grid_angles(&self) -> Vec<f64> {
+        let projection = self.projection();
+        self.locations_iter()
+            .map(|(_, longitude)| {
+                -projection.cone_constant * longitude_difference(longitude, projection.orientation_longitude).to_radians()
+            })
+            .collect()
+    }
+
+    fn locations_iter(&self) -> Box<dyn Iterator<Item = (f64, f64)>> {
+        let projection = self.projection();
+        let ((x, y), (x_step, y_step)) = self.grid_steps();
+        let (shape, scanning_mode) = (self.shape(), self.scanning_mode_flags());
+
+        Box::new((0..self.grid_point_count()).map(move |index| {
+            let (i, j) = scanned_position(scanning_mode, shape, index);
+            projection.unproject(x + i as f64 * x_step, y + j as f64 * y_step)
+        }))
+    }
+
+    fn index_for_location(&self, latitude: f64, longitude: f64) -> Result<usize, GribError> {
+        let ((x, y), (x_step, y_step)) = self.grid_steps();
+        let (position_x, position_y) = self.project(latitude, longitude);
+        let (i, j) = (((position_x - x) / x_step).round(), ((position_y - y) / y_step).round());
+
+        let (rows, columns) = self.shape();
+        if !(0.0..columns as f64).contains(&i) || !(0.0..rows as f64).contains(&j) {
+            return Err(GribError::OutOfRange("Location is outside of the grid".into()));
+        }
+        Ok(scanned_index(self.scanning_mode_flags(), self.shape(), i as usize, j as usize))
+    }
+
+    fn location_for_index(&self, index: usize) -> Result<(f64, f64), GribError> {
+        if index >= self.grid_point_count() {
+            return Err(GribError::OutOfRange("Index out of range".into()));
+        }
+
+        let ((x, y), (x_step, y_step)) = self.grid_steps();
+        let (i, j) = scanned_position(self.scanning_mode_flags(), self.shape(), index);
+        Ok(self.unproject(x + i as f64 * x_step, y + j as f64 * y_step))
+    }
+}
+
+pub struct RotatedLatitudeLongitudeGridTemplate<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Template for RotatedLatitudeLongitudeGridTemplate<'a> {
+    fn template_type(&self) -> TemplateType {
+        TemplateType::Grid
+    }
+
+    fn template_number(&self) -> u16 {
+        1
+    }
+
+    fn data(&self) -> &[u8] {
+        self.data
+    }
+
+    fn template_name(&self) -> &str {
+        "Rotated Latitude Longitude"
+    }
+}
+
+impl<'a> RotatedLatitudeLongitudeGridTemplate<'a> {
+    // The length of a section holding template 3.1
+    pub const LENGTH: usize = 84;
+
+    pub fn new(data: &'a [u8]) -> RotatedLatitudeLongitudeGridTemplate<'a> {
+        RotatedLatitudeLongitudeGridTemplate { data }
+    }
+
+    // The grid in rotated coordinates, laid out the same as template 3.0
+    pub fn rotated_grid(&self) -> LatitudeLongitudeGridTemplate<'a> {
+        LatitudeLongitudeGridTemplate::new(self.data)
+    }
+
+    pub fn earth_shape(&self) -> EarthShape {
+        self.data[14].into()
+    }
+
+    pub fn south_pole_latitude(&self) -> f64 {
+        let value = read_signed_from_bytes(self.data, 72).unwrap_or(0) as f64;
+        value * (10f64.powf(-6.0))
+    }
+
+    pub fn south_pole_longitude(&self) -> f64 {
+        let value = read_u32_from_bytes(self.data, 76).unwrap_or(0) as f64;
+        value * (10f64.powf(-6.0))
+    }
+
+    // In degrees, about the rotated polar axis
+    pub fn rotation_angle(&self) -> f64 {
+        read_f32_from_bytes(self.data, 80).unwrap_or(0.0) as f64
+    }
+
+    // The geographic location of a point given in rotated coordinates
+    pub fn to_geographic(&self, latitude: f64, longitude: f64) -> (f64, f64) {
+        self.rotation().to_geographic(latitude, longitude)
+    }
+
+    // The rotated coordinates of a geographic location, the reverse of to_geographic
+    pub fn to_rotated(&self, latitude: f64, longitude: f64) -> (f64, f64) {
+        self.rotation().to_rotated(latitude, longitude)
+    }
+
+    fn rotation(&self) -> PoleRotation {
+        PoleRotation {
+            south_pole_latitude: self.south_pole_latitude(),
+            south_pole_longitude: self.south_pole_longitude(),
+            angle: self.rotation_angle(),
+        }
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        let grid = self.rotated_grid();
+        (grid.latitude_count(), grid.longitude_count())
+    }
+
+    // The rotated coordinates of every grid point in the order they are stored
+    fn rotated_locations(&self) -> impl Iterator<Item = (f64, f64)> {
+        let grid = self.rotated_grid();
+        let (first_latitude, latitude_step) = (grid.start_latitude(), grid.j_direction_increment());
+        let (first_longitude, longitude_step) = (grid.start_longitude(), grid.i_direction_increment());
+        let (shape, scanning_mode) = (self.shape(), grid.scanning_mode_flags());
+
+        (0..grid.grid_point_count()).map(move |index| {
+            let (i, j) = scanned_position(scanning_mode, shape, index);
+            (first_latitude + j as f64 * latitude_step, first_longitude + i as f64 * longitude_step)
+        })
+    }
+}
+
+// Moves the south pole of the coordinate system to a new location and turns it about the new
+// polar axis
+#[derive(Clone, Copy)]
+struct PoleRotation {
+    south_pole_latitude: f64,
+    south_pole_longitude: f64,
+    angle: f64,
+}
+
+impl PoleRotation {
+    // Longitudes are given from 0 to 360
+    fn to_geographic(self, latitude: f64, longitude: f64) -> (f64, f64) {
+        let (pole_sin, pole_cos) = (self.south_pole_latitude + 90.0).to_radians().sin_cos();
+        let (longitude_sin, longitude_cos) = (longitude - self.angle).to_radians().sin_cos();
+        let (latitude_sin, latitude_cos) = latitude.to_radians().sin_cos();
+
+        let geographic_latitude = (pole_cos * latitude_sin + pole_sin * latitude_cos * longitude_cos).clamp(-1.0, 1.0).asin();
+        let geographic_cos = geographic_latitude.cos();
+        let difference_cos = ((pole_cos * latitude_cos * longitude_cos - pole_sin * latitude_sin) / geographic_cos).clamp(-1.0, 1.0);
+        let difference = difference_cos.acos().copysign(latitude_cos * longitude_sin);
+
+        (
+            geographic_latitude.to_degrees(),
+            (difference.to_degrees() + self.south_pole_longitude).rem_euclid(360.0),
+        )
+    }
+
+    // Longitudes are given from -180 to 180
+    fn to_rotated(self, latitude: f64, longitude: f64) -> (f64, f64) {
+        let (pole_sin, pole_cos) = (self.south_pole_latitude + 90.0).to_radians().sin_cos();
+        let (difference_sin, difference_cos) = (longitude - self.south_pole_longitude).to_radians().sin_cos();
+        let (latitude_sin, latitude_cos) = latitude.to_radians().sin_cos();
+
+        let rotated_latitude = (pole_cos * latitude_sin - pole_sin * latitude_cos * difference_cos).clamp(-1.0, 1.0).asin();
+        let rotated_cos = rotated_latitude.cos();
+        let longitude_cos = ((pole_cos * latitude_cos * difference_cos + pole_sin * latitude_sin) / rotated_cos).clamp(-1.0, 1.0);
+        let rotated_longitude = longitude_cos.acos().copysign(latitude_cos * difference_sin);
+
+        (
+            rotated_latitude.to_degrees(),
+            longitude_difference(rotated_longitude.to_degrees() + self.angle, 0.0),
+        )
+    }
+}
+
+impl<'a> GridDefinitionTemplate for RotatedLatitudeLongitudeGridTemplate<'a> {
+    fn grid_point_count(&self) -> usize {
+        self.rotated_grid().grid_point_count()
+    }
+
+    fn start(&self) -> (f64, f64) {
+        let (latitude, longitude) = self.rotated_grid().start();
+        self.to_geographic(latitude, longitude)
+    }
+
+    fn origin(&self) -> (f64, f64) {
+        let (latitude, longitude) = self.rotated_grid().origin();
+        self.to_geographic(latitude, longitude)
+    }
+
+    fn end(&self) -> (f64, f64) {
+        let (latitude, longitude) = self.rotated_grid().end();
+        self.to_geographic(latitude, longitude)
+    }
+
+    fn latitude_count(&self) -> usize {
+        self.rotated_grid().latitude_count()
+    }
+
+    fn longitude_count(&self) -> usize {
+        self.rotated_grid().longitude_count()
+    }
+
+    // In degrees of the rotated coordinates
+    fn latitude_resolution(&self) -> f64 {
+        self.rotated_grid().latitude_resolution()
+    }
+
+    fn longitude_resolution(&self) -> f64 {
+        self.rotated_grid().longitude_resolution()
+    }
+
+    fn latitudes(&self) -> Vec<f64> {
+        self.locations_iter().map(|(latitude, _)| latitude).collect()
+    }
+
+    fn longitudes(&self) -> Vec<f64> {
+        self.locations_iter().map(|(_, longitude)| longitude).collect()
+    }
+
+    fn locations(&self) -> Vec<(f64, f64)> {
+        self.locations_iter().collect()
+    }
+
+    fn scanning_mode(&self) -> u8 {
+        self.rotated_grid().scanning_mode_flags()
+    }
+
+    fn is_grid_relative(&self) -> bool {
+        self.data[54] & GRID_RELATIVE_FLAG != 0
+    }
+
+    // Halfway along both rotated axes, then turned to geographic coordinates
+    fn cell_corners(&self) -> Vec<[(f64, f64); 4]> {
+        let rotation = self.rotation();
+        let grid = self.rotated_grid();
+        let (half_height, half_width) = (grid.latitude_resolution().abs() / 2.0, grid.longitude_resolution().abs() / 2.0);
+
+        self.rotated_locations()
+            .map(|(latitude, longitude)| {
+                let corners = [(-1.0, -1.0), (-1.0, 1.0), (1.0, 1.0), (1.0, -1.0)].map(|(dy, dx)| {
+                    rotation.to_geographic((latitude + dy * half_height).clamp(-90.0, 90.0), longitude + dx * half_width)
+                });
+                unwrap_corners(rotation.to_geographic(latitude, longitude).1, corners)
+            })
+            .collect()
+    }
+
+    // Taken from the direction a small step along the rotated parallel runs in geographic
+    // coordinates
+    fn grid_angles(&self) -> Vec<f64> {
+        let rotation = self.rotation();
+        self.rotated_locations()
+            .map(|(latitude, longitude)| {
+                let west = rotation.to_geographic(latitude, longitude - 0.01);
+                let east = rotation.to_geographic(latitude, longitude + 0.01);
+                let northward = east.0 - west.0;
+                let eastward = longitude_difference(east.1, west.1) * ((east.0 + west.0) / 2.0).to_radians().cos();
+                northward.atan2(eastward)
+            })
+            .collect()
+    }
+
+    fn locations_iter(&self) -> Box<dyn Iterator<Item = (f64, f64)>> {
+        let rotation = self.rotation();
+        let locations = self.rotated_locations().collect::<Vec<_>>();
+        Box::new(locations.into_iter().map(move |(latitude, longitude)| rotation.to_geographic(latitude, longitude)))
+    }
+
+    fn index_for_location(&self, latitude: f64, longitude: f64) -> Result<usize, GribError> {
+        let grid = self.rotated_grid();
+        let (latitude, longitude) = self.to_rotated(latitude, longitude);
+        grid.index_for_location(latitude, longitude)
+    }
+
+    fn location_for_index(&self, index: usize) -> Result<(f64, f64), GribError> {
+        if index >= self.grid_point_count() {
+            return Err(GribError::OutOfRange("Index out of range".into()));
+        }
+
+        let grid = self.rotated_grid();
+        let (i, j) = scanned_position(grid.scanning_mode_flags(), self.shape(), index);
+        Ok(self.to_geographic(
+            grid.start_latitude() + j as f64 * grid.j_direction_increment(),
+            grid.start_longitude() + i as f64 * grid.i_direction_increment(),
+        ))
+    }
+}
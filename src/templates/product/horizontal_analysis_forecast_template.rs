@@ -0,0 +1,402 @@
+use gribberish_types::Parameter;
+use crate::templates::template::{Template, TemplateType};
+use crate::utils::{grib_power, read_u16_from_bytes, read_u32_from_bytes};
+use chrono::{Utc, DateTime, Duration};
+use super::tables::{ChemicalConstituent, EnsembleForecastType, FixedSurfaceTypes, GeneratingProcess, StatisticalProcess, TimeUnit};
+use super::{meteorological, hydrological, land_surface, space_weather, oceanographic, local};
+
+#[derive(Clone)]
+pub struct HorizontalAnalysisForecastTemplate<'a> {
+	data: &'a[u8],
+	template_number: u16,
+	discipline: u8,
+	center: u16,
+	local_table_version: u8,
+}
+
+impl <'a> Template for HorizontalAnalysisForecastTemplate<'a> {
+	fn data(&self) -> &[u8] {
+    	self.data
+ 	}
+
+ 	fn template_number(&self) -> u16 {
+ 	    self.template_number
+ 	}
+
+ 	fn template_type(&self) -> TemplateType {
+ 	    TemplateType::Product
+ 	}
+ 	
+    fn template_name(&self) -> &str {
+        match self.template_number {
+            1 => "Individual ensemble forecast, control and perturbed, at a horizontal level or in a horizontal layer at a point in time",
+            8 => "Average, accumulation, extreme values or other statistically processed values at a horizontal level or in a horizontal layer in a continuous or non-continuous time interval",
+            11 => "Individual ensemble forecast, control and perturbed, at a horizontal level or in a horizontal layer, in a continuous or non-continuous time interval",
+            40 => "Analysis or forecast at a horizontal level or in a horizontal layer at a point in time for atmospheric chemical constituents",
+            _ => "Analysis or forecast at a horizontal level or in a horizontal layer at a point in time",
+        }
+    }
+}
+
+impl <'a> HorizontalAnalysisForecastTemplate<'a> {
+
+	pub fn new(template_number: u16, data: &'a[u8], discipline: u8, center: u16, local_table_version: u8) -> HorizontalAnalysisForecastTemplate<'a> {
+		HorizontalAnalysisForecastTemplate {
+			data,
+			template_number,
+			discipline,
+			center,
+			local_table_version,
+		}
+	}
+
+	// The length of a section holding the template, up to the fixed surfaces. The ensemble and
+	// statistical parts that follow are checked as they are read.
+	pub fn length(template_number: u16) -> usize {
+		match template_number {
+			40 => 36,
+			_ => 34,
+		}
+	}
+
+	pub fn center(&self) -> u16 {
+		self.center
+	}
+
+	pub fn local_table_version(&self) -> u8 {
+		self.local_table_version
+	}
+
+	// Template 4.40 inserts the chemical constituent type after the parameter number,
+	// shifting every following octet
+	fn offset(&self) -> usize {
+		match self.template_number {
+			40 => 2,
+			_ => 0,
+		}
+	}
+
+	pub fn category_value(&self) -> u8 {
+		self.data[9]
+	}
+
+	pub fn parameter_value(&self) -> u8{
+		self.data[10]
+	}
+
+	pub fn category(&self) -> &'static str {
+		let category = self.category_value();
+		match self.discipline {
+			0 => meteorological::category(category),
+			1 => hydrological::category(category),
+			2 => land_surface::category(category),
+			4 => space_weather::category(category),
+			10 => oceanographic::category(category),
+			_ => "",
+		}
+	}
+
+	pub fn chemical_constituent(&self) -> Option<ChemicalConstituent> {
+		match self.template_number {
+			40 => read_u16_from_bytes(self.data, 11).map(ChemicalConstituent::from),
+			_ => None,
+		}
+	}
+
+	pub fn parameter(&self) -> Option<Parameter> {
+		let parameter = self.table_parameter()?;
+
+		match self.chemical_constituent() {
+			Some(constituent) => Some(Parameter {
+				name: format!("{} ({})", parameter.name, constituent),
+				..parameter
+			}),
+			None => Some(parameter),
+		}
+	}
+
+	fn table_parameter(&self) -> Option<Parameter> {
+		let category = self.category_value();
+		let parameter = self.parameter_value();
+
+		if local::is_local(self.discipline, category, parameter) {
+			return local::parameter(self.center, self.local_table_version, self.discipline, category, parameter);
+		}
+
+		match self.discipline {
+			0 => meteorological::parameter(category, parameter),
+			1 => hydrological::parameter(category, parameter),
+			2 => land_surface::parameter(category, parameter),
+			4 => space_weather::parameter(category, parameter),
+			10 => oceanographic::parameter(category, parameter),
+			_ => None,
+		}
+	}
+
+	pub fn generating_process(&self) -> GeneratingProcess {
+		self.data[12 + self.offset()].into()
+	}
+
+	pub fn observation_cutoff_hours_after_reference_time(&self) -> u16 {
+		read_u16_from_bytes(self.data, 14 + self.offset()).unwrap_or(0)
+	}
+
+	pub fn observation_cutoff_minutes_after_cutoff_time(&self) -> u8 {
+		self.data[16 + self.offset()]
+	}
+
+	pub fn time_unit(&self) -> TimeUnit {
+		self.data[17 + self.offset()].into()
+	}
+
+	pub fn forecast_time(&self) -> u32 {
+		read_u32_from_bytes(self.data, 18 + self.offset()).unwrap_or(0)
+	}
+
+	pub fn forecast_datetime(&self, reference_date: DateTime<Utc>) -> DateTime<Utc> {
+		let forecast_offset = self.forecast_time();
+		let offset_duration: Duration = self.time_unit().duration(forecast_offset as i64);
+		reference_date + offset_duration
+	}
+
+    pub fn first_fixed_surface_type(&self) -> FixedSurfaceTypes {
+        self.data[22 + self.offset()].into()
+    }
+
+    pub fn first_fixed_surface_scale_factor(&self) -> u8 {
+        self.data[23 + self.offset()]
+    }
+
+    pub fn first_fixed_surface_scaled_value(&self) -> u32 {
+        read_u32_from_bytes(self.data, 24 + self.offset()).unwrap_or(0)
+    }
+
+    pub fn second_fixed_surface_type(&self) -> FixedSurfaceTypes {
+        self.data[28 + self.offset()].into()
+    }
+
+    pub fn second_fixed_surface_scale_factor(&self) -> u8 {
+        self.data[29 + self.offset()]
+    }
+
+    pub fn second_fixed_surface_scaled_value(&self) -> u32 {
+        read_u32_from_bytes(self.data, 30 + self.offset()).unwrap_or(0)
+    }
+
+    pub fn first_fixed_surface_value(&self) -> Option<f64> {
+        fixed_surface_value(self.first_fixed_surface_scale_factor(), self.first_fixed_surface_scaled_value())
+    }
+
+    pub fn second_fixed_surface_value(&self) -> Option<f64> {
+        fixed_surface_value(self.second_fixed_surface_scale_factor(), self.second_fixed_surface_scaled_value())
+    }
+
+    // Templates 4.1 and 4.11 describe the ensemble member after the fixed surfaces,
+    // as the forecast type, perturbation number and number of forecasts in the ensemble
+    pub fn ensemble_member(&self) -> Option<(EnsembleForecastType, u8, u8)> {
+        match self.template_number {
+            1 | 11 if self.data.len() >= 37 => Some((self.data[34].into(), self.data[35], self.data[36])),
+            _ => None,
+        }
+    }
+
+    // Templates 4.8 and 4.11 end with the statistical process over the first time range
+    fn time_range_offset(&self) -> Option<usize> {
+        let offset = match self.template_number {
+            8 => 34,
+            11 => 37,
+            _ => return None,
+        };
+
+        if self.data.len() >= offset + 19 {
+            Some(offset)
+        } else {
+            None
+        }
+    }
+
+    pub fn statistical_process(&self) -> Option<StatisticalProcess> {
+        self.time_range_offset().map(|offset| self.data[offset + 12].into())
+    }
+
+    pub fn time_range(&self) -> Option<(TimeUnit, u32)> {
+        self.time_range_offset().map(|offset| {
+            let unit = self.data[offset + 14].into();
+            (unit, read_u32_from_bytes(self.data, offset + 15).unwrap_or(0))
+        })
+    }
+
+    pub fn pressure_hpa(&self) -> Option<f64> {
+        match self.first_fixed_surface_type() {
+            FixedSurfaceTypes::IsobaricSurface => self.first_fixed_surface_value().map(|pa| pa / 100.0),
+            _ => None,
+        }
+    }
+
+    pub fn height_m(&self) -> Option<f64> {
+        match self.first_fixed_surface_type() {
+            FixedSurfaceTypes::SpecificAltitudeAboveMeanSeaLevel |
+            FixedSurfaceTypes::SpecifiedHeightLevelAboveGround => self.first_fixed_surface_value(),
+            _ => None,
+        }
+    }
+
+    pub fn depth_m(&self) -> Option<f64> {
+        match self.first_fixed_surface_type() {
+            FixedSurfaceTypes::DepthBelowLandSurface |
+            FixedSurfaceTypes::DepthBelowSeaLevel |
+            FixedSurfaceTypes::DepthBelowWaterSurface => self.first_fixed_surface_value(),
+            _ => None,
+        }
+    }
+}
+
+// The scale factor is a sign and magnitude byte, and all bits set marks a missing surface value
+fn fixed_surface_value(scale_factor: u8, scaled_value: u32) -> Option<f64> {
+    if scale_factor == 0xFF || scaled_value == 0xFFFF_FFFF {
+        return None;
+    }
+
+    let magnitude = (scale_factor & 0x7F) as i32;
+    let scale = if scale_factor & 0x80 == 0 { magnitude } else { -magnitude };
+    Some(scaled_value as f64 * grib_power(-scale, 10))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::HorizontalAnalysisForecastTemplate;
+	use crate::templates::product::tables::{ChemicalConstituent, FixedSurfaceTypes, TimeUnit};
+	use chrono::{TimeZone, Utc};
+
+	const NCEP_CENTER: u16 = 7;
+	const DWD_CENTER: u16 = 78;
+	const ECMWF_CENTER: u16 = 98;
+
+	fn template_data(category: u8, parameter: u8) -> Vec<u8> {
+		let mut data = vec![0u8; 34];
+		data[9] = category;
+		data[10] = parameter;
+		data
+	}
+
+	#[test]
+	fn resolve_land_surface_parameters() {
+		let data = template_data(0, 2);
+		let template = HorizontalAnalysisForecastTemplate::new(0, &data, 2, NCEP_CENTER, 1);
+		assert_eq!(template.category(), "vegetation/biomass");
+		assert_eq!(template.parameter().unwrap().abbrev, "TSOIL");
+
+		let data = template_data(3, 192);
+		let template = HorizontalAnalysisForecastTemplate::new(0, &data, 2, NCEP_CENTER, 1);
+		assert_eq!(template.category(), "soil");
+		assert_eq!(template.parameter().unwrap().abbrev, "SOILL");
+	}
+
+	#[test]
+	fn resolve_hydrology_parameters() {
+		let data = template_data(0, 7);
+		let template = HorizontalAnalysisForecastTemplate::new(0, &data, 1, NCEP_CENTER, 1);
+		assert_eq!(template.category(), "hydrology basic");
+		let parameter = template.parameter().unwrap();
+		assert_eq!(parameter.abbrev, "DISRS");
+		assert_eq!(parameter.unit, "m3s-1");
+	}
+
+	#[test]
+	fn resolve_space_weather_parameters() {
+		let data = template_data(2, 4);
+		let template = HorizontalAnalysisForecastTemplate::new(0, &data, 4, NCEP_CENTER, 1);
+		assert_eq!(template.category(), "charged particle mass and number");
+		assert_eq!(template.parameter().unwrap().abbrev, "VTEC");
+	}
+
+	#[test]
+	fn resolve_local_parameters_by_center() {
+		let data = template_data(1, 192);
+		let template = HorizontalAnalysisForecastTemplate::new(0, &data, 0, NCEP_CENTER, 1);
+		assert_eq!(template.parameter().unwrap().abbrev, "CRAIN");
+
+		let template = HorizontalAnalysisForecastTemplate::new(0, &data, 0, NCEP_CENTER, 0);
+		assert!(template.parameter().is_none());
+
+		let template = HorizontalAnalysisForecastTemplate::new(0, &data, 0, 85, 1);
+		assert!(template.parameter().is_none());
+
+		let data = template_data(17, 192);
+		let template = HorizontalAnalysisForecastTemplate::new(0, &data, 0, DWD_CENTER, 1);
+		assert_eq!(template.parameter().unwrap().abbrev, "LPI");
+
+		let data = template_data(128, 167);
+		let template = HorizontalAnalysisForecastTemplate::new(0, &data, 192, ECMWF_CENTER, 0);
+		assert_eq!(template.parameter().unwrap().abbrev, "2t");
+
+		let data = template_data(140, 229);
+		let template = HorizontalAnalysisForecastTemplate::new(0, &data, 192, ECMWF_CENTER, 0);
+		assert_eq!(template.parameter().unwrap().abbrev, "swh");
+	}
+
+	fn surface_data(surface_type: u8, scale_factor: u8, scaled_value: u32) -> Vec<u8> {
+		let mut data = template_data(0, 0);
+		data[22] = surface_type;
+		data[23] = scale_factor;
+		data[24..28].copy_from_slice(&scaled_value.to_be_bytes());
+		data[28] = 255;
+		data[29] = 255;
+		data[30..34].copy_from_slice(&[0xFF; 4]);
+		data
+	}
+
+	#[test]
+	fn read_fixed_surface_levels() {
+		let data = surface_data(100, 0, 85000);
+		let template = HorizontalAnalysisForecastTemplate::new(0, &data, 0, NCEP_CENTER, 1);
+		assert_eq!(template.first_fixed_surface_type(), FixedSurfaceTypes::IsobaricSurface);
+		assert_eq!(template.pressure_hpa(), Some(850.0));
+		assert_eq!(template.height_m(), None);
+		assert_eq!(template.second_fixed_surface_type(), FixedSurfaceTypes::Missing);
+		assert_eq!(template.second_fixed_surface_value(), None);
+
+		let data = surface_data(103, 0, 2);
+		let template = HorizontalAnalysisForecastTemplate::new(0, &data, 0, NCEP_CENTER, 1);
+		assert_eq!(template.height_m(), Some(2.0));
+
+		let data = surface_data(106, 1, 5);
+		let template = HorizontalAnalysisForecastTemplate::new(0, &data, 2, NCEP_CENTER, 1);
+		assert!((template.depth_m().unwrap() - 0.5).abs() < 1e-9);
+	}
+
+	#[test]
+	fn forecast_datetime_time_units() {
+		let reference_date = Utc.with_ymd_and_hms(2020, 9, 12, 0, 0, 0).unwrap();
+
+		let mut data = template_data(0, 0);
+		data[17] = 13;
+		data[18..22].copy_from_slice(&90u32.to_be_bytes());
+		let template = HorizontalAnalysisForecastTemplate::new(0, &data, 0, NCEP_CENTER, 1);
+		assert_eq!(template.time_unit(), TimeUnit::Seconds);
+		assert_eq!(template.forecast_datetime(reference_date), Utc.with_ymd_and_hms(2020, 9, 12, 0, 1, 30).unwrap());
+
+		data[17] = 255;
+		let template = HorizontalAnalysisForecastTemplate::new(0, &data, 0, NCEP_CENTER, 1);
+		assert_eq!(template.time_unit(), TimeUnit::Missing);
+		assert_eq!(template.forecast_datetime(reference_date), reference_date);
+	}
+
+	#[test]
+	fn resolve_chemical_constituent() {
+		let mut data = vec![0u8; 36];
+		data[9] = 20;
+		data[10] = 0;
+		data[11..13].copy_from_slice(&0u16.to_be_bytes());
+		data[19] = 1;
+		data[20..24].copy_from_slice(&6u32.to_be_bytes());
+		let template = HorizontalAnalysisForecastTemplate::new(40, &data, 0, NCEP_CENTER, 1);
+		assert_eq!(template.chemical_constituent(), Some(ChemicalConstituent::Ozone));
+		assert_eq!(template.time_unit(), TimeUnit::Hour);
+		assert_eq!(template.forecast_time(), 6);
+
+		let parameter = template.parameter().unwrap();
+		assert_eq!(parameter.abbrev, "MASSDEN");
+		assert_eq!(parameter.name, "massdensity (ozone)");
+	}
+}
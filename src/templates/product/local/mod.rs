@@ -0,0 +1,23 @@
+pub use gribberish_types::parameters::local::{ncep, ecmwf, dwd};
+
+use gribberish_types::Parameter;
+use crate::sections::identification::Center;
+
+pub fn is_local(discipline: u8, category: u8, parameter: u8) -> bool {
+	discipline >= 192 || category >= 192 || parameter >= 192
+}
+
+/// Resolves a parameter from the local tables of the originating center. A local table
+/// version of 0 means only master table entries are valid, unless the discipline itself is local.
+pub fn parameter(center: u16, local_table_version: u8, discipline: u8, category: u8, parameter: u8) -> Option<Parameter> {
+	if local_table_version == 0 && discipline < 192 {
+		return None;
+	}
+
+	match Center::from_id(center) {
+		Center::NCEP => ncep::parameter(discipline, category, parameter),
+		Center::DWD => dwd::parameter(discipline, category, parameter),
+		Center::ECMWF => ecmwf::parameter(discipline, category, parameter),
+		_ => None,
+	}
+}
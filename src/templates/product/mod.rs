@@ -0,0 +1,8 @@
+pub mod tables;
+pub mod local;
+pub mod product_template;
+pub mod horizontal_analysis_forecast_template;
+
+pub use gribberish_types::parameters::{meteorological, hydrological, land_surface, space_weather, oceanographic};
+pub use product_template::{ProductDiscipline, ProductTemplate};
+pub use horizontal_analysis_forecast_template::HorizontalAnalysisForecastTemplate;
@@ -0,0 +1,22 @@
+use super::horizontal_analysis_forecast_template::HorizontalAnalysisForecastTemplate;
+use gribberish_types::Parameter;
+
+pub trait ProductDiscipline {
+	fn from_category_parameter(category: u8, parameter: u8) -> Self;
+
+	fn parameter(&self) -> Option<Parameter>;
+}
+
+pub enum ProductTemplate<'a> {
+	HorizontalAnalysisForecast(HorizontalAnalysisForecastTemplate<'a>),
+	Other,
+}
+
+impl <'a> ProductTemplate<'a> {
+	pub fn from_template_number(template_number: u16, data: &'a[u8], discipline: u8, center: u16, local_table_version: u8) -> ProductTemplate<'a> {
+		match template_number {
+			0 | 1 | 8 | 11 | 40 => ProductTemplate::HorizontalAnalysisForecast(HorizontalAnalysisForecastTemplate::new(template_number, data, discipline, center, local_table_version)),
+			_ => ProductTemplate::Other,
+		}
+	}
+}
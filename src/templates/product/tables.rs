@@ -0,0 +1,541 @@
+use gribberish_macros::{DisplayDescription, FromValue};
+use chrono::Duration;
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ClusteringMethod {
+	#[description = "anomoly correlation"]
+	AnomolyCorrelation = 0,
+	#[description = "root mean square"]
+	RMS = 1,
+	Unknown(u8),
+}
+
+#[repr(u16)]
+#[derive(Eq, Clone, Copy, PartialEq, Debug, DisplayDescription, FromValue)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FixedSurfaceTypes {
+	#[description = "ground or water surface"]
+	GroundOrWater = 1,
+	#[description = "cloud base level"]
+	CloudBase = 2,
+	#[description = "cloud tops level"]
+	CloudTop = 3,
+	#[description = "level of 0 degree C isotherm"]
+	ZeroDegreeIsotherm = 4,
+	#[description = "level of adiabatic condensation lifted from the surface"]
+	AdiabaticCondensationLevel = 5,
+	#[description = "maximum wind level"]
+	MaximumWindLevel = 6,
+	Tropopause = 7,
+	#[description = "nominal top of the atmosphere"]
+	NominalTopOfAtmosphere = 8,
+	#[description = "sea bottom"]
+	SeaBottom = 9,
+	#[description = "entire atmosphere"]
+	EntireAtmosphere = 10,
+	#[description = "cumulonimbus base"]
+	CumulonimbusBase = 11,
+	#[description = "cumulonimbus top"]
+	CumulonimbusTop = 12,
+	#[description = "lowest level where vertically integrated cloud cover exceeds the specified percentage"]
+	CloudCoverExceedsPercentage = 13,
+	#[description = "level of free convection"]
+	LevelOfFreeConvection = 14,
+	#[description = "convection condensation level"]
+	ConvectionCondensationLevel = 15,
+	#[description = "level of neutral buoyancy or equilibrium"]
+	LevelOfNeutralBuoyancy = 16,
+	#[description = "departure level of the most unstable parcel of air"]
+	MostUnstableParcelDepartureLevel = 17,
+	#[description = "departure level of a mixed layer parcel of air with specified layer depth"]
+	MixedLayerParcelDepartureLevel = 18,
+	#[description = "isothermal level"]
+	IsothermalLevel = 20,
+	#[description = "lowest level where mass density exceeds the specified value"]
+	LowestMassDensityExceedance = 21,
+	#[description = "highest level where mass density exceeds the specified value"]
+	HighestMassDensityExceedance = 22,
+	#[description = "lowest level where air concentration exceeds the specified value"]
+	LowestAirConcentrationExceedance = 23,
+	#[description = "highest level where air concentration exceeds the specified value"]
+	HighestAirConcentrationExceedance = 24,
+	#[description = "highest level where radar reflectivity exceeds the specified value"]
+	HighestRadarReflectivityExceedance = 25,
+	#[description = "convective cloud layer base"]
+	ConvectiveCloudLayerBase = 26,
+	#[description = "convective cloud layer top"]
+	ConvectiveCloudLayerTop = 27,
+	#[description = "specified radius from the centre of the sun"]
+	SpecifiedRadiusFromSun = 30,
+	#[description = "solar photosphere"]
+	SolarPhotosphere = 31,
+	#[description = "ionospheric D-region level"]
+	IonosphericDRegion = 32,
+	#[description = "ionospheric E-region level"]
+	IonosphericERegion = 33,
+	#[description = "ionospheric F1-region level"]
+	IonosphericF1Region = 34,
+	#[description = "ionospheric F2-region level"]
+	IonosphericF2Region = 35,
+	#[description = "isobaric surface"]
+	IsobaricSurface = 100,
+	#[description = "mean sea level"]
+	MeanSeaLevel = 101,
+	#[description = "specific altitude above mean sea level"]
+	SpecificAltitudeAboveMeanSeaLevel = 102,
+	#[description = "specified height level above ground"]
+	SpecifiedHeightLevelAboveGround = 103,
+	#[description = "sigma level"]
+	SigmaLevel = 104,
+	#[description = "hybrid level"]
+	HybridLevel = 105,
+	#[description = "depth below land surface"]
+	DepthBelowLandSurface = 106,
+	#[description = "isentropic (theta) level"]
+	IsentropicLevel = 107,
+	#[description = "level at specified pressure difference from ground to level"]
+	PressureDifferenceFromGround = 108,
+	#[description = "potential vorticity surface"]
+	PotentialVorticitySurface = 109,
+	#[description = "eta level"]
+	EtaLevel = 111,
+	#[description = "logarithmic hybrid level"]
+	LogarithmicHybridLevel = 113,
+	#[description = "snow level"]
+	SnowLevel = 114,
+	#[description = "sigma height level"]
+	SigmaHeightLevel = 115,
+	#[description = "mixed layer depth"]
+	MixedLayerDepth = 117,
+	#[description = "hybrid height level"]
+	HybridHeightLevel = 118,
+	#[description = "hybrid pressure level"]
+	HybridPressureLevel = 119,
+	#[description = "generalized vertical height coordinate"]
+	GeneralizedVerticalHeight = 150,
+	#[description = "soil level"]
+	SoilLevel = 151,
+	#[description = "sea-ice level"]
+	SeaIceLevel = 152,
+	#[description = "depth below sea level"]
+	DepthBelowSeaLevel = 160,
+	#[description = "depth below water surface"]
+	DepthBelowWaterSurface = 161,
+	#[description = "lake or river bottom"]
+	LakeOrRiverBottom = 162,
+	#[description = "bottom of sediment layer"]
+	BottomOfSedimentLayer = 163,
+	#[description = "bottom of thermally active sediment layer"]
+	BottomOfThermallyActiveSedimentLayer = 164,
+	#[description = "bottom of sediment layer penetrated by thermal wave"]
+	BottomOfThermalWaveSedimentLayer = 165,
+	#[description = "mixing layer"]
+	MixingLayer = 166,
+	#[description = "bottom of root zone"]
+	BottomOfRootZone = 167,
+	#[description = "ocean model level"]
+	OceanModelLevel = 168,
+	#[description = "ocean level defined by water density difference from near-surface to level"]
+	OceanDensityDifferenceLevel = 169,
+	#[description = "ocean level defined by water potential temperature difference from near-surface to level"]
+	OceanPotentialTemperatureDifferenceLevel = 170,
+	#[description = "ocean level defined by vertical eddy diffusivity difference from near-surface to level"]
+	OceanEddyDiffusivityDifferenceLevel = 171,
+	#[description = "top surface of ice on sea, lake or river"]
+	TopSurfaceOfIce = 174,
+	#[description = "top surface of ice, under snow cover, on sea, lake or river"]
+	TopSurfaceOfIceUnderSnow = 175,
+	#[description = "bottom surface (underside) ice on sea, lake or river"]
+	BottomSurfaceOfIce = 176,
+	#[description = "deep soil (of indefinite depth)"]
+	DeepSoil = 177,
+	#[description = "top surface of glacier ice and inland ice"]
+	TopSurfaceOfGlacierIce = 178,
+	#[description = "deep inland or glacier ice (of indefinite depth)"]
+	DeepGlacierIce = 179,
+	#[description = "grid tile land fraction as a model surface"]
+	GridTileLandFraction = 180,
+	#[description = "grid tile water fraction as a model surface"]
+	GridTileWaterFraction = 181,
+	#[description = "grid tile ice fraction on sea, lake or river as a model surface"]
+	GridTileIceFraction = 182,
+	#[description = "grid tile glacier ice and inland ice fraction as a model surface"]
+	GridTileGlacierIceFraction = 183,
+	#[description = "entire atmosphere (considered as a single layer)"]
+	EntireAtmosphereLayer = 200,
+	#[description = "entire ocean (considered as a single layer)"]
+	EntireOceanLayer = 201,
+	#[description = "highest tropospheric freezing level"]
+	HighestTroposphericFreezingLevel = 204,
+	#[description = "grid scale cloud bottom level"]
+	GridScaleCloudBottom = 206,
+	#[description = "grid scale cloud top level"]
+	GridScaleCloudTop = 207,
+	#[description = "boundary layer cloud bottom level"]
+	BoundaryLayerCloudBottom = 209,
+	#[description = "boundary layer cloud top level"]
+	BoundaryLayerCloudTop = 210,
+	#[description = "boundary layer cloud layer"]
+	BoundaryLayerCloudLayer = 211,
+	#[description = "low cloud bottom level"]
+	LowCloudBottom = 212,
+	#[description = "low cloud top level"]
+	LowCloudTop = 213,
+	#[description = "low cloud layer"]
+	LowCloudLayer = 214,
+	#[description = "cloud ceiling"]
+	CloudCeiling = 215,
+	#[description = "planetary boundary layer"]
+	PlanetaryBoundaryLayer = 220,
+	#[description = "layer between two hybrid levels"]
+	HybridLevelLayer = 221,
+	#[description = "middle cloud bottom level"]
+	MiddleCloudBottom = 222,
+	#[description = "middle cloud top level"]
+	MiddleCloudTop = 223,
+	#[description = "middle cloud layer"]
+	MiddleCloudLayer = 224,
+	#[description = "high cloud bottom level"]
+	HighCloudBottom = 232,
+	#[description = "high cloud top level"]
+	HighCloudTop = 233,
+	#[description = "high cloud layer"]
+	HighCloudLayer = 234,
+	#[description = "ocean isotherm level"]
+	OceanIsothermLevel = 235,
+	#[description = "layer between two depths below ocean surface"]
+	OceanDepthLayer = 236,
+	#[description = "bottom of ocean mixed layer"]
+	OceanMixedLayerBottom = 237,
+	#[description = "bottom of ocean isothermal layer"]
+	OceanIsothermalLayerBottom = 238,
+	#[description = "layer ocean surface and 26C ocean isothermal level"]
+	OceanSurfaceTo26CIsothermLayer = 239,
+	#[description = "ocean mixed layer"]
+	OceanMixedLayer = 240,
+	#[description = "Ordered Sequence of Data"]
+	OrderedSequence = 241,
+	#[description = "convective cloud bottom level"]
+	ConvectiveCloudBottom = 242,
+	#[description = "convective cloud top level"]
+	ConvectiveCloudTop = 243,
+	#[description = "convective cloud layer"]
+	ConvectiveCloudLayer = 244,
+	#[description = "lowest level of the wet bulb zero"]
+	LowestWetBulbZero = 245,
+	#[description = "maximum equivalent potential temperature level"]
+	MaximumEquivalentPotentialTemperatureLevel = 246,
+	#[description = "equilibrium level"]
+	EquilibriumLevel = 247,
+	#[description = "shallow convective cloud bottom level"]
+	ShallowConvectiveCloudBottom = 248,
+	#[description = "shallow convective cloud top level"]
+	ShallowConvectiveCloudTop = 249,
+	#[description = "deep convective cloud bottom level"]
+	DeepConvectiveCloudBottom = 251,
+	#[description = "deep convective cloud top level"]
+	DeepConvectiveCloudTop = 252,
+	#[description = "lowest bottom level of supercooled liquid water layer"]
+	LowestSupercooledLiquidWaterBottom = 253,
+	#[description = "highest top level of supercooled liquid water layer"]
+	HighestSupercooledLiquidWaterTop = 254,
+	Missing = 255,
+	Unknown(u8) = 256,
+}
+
+#[repr(u32)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChemicalConstituent {
+	Ozone = 0,
+	#[description = "water vapour"]
+	WaterVapour = 1,
+	Methane = 2,
+	#[description = "carbon dioxide"]
+	CarbonDioxide = 3,
+	#[description = "carbon monoxide"]
+	CarbonMonoxide = 4,
+	#[description = "nitrogen dioxide"]
+	NitrogenDioxide = 5,
+	#[description = "nitrous oxide"]
+	NitrousOxide = 6,
+	Formaldehyde = 7,
+	#[description = "sulphur dioxide"]
+	SulphurDioxide = 8,
+	Ammonia = 9,
+	Ammonium = 10,
+	#[description = "nitrogen monoxide"]
+	NitrogenMonoxide = 11,
+	#[description = "atomic oxygen"]
+	AtomicOxygen = 12,
+	#[description = "nitrate radical"]
+	NitrateRadical = 13,
+	#[description = "hydroperoxyl radical"]
+	HydroperoxylRadical = 14,
+	#[description = "dinitrogen pentoxide"]
+	DinitrogenPentoxide = 15,
+	#[description = "nitrous acid"]
+	NitrousAcid = 16,
+	#[description = "nitric acid"]
+	NitricAcid = 17,
+	#[description = "peroxynitric acid"]
+	PeroxynitricAcid = 18,
+	#[description = "hydrogen peroxide"]
+	HydrogenPeroxide = 19,
+	#[description = "molecular hydrogen"]
+	MolecularHydrogen = 20,
+	#[description = "atomic nitrogen"]
+	AtomicNitrogen = 21,
+	Sulphate = 22,
+	Radon = 23,
+	#[description = "elemental mercury"]
+	ElementalMercury = 24,
+	#[description = "divalent mercury"]
+	DivalentMercury = 25,
+	#[description = "atomic chlorine"]
+	AtomicChlorine = 26,
+	#[description = "chlorine monoxide"]
+	ChlorineMonoxide = 27,
+	#[description = "dichlorine peroxide"]
+	DichlorinePeroxide = 28,
+	#[description = "hypochlorous acid"]
+	HypochlorousAcid = 29,
+	#[description = "chlorine nitrate"]
+	ChlorineNitrate = 30,
+	#[description = "chlorine dioxide"]
+	ChlorineDioxide = 31,
+	#[description = "atomic bromine"]
+	AtomicBromine = 32,
+	#[description = "bromine monoxide"]
+	BromineMonoxide = 33,
+	#[description = "bromine chloride"]
+	BromineChloride = 34,
+	#[description = "hydrogen bromide"]
+	HydrogenBromide = 35,
+	#[description = "hypobromous acid"]
+	HypobromousAcid = 36,
+	#[description = "bromine nitrate"]
+	BromineNitrate = 37,
+	#[description = "hydroxyl radical"]
+	HydroxylRadical = 10000,
+	#[description = "methyl peroxy radical"]
+	MethylPeroxyRadical = 10001,
+	#[description = "methyl hydroperoxide"]
+	MethylHydroperoxide = 10002,
+	Methanol = 10004,
+	#[description = "formic acid"]
+	FormicAcid = 10005,
+	#[description = "hydrogen cyanide"]
+	HydrogenCyanide = 10006,
+	#[description = "aceto nitrile"]
+	AcetoNitrile = 10007,
+	Ethane = 10008,
+	Ethene = 10009,
+	Ethyne = 10010,
+	Ethanol = 10011,
+	#[description = "acetic acid"]
+	AceticAcid = 10012,
+	#[description = "peroxyacetyl nitrate"]
+	PeroxyacetylNitrate = 10013,
+	Propane = 10014,
+	Propene = 10015,
+	Butanes = 10016,
+	Isoprene = 10017,
+	#[description = "alpha pinene"]
+	AlphaPinene = 10018,
+	#[description = "beta pinene"]
+	BetaPinene = 10019,
+	Limonene = 10020,
+	Benzene = 10021,
+	Toluene = 10022,
+	Xylene = 10023,
+	#[description = "total aerosol"]
+	TotalAerosol = 62000,
+	#[description = "dust dry"]
+	DustDry = 62001,
+	#[description = "water in ambient"]
+	WaterInAmbient = 62002,
+	#[description = "ammonium dry"]
+	AmmoniumDry = 62003,
+	#[description = "nitrate dry"]
+	NitrateDry = 62004,
+	#[description = "nitric acid trihydrate"]
+	NitricAcidTrihydrate = 62005,
+	#[description = "sulphate dry"]
+	SulphateDry = 62006,
+	#[description = "mercury dry"]
+	MercuryDry = 62007,
+	#[description = "sea salt dry"]
+	SeaSaltDry = 62008,
+	#[description = "black carbon dry"]
+	BlackCarbonDry = 62009,
+	#[description = "particulate organic matter dry"]
+	ParticulateOrganicMatterDry = 62010,
+	#[description = "primary particulate organic matter dry"]
+	PrimaryParticulateOrganicMatterDry = 62011,
+	#[description = "secondary particulate organic matter dry"]
+	SecondaryParticulateOrganicMatterDry = 62012,
+	#[description = "black carbon hydrophilic dry"]
+	BlackCarbonHydrophilicDry = 62013,
+	#[description = "black carbon hydrophobic dry"]
+	BlackCarbonHydrophobicDry = 62014,
+	#[description = "particulate organic matter hydrophilic dry"]
+	ParticulateOrganicMatterHydrophilicDry = 62015,
+	#[description = "particulate organic matter hydrophobic dry"]
+	ParticulateOrganicMatterHydrophobicDry = 62016,
+	#[description = "nitrate hydrophilic dry"]
+	NitrateHydrophilicDry = 62017,
+	#[description = "nitrate hydrophobic dry"]
+	NitrateHydrophobicDry = 62018,
+	#[description = "smoke - high absorption"]
+	SmokeHighAbsorption = 62020,
+	#[description = "smoke - low absorption"]
+	SmokeLowAbsorption = 62021,
+	#[description = "aerosol - high absorption"]
+	AerosolHighAbsorption = 62022,
+	#[description = "aerosol - low absorption"]
+	AerosolLowAbsorption = 62023,
+	#[description = "volcanic ash"]
+	VolcanicAsh = 62025,
+	Missing = 65535,
+	Unknown(u16) = 65536,
+}
+
+#[repr(u8)]
+#[derive(Eq, Clone, Copy, PartialEq, Debug, DisplayDescription, FromValue)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GeneratingProcess {
+	Analysis = 0,
+	Initialization = 1,
+	Forecast = 2,
+	#[description = "bias corrected forecast"]
+	BiasCorrectedForecast = 3,
+	#[description = "ensemble forecast"]
+	EnsembleForecast = 4,
+	#[description = "probability forecast"]
+	ProbabilityForecast = 5,
+	#[description = "forecast error"]
+	ForecastError = 6,
+	#[description = "analysis error"]
+	AnalysisError = 7,
+	Observation = 8,
+	Climatological = 9,
+	#[description = "probability weighted forecast"]
+	ProbabilityWeightedForecast = 10,
+	#[description = "bias corrected ensemble forecast"]
+	BiasCorrectedEnsembleForecast = 11,
+	#[description = "post-processed analysis"]
+	PostProcessedAnalysis = 12,
+	#[description = "post-processed forecast"]
+	PostProcessedForecast = 13,
+	Nowcast = 14, 
+	Hindcast = 15,
+	#[description = "physical retrieval"]
+	PhysicalRetrieval = 16,
+	#[description = "regression analysis"]
+	RegressionAnalysis = 17,
+	#[description = "difference between two forecasts"]
+	DifferenceBetweenTwoForecasts = 18,
+	#[description = "forecast confidence indicator"]
+	ForecastConfidenceIndicator = 192,
+	#[description = "probability matched mean"]
+	ProbabilityMatchedMean = 193, 
+	#[description = "neighborhood probability"]
+	NeighborhoodProbability = 194, 
+	#[description = "bias corrected downscaled ensemble forecast"]
+	BiasCorrectedDownscaledEnsembleForecast = 195,
+	#[description = "perturbed analysis for ensemble initialization"]
+	PerturbedAnalysisForEnsembleInitialization = 196,
+	Unknown(u8),
+}
+
+#[repr(u16)]
+#[derive(Eq, Clone, Copy, PartialEq, Debug, DisplayDescription, FromValue)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimeUnit {
+	Minute = 0,
+	Hour = 1, 
+	Day = 2, 
+	Month = 3,
+	Year = 4, 
+	Decade = 5, 
+	Normal = 6, 
+	Century = 7,
+	#[description = "3 hours"]
+	ThreeHours = 10,
+	#[description = "6 hours"]
+	SixHours = 11,
+	#[description = "12 hours"]
+	TwelveHours = 12,
+	Seconds = 13,
+	Missing = 255,
+	Unknown(u8) = 256,
+}
+
+impl TimeUnit {
+	pub fn duration(&self, value: i64) -> Duration {
+		match self {
+			TimeUnit::Minute => Duration::minutes(value),
+			TimeUnit::Hour => Duration::hours(value),
+			TimeUnit::ThreeHours => Duration::hours(value * 3),
+			TimeUnit::SixHours => Duration::hours(value * 6),
+			TimeUnit::TwelveHours => Duration::hours(value * 12),
+			TimeUnit::Day => Duration::hours(value * 24),
+			TimeUnit::Month => Duration::hours(value * 730),
+			TimeUnit::Year => Duration::hours(value * 8760),
+			TimeUnit::Decade => Duration::hours(value * 87600),
+			TimeUnit::Normal => Duration::hours(value * 262800),
+			TimeUnit::Century => Duration::hours(value * 876000),
+			TimeUnit::Seconds => Duration::seconds(value),
+			TimeUnit::Missing | TimeUnit::Unknown(_) => Duration::zero(),
+		}
+	}
+}
+
+#[repr(u16)]
+#[derive(Eq, Clone, Copy, PartialEq, Debug, DisplayDescription, FromValue)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EnsembleForecastType {
+	#[description = "unperturbed high-resolution control forecast"]
+	HighResolutionControl = 0,
+	#[description = "unperturbed low-resolution control forecast"]
+	LowResolutionControl = 1,
+	#[description = "negatively perturbed forecast"]
+	NegativelyPerturbed = 2,
+	#[description = "positively perturbed forecast"]
+	PositivelyPerturbed = 3,
+	#[description = "multi-model forecast"]
+	MultiModel = 4,
+	#[description = "perturbed ensemble member"]
+	PerturbedMember = 192,
+	Missing = 255,
+	Unknown(u8) = 256,
+}
+
+#[repr(u16)]
+#[derive(Eq, Clone, Copy, PartialEq, Debug, DisplayDescription, FromValue)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StatisticalProcess {
+	Average = 0,
+	Accumulation = 1,
+	Maximum = 2,
+	Minimum = 3,
+	#[description = "difference (value at the end of the time range minus value at the beginning)"]
+	Difference = 4,
+	#[description = "root mean square"]
+	RootMeanSquare = 5,
+	#[description = "standard deviation"]
+	StandardDeviation = 6,
+	#[description = "covariance (temporal variance)"]
+	Covariance = 7,
+	#[description = "difference (value at the beginning of the time range minus value at the end)"]
+	ReverseDifference = 8,
+	Ratio = 9,
+	#[description = "standardized anomaly"]
+	StandardizedAnomaly = 10,
+	Summation = 11,
+	Missing = 255,
+	Unknown(u8) = 256,
+}
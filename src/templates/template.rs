@@ -1,11 +1,16 @@
 use gribberish_macros::{DisplayDescription, FromValue};
 
 #[repr(u8)]
-#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue)]
+#[derive(Eq, Clone, Copy, PartialEq, Debug, DisplayDescription, FromValue)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TemplateType {
+    #[description = "Grid definition"]
     Grid = 3,
+    #[description = "Product definition"]
     Product = 4,
+    #[description = "Data representation"]
     DataRepresentation = 5,
+    #[description = "Data"]
     Data = 6,
 }
 
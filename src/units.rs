@@ -0,0 +1,167 @@
+use crate::error::GribError;
+use std::collections::HashMap;
+
+// A linear change of units, applied as value * scale + offset
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Conversion {
+    pub scale: f64,
+    pub offset: f64,
+}
+
+impl Conversion {
+    pub const IDENTITY: Conversion = Conversion { scale: 1.0, offset: 0.0 };
+
+    pub fn scaled(scale: f64) -> Conversion {
+        Conversion { scale, offset: 0.0 }
+    }
+
+    pub fn apply(&self, value: f64) -> f64 {
+        value * self.scale + self.offset
+    }
+
+    pub fn inverse(&self) -> Conversion {
+        Conversion {
+            scale: 1.0 / self.scale,
+            offset: -self.offset / self.scale,
+        }
+    }
+
+    // This conversion followed by the other one
+    pub fn then(&self, other: &Conversion) -> Conversion {
+        Conversion {
+            scale: self.scale * other.scale,
+            offset: self.offset * other.scale + other.offset,
+        }
+    }
+}
+
+// Converts between units written the way the parameter tables write them, such as "K", "ms-1"
+// or "kgm-2". Every unit is registered against the base unit of what it measures, so any two
+// units sharing a base convert through it. Units that are not registered are their own base.
+#[derive(Clone, Debug)]
+pub struct UnitRegistry {
+    units: HashMap<String, (String, Conversion)>,
+}
+
+impl UnitRegistry {
+    // A registry without any conversions
+    pub fn empty() -> UnitRegistry {
+        UnitRegistry { units: HashMap::new() }
+    }
+
+    // Registers the conversion from the unit to its base unit
+    pub fn register(&mut self, unit: &str, base: &str, to_base: Conversion) {
+        self.units.insert(normalize(unit), (normalize(base), to_base));
+    }
+
+    pub fn conversion(&self, from: &str, to: &str) -> Option<Conversion> {
+        let (from_base, from_conversion) = self.to_base(from);
+        let (to_base, to_conversion) = self.to_base(to);
+        if from_base != to_base {
+            return None;
+        }
+        Some(from_conversion.then(&to_conversion.inverse()))
+    }
+
+    // Converts the values in place, NaN values stay NaN
+    pub fn convert(&self, values: &mut [f64], from: &str, to: &str) -> Result<(), GribError> {
+        let conversion = unwrap_or_return!(
+            self.conversion(from, to),
+            GribError::UnitConversion {
+                from: from.into(),
+                to: to.into(),
+            }
+        );
+
+        values.iter_mut().for_each(|value| *value = conversion.apply(*value));
+        Ok(())
+    }
+
+    fn to_base(&self, unit: &str) -> (String, Conversion) {
+        let unit = normalize(unit);
+        match self.units.get(&unit) {
+            Some((base, conversion)) => (base.clone(), *conversion),
+            None => (unit, Conversion::IDENTITY),
+        }
+    }
+}
+
+impl Default for UnitRegistry {
+    // Temperature, speed, pressure and precipitation units in common use
+    fn default() -> UnitRegistry {
+        let mut registry = UnitRegistry::empty();
+
+        registry.register("degC", "K", Conversion { scale: 1.0, offset: 273.15 });
+        registry.register("degF", "K", Conversion { scale: 5.0 / 9.0, offset: 273.15 - 32.0 * 5.0 / 9.0 });
+
+        registry.register("knots", "ms-1", Conversion::scaled(1852.0 / 3600.0));
+        registry.register("kmh-1", "ms-1", Conversion::scaled(1.0 / 3.6));
+        registry.register("mph", "ms-1", Conversion::scaled(0.44704));
+
+        registry.register("hPa", "Pa", Conversion::scaled(100.0));
+        registry.register("kPa", "Pa", Conversion::scaled(1000.0));
+
+        // A kilogram of water over a square metre is a millimetre deep
+        registry.register("mm", "kgm-2", Conversion::IDENTITY);
+        registry.register("cm", "kgm-2", Conversion::scaled(10.0));
+        registry.register("in", "kgm-2", Conversion::scaled(25.4));
+
+        registry
+    }
+}
+
+// Folds the ways a unit may be written into the one the registry keys on
+fn normalize(unit: &str) -> String {
+    let unit = unit.trim().to_lowercase().replace(' ', "");
+    match unit.as_str() {
+        "°c" | "c" | "celsius" => "degc",
+        "°f" | "f" | "fahrenheit" => "degf",
+        "kelvin" => "k",
+        "m/s" | "mps" => "ms-1",
+        "kt" | "kts" | "knot" => "knots",
+        "km/h" | "kph" => "kmh-1",
+        "mb" | "mbar" | "millibar" => "hpa",
+        // The tables give pressure as both Pa and N m-2
+        "nm-2" => "pa",
+        "kg/m2" | "kg/m^2" => "kgm-2",
+        "inch" | "inches" => "in",
+        other => other,
+    }
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Conversion, UnitRegistry};
+
+    #[test]
+    fn convert_common_units() {
+        let registry = UnitRegistry::default();
+        let close = |from: &str, to: &str, value: f64, expected: f64| {
+            let converted = registry.conversion(from, to).unwrap().apply(value);
+            assert!((converted - expected).abs() < 1e-6, "{} {} is {} {}, not {}", value, from, converted, to, expected);
+        };
+
+        close("K", "degC", 273.15, 0.0);
+        close("K", "°F", 373.15, 212.0);
+        close("degF", "C", 32.0, 0.0);
+        close("m s-1", "knots", 1852.0 / 3600.0, 1.0);
+        close("kt", "km/h", 1.0, 1.852);
+        close("pa", "hPa", 101_325.0, 1013.25);
+        close("Nm-2", "mb", 100.0, 1.0);
+        close("kgm-2", "in", 25.4, 1.0);
+        close("K", "K", 280.0, 280.0);
+
+        assert_eq!(registry.conversion("K", "ms-1"), None);
+        let mut values = [273.15, f64::NAN];
+        registry.convert(&mut values, "K", "degC").unwrap();
+        assert!(values[0].abs() < 1e-9 && values[1].is_nan());
+        assert!(registry.convert(&mut values, "degC", "hPa").is_err());
+
+        // Units registered later convert through their base alongside the built in ones
+        let mut registry = UnitRegistry::default();
+        registry.register("ft", "m", Conversion::scaled(0.3048));
+        registry.register("km", "m", Conversion::scaled(1000.0));
+        assert!((registry.conversion("km", "ft").unwrap().apply(0.3048) - 1000.0).abs() < 1e-9);
+    }
+}
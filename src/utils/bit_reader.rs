@@ -0,0 +1,140 @@
+use crate::error::GribError;
+
+// Reads big endian unsigned values of any bit width directly from packed bytes
+pub struct BitReader<'a> {
+    data: &'a [u8],
+    // The position of the next bit to read, counted from the most significant bit of the first byte
+    offset: usize,
+}
+
+impl<'a> BitReader<'a> {
+    pub fn new(data: &'a [u8]) -> BitReader<'a> {
+        BitReader { data, offset: 0 }
+    }
+
+    pub fn bit_offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn remaining_bits(&self) -> usize {
+        (self.data.len() * 8).saturating_sub(self.offset)
+    }
+
+    pub fn seek(&mut self, bit_offset: usize) {
+        self.offset = bit_offset;
+    }
+
+    pub fn read(&mut self, count: usize) -> Result<u64, GribError> {
+        if count > 64 {
+            return Err(GribError::InvalidData(format!("Unable to read {} bits into a single value", count)));
+        } else if count > self.remaining_bits() {
+            return Err(GribError::InvalidData(format!(
+                "Packed data ends after {} bits, {} more are needed",
+                self.data.len() * 8,
+                count - self.remaining_bits()
+            )));
+        } else if count == 0 {
+            return Ok(0);
+        }
+
+        // Most values fit in a single big endian word read from where they start
+        let first_byte = self.offset / 8;
+        let shift = self.offset % 8;
+        if shift + count <= 64 && first_byte + 8 <= self.data.len() {
+            let mut word = [0; 8];
+            word.copy_from_slice(&self.data[first_byte..first_byte + 8]);
+            self.offset += count;
+            return Ok((u64::from_be_bytes(word) << shift) >> (64 - count));
+        }
+
+        let mut value: u64 = 0;
+        let mut remaining = count;
+        while remaining > 0 {
+            let byte = self.data[self.offset / 8];
+            let available = 8 - self.offset % 8;
+            let taken = available.min(remaining);
+            let bits = (byte >> (available - taken)) & (((1u16 << taken) - 1) as u8);
+
+            value = (value << taken) | bits as u64;
+            remaining -= taken;
+            self.offset += taken;
+        }
+
+        Ok(value)
+    }
+
+    // Sign and magnitude, with the sign in the first bit
+    pub fn read_signed(&mut self, count: usize) -> Result<i64, GribError> {
+        if count == 0 {
+            return Ok(0);
+        }
+
+        let negative = self.read(1)? == 1;
+        let magnitude = self.read(count - 1)? as i64;
+        Ok(if negative { -magnitude } else { magnitude })
+    }
+
+    pub fn read_bit(&mut self) -> Result<bool, GribError> {
+        self.read(1).map(|b| b == 1)
+    }
+
+    // Moves to the start of the next byte, groups of values are padded to whole bytes
+    pub fn align(&mut self) {
+        self.offset = self.offset.div_ceil(8) * 8;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BitReader;
+
+    #[test]
+    fn read_values_across_bytes() {
+        let data = [0b1010_1100, 0b0101_1111, 0x00, 0xff];
+        let mut reader = BitReader::new(&data);
+        assert_eq!(reader.read(3), Ok(0b101));
+        assert_eq!(reader.read(7), Ok(0b011_0001));
+        assert_eq!(reader.read_bit(), Ok(false));
+        assert_eq!(reader.read(5), Ok(0b11111));
+        reader.align();
+        assert_eq!(reader.remaining_bits(), 16);
+        assert_eq!(reader.read(16), Ok(0x00ff));
+        assert!(reader.read(1).is_err());
+
+        // Small values keep their leading zeros
+        let mut reader = BitReader::new(&[0x01, 0x02]);
+        assert_eq!(reader.read(8), Ok(1));
+        assert_eq!(reader.read(8), Ok(2));
+    }
+
+    #[test]
+    fn read_wide_and_signed_values() {
+        let data = [0xff; 9];
+        let mut reader = BitReader::new(&data);
+        reader.seek(4);
+        assert_eq!(reader.read(64), Ok(u64::MAX));
+        assert!(reader.read(65).is_err());
+
+        let mut reader = BitReader::new(&[0b1000_0011, 0b0000_0011]);
+        assert_eq!(reader.read_signed(8), Ok(-3));
+        assert_eq!(reader.read_signed(8), Ok(3));
+        assert_eq!(reader.read_signed(0), Ok(0));
+    }
+
+    #[test]
+    fn read_whole_words_and_the_tail_alike() {
+        // Values away from the end are read a word at a time, the last ones byte by byte
+        let data = (0..40u8).map(|i| i.wrapping_mul(37)).collect::<Vec<u8>>();
+        for width in [1, 7, 13, 24, 31, 57] {
+            let mut reader = BitReader::new(&data);
+            let mut expected = 0u64;
+            while reader.remaining_bits() >= width {
+                let start = reader.bit_offset();
+                for bit in start..start + width {
+                    expected = (expected << 1) | ((data[bit / 8] >> (7 - bit % 8)) & 1) as u64;
+                }
+                assert_eq!(reader.read(width), Ok(expected & (u64::MAX >> (64 - width))));
+            }
+        }
+    }
+}
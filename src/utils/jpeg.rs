@@ -1,3 +1,4 @@
+use crate::error::GribError;
 use std::ffi::c_void;
 use std::slice;
 use std::ptr::null_mut;
@@ -22,6 +23,16 @@ impl<'a> JpegUserData<'a> {
             input: data,
         }
     }
+
+    #[cfg(feature = "jpeg2000-encoder")]
+    pub fn new_output() -> Self {
+        JpegUserData {
+            input_stream: false,
+            offset: 0,
+            output: Vec::new(),
+            input: &[],
+        }
+    }
 }
 
 pub unsafe extern "C" fn jpeg_opj_stream_read_fn(
@@ -29,11 +40,11 @@ pub unsafe extern "C" fn jpeg_opj_stream_read_fn(
     p_nb_bytes: usize,
     p_user_data: *mut c_void,
 ) -> usize {
-    let userdata = p_user_data as *mut JpegUserData;
-    assert!((*userdata).input_stream);
+    let userdata = &mut *(p_user_data as *mut JpegUserData);
+    assert!(userdata.input_stream);
 
-    let n_imgsize = (*userdata).input.len();
-    let n_byteleft = n_imgsize - (*userdata).offset;
+    let n_imgsize = userdata.input.len();
+    let n_byteleft = n_imgsize - userdata.offset;
 
     let mut n_read = p_nb_bytes;
 
@@ -41,17 +52,17 @@ pub unsafe extern "C" fn jpeg_opj_stream_read_fn(
         n_read = n_byteleft;
     }
 
-    if (*userdata).input.is_empty() || p_buffer.is_null() || n_read == 0 || n_byteleft == 0 {
-        // TODO: The original returned -1 here,
-        // but for some reason our signature is usize...
-        return 0;
+    // OpenJPEG takes (OPJ_SIZE_T)-1 as the end of the stream, and keeps asking for more
+    // data from a stream that only ever returns nothing
+    if userdata.input.is_empty() || p_buffer.is_null() || n_read == 0 || n_byteleft == 0 {
+        return usize::MAX;
     }
 
     let target = slice::from_raw_parts_mut(p_buffer as *mut u8, n_read);
-    let offset = (*userdata).offset;
-    target.copy_from_slice(&(*userdata).input[offset..offset + n_read]);
+    let offset = userdata.offset;
+    target.copy_from_slice(&userdata.input[offset..offset + n_read]);
 
-    (*userdata).offset += n_read;
+    userdata.offset += n_read;
 
     n_read
 }
@@ -61,27 +72,52 @@ pub unsafe extern "C" fn jpeg_opj_stream_write_fn(
     p_nb_bytes: usize,
     p_user_data: *mut c_void,
 ) -> usize {
-    let userdata = p_user_data as *mut JpegUserData;
-    assert!(!(*userdata).input_stream);
+    let userdata = &mut *(p_user_data as *mut JpegUserData);
+    assert!(!userdata.input_stream);
 
-    let buffer = p_buffer as *mut u8;
+    let buffer = slice::from_raw_parts(p_buffer as *const u8, p_nb_bytes);
 
-    (*userdata)
-        .output
-        .reserve((*userdata).output.len() + p_nb_bytes);
-    (*userdata)
-        .output
-        .extend_from_slice(slice::from_raw_parts(buffer, p_nb_bytes));
+    // The encoder may seek back to rewrite markers, so write at the current offset
+    let end = userdata.offset + p_nb_bytes;
+    if userdata.output.len() < end {
+        userdata.output.resize(end, 0);
+    }
+    userdata.output[userdata.offset..end].copy_from_slice(buffer);
+    userdata.offset = end;
+
+    p_nb_bytes
+}
+
+#[cfg(feature = "jpeg2000-encoder")]
+pub unsafe extern "C" fn jpeg_opj_stream_output_skip_fn(p_nb_bytes: i64, p_user_data: *mut c_void) -> i64 {
+    let userdata = &mut *(p_user_data as *mut JpegUserData);
+    assert!(!userdata.input_stream);
 
+    userdata.offset += p_nb_bytes as usize;
+    if userdata.output.len() < userdata.offset {
+        userdata.output.resize(userdata.offset, 0);
+    }
     p_nb_bytes
 }
 
+#[cfg(feature = "jpeg2000-encoder")]
+pub unsafe extern "C" fn jpeg_opj_stream_output_seek_fn(p_nb_bytes: i64, p_user_data: *mut c_void) -> i32 {
+    let userdata = &mut *(p_user_data as *mut JpegUserData);
+    assert!(!userdata.input_stream);
+
+    userdata.offset = p_nb_bytes as usize;
+    if userdata.output.len() < userdata.offset {
+        userdata.output.resize(userdata.offset, 0);
+    }
+    1
+}
+
 pub unsafe extern "C" fn jpeg_opj_stream_skip_fn(p_nb_bytes: i64, p_user_data: *mut c_void) -> i64 {
-    let userdata = p_user_data as *mut JpegUserData;
-    assert!((*userdata).input_stream);
+    let userdata = &mut *(p_user_data as *mut JpegUserData);
+    assert!(userdata.input_stream);
 
-    let n_imgsize = (*userdata).input.len();
-    let n_byteleft = (n_imgsize - (*userdata).offset) as i64;
+    let n_imgsize = userdata.input.len();
+    let n_byteleft = (n_imgsize - userdata.offset) as i64;
 
     let mut n_skip = p_nb_bytes;
 
@@ -89,26 +125,26 @@ pub unsafe extern "C" fn jpeg_opj_stream_skip_fn(p_nb_bytes: i64, p_user_data: *
         n_skip = n_byteleft;
     }
 
-    (*userdata).offset += n_skip as usize;
-    (*userdata).offset as i64
+    userdata.offset += n_skip as usize;
+    userdata.offset as i64
 }
 
 pub unsafe extern "C" fn jpeg_opj_stream_seek_fn(p_nb_bytes: i64, p_user_data: *mut c_void) -> i32 {
-    let userdata = p_user_data as *mut JpegUserData;
-    assert!((*userdata).input_stream);
+    let userdata = &mut *(p_user_data as *mut JpegUserData);
+    assert!(userdata.input_stream);
 
-    let n_imgsize = (*userdata).input.len();
+    let n_imgsize = userdata.input.len();
     let n_seek = p_nb_bytes as usize;
 
     if n_seek > n_imgsize {
         0
     } else {
-        (*userdata).offset = n_seek;
+        userdata.offset = n_seek;
         1
     }
 }
 
-pub fn extract_jpeg_data(raw_data: &Vec<u8>) -> Result<Vec<i32>, String> {
+pub fn extract_jpeg_data(raw_data: &[u8]) -> Result<Vec<i32>, GribError> {
     let mut output_data: Vec<i32>;
 
     unsafe {
@@ -151,13 +187,13 @@ pub fn extract_jpeg_data(raw_data: &Vec<u8>) -> Result<Vec<i32>, String> {
         if openjpeg_sys::opj_read_header(stream, dinfo, &mut image) != 1 {
             openjpeg_sys::opj_destroy_codec(dinfo);
             openjpeg_sys::opj_image_destroy(image);
-            return Err("Failed to decode JPEG byte stream header".into());
+            return Err(GribError::InvalidData("Failed to decode JPEG byte stream header".into()));
         }
 
         if openjpeg_sys::opj_decode(dinfo, stream, image) != 1 {
             openjpeg_sys::opj_destroy_codec(dinfo);
             openjpeg_sys::opj_image_destroy(image);
-            return Err("Failed to decode JPEG byte stream".into());
+            return Err(GribError::InvalidData("Failed to decode JPEG byte stream".into()));
         }
 
         // Do things to the data
@@ -178,9 +214,95 @@ pub fn extract_jpeg_data(raw_data: &Vec<u8>) -> Result<Vec<i32>, String> {
         openjpeg_sys::opj_image_destroy(image);
     }
 
-    if output_data.len() == 0  {
-        Err("Unknown failure extracting JPEG data".into())
+    if output_data.is_empty() {
+        Err(GribError::InvalidData("Unknown failure extracting JPEG data".into()))
     } else {
         Ok(output_data)
     }
-}
\ No newline at end of file
+}
+// https://github.com/ecmwf/eccodes/blob/develop/src/grib_openjpeg_encoding.c
+#[cfg(feature = "jpeg2000-encoder")]
+pub fn encode_jpeg_data(values: &[u32], width: usize, height: usize, bit_count: u8) -> Result<Vec<u8>, GribError> {
+    if values.len() != width * height {
+        return Err(GribError::Encoding("JPEG image dimensions do not match the number of values".into()));
+    }
+
+    let output;
+
+    unsafe {
+        let mut parameters: openjpeg_sys::opj_cparameters_t = std::mem::zeroed();
+        openjpeg_sys::opj_set_default_encoder_parameters(&mut parameters);
+
+        // Lossless, with as many resolutions as the image dimensions allow
+        parameters.tcp_numlayers = 1;
+        parameters.cp_disto_alloc = 1;
+        parameters.tcp_rates[0] = 0.0;
+        parameters.numresolution = 1;
+        while parameters.numresolution < 6
+            && (width >> parameters.numresolution) > 0
+            && (height >> parameters.numresolution) > 0
+        {
+            parameters.numresolution += 1;
+        }
+
+        let mut component = openjpeg_sys::opj_image_cmptparm_t {
+            dx: 1,
+            dy: 1,
+            w: width as u32,
+            h: height as u32,
+            x0: 0,
+            y0: 0,
+            prec: bit_count as u32,
+            bpp: bit_count as u32,
+            sgnd: 0,
+        };
+
+        let image = openjpeg_sys::opj_image_create(1, &mut component, openjpeg_sys::COLOR_SPACE::OPJ_CLRSPC_GRAY);
+        if image.is_null() {
+            return Err(GribError::Encoding("Failed to create JPEG image".into()));
+        }
+
+        (*image).x0 = 0;
+        (*image).y0 = 0;
+        (*image).x1 = width as u32;
+        (*image).y1 = height as u32;
+
+        let comp = (*image).comps.offset(0);
+        let image_data = slice::from_raw_parts_mut((*comp).data, values.len());
+        for (pixel, value) in image_data.iter_mut().zip(values.iter()) {
+            *pixel = *value as i32;
+        }
+
+        let cinfo = openjpeg_sys::opj_create_compress(openjpeg_sys::CODEC_FORMAT::OPJ_CODEC_J2K);
+        if openjpeg_sys::opj_setup_encoder(cinfo, &mut parameters, image) != 1 {
+            openjpeg_sys::opj_destroy_codec(cinfo);
+            openjpeg_sys::opj_image_destroy(image);
+            return Err(GribError::Encoding("Failed to setup the JPEG encoder".into()));
+        }
+
+        let mut userdata = JpegUserData::new_output();
+        let stream = openjpeg_sys::opj_stream_default_create(0);
+        openjpeg_sys::opj_stream_set_write_function(stream, Some(jpeg_opj_stream_write_fn));
+        openjpeg_sys::opj_stream_set_skip_function(stream, Some(jpeg_opj_stream_output_skip_fn));
+        openjpeg_sys::opj_stream_set_seek_function(stream, Some(jpeg_opj_stream_output_seek_fn));
+
+        let userdata_ptr: *mut JpegUserData = &mut userdata;
+        openjpeg_sys::opj_stream_set_user_data(stream, userdata_ptr as *mut c_void, None);
+
+        let success = openjpeg_sys::opj_start_compress(cinfo, image, stream) == 1
+            && openjpeg_sys::opj_encode(cinfo, stream) == 1
+            && openjpeg_sys::opj_end_compress(cinfo, stream) == 1;
+
+        openjpeg_sys::opj_stream_destroy(stream);
+        openjpeg_sys::opj_destroy_codec(cinfo);
+        openjpeg_sys::opj_image_destroy(image);
+
+        if !success {
+            return Err(GribError::Encoding("Failed to encode JPEG byte stream".into()));
+        }
+
+        output = userdata.output;
+    }
+
+    Ok(output)
+}
@@ -1,5 +1,14 @@
+#[allow(clippy::module_inception)]
 pub mod utils;
+pub mod bit_reader;
 pub mod jpeg;
+#[cfg(feature = "png")]
+pub mod png;
 
 pub use utils::*;
-pub use jpeg::extract_jpeg_data;
\ No newline at end of file
+pub use bit_reader::BitReader;
+pub use jpeg::extract_jpeg_data;
+#[cfg(feature = "jpeg2000-encoder")]
+pub use jpeg::encode_jpeg_data;
+#[cfg(feature = "png")]
+pub use self::png::{encode_png_data, extract_png_data, png_bit_depth};
@@ -0,0 +1,95 @@
+use crate::error::GribError;
+use super::utils::pack_bits;
+use std::io::Cursor;
+
+// PNG only supports a handful of sample sizes, 24 and 32 bit samples are stored as RGB and RGBA
+pub fn png_bit_depth(bit_count: u8) -> u8 {
+    match bit_count {
+        0..=1 => 1,
+        2 => 2,
+        3..=4 => 4,
+        5..=8 => 8,
+        9..=16 => 16,
+        17..=24 => 24,
+        _ => 32,
+    }
+}
+
+fn png_format(depth: u8) -> Result<(png::ColorType, png::BitDepth), GribError> {
+    match depth {
+        1 => Ok((png::ColorType::Grayscale, png::BitDepth::One)),
+        2 => Ok((png::ColorType::Grayscale, png::BitDepth::Two)),
+        4 => Ok((png::ColorType::Grayscale, png::BitDepth::Four)),
+        8 => Ok((png::ColorType::Grayscale, png::BitDepth::Eight)),
+        16 => Ok((png::ColorType::Grayscale, png::BitDepth::Sixteen)),
+        24 => Ok((png::ColorType::Rgb, png::BitDepth::Eight)),
+        32 => Ok((png::ColorType::Rgba, png::BitDepth::Eight)),
+        _ => Err(GribError::Encoding(format!("Invalid PNG bit depth of {}", depth))),
+    }
+}
+
+pub fn encode_png_data(values: &[u32], width: usize, height: usize, depth: u8) -> Result<Vec<u8>, GribError> {
+    if values.len() != width * height {
+        return Err(GribError::Encoding("PNG image dimensions do not match the number of values".into()));
+    }
+
+    let (color_type, bit_depth) = png_format(depth)?;
+
+    // Rows are padded to a whole number of bytes
+    let image_data = values
+        .chunks(width)
+        .flat_map(|row| pack_bits(row, depth as usize))
+        .collect::<Vec<u8>>();
+
+    let mut output = Vec::new();
+    {
+        let mut encoder = png::Encoder::new(&mut output, width as u32, height as u32);
+        encoder.set_color(color_type);
+        encoder.set_depth(bit_depth);
+
+        let mut writer = encoder.write_header().map_err(|e| GribError::Encoding(e.to_string()))?;
+        writer.write_image_data(&image_data).map_err(|e| GribError::Encoding(e.to_string()))?;
+    }
+
+    Ok(output)
+}
+
+pub fn extract_png_data(raw_data: &[u8]) -> Result<Vec<u32>, GribError> {
+    let mut decoder = png::Decoder::new(Cursor::new(raw_data));
+    decoder.set_transformations(png::Transformations::IDENTITY);
+
+    let mut reader = decoder.read_info().map_err(|e| GribError::InvalidData(e.to_string()))?;
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buffer).map_err(|e| GribError::InvalidData(e.to_string()))?;
+
+    let depth = info.bit_depth as usize * info.color_type.samples();
+    let width = info.width as usize;
+
+    let mut values = Vec::with_capacity(width * info.height as usize);
+    for row in buffer.chunks(info.line_size).take(info.height as usize) {
+        for i in 0..width {
+            let start = i * depth;
+            let value = (start..start + depth).fold(0u32, |acc, bit| {
+                (acc << 1) | ((row[bit / 8] >> (7 - bit % 8)) & 1) as u32
+            });
+            values.push(value);
+        }
+    }
+
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn png_round_trip() {
+        for depth in [1, 2, 4, 8, 16, 24, 32] {
+            let max = if depth == 32 { u32::MAX } else { (1u32 << depth) - 1 };
+            let values = (0..15u32).map(|v| v.wrapping_mul(2654435761) % max.max(1)).collect::<Vec<u32>>();
+            let encoded = encode_png_data(&values, 5, 3, depth).unwrap();
+            assert_eq!(extract_png_data(&encoded).unwrap(), values);
+        }
+    }
+}
@@ -1,4 +1,3 @@
-use std::convert::From;
 use std::vec::Vec;
 
 pub fn read_u16_from_bytes(data: &[u8], offset: usize) -> Option<u16> {
@@ -36,9 +35,14 @@ pub fn read_i16_from_bytes(data: &[u8], offset: usize) -> Option<i16> {
         return None;
     }
 
-    let mut l: [u8; 2] = Default::default();
-    l.copy_from_slice(&data[offset..offset + 2]);
-    Some(i16::from_be_bytes(l))
+    let value = read_u16_from_bytes(data, offset)?;
+
+    // Scale factors are sign and magnitude as well
+    if value & 0x8000 == 0 {
+        Some(value as i16)
+    } else {
+        Some(-((value & 0x7fff) as i16))
+    }
 }
 
 pub fn read_f32_from_bytes(data: &[u8], offset: usize) -> Option<f32> {
@@ -51,6 +55,7 @@ pub fn read_f32_from_bytes(data: &[u8], offset: usize) -> Option<f32> {
     Some(f32::from_be_bytes(l))
 }
 
+#[allow(dead_code)]
 pub fn read_f64_from_bytes(data: &[u8], offset: usize) -> Option<f64> {
     if data.len() < offset + 8 {
         return None;
@@ -62,85 +67,55 @@ pub fn read_f64_from_bytes(data: &[u8], offset: usize) -> Option<f64> {
 }
 
 pub fn read_signed_from_bytes(data: &[u8], offset: usize) -> Option<i32> {
-    let mut bits = bit_array_from_bytes(&data[offset..offset + 4]);
+    let value = read_u32_from_bytes(data, offset)?;
 
-    let mut is_negative = false;
-    if bits[0] == 1 {
-        bits[0] = 0;
-        is_negative = true;
+    // GRIB stores signed values as sign and magnitude, not twos complement
+    if value & 0x8000_0000 == 0 {
+        Some(value as i32)
     } else {
-        return match read_u32_from_bytes(data, offset) {
-            Some(v) => Some(v as i32),
-            None => None,
-        };
+        Some(-((value & 0x7fff_ffff) as i32))
     }
+}
 
-    let data = bits_to_bytes(bits).unwrap();
-
-    let value = read_u32_from_bytes(&data[0..], 0);
-    if let Some(value) = value {
-        if is_negative {
-            Some(value as i32 * -1)
-        } else {
-            Some(value as i32)
-        }
+pub fn signed_to_bytes(value: i32) -> [u8; 4] {
+    let magnitude = value.unsigned_abs() & 0x7fff_ffff;
+    if value < 0 {
+        (magnitude | 0x8000_0000).to_be_bytes()
     } else {
-        None
+        magnitude.to_be_bytes()
     }
 }
 
-pub fn from_bits<T>(bits: &[u8]) -> Option<T>
-where
-    T: num::integer::Integer + From<u8>,
-{
-    if bits.len() != (std::mem::size_of::<T>() * 8) {
-        return None;
+pub fn signed_i16_to_bytes(value: i16) -> [u8; 2] {
+    let magnitude = value.unsigned_abs() & 0x7fff;
+    if value < 0 {
+        (magnitude | 0x8000).to_be_bytes()
+    } else {
+        magnitude.to_be_bytes()
     }
-
-    Some(
-        bits.iter()
-            .fold(T::from(0), |acc, &b| acc * T::from(2) + T::from(b)),
-    )
 }
 
-pub fn bit_array_from_bytes(data: &[u8]) -> Vec<u8> {
-    data.iter()
-        .map(|r| format!("{:08b}", r))
-        .flat_map(|s| {
-            s.chars()
-                .map(|c| c.to_digit(2).unwrap_or(0) as u8)
-                .collect::<Vec<u8>>()
-        })
-        .collect::<Vec<u8>>()
-}
-
-pub fn byte_to_bits(data: &u8) -> [u8; 8] {
-    let mut result = [0; 8];
-    let bit_string = format!("{:08b}", data);
-    for (i, b) in bit_string.char_indices() {
-        result[i] = b.to_digit(2).unwrap_or(0) as u8;
-    }
+pub fn pack_bits(values: &[u32], bit_count: usize) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity((values.len() * bit_count).div_ceil(8));
+    let mut buffer: u64 = 0;
+    let mut buffered_bits = 0;
 
-    result
-}
-
-pub fn positive_bit_count(data: &u8) -> u8 {
-    let bits = byte_to_bits(data);
-    bits.iter().sum()
-}
+    for value in values {
+        buffer = (buffer << bit_count) | (*value as u64 & ((1u64 << bit_count) - 1));
+        buffered_bits += bit_count;
 
-pub fn bits_to_bytes(bits: Vec<u8>) -> Option<Vec<u8>> {
-    if bits.len() % 8 != 0 {
-        println!("{}", bits.len());
-        return None;
+        while buffered_bits >= 8 {
+            buffered_bits -= 8;
+            bytes.push((buffer >> buffered_bits) as u8);
+        }
+        buffer &= (1u64 << buffered_bits) - 1;
     }
 
-    let mut bytes = Vec::new();
-    for i in (0..bits.len()).step_by(8) {
-        let value = bits[i..i + 8].iter().fold(0u8, |acc, &b| acc * 2 + b);
-        bytes.push(value);
+    if buffered_bits > 0 {
+        bytes.push((buffer << (8 - buffered_bits)) as u8);
     }
-    Some(bytes)
+
+    bytes
 }
 
 // https://github.com/erdc/grib_api/blob/master/src/grib_scaling.c
@@ -158,7 +133,7 @@ pub fn grib_power(s: i32, n: i32) -> f64 {
         ss -= 1;
     }
 
-    return divisor;
+    divisor
 }
 
 #[cfg(test)]
@@ -166,26 +141,18 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_byte_to_bits() {
-        let test_value: u8 = 255;
-        let result = byte_to_bits(&test_value);
-        for i in 0..8 {
-            assert_eq!(result[i], 1);
-        }
+    fn test_sign_magnitude() {
+        assert_eq!(read_i16_from_bytes(&signed_i16_to_bytes(-3), 0), Some(-3));
+        assert_eq!(read_i16_from_bytes(&[0x80, 0x01], 0), Some(-1));
+        assert_eq!(read_signed_from_bytes(&signed_to_bytes(-41_000_000), 0), Some(-41_000_000));
     }
 
     #[test]
-    fn test_positive_bit_count() {
-        let test_value: u8 = 255;
-        let result = positive_bit_count(&test_value);
-        assert_eq!(result, 8);
-
-        let test_value: u8 = 1;
-        let result = positive_bit_count(&test_value);
-        assert_eq!(result, 1);
-
-        let test_value: u8 = 20;
-        let result = positive_bit_count(&test_value);
-        assert_eq!(result, 2);
+    fn test_pack_bits() {
+        let packed = pack_bits(&[1, 2, 3], 4);
+        assert_eq!(packed, vec![0x12, 0x30]);
+
+        let packed = pack_bits(&[0x1ff, 0], 9);
+        assert_eq!(packed, vec![0xff, 0x80, 0x00]);
     }
 }
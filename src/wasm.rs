@@ -0,0 +1,102 @@
+use crate::error::GribError;
+use crate::idx::{forecast_description, level_description};
+use crate::message::Message;
+use wasm_bindgen::prelude::*;
+
+// A message copied out of the bytes handed over from JavaScript, which are only borrowed for the
+// length of the call. It is parsed again for every accessor, with the data decoded on demand.
+#[wasm_bindgen]
+pub struct GribMessage {
+    bytes: Vec<u8>,
+    offset: usize,
+}
+
+// Every message that can be read from the bytes, stopping at the first that is not valid
+#[wasm_bindgen(js_name = parseMessages)]
+pub fn parse_messages(data: &[u8]) -> Vec<GribMessage> {
+    Message::parse_all(data)
+        .iter()
+        .map(|message| GribMessage {
+            bytes: message.raw_bytes().to_vec(),
+            offset: message.offset(),
+        })
+        .collect()
+}
+
+impl GribMessage {
+    fn message(&self) -> Result<Message<'_>, GribError> {
+        Message::parse(&self.bytes, 0)
+    }
+}
+
+#[wasm_bindgen]
+impl GribMessage {
+    // Where the message starts in the bytes it was parsed from
+    #[wasm_bindgen(getter)]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.bytes.len()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> Result<String, JsError> {
+        Ok(self.message()?.metadata()?.variable_name)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn abbreviation(&self) -> Result<String, JsError> {
+        Ok(self.message()?.metadata()?.variable_abbreviation)
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn units(&self) -> Result<String, JsError> {
+        Ok(self.message()?.metadata()?.units)
+    }
+
+    // The level and forecast as a wgrib2 inventory writes them, such as "500 mb" and "6 hour fcst"
+    #[wasm_bindgen(getter)]
+    pub fn level(&self) -> Result<String, JsError> {
+        Ok(level_description(&self.message()?.product_template()?))
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn forecast(&self) -> Result<String, JsError> {
+        Ok(forecast_description(&self.message()?.product_template()?))
+    }
+
+    // Dates are RFC 3339 strings, which JavaScript's Date reads
+    #[wasm_bindgen(getter = referenceDate)]
+    pub fn reference_date(&self) -> Result<String, JsError> {
+        Ok(self.message()?.reference_date()?.to_rfc3339())
+    }
+
+    #[wasm_bindgen(getter = forecastDate)]
+    pub fn forecast_date(&self) -> Result<String, JsError> {
+        Ok(self.message()?.forecast_date()?.to_rfc3339())
+    }
+
+    // The number of rows and columns of the grid
+    #[wasm_bindgen(getter)]
+    pub fn shape(&self) -> Result<Vec<u32>, JsError> {
+        let (rows, columns) = self.message()?.shape()?;
+        Ok(vec![rows as u32, columns as u32])
+    }
+
+    // The values in the order they are stored, NaN where there is no data
+    pub fn data(&self) -> Result<Vec<f32>, JsError> {
+        Ok(self.message()?.data()?.into_iter().map(|value| value as f32).collect())
+    }
+
+    // The latitude and longitude of every value, in the order they are stored
+    pub fn latitudes(&self) -> Result<Vec<f64>, JsError> {
+        Ok(self.message()?.data_locations()?.into_iter().map(|(latitude, _)| latitude).collect())
+    }
+
+    pub fn longitudes(&self) -> Result<Vec<f64>, JsError> {
+        Ok(self.message()?.data_locations()?.into_iter().map(|(_, longitude)| longitude).collect())
+    }
+}
@@ -0,0 +1,41 @@
+use crate::error::GribError;
+use crate::message::Message;
+use crate::sections::grib_section::GribSection;
+use crate::sections::section::Section;
+
+// Resolves grid relative U and V winds into east and north components. The axes of Lambert
+// conformal and rotated latitude longitude grids turn away from the meridians across the grid,
+// so their raw components only make sense against the grid itself. Winds that are already earth
+// relative come back as they are.
+pub fn rotate_winds(u: &Message, v: &Message) -> Result<(Vec<f64>, Vec<f64>), GribError> {
+    if grid_section(u)? != grid_section(v)? {
+        return Err(GribError::InvalidData("The U and V components are not on the same grid".into()));
+    }
+
+    let (mut u_values, mut v_values) = (u.data()?, v.data()?);
+    let grid_template = u.grid_template()?;
+    if !grid_template.is_grid_relative() {
+        return Ok((u_values, v_values));
+    }
+
+    for ((u, v), angle) in u_values.iter_mut().zip(v_values.iter_mut()).zip(grid_template.grid_angles()) {
+        let (sin, cos) = angle.sin_cos();
+        let (grid_u, grid_v) = (*u, *v);
+        *u = grid_u * cos - grid_v * sin;
+        *v = grid_u * sin + grid_v * cos;
+    }
+
+    Ok((u_values, v_values))
+}
+
+// The bytes of the grid definition section, which match for messages on the same grid
+pub(crate) fn grid_section<'a>(message: &'a Message) -> Result<&'a [u8], GribError> {
+    let grid_definition = unwrap_or_return!(
+        message.sections.iter().find_map(|s| match s {
+            Section::GridDefinition(grid_definition) => Some(grid_definition),
+            _ => None,
+        }),
+        GribError::MissingSection(3)
+    );
+    Ok(grid_definition.data())
+}
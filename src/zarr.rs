@@ -0,0 +1,226 @@
+use crate::cube::{cf_units, hours_since, level_attributes, level_name, standard_name, Cube, CubeVariable};
+use crate::error::GribError;
+use crate::message::Message;
+use crate::templates::product::tables::FixedSurfaceTypes;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use std::io::Write;
+use std::path::Path;
+
+// The codec the data chunks of a written store are compressed with
+const ZLIB_CODECS: &str = r#""compressor":{"id":"zlib","level":4},"filters":null"#;
+
+// Writes the messages to a directory as a Zarr v2 store with consolidated metadata, laid out
+// like write_netcdf with CF attributes and xarray's dimension names. Each message is a chunk of
+// its own, so a chunk covers the whole grid at one time and level, and times and levels
+// without a message are left for readers to fill. Coordinates are written uncompressed.
+pub fn write_zarr<P: AsRef<Path>>(messages: &[Message], path: P) -> Result<(), GribError> {
+    let path = path.as_ref();
+    let cube = Cube::new(messages)?;
+    let store = StoreMetadata::new(&cube, |_| ZLIB_CODECS.to_string())?;
+
+    let write = |key: &str, contents: &[u8]| {
+        let file = path.join(key);
+        if let Some(directory) = file.parent() {
+            std::fs::create_dir_all(directory)?;
+        }
+        std::fs::write(&file, contents).map_err(|e| GribError::Io(format!("Failed to write {}: {}", file.display(), e)))
+    };
+
+    for (key, document) in &store.documents {
+        write(key, document.as_bytes())?;
+    }
+    write(".zmetadata", store.consolidated().as_bytes())?;
+    for (key, chunk) in &store.coordinates {
+        write(key, chunk)?;
+    }
+
+    for variable in &cube.variables {
+        for (key, message) in chunk_keys(&cube, variable) {
+            let values = cube.plane(message)?.into_iter().flat_map(|value| (value as f32).to_le_bytes()).collect::<Vec<_>>();
+            let mut encoder = ZlibEncoder::new(Vec::new(), Compression::new(4));
+            encoder.write_all(&values)?;
+            write(&key, &encoder.finish()?)?;
+        }
+    }
+
+    Ok(())
+}
+
+// The metadata documents of a store holding the cube, keyed by where they are in the store, and
+// the single chunk of every coordinate array as little endian bytes
+pub(crate) struct StoreMetadata {
+    pub documents: Vec<(String, String)>,
+    pub coordinates: Vec<(String, Vec<u8>)>,
+}
+
+impl StoreMetadata {
+    // The codecs give the compressor and filters of each data variable, as JSON members
+    pub fn new<F: Fn(&CubeVariable) -> String>(cube: &Cube, codecs: F) -> Result<StoreMetadata, GribError> {
+        let mut store = StoreMetadata {
+            documents: vec![
+                (".zgroup".into(), r#"{"zarr_format":2}"#.into()),
+                (".zattrs".into(), r#"{"Conventions":"CF-1.8","source":"GRIB2"}"#.into()),
+            ],
+            coordinates: Vec::new(),
+        };
+
+        let time_units = hours_since(&cube.epoch);
+        store.add_coordinate(
+            "time",
+            &["time"],
+            &[cube.times.len()],
+            &cube.hours(),
+            &[("standard_name", "time"), ("units", &time_units), ("calendar", "proleptic_gregorian"), ("axis", "T")],
+        );
+        if cube.single_reference {
+            store.add_coordinate(
+                "reference_time",
+                &[],
+                &[],
+                &[0.0],
+                &[("standard_name", "forecast_reference_time"), ("units", &time_units), ("calendar", "proleptic_gregorian")],
+            );
+        }
+
+        for (surface, levels) in &cube.levels {
+            let name = level_name(*surface);
+            let long_name = FixedSurfaceTypes::from(*surface).to_string();
+            let mut attributes = vec![("long_name", long_name.as_str()), ("axis", "Z")];
+            attributes.extend(level_attributes(*surface).iter().copied());
+            store.add_coordinate(&name, &[&name], &[levels.len()], levels, &attributes);
+        }
+
+        let (latitudes, longitudes) = cube.locations()?;
+        let (rows, columns) = cube.shape;
+        let latitude_attributes = [("standard_name", "latitude"), ("units", "degrees_north")];
+        let longitude_attributes = [("standard_name", "longitude"), ("units", "degrees_east")];
+        let horizontal = if cube.regular {
+            store.add_coordinate("latitude", &["latitude"], &[rows], &latitudes, &latitude_attributes);
+            store.add_coordinate("longitude", &["longitude"], &[columns], &longitudes, &longitude_attributes);
+            ["latitude", "longitude"]
+        } else {
+            store.add_coordinate("latitude", &["y", "x"], &[rows, columns], &latitudes, &latitude_attributes);
+            store.add_coordinate("longitude", &["y", "x"], &[rows, columns], &longitudes, &longitude_attributes);
+            ["y", "x"]
+        };
+
+        let mut coordinates = Vec::new();
+        if cube.single_reference {
+            coordinates.push("reference_time");
+        }
+        if !cube.regular {
+            coordinates.extend(["latitude", "longitude"]);
+        }
+        let coordinates = coordinates.join(" ");
+
+        for variable in &cube.variables {
+            let level_count = cube.level_count(variable);
+            let level_dimension = level_name(variable.surface);
+            let mut dimensions = vec!["time"];
+            dimensions.extend(level_count.map(|_| level_dimension.as_str()));
+            dimensions.extend(horizontal);
+
+            let mut shape = vec![cube.times.len()];
+            shape.extend(level_count);
+            shape.extend([rows, columns]);
+            let mut chunks = vec![1; shape.len() - 2];
+            chunks.extend([rows, columns]);
+
+            let parameter = &variable.parameter;
+            let units = cf_units(&parameter.unit);
+            let mut attributes = vec![("long_name", parameter.name.as_str()), ("units", units.as_str())];
+            attributes.extend(standard_name(&parameter.abbrev).map(|name| ("standard_name", name)));
+            if !coordinates.is_empty() {
+                attributes.push(("coordinates", &coordinates));
+            }
+
+            store.add_array(&variable.name, &shape, &chunks, "<f4", r#""NaN""#, &codecs(variable), &dimensions, &attributes);
+        }
+
+        Ok(store)
+    }
+
+    // The consolidated metadata readers open the store from without listing it
+    pub fn consolidated(&self) -> String {
+        let documents = self
+            .documents
+            .iter()
+            .map(|(key, document)| format!("{}:{}", json_string(key), document))
+            .collect::<Vec<_>>();
+        format!(r#"{{"metadata":{{{}}},"zarr_consolidated_format":1}}"#, documents.join(","))
+    }
+
+    // Coordinates are stored as doubles in a single uncompressed chunk
+    fn add_coordinate(&mut self, name: &str, dimensions: &[&str], shape: &[usize], values: &[f64], attributes: &[(&str, &str)]) {
+        let chunk_key = match shape.len() {
+            0 => "0".to_string(),
+            rank => vec!["0"; rank].join("."),
+        };
+
+        self.add_array(name, shape, shape, "<f8", "null", r#""compressor":null,"filters":null"#, dimensions, attributes);
+        self.coordinates
+            .push((format!("{}/{}", name, chunk_key), values.iter().flat_map(|value| value.to_le_bytes()).collect()));
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn add_array(
+        &mut self,
+        name: &str,
+        shape: &[usize],
+        chunks: &[usize],
+        dtype: &str,
+        fill_value: &str,
+        codecs: &str,
+        dimensions: &[&str],
+        attributes: &[(&str, &str)],
+    ) {
+        let list = |values: &[usize]| values.iter().map(usize::to_string).collect::<Vec<_>>().join(",");
+        self.documents.push((
+            format!("{}/.zarray", name),
+            format!(
+                r#"{{"zarr_format":2,"shape":[{}],"chunks":[{}],"dtype":"{}",{},"fill_value":{},"order":"C"}}"#,
+                list(shape),
+                list(chunks),
+                dtype,
+                codecs,
+                fill_value
+            ),
+        ));
+
+        let dimensions = dimensions.iter().map(|dimension| json_string(dimension)).collect::<Vec<_>>();
+        let mut members = vec![format!(r#""_ARRAY_DIMENSIONS":[{}]"#, dimensions.join(","))];
+        members.extend(attributes.iter().map(|(key, value)| format!("{}:{}", json_string(key), json_string(value))));
+        self.documents.push((format!("{}/.zattrs", name), format!("{{{}}}", members.join(","))));
+    }
+}
+
+// The key of the chunk each message of the variable is stored in
+pub(crate) fn chunk_keys<'c, 'm, 'a>(
+    cube: &'c Cube,
+    variable: &'c CubeVariable<'m, 'a>,
+) -> impl Iterator<Item = (String, &'m Message<'a>)> + 'c {
+    let leveled = cube.level_count(variable).is_some();
+    variable.fields.iter().map(move |((time_index, level_index), message)| {
+        let key = match leveled {
+            true => format!("{}/{}.{}.0.0", variable.name, time_index, level_index),
+            false => format!("{}/{}.0.0", variable.name, time_index),
+        };
+        (key, *message)
+    })
+}
+
+pub(crate) fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for character in value.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            character if (character as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", character as u32)),
+            character => escaped.push(character),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
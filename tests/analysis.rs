@@ -0,0 +1,272 @@
+extern crate gribberish;
+
+mod common;
+
+use chrono::{TimeZone, Utc};
+use common::{message_builder, reference_date};
+use gribberish::encoding::{LatLngGrid, MessageBuilder};
+use gribberish::location_index::InterpolationMethod;
+use gribberish::message::Message;
+use gribberish::contour::{feature_collection, isobands, isolines, Isoband};
+use gribberish::derive::wind_speed_direction;
+use gribberish::ensemble::{ensemble_statistics, exceedance_probability};
+use gribberish::polygon::{area_statistics, points_in_polygon, Polygon};
+use gribberish::series::{group_by_forecast_date, time_series, vertical_profile};
+use gribberish::sections::indicator::Discipline;
+use gribberish::templates::product::tables::{EnsembleForecastType, FixedSurfaceTypes};
+
+#[test]
+fn derive_wind_speed_and_direction() {
+    let grid = LatLngGrid {
+        start: (40.0, 288.0),
+        end: (40.0, 289.25),
+        shape: (1, 6),
+    };
+    let build = |values: Vec<f64>, forecast_hours: i64| {
+        message_builder(Discipline::Meteorological, 2, 2, forecast_hours, grid)
+            .data(values)
+            .build()
+            .expect("failed to build grib message")
+    };
+
+    // From the south, the west, the north, the east, calm, and missing
+    let u = build(vec![0.0, 3.0, 0.0, -4.0, 0.0, f64::NAN], 6);
+    let v = build(vec![5.0, 0.0, -2.0, 0.0, 0.0, 1.0], 6);
+    let (u_message, v_message) = (Message::parse(&u, 0).unwrap(), Message::parse(&v, 0).unwrap());
+
+    let (speed, direction) = wind_speed_direction(&u_message, &v_message).unwrap();
+    let expected = [(5.0, 180.0), (3.0, 270.0), (2.0, 0.0), (4.0, 90.0), (0.0, 0.0)];
+    for (index, (expected_speed, expected_direction)) in expected.iter().enumerate() {
+        assert!((speed[index] - expected_speed).abs() < 1e-3);
+        assert!((direction[index] - expected_direction).abs() < 1e-3);
+    }
+    assert!(speed[5].is_nan() && direction[5].is_nan());
+
+    // Components valid at different times do not make a wind
+    let later = build(vec![5.0, 0.0, -2.0, 0.0, 0.0, 1.0], 12);
+    assert!(wind_speed_direction(&u_message, &Message::parse(&later, 0).unwrap()).is_err());
+}
+
+#[test]
+fn extract_time_series_at_point() {
+    let grid = LatLngGrid {
+        start: (40.0, 288.0),
+        end: (41.0, 289.0),
+        shape: (2, 2),
+    };
+    let build = |category: u8, values: Vec<f64>, forecast_hours: i64| {
+        message_builder(Discipline::Meteorological, category, 0, forecast_hours, grid)
+            .data(values)
+            .build()
+            .expect("failed to build grib message")
+    };
+
+    let raw = [
+        build(0, vec![284.0, 286.0, 288.0, 290.0], 6),
+        build(0, vec![280.0, 282.0, 284.0, 286.0], 0),
+        build(0, vec![f64::NAN, f64::NAN, f64::NAN, 283.0], 3),
+    ];
+    let messages = raw.iter().map(|raw| Message::parse(raw, 0).unwrap()).collect::<Vec<_>>();
+
+    let series = time_series(&messages, &(40.5, 288.5), InterpolationMethod::Bilinear).unwrap();
+    let hours = series.iter().map(|(date, _)| (*date - reference_date()).num_hours()).collect::<Vec<_>>();
+    assert_eq!(hours, vec![0, 3, 6]);
+    assert!((series[0].1 - 283.0).abs() < 1e-3);
+    assert!((series[1].1 - 283.0).abs() < 1e-3);
+    assert!((series[2].1 - 287.0).abs() < 1e-3);
+
+    let nearest = time_series(&messages, &(40.1, 288.1), InterpolationMethod::Nearest).unwrap();
+    assert!((nearest[0].1 - 280.0).abs() < 1e-3);
+    assert!(nearest[1].1.is_nan());
+    assert!((nearest[2].1 - 284.0).abs() < 1e-3);
+
+    assert_eq!(time_series(&[], &(40.5, 288.5), InterpolationMethod::Nearest), Ok(Vec::new()));
+
+    // Moisture does not belong in a series of temperatures
+    let humidity = build(1, vec![50.0; 4], 9);
+    let mixed = [Message::parse(&raw[0], 0).unwrap(), Message::parse(&humidity, 0).unwrap()];
+    assert!(time_series(&mixed, &(40.5, 288.5), InterpolationMethod::Nearest).is_err());
+}
+
+#[test]
+fn extract_vertical_profile() {
+    let grid = LatLngGrid {
+        start: (40.0, 288.0),
+        end: (41.0, 289.0),
+        shape: (2, 2),
+    };
+    let build = |surface: FixedSurfaceTypes, level: f64, value: f64, forecast_hours: i64| {
+        message_builder(Discipline::Meteorological, 0, 0, forecast_hours, grid)
+            .fixed_surface(surface, level)
+            .data(vec![value, value + 1.0, value + 2.0, value + 3.0])
+            .build()
+            .expect("failed to build grib message")
+    };
+
+    let raw = [
+        build(FixedSurfaceTypes::IsobaricSurface, 50_000.0, 250.0, 0),
+        build(FixedSurfaceTypes::IsobaricSurface, 85_000.0, 275.0, 0),
+        build(FixedSurfaceTypes::SpecifiedHeightLevelAboveGround, 2.0, 290.0, 0),
+        build(FixedSurfaceTypes::IsobaricSurface, 70_000.0, 265.0, 6),
+        build(FixedSurfaceTypes::IsobaricSurface, 100_000.0, 285.0, 0),
+    ];
+    let messages = raw.iter().map(|raw| Message::parse(raw, 0).unwrap()).collect::<Vec<_>>();
+
+    let groups = group_by_forecast_date(&messages).unwrap();
+    assert_eq!(groups.values().map(Vec::len).collect::<Vec<_>>(), vec![4, 1]);
+    let analysis = &groups[&reference_date()];
+
+    // The height above ground is not a pressure level
+    let profile = vertical_profile(analysis, &(40.5, 288.5), InterpolationMethod::Bilinear).unwrap();
+    let levels = profile.iter().map(|(level, _)| *level).collect::<Vec<_>>();
+    assert_eq!(levels, vec![1000.0, 850.0, 500.0]);
+    let values = [286.5, 276.5, 251.5];
+    for ((_, value), expected) in profile.iter().zip(values.iter()) {
+        assert!((value - expected).abs() < 1e-3);
+    }
+
+    let mixed_times = messages.iter().filter(|message| message.product_template().unwrap().pressure_hpa().is_some()).collect::<Vec<_>>();
+    assert!(vertical_profile(&mixed_times, &(40.5, 288.5), InterpolationMethod::Nearest).is_err());
+    assert_eq!(vertical_profile(&[], &(40.5, 288.5), InterpolationMethod::Nearest), Ok(Vec::new()));
+}
+
+#[test]
+fn aggregate_over_polygon() {
+    let values = vec![1.0, 1.0, 1.0, 2.0, 2.0, 2.0, 3.0, 3.0, f64::NAN];
+    let raw = MessageBuilder::new(Discipline::Meteorological, 1, 8)
+        .reference_date(Utc.with_ymd_and_hms(2021, 3, 4, 12, 0, 0).unwrap())
+        .grid(LatLngGrid {
+            start: (0.0, 10.0),
+            end: (60.0, 30.0),
+            shape: (3, 3),
+        })
+        .data(values)
+        .build()
+        .expect("failed to build grib message");
+    let message = Message::parse(&raw, 0).unwrap();
+
+    // The eastern two columns
+    let polygon = Polygon::new(vec![(-10.0, 15.0), (-10.0, 35.0), (70.0, 35.0), (70.0, 15.0)]);
+    assert_eq!(points_in_polygon(&message, &polygon), Ok(vec![1, 2, 4, 5, 7, 8]));
+
+    // Rows nearer the pole count for less, and the missing point not at all
+    let statistics = area_statistics(&message, &polygon).unwrap().unwrap();
+    let row_area = |south: f64, north: f64| north.to_radians().sin() - south.to_radians().sin();
+    let areas = [row_area(-15.0, 15.0), row_area(15.0, 45.0), row_area(45.0, 75.0)];
+    let expected = (areas[0] * 2.0 + areas[1] * 4.0 + areas[2] * 3.0) / (areas[0] * 2.0 + areas[1] * 2.0 + areas[2]);
+    assert_eq!(statistics.count, 5);
+    assert!((statistics.mean - expected).abs() < 1e-3);
+    assert_eq!((statistics.min, statistics.max), (1.0, 3.0));
+    assert!(statistics.area > 0.0);
+
+    let elsewhere = Polygon::new(vec![(-60.0, -60.0), (-60.0, -50.0), (-50.0, -50.0)]);
+    assert_eq!(area_statistics(&message, &elsewhere), Ok(None));
+
+    #[cfg(feature = "geo-types")]
+    {
+        let polygon = geo_types::Polygon::new(
+            geo_types::LineString::from(vec![(15.0, -10.0), (35.0, -10.0), (35.0, 70.0), (15.0, 70.0)]),
+            Vec::new(),
+        );
+        assert_eq!(points_in_polygon(&message, &Polygon::from(polygon)), Ok(vec![1, 2, 4, 5, 7, 8]));
+    }
+}
+
+#[test]
+fn combine_ensemble_members() {
+    let build = |member: u8, values: Vec<f64>, level: f64, end: (f64, f64)| {
+        let grid = LatLngGrid {
+            start: (40.0, 288.0),
+            end,
+            shape: (1, 3),
+        };
+        message_builder(Discipline::Meteorological, 0, 0, 24, grid)
+            .fixed_surface(FixedSurfaceTypes::IsobaricSurface, level)
+            .ensemble_member(EnsembleForecastType::PositivelyPerturbed, member, 3)
+            .data(values)
+            .build()
+            .expect("failed to build grib message")
+    };
+
+    let raw = [
+        build(1, vec![270.0, 280.0, f64::NAN], 85_000.0, (40.0, 289.0)),
+        build(2, vec![272.0, 284.0, f64::NAN], 85_000.0, (40.0, 289.0)),
+        build(3, vec![274.0, 290.0, 300.0], 85_000.0, (40.0, 289.0)),
+    ];
+    let members = raw.iter().map(|raw| Message::parse(raw, 0).unwrap()).collect::<Vec<_>>();
+
+    let statistics = ensemble_statistics(&members).unwrap();
+    assert_eq!(statistics.member_count, 3);
+    let expected = [(272.0, (8.0f64 / 3.0).sqrt()), (284.667, 4.110), (300.0, 0.0)];
+    for (index, (mean, spread)) in expected.iter().enumerate() {
+        assert!((statistics.mean[index] - mean).abs() < 1e-2);
+        assert!((statistics.spread[index] - spread).abs() < 1e-2);
+    }
+
+    let probability = exceedance_probability(&members, 275.0).unwrap();
+    assert_eq!(probability.len(), 3);
+    assert!(probability[0].abs() < 1e-9);
+    assert!((probability[1] - 1.0).abs() < 1e-9);
+    assert!((probability[2] - 1.0).abs() < 1e-9);
+    let probability = exceedance_probability(&members, 282.0).unwrap();
+    assert!((probability[1] - 2.0 / 3.0).abs() < 1e-9);
+
+    // Members of another level or grid do not belong in the ensemble
+    for other in [
+        build(4, vec![270.0, 280.0, 290.0], 50_000.0, (40.0, 289.0)),
+        build(4, vec![270.0, 280.0, 290.0], 85_000.0, (40.0, 290.0)),
+    ] {
+        let mixed = [Message::parse(&raw[0], 0).unwrap(), Message::parse(&other, 0).unwrap()];
+        assert!(ensemble_statistics(&mixed).is_err());
+        assert!(exceedance_probability(&mixed, 275.0).is_err());
+    }
+    assert!(ensemble_statistics(&[]).is_err());
+}
+
+#[test]
+fn trace_contours() {
+    // A cone peaking at 4 in the middle of the grid, falling by one for each step along the axes
+    let values = (0..25).map(|k| 4.0 - ((k % 5) as f64 - 2.0).abs() - ((k / 5) as f64 - 2.0).abs()).collect::<Vec<_>>();
+    let raw = MessageBuilder::new(Discipline::Meteorological, 3, 0)
+        .reference_date(Utc.with_ymd_and_hms(2021, 3, 4, 12, 0, 0).unwrap())
+        .grid(LatLngGrid {
+            start: (40.0, 288.0),
+            end: (42.0, 290.0),
+            shape: (5, 5),
+        })
+        .data(values)
+        .build()
+        .expect("failed to build grib message");
+    let message = Message::parse(&raw, 0).unwrap();
+    let steps = |(latitude, longitude): (f64, f64)| ((latitude - 41.0).abs() + (longitude - 289.0).abs()) / 0.5;
+
+    let lines = isolines(&message, &[2.5, 10.0]).unwrap();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0].lines.len(), 1);
+    assert!(lines[1].lines.is_empty());
+    let ring = &lines[0].lines[0];
+    assert_eq!(ring.first(), ring.last());
+    assert!(ring.iter().all(|vertex| (steps(*vertex) - 1.5).abs() < 1e-6));
+
+    // The middle band rings the peak, so it comes back with a hole
+    let bands = isobands(&message, &[5.0, 1.0, 3.0]).unwrap();
+    assert_eq!(bands.iter().map(|band| (band.lower, band.upper)).collect::<Vec<_>>(), vec![(1.0, 3.0), (3.0, 5.0)]);
+    assert_eq!(bands[0].polygons.len(), 1);
+    let ring_band = &bands[0].polygons[0];
+    assert_eq!(ring_band.interiors.len(), 1);
+    // The outline follows the edge of the grid between the corners, where the field drops below 1
+    assert!(ring_band.exterior.iter().all(|vertex| steps(*vertex) < 3.0 + 1e-6));
+    assert!(ring_band.exterior.iter().any(|vertex| (steps(*vertex) - 2.0).abs() < 1e-6));
+    assert!(ring_band.interiors[0].iter().all(|vertex| (steps(*vertex) - 1.0).abs() < 1e-6));
+    assert!(ring_band.contains(41.0, 289.75) && !ring_band.contains(41.0, 289.0));
+
+    assert_eq!(bands[1].polygons.len(), 1);
+    assert!(bands[1].polygons[0].interiors.is_empty());
+    assert!(bands[1].polygons[0].contains(41.0, 289.0));
+
+    let geojson = feature_collection(bands.iter().map(Isoband::to_geojson));
+    assert!(geojson.starts_with(r#"{"type":"FeatureCollection","features":[{"type":"Feature","properties":{"lower":1,"upper":3}"#));
+    assert_eq!(geojson.matches(r#""type":"MultiPolygon""#).count(), 2);
+    let feature = lines[0].to_geojson();
+    assert!(feature.contains(r#""properties":{"level":2.5},"geometry":{"type":"MultiLineString","coordinates":[[["#));
+}
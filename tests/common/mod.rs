@@ -0,0 +1,77 @@
+// Builds the messages the integration tests read back. Each test binary uses its own share of
+// the helpers.
+#![allow(dead_code)]
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use gribberish::encoding::{LatLngGrid, MessageBuilder};
+use gribberish::sections::indicator::Discipline;
+use gribberish::templates::product::tables::FixedSurfaceTypes;
+
+// The reference time of every built message
+pub fn reference_date() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2021, 3, 4, 12, 0, 0).unwrap()
+}
+
+// A message for the parameter on the grid, valid forecast_hours after the reference time, for
+// tests that set more of it before building
+pub fn message_builder(discipline: Discipline, category: u8, parameter: u8, forecast_hours: i64, grid: LatLngGrid) -> MessageBuilder {
+    MessageBuilder::new(discipline, category, parameter)
+        .reference_date(reference_date())
+        .forecast_date(reference_date() + Duration::hours(forecast_hours))
+        .grid(grid)
+}
+
+// A temperature field on the isobaric level, given in pascals
+pub fn build_message(level: f64, forecast_hours: i64, grid: LatLngGrid, data: Vec<f64>) -> Vec<u8> {
+    message_builder(Discipline::Meteorological, 0, 0, forecast_hours, grid)
+        .fixed_surface(FixedSurfaceTypes::IsobaricSurface, level)
+        .data(data)
+        .build()
+        .expect("failed to build grib message")
+}
+
+// Swaps the grid definition section of a built message for another one
+pub fn with_grid_section(raw: &[u8], section: &[u8]) -> Vec<u8> {
+    let grid_definition = gribberish::reader::scan(raw)[0].section(3).unwrap().clone();
+    let start = grid_definition.offset as usize;
+    let mut message = raw.to_vec();
+    message.splice(start..start + grid_definition.length as usize, section.iter().cloned());
+
+    let total_length = message.len() as u64;
+    message[8..16].copy_from_slice(&total_length.to_be_bytes());
+    message
+}
+
+pub fn grid_section(template_number: u16, length: usize, point_count: u32) -> Vec<u8> {
+    let mut section = vec![0u8; length];
+    section[0..4].copy_from_slice(&(length as u32).to_be_bytes());
+    section[4] = 3;
+    section[6..10].copy_from_slice(&point_count.to_be_bytes());
+    section[12..14].copy_from_slice(&template_number.to_be_bytes());
+    section[14] = 6;
+    section
+}
+
+pub fn micro_degrees(value: f64) -> [u8; 4] {
+    let magnitude = (value.abs() * 1e6).round() as u32;
+    (if value < 0.0 { magnitude | 0x8000_0000 } else { magnitude }).to_be_bytes()
+}
+
+// A 4 by 3 Lambert conformal grid tangent at 25N, like the NCEP grids over North America
+pub fn lambert_grid_section(flags: u8) -> Vec<u8> {
+    let mut section = grid_section(30, 81, 12);
+    section[30..34].copy_from_slice(&4u32.to_be_bytes());
+    section[34..38].copy_from_slice(&3u32.to_be_bytes());
+    section[38..42].copy_from_slice(&micro_degrees(21.138));
+    section[42..46].copy_from_slice(&micro_degrees(237.28));
+    section[46] = flags;
+    section[47..51].copy_from_slice(&micro_degrees(25.0));
+    section[51..55].copy_from_slice(&micro_degrees(265.0));
+    section[55..59].copy_from_slice(&40_635_000u32.to_be_bytes());
+    section[59..63].copy_from_slice(&40_635_000u32.to_be_bytes());
+    section[64] = 0x40;
+    section[65..69].copy_from_slice(&micro_degrees(25.0));
+    section[69..73].copy_from_slice(&micro_degrees(25.0));
+    section[73..77].copy_from_slice(&micro_degrees(-90.0));
+    section
+}
@@ -0,0 +1,416 @@
+extern crate gribberish;
+
+mod common;
+
+use chrono::{TimeZone, Utc};
+use common::message_builder;
+use gribberish::encoding::{LatLngGrid, MessageBuilder};
+use gribberish::message::Message;
+use gribberish::export::{to_coverage_json, to_geojson, GeoJsonGeometry};
+use gribberish::sections::indicator::Discipline;
+use gribberish::templates::product::tables::FixedSurfaceTypes;
+
+#[cfg(feature = "geotiff")]
+#[test]
+fn export_geotiff() {
+    use tiff::decoder::{Decoder, DecodingResult};
+    use tiff::tags::Tag;
+
+    let raw = MessageBuilder::new(Discipline::Meteorological, 0, 0)
+        .reference_date(Utc.with_ymd_and_hms(2021, 3, 4, 12, 0, 0).unwrap())
+        .grid(LatLngGrid {
+            start: (40.0, 288.0),
+            end: (41.0, 290.0),
+            shape: (2, 3),
+        })
+        .data(vec![0.0, 1.0, 2.0, 3.0, 4.0, f64::NAN])
+        .build()
+        .expect("failed to build grib message");
+    let message = Message::parse(&raw, 0).unwrap();
+    let tiff = gribberish::geotiff::to_geotiff(&message).unwrap();
+
+    // The rows run north to south, so the last row of the grid comes first
+    let mut decoder = Decoder::new(std::io::Cursor::new(tiff)).unwrap();
+    assert_eq!(decoder.dimensions().unwrap(), (3, 2));
+    assert_eq!(decoder.get_tag_f64_vec(Tag::ModelPixelScaleTag).unwrap(), vec![1.0, 1.0, 0.0]);
+    assert_eq!(decoder.get_tag_f64_vec(Tag::ModelTiepointTag).unwrap(), vec![0.0, 0.0, 0.0, 287.5, 41.5, 0.0]);
+    assert_eq!(decoder.get_tag_f64_vec(Tag::GeoDoubleParamsTag).unwrap(), vec![6_371_229.0, 6_371_229.0]);
+    let keys = decoder.get_tag_u16_vec(Tag::GeoKeyDirectoryTag).unwrap();
+    assert_eq!(&keys[4..8], &[1024, 0, 1, 2]);
+    assert!(keys.chunks(4).any(|key| key == [2048, 0, 1, 32767]));
+    match decoder.read_image().unwrap() {
+        DecodingResult::F32(values) => {
+            assert_eq!((&values[..2], &values[3..]), (&[3.0, 4.0][..], &[0.0, 1.0, 2.0][..]));
+            assert!(values[2].is_nan());
+        }
+        _ => panic!("the raster is not 32 bit floats"),
+    }
+
+    // Lambert conformal grids are placed in metres on their projection
+    let raw = MessageBuilder::new(Discipline::Meteorological, 0, 0)
+        .reference_date(Utc.with_ymd_and_hms(2021, 3, 4, 12, 0, 0).unwrap())
+        .grid(LatLngGrid {
+            start: (40.0, 288.0),
+            end: (42.0, 291.0),
+            shape: (3, 4),
+        })
+        .data((0..12).map(|i| i as f64).collect())
+        .build()
+        .expect("failed to build grib message");
+    let lambert = common::with_grid_section(&raw, &common::lambert_grid_section(0x08));
+    let message = Message::parse(&lambert, 0).unwrap();
+    let mut decoder = Decoder::new(std::io::Cursor::new(gribberish::geotiff::to_geotiff(&message).unwrap())).unwrap();
+    assert_eq!(decoder.dimensions().unwrap(), (4, 3));
+    let scale = decoder.get_tag_f64_vec(Tag::ModelPixelScaleTag).unwrap();
+    assert!((scale[0] - 40_635.0).abs() < 1e-3 && (scale[1] - 40_635.0).abs() < 1e-3);
+    let keys = decoder.get_tag_u16_vec(Tag::GeoKeyDirectoryTag).unwrap();
+    assert_eq!(&keys[4..8], &[1024, 0, 1, 1]);
+    assert!(keys.chunks(4).any(|key| key == [3075, 0, 1, 8]));
+    match decoder.read_image().unwrap() {
+        DecodingResult::F32(values) => assert_eq!(&values[..4], &[8.0, 9.0, 10.0, 11.0]),
+        _ => panic!("the raster is not 32 bit floats"),
+    }
+}
+
+#[test]
+fn export_geojson() {
+    let raw = MessageBuilder::new(Discipline::Meteorological, 0, 0)
+        .reference_date(Utc.with_ymd_and_hms(2021, 3, 4, 12, 0, 0).unwrap())
+        .grid(LatLngGrid {
+            start: (40.0, 359.0),
+            end: (41.0, 361.0),
+            shape: (2, 3),
+        })
+        .data(vec![0.5, 1.0, 2.0, 3.0, f64::NAN, 5.0])
+        .build()
+        .expect("failed to build grib message");
+    let message = Message::parse(&raw, 0).unwrap();
+
+    let points: serde_json::Value = serde_json::from_str(&to_geojson(&message, GeoJsonGeometry::Points).unwrap()).unwrap();
+    let features = points["features"].as_array().unwrap();
+    assert_eq!(features.len(), 6);
+    assert_eq!(features[0]["geometry"], serde_json::json!({"type": "Point", "coordinates": [359, 40]}));
+    assert_eq!(features[0]["properties"]["value"], 0.5);
+    assert!(features[4]["properties"]["value"].is_null());
+
+    // The edge runs counter clockwise without jumping back across the meridian
+    let boundary: serde_json::Value = serde_json::from_str(&to_geojson(&message, GeoJsonGeometry::Boundary).unwrap()).unwrap();
+    let feature = &boundary["features"][0];
+    assert_eq!(feature["properties"], serde_json::json!({"parameter": "TMP", "valid_time": "2021-03-04T12:00:00+00:00"}));
+    assert_eq!(
+        feature["geometry"]["coordinates"],
+        serde_json::json!([[[359, 40], [360, 40], [361, 40], [361, 41], [360, 41], [359, 41], [359, 40]]])
+    );
+}
+
+#[test]
+fn export_coverage_json() {
+    let grid = LatLngGrid {
+        start: (41.0, 288.0),
+        end: (40.0, 289.0),
+        shape: (2, 2),
+    };
+    let build = |number: u8, level: f64, value: f64, forecast_hours: i64| {
+        message_builder(Discipline::Meteorological, 0, number, forecast_hours, grid)
+            .fixed_surface(FixedSurfaceTypes::IsobaricSurface, level)
+            .data(vec![value, value + 1.0, value + 2.0, value + 3.0])
+            .build()
+            .expect("failed to build grib message")
+    };
+    let raw = [build(0, 85_000.0, 275.0, 0), build(0, 50_000.0, 250.0, 0), build(0, 85_000.0, 277.0, 6)];
+    let messages = raw.iter().map(|raw| Message::parse(raw, 0).unwrap()).collect::<Vec<_>>();
+
+    let coverage: serde_json::Value = serde_json::from_str(&to_coverage_json(&messages).unwrap()).unwrap();
+    let domain = &coverage["domain"];
+    assert_eq!(domain["domainType"], "Grid");
+    assert_eq!(domain["axes"]["y"]["values"], serde_json::json!([41, 40]));
+    assert_eq!(domain["axes"]["z"]["values"], serde_json::json!([50000, 85000]));
+    assert_eq!(domain["axes"]["t"]["values"], serde_json::json!(["2021-03-04T12:00:00Z", "2021-03-04T18:00:00Z"]));
+    assert_eq!(domain["referencing"][1]["system"]["cs"]["csAxes"][0]["direction"], "down");
+    assert_eq!(coverage["parameters"]["TMP"]["unit"]["symbol"], "K");
+
+    // 500 hPa is not given at six hours
+    let range = &coverage["ranges"]["TMP"];
+    assert_eq!(range["axisNames"], serde_json::json!(["t", "z", "y", "x"]));
+    assert_eq!(range["shape"], serde_json::json!([2, 2, 2, 2]));
+    let values = range["values"].as_array().unwrap();
+    assert_eq!(values[..8], serde_json::json!([250, 251, 252, 253, 275, 276, 277, 278]).as_array().unwrap()[..]);
+    assert!(values[8..12].iter().all(|value| value.is_null()));
+
+    // A single message is a cube of one time and one level
+    let surface = message_builder(Discipline::Meteorological, 3, 1, 0, grid)
+        .data(vec![101_000.0; 4])
+        .build()
+        .expect("failed to build grib message");
+    let coverage: serde_json::Value = serde_json::from_str(&to_coverage_json(&[Message::parse(&surface, 0).unwrap()]).unwrap()).unwrap();
+    assert_eq!(coverage["ranges"]["PRMSL"]["shape"], serde_json::json!([1, 1, 2, 2]));
+    assert_eq!(coverage["domain"]["referencing"][1]["system"]["cs"]["csAxes"][0]["name"]["en"], "ground or water surface");
+
+    let other_parameter = build(2, 85_000.0, 0.0, 12);
+    let mixed = vec![Message::parse(&raw[0], 0).unwrap(), Message::parse(&other_parameter, 0).unwrap()];
+    assert!(to_coverage_json(&mixed).is_err());
+    assert!(to_coverage_json(&[]).is_err());
+}
+
+#[cfg(feature = "netcdf")]
+#[test]
+fn write_cf_netcdf() {
+    let grid = LatLngGrid {
+        start: (40.0, 288.0),
+        end: (41.0, 289.0),
+        shape: (2, 2),
+    };
+    let build = |number: u8, surface: FixedSurfaceTypes, level: f64, value: f64, forecast_hours: i64| {
+        message_builder(Discipline::Meteorological, if number == 0 { 0 } else { 2 }, number, forecast_hours, grid)
+            .fixed_surface(surface, level)
+            .data(vec![value, value + 1.0, value + 2.0, value + 3.0])
+            .build()
+            .expect("failed to build grib message")
+    };
+
+    // Temperatures on two pressure levels and above the ground, with one level missing later on
+    let raw = [
+        build(0, FixedSurfaceTypes::IsobaricSurface, 85_000.0, 275.0, 0),
+        build(0, FixedSurfaceTypes::IsobaricSurface, 50_000.0, 250.0, 0),
+        build(0, FixedSurfaceTypes::IsobaricSurface, 85_000.0, 277.0, 6),
+        build(0, FixedSurfaceTypes::SpecifiedHeightLevelAboveGround, 2.0, 290.0, 6),
+        build(2, FixedSurfaceTypes::IsobaricSurface, 85_000.0, 5.0, 0),
+    ];
+    let messages = raw.iter().map(|raw| Message::parse(raw, 0).unwrap()).collect::<Vec<_>>();
+
+    let path = std::env::temp_dir().join(format!("gribberish-{}.nc", std::process::id()));
+    gribberish::netcdf::write_netcdf(&messages, &path).unwrap();
+    let mut reader = netcdf3::FileReader::open(&path).unwrap();
+    let data_set = reader.data_set();
+    assert_eq!(data_set.get_global_attr_as_string("Conventions").as_deref(), Some("CF-1.8"));
+    assert_eq!(data_set.dim_size("time"), Some(2));
+    assert_eq!(data_set.dim_size("latitude"), Some(2));
+
+    let temperature = data_set.get_var("TMP_isobaric").unwrap();
+    assert_eq!(temperature.dim_names(), vec!["time", "isobaric", "latitude", "longitude"]);
+    assert_eq!(data_set.get_var_attr_as_string("TMP_isobaric", "standard_name").as_deref(), Some("air_temperature"));
+    assert_eq!(data_set.get_var_attr_as_string("TMP_isobaric", "coordinates").as_deref(), Some("reference_time"));
+    assert_eq!(data_set.get_var("TMP_height_above_ground").unwrap().dim_names()[1], "height_above_ground");
+    assert_eq!(data_set.get_var_attr_as_string("UGRD", "units").as_deref(), Some("m s-1"));
+    assert_eq!(data_set.get_var_attr_as_string("time", "units").as_deref(), Some("hours since 2021-03-04 12:00:00"));
+
+    let read_f64 = |reader: &mut netcdf3::FileReader, name: &str| reader.read_var(name).unwrap().get_f64_into().unwrap();
+    assert_eq!(read_f64(&mut reader, "time"), vec![0.0, 6.0]);
+    assert_eq!(read_f64(&mut reader, "isobaric"), vec![50_000.0, 85_000.0]);
+    assert_eq!(read_f64(&mut reader, "latitude"), vec![40.0, 41.0]);
+    assert_eq!(read_f64(&mut reader, "height_above_ground"), vec![2.0]);
+
+    // The pressure levels ascend, and 500 hPa is not given at six hours
+    let values = reader.read_var("TMP_isobaric").unwrap().get_f32_into().unwrap();
+    assert_eq!(values.len(), 16);
+    assert_eq!(&values[..8], &[250.0, 251.0, 252.0, 253.0, 275.0, 276.0, 277.0, 278.0]);
+    assert!(values[8..12].iter().all(|value| *value == netcdf3::NC_FILL_F32));
+    assert_eq!(&values[12..], &[277.0, 278.0, 279.0, 280.0]);
+    let _ = std::fs::remove_file(&path);
+
+    let other_grid = LatLngGrid {
+        start: (40.0, 288.0),
+        end: (42.0, 289.0),
+        shape: (3, 2),
+    };
+    let other_grid = message_builder(Discipline::Meteorological, 0, 0, 0, other_grid)
+        .data(vec![0.0; 6])
+        .build()
+        .expect("failed to build grib message");
+    let mixed = vec![Message::parse(&raw[0], 0).unwrap(), Message::parse(&other_grid, 0).unwrap()];
+    assert!(gribberish::netcdf::write_netcdf(&mixed, &path).is_err());
+    assert!(gribberish::netcdf::write_netcdf(&[], &path).is_err());
+}
+
+#[cfg(feature = "parquet")]
+#[test]
+fn write_parquet_archives() {
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+    use parquet::record::{Field, RowAccessor};
+
+    let grid = LatLngGrid {
+        start: (40.0, 288.0),
+        end: (41.0, 289.0),
+        shape: (2, 2),
+    };
+    let build = |values: Vec<f64>, forecast_hours: i64| {
+        message_builder(Discipline::Meteorological, 0, 0, forecast_hours, grid)
+            .fixed_surface(FixedSurfaceTypes::SpecifiedHeightLevelAboveGround, 2.0)
+            .data(values)
+            .build()
+            .expect("failed to build grib message")
+    };
+    let mut data = build(vec![280.0, 281.0, f64::NAN, 283.0], 0);
+    data.extend(build(vec![290.0; 4], 6));
+    let messages = Message::parse_all(&data);
+
+    let path = std::env::temp_dir().join(format!("gribberish-points-{}.parquet", std::process::id()));
+    gribberish::parquet::write_points_parquet(&messages, std::fs::File::create(&path).unwrap()).unwrap();
+    let reader = SerializedFileReader::new(std::fs::File::open(&path).unwrap()).unwrap();
+    assert_eq!(reader.metadata().num_row_groups(), 2);
+    let rows = reader.get_row_iter(None).unwrap().map(|row| row.unwrap()).collect::<Vec<_>>();
+    assert_eq!(rows.len(), 8);
+
+    // The missing point is a null, the first point of the second message is on the same spot
+    assert_eq!(rows[1].get_string(1).unwrap(), "TMP");
+    assert_eq!(rows[1].get_string(2).unwrap(), "2 m above ground");
+    assert_eq!((rows[1].get_double(5).unwrap(), rows[1].get_double(6).unwrap()), (40.0, 289.0));
+    assert_eq!(rows[1].get_double(7).unwrap(), 281.0);
+    assert!(matches!(rows[2].get_column_iter().nth(7), Some((_, Field::Null))));
+    assert_eq!(rows[4].get_int(0).unwrap(), 2);
+    assert_eq!(rows[4].get_timestamp_millis(4).unwrap(), (common::reference_date() + chrono::Duration::hours(6)).timestamp_millis());
+
+    gribberish::parquet::write_inventory_parquet(&messages, std::fs::File::create(&path).unwrap()).unwrap();
+    let reader = SerializedFileReader::new(std::fs::File::open(&path).unwrap()).unwrap();
+    let rows = reader.get_row_iter(None).unwrap().map(|row| row.unwrap()).collect::<Vec<_>>();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[1].get_long(1).unwrap(), messages[1].offset() as i64);
+    assert_eq!(rows[1].get_string(5).unwrap(), "temperature");
+    assert_eq!(rows[1].get_string(8).unwrap(), "6 hour fcst");
+    assert_eq!(rows[1].get_long(11).unwrap(), 4);
+    let _ = std::fs::remove_file(&path);
+}
+
+#[cfg(feature = "zarr")]
+#[test]
+fn write_zarr_store() {
+    use std::convert::TryInto;
+    use std::io::Read;
+
+    let grid = LatLngGrid {
+        start: (40.0, 288.0),
+        end: (41.0, 290.0),
+        shape: (2, 3),
+    };
+    let build = |surface: FixedSurfaceTypes, level: f64, value: f64, forecast_hours: i64| {
+        message_builder(Discipline::Meteorological, 0, 0, forecast_hours, grid)
+            .fixed_surface(surface, level)
+            .data((0..6).map(|i| value + i as f64).collect())
+            .build()
+            .expect("failed to build grib message")
+    };
+    let raw = [
+        build(FixedSurfaceTypes::IsobaricSurface, 85_000.0, 275.0, 0),
+        build(FixedSurfaceTypes::IsobaricSurface, 50_000.0, 250.0, 6),
+        build(FixedSurfaceTypes::SpecifiedHeightLevelAboveGround, 2.0, 290.0, 0),
+    ];
+    let messages = raw.iter().map(|raw| Message::parse(raw, 0).unwrap()).collect::<Vec<_>>();
+
+    let path = std::env::temp_dir().join(format!("gribberish-{}.zarr", std::process::id()));
+    gribberish::zarr::write_zarr(&messages, &path).unwrap();
+    let read_json = |key: &str| -> serde_json::Value { serde_json::from_slice(&std::fs::read(path.join(key)).unwrap()).unwrap() };
+
+    let consolidated = read_json(".zmetadata");
+    let metadata = &consolidated["metadata"];
+    assert_eq!(metadata[".zgroup"], serde_json::json!({"zarr_format": 2}));
+    assert_eq!(metadata["TMP_isobaric/.zarray"], read_json("TMP_isobaric/.zarray"));
+    assert_eq!(metadata["TMP_isobaric/.zarray"]["shape"], serde_json::json!([2, 2, 2, 3]));
+    assert_eq!(metadata["TMP_isobaric/.zarray"]["chunks"], serde_json::json!([1, 1, 2, 3]));
+    assert_eq!(metadata["TMP_isobaric/.zarray"]["fill_value"], "NaN");
+    assert_eq!(
+        metadata["TMP_isobaric/.zattrs"]["_ARRAY_DIMENSIONS"],
+        serde_json::json!(["time", "isobaric", "latitude", "longitude"])
+    );
+    assert_eq!(metadata["TMP_height_above_ground/.zattrs"]["standard_name"], "air_temperature");
+    assert_eq!(metadata["time/.zattrs"]["units"], "hours since 2021-03-04 12:00:00");
+
+    // Each message is a chunk, and times and levels without one have no chunk at all
+    let mut chunk = Vec::new();
+    flate2::read::ZlibDecoder::new(std::fs::File::open(path.join("TMP_isobaric/1.0.0.0")).unwrap())
+        .read_to_end(&mut chunk)
+        .unwrap();
+    let values = chunk.chunks(4).map(|bytes| f32::from_le_bytes(bytes.try_into().unwrap())).collect::<Vec<_>>();
+    assert_eq!(values, vec![250.0, 251.0, 252.0, 253.0, 254.0, 255.0]);
+    assert!(path.join("TMP_isobaric/0.1.0.0").exists());
+    assert!(!path.join("TMP_isobaric/0.0.0.0").exists());
+
+    let times = std::fs::read(path.join("time/0")).unwrap();
+    let times = times.chunks(8).map(|bytes| f64::from_le_bytes(bytes.try_into().unwrap())).collect::<Vec<_>>();
+    assert_eq!(times, vec![0.0, 6.0]);
+    let _ = std::fs::remove_dir_all(&path);
+}
+
+#[cfg(feature = "kerchunk")]
+#[test]
+fn kerchunk_reference_set() {
+    let grid = LatLngGrid {
+        start: (40.0, 288.0),
+        end: (41.0, 290.0),
+        shape: (2, 3),
+    };
+    let build = |level: f64, forecast_hours: i64| {
+        common::build_message(level, forecast_hours, grid, (0..6).map(|i| 250.0 + i as f64).collect())
+    };
+    let grib_data = [build(85_000.0, 0), build(50_000.0, 0), build(50_000.0, 6)].concat();
+    let messages = Message::parse_all(grib_data.as_slice());
+
+    let references = gribberish::kerchunk::kerchunk_references(&messages, "s3://bucket/file.grib2").unwrap();
+    let references: serde_json::Value = serde_json::from_str(&references).unwrap();
+    assert_eq!(references["version"], 1);
+    let refs = &references["refs"];
+
+    // Data chunks point at the whole message holding them
+    let last = &messages[2];
+    assert_eq!(
+        refs["TMP/1.0.0.0"],
+        serde_json::json!(["s3://bucket/file.grib2", last.offset(), last.len()])
+    );
+    assert_eq!(refs["TMP/0.1.0.0"][1], messages[0].offset());
+    assert!(refs["TMP/1.1.0.0"].is_null());
+
+    let array: serde_json::Value = serde_json::from_str(refs["TMP/.zarray"].as_str().unwrap()).unwrap();
+    assert_eq!(array["shape"], serde_json::json!([2, 2, 2, 3]));
+    assert_eq!(array["compressor"], serde_json::Value::Null);
+    assert_eq!(array["filters"], serde_json::json!([{"id": "grib", "var": "TMP", "dtype": "float32"}]));
+    let consolidated: serde_json::Value = serde_json::from_str(refs[".zmetadata"].as_str().unwrap()).unwrap();
+    assert_eq!(consolidated["metadata"]["TMP/.zarray"], array);
+
+    // Two hours as little endian doubles, 0 and 6
+    assert_eq!(refs["time/0"], "base64:AAAAAAAAAAAAAAAAAAAYQA==");
+}
+
+#[cfg(feature = "render")]
+#[test]
+fn render_field_png() {
+    use gribberish::error::GribError;
+    use gribberish::render::{render_png, ColorScale, Colormap};
+
+    let reference_date = Utc.with_ymd_and_hms(2021, 3, 4, 12, 0, 0).unwrap();
+    let raw = MessageBuilder::new(Discipline::Meteorological, 0, 0)
+        .reference_date(reference_date)
+        .forecast_date(reference_date)
+        .grid(LatLngGrid {
+            start: (40.0, 288.0),
+            end: (41.0, 290.0),
+            shape: (2, 3),
+        })
+        .data(vec![0.0, 1.0, 2.0, 3.0, f64::NAN, 5.0])
+        .build()
+        .expect("failed to build grib message");
+    let message = Message::parse(&raw, 0).unwrap();
+
+    let read = |png: Vec<u8>| {
+        let mut reader = png::Decoder::new(png.as_slice()).read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        assert_eq!((info.width, info.height, info.color_type), (3, 2, png::ColorType::Rgba));
+        pixels
+    };
+
+    // The grid runs north from 40, so its second row is drawn first
+    let pixels = read(render_png(&message, Colormap::Grayscale, ColorScale::DataRange).unwrap());
+    assert_eq!(&pixels[..4], &[153, 153, 153, 255]);
+    assert_eq!(&pixels[4..8], &[0, 0, 0, 0]);
+    assert_eq!(&pixels[12..16], &[0, 0, 0, 255]);
+    assert_eq!(&pixels[20..], &[102, 102, 102, 255]);
+
+    let pixels = read(render_png(&message, Colormap::Viridis, ColorScale::Fixed { min: 0.0, max: 2.0 }).unwrap());
+    assert_eq!(&pixels[..4], &[253, 231, 37, 255]);
+    assert_eq!(&pixels[12..16], &[68, 1, 84, 255]);
+    assert_eq!(&pixels[16..20], &[33, 144, 141, 255]);
+
+    assert!(matches!(
+        render_png(&message, Colormap::Viridis, ColorScale::Fixed { min: 1.0, max: 1.0 }),
+        Err(GribError::InvalidData(_))
+    ));
+}
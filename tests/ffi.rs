@@ -0,0 +1,61 @@
+#![cfg(feature = "ffi")]
+
+extern crate gribberish;
+
+mod common;
+
+use common::build_message;
+use gribberish::encoding::LatLngGrid;
+
+#[test]
+fn ffi_file_handle() {
+    use gribberish::ffi::*;
+    use std::ffi::CStr;
+
+    let grid = LatLngGrid {
+        start: (40.0, 288.0),
+        end: (41.0, 290.0),
+        shape: (2, 3),
+    };
+    let build = |level: f64, forecast_hours: i64| {
+        build_message(level, forecast_hours, grid, vec![250.0, 251.0, 252.0, 253.0, f64::NAN, 255.0])
+    };
+    let first = build(85_000.0, 0);
+    let grib_data = [first.clone(), build(50_000.0, 6)].concat();
+
+    unsafe {
+        let file = gribberish_file_open(grib_data.as_ptr(), grib_data.len());
+        assert!(!file.is_null());
+        assert_eq!(gribberish_file_message_count(file), 2);
+
+        let take = |string: *mut std::os::raw::c_char| {
+            let value = CStr::from_ptr(string).to_str().unwrap().to_string();
+            gribberish_string_free(string);
+            value
+        };
+        let inventory = take(gribberish_file_inventory(file));
+        assert_eq!(inventory.lines().nth(1).unwrap(), format!("2:{}:d=2021030412:TMP:500 mb:6 hour fcst:", first.len()));
+        assert_eq!(take(gribberish_message_abbreviation(file, 1)), "TMP");
+        assert_eq!(take(gribberish_message_units(file, 1)), "K");
+        assert_eq!(take(gribberish_message_level(file, 0)), "850 mb");
+
+        let mut metadata = std::mem::MaybeUninit::<GribMetadata>::uninit();
+        assert_eq!(gribberish_message_metadata(file, 1, metadata.as_mut_ptr()), GribStatus::Ok);
+        let metadata = metadata.assume_init();
+        assert_eq!((metadata.rows, metadata.columns, metadata.point_count), (2, 3, 6));
+        assert_eq!(metadata.forecast_time - metadata.reference_time, 6 * 3600);
+
+        let mut values = vec![0.0; metadata.point_count];
+        assert_eq!(gribberish_message_data(file, 1, values.as_mut_ptr(), values.len()), GribStatus::Ok);
+        assert_eq!(values[..4], [250.0, 251.0, 252.0, 253.0]);
+        assert!(values[4].is_nan());
+
+        // Failures leave their reason for the caller
+        assert_eq!(gribberish_message_data(file, 2, values.as_mut_ptr(), values.len()), GribStatus::Error);
+        assert!(CStr::from_ptr(gribberish_last_error()).to_str().unwrap().contains("no message 2"));
+        assert_eq!(gribberish_message_data(file, 0, values.as_mut_ptr(), 3), GribStatus::Error);
+        assert!(gribberish_message_name(std::ptr::null(), 0).is_null());
+
+        gribberish_file_free(file);
+    }
+}
@@ -0,0 +1,298 @@
+extern crate gribberish;
+
+mod common;
+
+use chrono::{TimeZone, Utc};
+use common::{grid_section, lambert_grid_section, message_builder, micro_degrees, with_grid_section};
+use gribberish::encoding::{LatLngGrid, MessageBuilder};
+use gribberish::message::{Downsampling, LongitudeConvention, Message, ParseOptions};
+use gribberish::winds::rotate_winds;
+use gribberish::sections::indicator::Discipline;
+use gribberish::templates::grid::LatLngBounds;
+
+#[test]
+fn rotate_grid_relative_winds() {
+    let build = |value: f64, point_count: usize| {
+        let grid = LatLngGrid {
+            start: (40.0, 288.0),
+            end: (40.0, 288.0 + point_count as f64 - 1.0),
+            shape: (1, point_count),
+        };
+        message_builder(Discipline::Meteorological, 2, 2, 0, grid)
+            .data(vec![value; point_count])
+            .build()
+            .expect("failed to build grib message")
+    };
+    let (u, v) = (build(1.0, 12), build(0.0, 12));
+
+    let (u_raw, v_raw) = (with_grid_section(&u, &lambert_grid_section(0x08)), with_grid_section(&v, &lambert_grid_section(0x08)));
+    let (u_message, v_message) = (Message::parse(&u_raw, 0).unwrap(), Message::parse(&v_raw, 0).unwrap());
+    assert_eq!(u_message.shape(), Ok((3, 4)));
+
+    let locations = u_message.data_locations().unwrap();
+    assert!((locations[0].0 - 21.138).abs() < 1e-6 && (locations[0].1 - 237.28).abs() < 1e-6);
+    assert!(locations[4].0 > locations[0].0);
+    assert_eq!(u_message.location_index().unwrap().nearest(locations[6].0, locations[6].1), Ok(6));
+
+    // Pointing along the grid's x axis, the wind turns north of east west of the orientation meridian
+    let (east, north) = rotate_winds(&u_message, &v_message).unwrap();
+    let cone_constant = 25f64.to_radians().sin();
+    for (index, (_, longitude)) in locations.iter().enumerate() {
+        let angle = cone_constant * (longitude - 265.0).to_radians();
+        assert!((east[index] - angle.cos()).abs() < 1e-6);
+        assert!((north[index] + angle.sin()).abs() < 1e-6);
+        assert!(north[index] > 0.0);
+    }
+
+    // Earth relative winds are left alone
+    let (u_raw, v_raw) = (with_grid_section(&u, &lambert_grid_section(0)), with_grid_section(&v, &lambert_grid_section(0)));
+    let (u_message, v_message) = (Message::parse(&u_raw, 0).unwrap(), Message::parse(&v_raw, 0).unwrap());
+    assert_eq!(rotate_winds(&u_message, &v_message), Ok((vec![1.0; 12], vec![0.0; 12])));
+
+    // A rotated grid with its south pole at 40S 10E, so the middle point sits at 50N 10E
+    let rotated = {
+        let mut section = grid_section(1, 84, 9);
+        section[30..34].copy_from_slice(&3u32.to_be_bytes());
+        section[34..38].copy_from_slice(&3u32.to_be_bytes());
+        section[46..50].copy_from_slice(&micro_degrees(-1.0));
+        section[50..54].copy_from_slice(&micro_degrees(359.0));
+        section[54] = 0x08;
+        section[55..59].copy_from_slice(&micro_degrees(1.0));
+        section[59..63].copy_from_slice(&micro_degrees(1.0));
+        section[63..67].copy_from_slice(&micro_degrees(1.0));
+        section[67..71].copy_from_slice(&micro_degrees(1.0));
+        section[71] = 0x40;
+        section[72..76].copy_from_slice(&micro_degrees(-40.0));
+        section[76..80].copy_from_slice(&micro_degrees(10.0));
+        section
+    };
+    let (u, v) = (build(1.0, 9), build(0.0, 9));
+    let (u_raw, v_raw) = (with_grid_section(&u, &rotated), with_grid_section(&v, &rotated));
+    let (u_message, v_message) = (Message::parse(&u_raw, 0).unwrap(), Message::parse(&v_raw, 0).unwrap());
+
+    let locations = u_message.data_locations().unwrap();
+    assert!((locations[4].0 - 50.0).abs() < 1e-6 && (locations[4].1 - 10.0).abs() < 1e-6);
+    assert_eq!(u_message.location_index().unwrap().nearest(50.0, 10.0), Ok(4));
+
+    // Along the rotated prime meridian the axes line up with the geographic ones, either side
+    // of it they turn opposite ways
+    let (east, north) = rotate_winds(&u_message, &v_message).unwrap();
+    for row in 0..3 {
+        let (west, middle, east_index) = (row * 3, row * 3 + 1, row * 3 + 2);
+        assert!((east[middle] - 1.0).abs() < 1e-6 && north[middle].abs() < 1e-6);
+        assert!(north[west] * north[east_index] < 0.0);
+        for index in [west, east_index] {
+            assert!((east[index].hypot(north[index]) - 1.0).abs() < 1e-6);
+        }
+    }
+
+    assert!(rotate_winds(&u_message, &Message::parse(&build(0.0, 9), 0).unwrap()).is_err());
+}
+
+#[cfg(feature = "proj")]
+#[test]
+fn describe_grid_crs() {
+    let build = |shape: (usize, usize)| {
+        let grid = LatLngGrid {
+            start: (40.0, 288.0),
+            end: (40.0 + (shape.0 - 1) as f64 * 0.5, 288.0 + (shape.1 - 1) as f64 * 0.5),
+            shape,
+        };
+        message_builder(Discipline::Meteorological, 0, 0, 0, grid)
+            .data(vec![1.0; shape.0 * shape.1])
+            .build()
+            .expect("failed to build grib message")
+    };
+    let raw = build((3, 5));
+    let message = Message::parse(&raw, 0).unwrap();
+    let crs = message.crs().unwrap();
+    assert_eq!(crs.proj_string(), "+proj=longlat +R=6371229 +no_defs");
+    assert_eq!(crs.epsg(), None);
+    assert_eq!(crs.to_projected(40.5, 289.0), (289.0, 40.5));
+    assert_eq!(crs.to_grid(40.5, -71.0), (2.0, 1.0));
+    assert_eq!(crs.from_grid(2.0, 1.0), (40.5, 289.0));
+
+    let mut wgs84 = raw.clone();
+    let grid_definition = gribberish::reader::scan(&raw)[0].section(3).unwrap().clone();
+    wgs84[grid_definition.offset as usize + 14] = 5;
+    let message = Message::parse(&wgs84, 0).unwrap();
+    assert_eq!(message.crs().unwrap().proj_string(), "+proj=longlat +datum=WGS84 +no_defs");
+    assert_eq!(message.crs().unwrap().epsg(), Some(4326));
+
+    // Lambert conformal grids project in metres from where the tangent latitude meets the
+    // orientation meridian, and fractional indices map back onto the grid points
+    let lambert = with_grid_section(&build((3, 4)), &lambert_grid_section(0x08));
+    let message = Message::parse(&lambert, 0).unwrap();
+    let crs = message.crs().unwrap();
+    assert_eq!(
+        crs.proj_string(),
+        "+proj=lcc +lat_1=25 +lat_2=25 +lat_0=25 +lon_0=265 +x_0=0 +y_0=0 +R=6371229 +units=m +no_defs"
+    );
+    let (x, y) = crs.to_projected(25.0, 265.0);
+    assert!(x.abs() < 1e-6 && y.abs() < 1e-6);
+
+    let locations = message.data_locations().unwrap();
+    for (index, (latitude, longitude)) in locations.iter().enumerate() {
+        let (i, j) = crs.to_grid(*latitude, *longitude);
+        assert!((i - (index % 4) as f64).abs() < 1e-6 && (j - (index / 4) as f64).abs() < 1e-6);
+
+        let (x, y) = crs.to_projected(*latitude, *longitude);
+        let (back_latitude, back_longitude) = crs.from_projected(x, y);
+        assert!((back_latitude - latitude).abs() < 1e-6 && (back_longitude - longitude).abs() < 1e-6);
+    }
+    let (latitude, longitude) = crs.from_grid(1.0, 2.0);
+    assert!((latitude - locations[9].0).abs() < 1e-6 && (longitude - locations[9].1).abs() < 1e-6);
+
+    let unsupported = with_grid_section(&build((3, 4)), &grid_section(40, 81, 12));
+    assert!(Message::parse(&unsupported, 0).unwrap().crs().is_err());
+}
+
+#[test]
+fn build_cell_polygons() {
+    let build = |point_count: usize| {
+        let grid = LatLngGrid {
+            start: (40.0, 359.5),
+            end: (40.0 + (point_count / 3 - 1) as f64 * 0.5, 360.5),
+            shape: (point_count / 3, 3),
+        };
+        message_builder(Discipline::Oceanographic, 0, 3, 0, grid)
+            .data((0..point_count).map(|i| i as f64).collect())
+            .build()
+            .expect("failed to build grib message")
+    };
+
+    // Cells reach halfway to the neighboring rows and columns
+    let raw = build(6);
+    let message = Message::parse(&raw, 0).unwrap();
+    let cells = message.cell_polygons().unwrap();
+    assert_eq!(cells.len(), 6);
+    assert_eq!(cells[0], [(39.75, 359.25), (39.75, 359.75), (40.25, 359.75), (40.25, 359.25)]);
+    assert_eq!(cells[5], [(40.25, 360.25), (40.25, 360.75), (40.75, 360.75), (40.75, 360.25)]);
+
+    // Moved whole to the longitude convention, so the cell over the prime meridian stays in one piece
+    let options = ParseOptions::strict().with_longitudes(LongitudeConvention::Minus180To180);
+    let message = Message::parse_with_options(&raw, 0, options).unwrap();
+    let cells = message.cell_polygons().unwrap();
+    assert_eq!(cells[1], [(39.75, -0.25), (39.75, 0.25), (40.25, 0.25), (40.25, -0.25)]);
+    assert_eq!(cells[2][0].1, 0.25);
+
+    // Projected cells share their corners with their neighbors and hold their grid point
+    let raw = with_grid_section(&build(12), &lambert_grid_section(0));
+    let message = Message::parse(&raw, 0).unwrap();
+    let cells = message.cell_polygons().unwrap();
+    let locations = message.data_locations().unwrap();
+    let close = |a: (f64, f64), b: (f64, f64)| (a.0 - b.0).abs() < 1e-9 && (a.1 - b.1).abs() < 1e-9;
+    assert!(close(cells[0][1], cells[1][0]) && close(cells[0][2], cells[1][3]));
+    assert!(close(cells[0][3], cells[4][0]) && close(cells[0][2], cells[4][1]));
+    for (corners, (latitude, longitude)) in cells.iter().zip(locations.iter()) {
+        assert!(corners[0].0 < *latitude && corners[2].0 > *latitude);
+        assert!(corners[0].1 < *longitude && corners[2].1 > *longitude);
+    }
+}
+
+#[test]
+fn downsample_grid() {
+    let mut values = (0..30).map(|k| k as f64).collect::<Vec<f64>>();
+    values[7] = f64::NAN;
+    let raw = MessageBuilder::new(Discipline::Meteorological, 0, 0)
+        .reference_date(Utc.with_ymd_and_hms(2021, 3, 4, 12, 0, 0).unwrap())
+        .grid(LatLngGrid {
+            start: (40.0, 359.0),
+            end: (41.0, 361.5),
+            shape: (5, 6),
+        })
+        .data(values)
+        .build()
+        .expect("failed to build grib message");
+    let message = Message::parse(&raw, 0).unwrap();
+
+    let strided = message.downsample(2, Downsampling::Stride).unwrap();
+    assert_eq!(strided.shape, (3, 3));
+    assert_eq!(strided.latitudes, vec![40.0, 40.5, 41.0]);
+    assert_eq!(strided.longitudes, vec![359.0, 360.0, 361.0]);
+    let expected = [0.0, 2.0, 4.0, 12.0, 14.0, 16.0, 24.0, 26.0, 28.0];
+    assert!(strided.values.iter().zip(expected.iter()).all(|(value, expected)| (value - expected).abs() < 1e-3));
+
+    // The last row of blocks only holds one row of the grid, and missing points are left out
+    let averaged = message.downsample(2, Downsampling::Mean).unwrap();
+    assert_eq!(averaged.shape, (3, 3));
+    assert_eq!(averaged.latitudes, vec![40.125, 40.625, 41.0]);
+    assert!((averaged.longitudes[0] - 359.25).abs() < 1e-9 && (averaged.longitudes[1] - 360.25).abs() < 1e-9);
+    let expected = [(0.0 + 1.0 + 6.0) / 3.0, 5.5, 7.5, 15.5, 17.5, 19.5, 24.5, 26.5, 28.5];
+    assert!(averaged.values.iter().zip(expected.iter()).all(|(value, expected)| (value - expected).abs() < 1e-3));
+    assert_eq!(averaged.value_at(2, 2), Some(averaged.values[8]));
+
+    assert_eq!(message.downsample(1, Downsampling::Stride).unwrap().values.len(), 30);
+    assert!(message.downsample(0, Downsampling::Mean).is_err());
+
+    // Projected grids give the location of every point
+    let raw = MessageBuilder::new(Discipline::Meteorological, 0, 0)
+        .reference_date(Utc.with_ymd_and_hms(2021, 3, 4, 12, 0, 0).unwrap())
+        .grid(LatLngGrid {
+            start: (40.0, 288.0),
+            end: (41.0, 289.5),
+            shape: (3, 4),
+        })
+        .data((0..12).map(|k| k as f64).collect())
+        .build()
+        .expect("failed to build grib message");
+    let lambert = with_grid_section(&raw, &lambert_grid_section(0));
+    let message = Message::parse(&lambert, 0).unwrap();
+    let locations = message.data_locations().unwrap();
+    let coarse = message.downsample(2, Downsampling::Stride).unwrap();
+    assert_eq!(coarse.shape, (2, 2));
+    assert_eq!(coarse.latitudes.len(), 4);
+    assert_eq!((coarse.latitudes[3], coarse.longitudes[3]), locations[10]);
+    assert!((coarse.values[3] - 10.0).abs() < 1e-3);
+}
+
+#[test]
+fn bound_grids_in_any_scanning_mode() {
+    let raw = MessageBuilder::new(Discipline::Meteorological, 0, 0)
+        .reference_date(Utc.with_ymd_and_hms(2021, 3, 4, 12, 0, 0).unwrap())
+        .grid(LatLngGrid {
+            start: (40.0, 10.0),
+            end: (42.0, 13.0),
+            shape: (3, 4),
+        })
+        .data(vec![1.0; 12])
+        .build()
+        .expect("failed to build grib message");
+    let section = |first: (f64, f64), last: (f64, f64), flags: u8, scanning_mode: u8| {
+        let mut section = grid_section(0, 72, 12);
+        section[30..34].copy_from_slice(&4u32.to_be_bytes());
+        section[34..38].copy_from_slice(&3u32.to_be_bytes());
+        section[46..50].copy_from_slice(&micro_degrees(first.0));
+        section[50..54].copy_from_slice(&micro_degrees(first.1));
+        section[54] = flags;
+        section[55..59].copy_from_slice(&micro_degrees(last.0));
+        section[59..63].copy_from_slice(&micro_degrees(last.1));
+        section[63..67].copy_from_slice(&micro_degrees(1.0));
+        section[67..71].copy_from_slice(&micro_degrees(1.0));
+        section[71] = scanning_mode;
+        section
+    };
+    let bounds = |section: Vec<u8>, options: ParseOptions| {
+        let raw = with_grid_section(&raw, &section);
+        Message::parse_with_options(&raw, 0, options).unwrap().metadata().unwrap().bounds
+    };
+    let expected = LatLngBounds { north: 42.0, south: 40.0, east: 13.0, west: 10.0 };
+
+    // Whichever corner the points start from, the box is the same
+    assert_eq!(bounds(section((42.0, 10.0), (40.0, 13.0), 0x30, 0x00), ParseOptions::default()), expected);
+    assert_eq!(bounds(section((40.0, 10.0), (42.0, 13.0), 0x30, 0x40), ParseOptions::default()), expected);
+    assert_eq!(bounds(section((42.0, 13.0), (40.0, 10.0), 0x30, 0x80), ParseOptions::default()), expected);
+    assert_eq!(bounds(section((40.0, 13.0), (42.0, 10.0), 0x30, 0xC0), ParseOptions::default()), expected);
+
+    // Without increments the last point closes the box, here across the prime meridian
+    let crossing = section((42.0, 358.0), (40.0, 1.0), 0x00, 0x00);
+    assert_eq!(
+        bounds(crossing.clone(), ParseOptions::default()),
+        LatLngBounds { north: 42.0, south: 40.0, east: 361.0, west: 358.0 }
+    );
+    let options = ParseOptions::default().with_longitudes(LongitudeConvention::Minus180To180);
+    assert_eq!(bounds(crossing.clone(), options), LatLngBounds { north: 42.0, south: 40.0, east: 1.0, west: -2.0 });
+    let options = ParseOptions::default().with_longitudes(LongitudeConvention::ZeroTo360);
+    let wrapped = bounds(crossing, options);
+    assert!(wrapped.east < wrapped.west);
+}
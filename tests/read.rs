@@ -1,14 +1,25 @@
 extern crate gribberish;
 
-use gribberish::message::Message;
+mod common;
+
+use chrono::{TimeZone, Utc};
+use common::message_builder;
+use gribberish::encoding::{DataRepresentationTemplateKind, LatLngGrid, MessageBuilder};
+use gribberish::error::GribError;
+use gribberish::location_index::InterpolationMethod;
+use gribberish::message::{FieldStatistics, LongitudeConvention, Message, MessageIterator, MissingValue, ParseOptions};
+use gribberish::idx::{byte_ranges, generate_idx, parse_idx, read_messages};
+use gribberish::regrid::regrid;
+use gribberish::reader::{scan, GribReader, MessageStream, RangeRead};
+use gribberish::report::GribReport;
+use gribberish::sections::identification::Center;
+use gribberish::sections::indicator::Discipline;
 use gribberish::sections::section::Section;
-use std::convert::TryFrom;
-use gribberish::sections::product_definition::ProductDefinitionSection;
-use std::path::Path;
+use gribberish::templates::template::TemplateType;
+use gribberish::templates::product::tables::FixedSurfaceTypes;
 use std::fs::File;
-use std::io::Read;
+use std::io::{Cursor, Read};
 use std::vec::Vec;
-use std::error::Error;
 
 fn read_grib_messages(path: &str) -> Vec<u8> {
     let mut grib_file = File::open(path).expect("file not found");
@@ -30,7 +41,7 @@ fn read_multi() {
         //assert_eq!(message.sections.len(), 8);
 
         let field = message.metadata();
-        if let Err(_) = field {
+        if field.is_err() {
             continue;
         }
 
@@ -38,7 +49,7 @@ fn read_multi() {
         println!("{}: {} +{}", field.variable_abbreviation, field.forecast_date, field.reference_date);
 
         let data = message.data();
-        if let Err(_) = data {
+        if data.is_err() {
             continue;
         }
 
@@ -46,3 +57,1644 @@ fn read_multi() {
         println!("Data: {:?}", data);
     }
 }
+
+#[test]
+fn read_center() {
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let messages = Message::parse_all(grib_data.as_slice());
+
+    let metadata = messages[0].metadata().unwrap();
+    assert_eq!(metadata.center, Center::NCEP);
+    assert_eq!(metadata.center.to_string(), "US National Weather Service - NCEP");
+    assert_eq!(metadata.subcenter, 0);
+}
+
+#[test]
+fn read_raw_bytes() {
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let messages = Message::parse_all(grib_data.as_slice());
+
+    let mut offset = 0;
+    for message in &messages {
+        let raw = message.raw_bytes();
+        assert_eq!(raw, &grib_data[offset..offset + message.len()]);
+        assert_eq!(&raw[0..4], b"GRIB");
+
+        let sections = message.section_bytes();
+        assert_eq!(sections.len(), message.section_count());
+        assert_eq!(sections.concat(), raw);
+        assert_eq!(sections.last().unwrap(), b"7777");
+
+        offset += message.len();
+    }
+    assert_eq!(offset, grib_data.len());
+}
+
+#[test]
+fn iterate_messages() {
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let messages = Message::parse_all(grib_data.as_slice());
+    assert!(messages.len() > 2);
+
+    let iterated = MessageIterator::new(grib_data.as_slice())
+        .collect::<Result<Vec<Message>, GribError>>()
+        .unwrap();
+    assert_eq!(iterated.len(), messages.len());
+
+    // Break the identification section length of the second message
+    let mut corrupted = grib_data.clone();
+    let second_offset = messages[0].len();
+    corrupted[second_offset + 16..second_offset + 20].copy_from_slice(&u32::MAX.to_be_bytes());
+    let results = MessageIterator::new(corrupted.as_slice()).collect::<Vec<_>>();
+    assert_eq!(results.len(), messages.len());
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+    assert!(results[2..].iter().all(|r| r.is_ok()));
+
+    // A truncated final message ends the iteration with an error
+    let truncated = &grib_data[..grib_data.len() - 10];
+    let results = MessageIterator::new(truncated).collect::<Vec<_>>();
+    assert_eq!(results.len(), messages.len());
+    assert!(results.last().unwrap().is_err());
+    assert_eq!(Message::parse_all(truncated).len(), messages.len() - 1);
+}
+
+#[test]
+fn reuse_unpacked_data() {
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let messages = Message::parse_all(grib_data.as_slice());
+    let message = &messages[0];
+
+    let location = message.data_locations().unwrap()[7];
+    let before_unpacking = message.data_at_location(&location);
+
+    let values = message.data_ref().unwrap();
+    assert!(std::ptr::eq(values, message.data_ref().unwrap()));
+    assert!(message.data().unwrap().iter().zip(values.iter()).all(|(a, b)| a.to_bits() == b.to_bits()));
+    assert!(before_unpacking.is_ok());
+    assert_eq!(message.data_iter().unwrap().filter(|v| !v.is_nan()).sum::<f64>(), values.iter().filter(|v| !v.is_nan()).sum::<f64>());
+    assert_eq!(message.data_at_location(&location), before_unpacking);
+}
+
+#[test]
+fn read_many_locations_at_once() {
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let messages = Message::parse_all(grib_data.as_slice());
+    let locations = messages[0].data_locations().unwrap();
+
+    let mut queries = locations.iter().step_by(97).copied().collect::<Vec<_>>();
+    queries.push((-89.0, 0.0));
+
+    // Both before and after the message data has been unpacked
+    let message = &messages[1];
+    let batched = message.data_at_locations(&queries).unwrap();
+    message.data_ref().unwrap();
+    assert_eq!(message.data_at_locations(&queries).unwrap(), batched);
+
+    assert_eq!(batched.len(), queries.len());
+    assert_eq!(batched.last(), Some(&None));
+    for (location, value) in queries.iter().zip(batched.iter()) {
+        assert_eq!(message.data_at_location(location).ok(), *value);
+    }
+    assert!(batched.iter().any(|v| v.is_some()));
+}
+
+#[test]
+fn read_values_with_locations() {
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let messages = Message::parse_all(grib_data.as_slice());
+    let message = &messages[0];
+
+    let data = message.data().unwrap();
+    let locations = message.data_locations().unwrap();
+    let triples = message.data_with_locations().unwrap().collect::<Vec<_>>();
+    assert_eq!(triples.len(), data.len());
+    assert!(triples.iter().any(|(_, _, value)| value.is_nan()));
+    for ((latitude, longitude, value), (location, expected)) in triples.iter().zip(locations.iter().zip(data.iter())) {
+        assert_eq!((*latitude, *longitude), *location);
+        assert_eq!(value.to_bits(), expected.to_bits());
+    }
+}
+
+#[test]
+fn normalize_longitudes() {
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let encoded = Message::parse(&grib_data, 0).unwrap();
+    let options = ParseOptions::strict().with_longitudes(LongitudeConvention::Minus180To180);
+    let message = Message::parse_with_options(&grib_data, 0, options).unwrap();
+
+    // The grid gives its longitudes from 0 to 360
+    let longitudes = message.longitudes().unwrap();
+    assert!(encoded.longitudes().unwrap().iter().all(|lng| *lng > 180.0));
+    assert!(longitudes.iter().all(|lng| (-180.0..180.0).contains(lng)));
+    for (normalized, original) in longitudes.iter().zip(encoded.longitudes().unwrap()) {
+        assert!((normalized + 360.0 - original).abs() < 1e-9);
+    }
+
+    let bounds = message.metadata().unwrap().bounds;
+    assert!(bounds.west < 0.0 && bounds.east < 0.0 && bounds.west < bounds.east);
+    assert!(message.data_locations().unwrap().iter().all(|(_, lng)| *lng < 0.0));
+    assert!(message.data_with_locations().unwrap().all(|(_, lng, _)| lng < 0.0));
+    assert!(message.data_in_region(41.3, 288.3, 41.7, 288.7).unwrap().longitudes.iter().all(|lng| *lng < 0.0));
+
+    // Lookups take either convention whichever is chosen
+    let location = message.data_locations().unwrap()[23];
+    let value = message.data_at_location(&location).unwrap_or(f64::NAN);
+    assert_eq!(value.to_bits(), encoded.data_at_location(&location).unwrap_or(f64::NAN).to_bits());
+    assert_eq!(
+        value.to_bits(),
+        message.data_at_location(&(location.0, location.1 + 360.0)).unwrap_or(f64::NAN).to_bits()
+    );
+
+    let grid_template = message
+        .sections
+        .iter()
+        .find_map(|s| match s {
+            Section::GridDefinition(grid_definition) => grid_definition.grid_definition_template(),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(grid_template.index_for_location(location.0, location.1), Ok(23));
+    assert_eq!(grid_template.index_for_location(location.0, location.1 + 360.0), Ok(23));
+    let (latitude, longitude) = grid_template.location_for_index(23).unwrap();
+    assert!((latitude - location.0).abs() < 1e-9 && (longitude - 360.0 - location.1).abs() < 1e-9);
+}
+
+#[test]
+fn read_region() {
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let messages = Message::parse_all(grib_data.as_slice());
+    let message = &messages[0];
+
+    let region = message.data_in_region(41.3, -71.7, 41.7, -71.3).unwrap();
+    assert_eq!(region.shape, (3, 3));
+    assert_eq!(region.values.len(), 9);
+    assert!(region.latitudes.iter().all(|lat| (41.3 - 1e-6..=41.7 + 1e-6).contains(lat)));
+    assert!(region.longitudes.iter().all(|lng| (288.3 - 1e-6..=288.7 + 1e-6).contains(lng)));
+
+    let locations = region
+        .latitudes
+        .iter()
+        .flat_map(|lat| region.longitudes.iter().map(move |lng| (*lat, *lng)))
+        .collect::<Vec<_>>();
+    let expected = message.data_at_locations(&locations).unwrap();
+    for (value, expected) in region.values.iter().zip(expected.iter()) {
+        assert_eq!(value.to_bits(), expected.unwrap_or(f64::NAN).to_bits());
+    }
+
+    // The same once the data is unpacked
+    message.data_ref().unwrap();
+    assert_eq!(message.data_in_region(41.3, -71.7, 41.7, -71.3).unwrap().shape, region.shape);
+    assert!(message.data_in_region(10.0, 0.0, 11.0, 1.0).is_err());
+
+    // Regions crossing the edge of a global grid carry on from the last column to the first
+    let values = (0..16).map(|i| i as f64).collect::<Vec<f64>>();
+    let raw = MessageBuilder::new(Discipline::Meteorological, 0, 0)
+        .reference_date(chrono::Utc::now())
+        .grid(LatLngGrid {
+            start: (0.0, 0.0),
+            end: (45.0, 315.0),
+            shape: (2, 8),
+        })
+        .data(values)
+        .build()
+        .unwrap();
+    let region = Message::parse(&raw, 0).unwrap().data_in_region(40.0, 300.0, 50.0, 50.0).unwrap();
+    assert_eq!(region.shape, (1, 3));
+    assert_eq!(region.latitudes, vec![45.0]);
+    assert_eq!(region.longitudes, vec![315.0, 0.0, 45.0]);
+    assert_eq!(region.values, vec![15.0, 8.0, 9.0]);
+}
+
+#[test]
+fn read_location_axes() {
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let messages = Message::parse_all(grib_data.as_slice());
+    let message = &messages[0];
+
+    let metadata = message.metadata().unwrap();
+    let latitudes = message.latitudes().unwrap();
+    let longitudes = message.longitudes().unwrap();
+    assert_eq!((latitudes.len(), longitudes.len()), metadata.location_grid);
+
+    let locations = message.data_locations().unwrap();
+    assert_eq!(locations.len(), latitudes.len() * longitudes.len());
+    assert!(message.locations_iter().unwrap().eq(locations.iter().copied()));
+    assert_eq!(locations[longitudes.len() + 2], (latitudes[1], longitudes[2]));
+}
+
+#[test]
+fn read_metadata_without_data_representation() {
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let messages = Message::parse_all(grib_data.as_slice());
+
+    // An unknown data representation template only matters once the data is unpacked
+    let mut raw = messages[0].raw_bytes().to_vec();
+    let sections = messages[0].section_bytes();
+    let data_representation_offset = sections[..4].iter().map(|s| s.len()).sum::<usize>();
+    assert_eq!(sections[4][4], 5);
+    raw[data_representation_offset + 9..data_representation_offset + 11].copy_from_slice(&999u16.to_be_bytes());
+
+    let message = Message::parse(&raw, 0).unwrap();
+    assert_eq!(message.variable_abbrev(), messages[0].variable_abbrev());
+    assert_eq!(message.variable_name(), messages[0].variable_name());
+    assert_eq!(message.forecast_date(), messages[0].forecast_date());
+    assert_eq!(message.metadata().unwrap().data_template_number, 999);
+    assert!(message.data().is_err());
+}
+
+// Rebuilds the message without the sections with the given numbers
+fn without_sections(message: &Message, numbers: &[u8]) -> Vec<u8> {
+    let mut raw = message
+        .sections
+        .iter()
+        .zip(message.section_bytes())
+        .filter(|(section, _)| !numbers.contains(&section.number()))
+        .flat_map(|(_, bytes)| bytes.to_vec())
+        .collect::<Vec<u8>>();
+
+    let length = raw.len() as u64;
+    raw[8..16].copy_from_slice(&length.to_be_bytes());
+    raw
+}
+
+#[test]
+fn read_message_without_bitmap_section() {
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let messages = Message::parse_all(grib_data.as_slice());
+    let message = &messages[0];
+
+    // The bitmap section is optional, and without one every grid point needs a packed value
+    let raw = without_sections(message, &[6]);
+    let stripped = Message::parse(&raw, 0).unwrap();
+    assert_eq!(stripped.section_count(), message.section_count() - 1);
+
+    let present = message.masked_data().unwrap().values;
+    let mismatch = GribError::PointCountMismatch {
+        section: 5,
+        expected: message.data().unwrap().len(),
+        found: present.len(),
+    };
+    assert_eq!(stripped.data(), Err(mismatch.clone()));
+    assert_eq!(stripped.masked_data().err(), Some(mismatch.clone()));
+    assert_eq!(stripped.data_iter().err(), Some(mismatch));
+
+    let values = (0..25).map(|i| i as f64 * 0.5).collect::<Vec<f64>>();
+    let built = MessageBuilder::new(Discipline::Oceanographic, 0, 3)
+        .reference_date(chrono::Utc::now())
+        .grid(LatLngGrid {
+            start: (40.0, 288.0),
+            end: (41.0, 289.0),
+            shape: (5, 5),
+        })
+        .data(values.clone())
+        .build()
+        .unwrap();
+    let built = Message::parse(&built, 0).unwrap();
+    let raw = without_sections(&built, &[6]);
+    let stripped = Message::parse(&raw, 0).unwrap();
+    assert_eq!(stripped.section_count(), built.section_count() - 1);
+    assert_eq!(stripped.data().unwrap(), values);
+    assert_eq!(stripped.masked_data().unwrap().values, values);
+    assert_eq!(stripped.data_iter().unwrap().count(), values.len());
+}
+
+#[test]
+fn reject_messages_missing_sections() {
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let messages = Message::parse_all(grib_data.as_slice());
+    let message = &messages[0];
+
+    let raw = without_sections(message, &[7]);
+    assert_eq!(Message::parse(&raw, 0).err(), Some(GribError::MissingSection(7)));
+
+    let raw = without_sections(message, &[3]);
+    assert_eq!(Message::parse(&raw, 0).err(), Some(GribError::MissingSection(3)));
+
+    // Without an end section the message runs into the end of the data or the next message
+    let raw = without_sections(message, &[8]);
+    assert_eq!(Message::parse(&raw, 0).err(), Some(GribError::MissingSection(8)));
+    let mut followed = raw.clone();
+    followed.extend_from_slice(message.raw_bytes());
+    assert_eq!(Message::parse(&followed, 0).err(), Some(GribError::MissingSection(8)));
+    assert_eq!(
+        Message::parse(&raw, 0).err().map(|e| e.to_string()),
+        Some("End section not found".to_string())
+    );
+}
+
+#[test]
+fn locate_invalid_sections() {
+    let mut grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let offsets = scan(grib_data.as_slice());
+
+    // A data representation section longer than the second message
+    let section = offsets[1].section(5).unwrap().offset;
+    let start = section as usize;
+    grib_data[start..start + 4].copy_from_slice(&u32::MAX.to_be_bytes());
+
+    let expected = GribError::InvalidSection {
+        number: 5,
+        offset: section,
+        reason: "length".into(),
+    };
+    assert!(expected.to_string().ends_with(&format!("at offset {:#X}", section)));
+
+    let mut iterator = MessageIterator::new(grib_data.as_slice());
+    assert!(iterator.next().unwrap().is_ok());
+    assert_eq!(iterator.next().unwrap().err(), Some(expected.clone()));
+
+    let error = GribReader::new(std::io::Cursor::new(&grib_data)).headers().unwrap_err();
+    assert_eq!(error, expected);
+
+    // Data that ends part way through a section header
+    let grid = offsets[0].section(3).unwrap().offset;
+    let truncated = &grib_data[..grid as usize + 2];
+    assert_eq!(Message::parse(truncated, 0).err(), Some(GribError::UnexpectedEof { offset: 0 }));
+    let lenient = Message::parse_with_options(truncated, 0, ParseOptions::lenient()).unwrap();
+    assert!(lenient.problems().contains(&GribError::UnexpectedEof { offset: grid }));
+}
+
+#[test]
+fn parse_strictly_or_leniently() {
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let messages = Message::parse_all(grib_data.as_slice());
+    let message = &messages[0];
+    let bytes = message.section_bytes();
+    let end = bytes.len() - 1;
+
+    // An unknown section before the end section, and a total length that does not match
+    let mut raw = bytes[..end].concat();
+    raw.extend_from_slice(&[0, 0, 0, 6, 12, 0]);
+    raw.extend_from_slice(bytes[end]);
+    let unknown_offset = (raw.len() - 10) as u64;
+
+    let error = Message::parse_with_options(&raw, 0, ParseOptions::strict()).err();
+    assert_eq!(
+        error,
+        Some(GribError::InvalidSection {
+            number: 12,
+            offset: unknown_offset,
+            reason: "number".into(),
+        })
+    );
+
+    let lenient = Message::parse_with_options(&raw, 0, ParseOptions::lenient()).unwrap();
+    assert_eq!(lenient.section_count(), message.section_count());
+    assert_eq!(lenient.problems().len(), 2);
+    assert!(matches!(lenient.problems()[1], GribError::InvalidMessage { .. }));
+    assert_eq!(lenient.masked_data().unwrap().values, message.masked_data().unwrap().values);
+    assert!(message.problems().is_empty());
+
+    // Without an end section a lenient parse still describes the message
+    let raw = without_sections(message, &[8]);
+    assert!(Message::parse(&raw, 0).is_err());
+    let lenient = Message::parse_with_options(&raw, 0, ParseOptions::lenient()).unwrap();
+    assert_eq!(lenient.problems(), &[GribError::MissingSection(8)]);
+    assert_eq!(lenient.variable_abbrev(), message.variable_abbrev());
+
+    // Lenient iteration skips what can not be read and carries on
+    let mut corrupted = grib_data.clone();
+    corrupted[messages[0].len() + 8..messages[0].len() + 16].copy_from_slice(&u64::MAX.to_be_bytes());
+    assert_eq!(MessageIterator::new(&corrupted).filter_map(Result::ok).count(), 1);
+    let recovered = MessageIterator::with_options(&corrupted, ParseOptions::lenient()).filter_map(Result::ok);
+    assert_eq!(recovered.count(), messages.len() - 1);
+}
+
+#[test]
+fn validate_messages() {
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    assert_eq!(Message::validate_all(&grib_data), vec![]);
+
+    let offsets = scan(grib_data.as_slice());
+    let second = offsets[1].offset as usize;
+    let mut corrupted = grib_data.clone();
+
+    // The wrong number of packed values for the second message, and a third message with no end marker
+    let data_representation = offsets[1].section(5).unwrap().offset;
+    corrupted[data_representation as usize + 8] ^= 1;
+    let end = offsets[2].section(8).unwrap().offset as usize;
+    corrupted[end..end + 4].copy_from_slice(b"7776");
+
+    let message = Message::parse(&corrupted, second).unwrap();
+    assert_eq!(message.offset(), second);
+    let findings = message.validate();
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].offset, data_representation);
+    assert_eq!(findings[0].section, Some(5));
+    assert!(matches!(findings[0].error, GribError::PointCountMismatch { section: 5, .. }));
+    assert_eq!(message.data().err(), Some(findings[0].error.clone()));
+
+    // A grid definition giving more points than its rows and columns hold
+    let mut miscounted = grib_data.clone();
+    let grid_definition = offsets[0].section(3).unwrap().offset as usize;
+    miscounted[grid_definition + 9] ^= 1;
+    let message = Message::parse(&miscounted, 0).unwrap();
+    let findings = message.validate();
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].offset, grid_definition as u64);
+    assert!(matches!(findings[0].error, GribError::PointCountMismatch { section: 3, .. }));
+    assert!(message.data().is_err());
+
+    let findings = Message::validate_all(&corrupted);
+    assert!(findings.len() >= 2);
+    assert_eq!(findings[0].offset, data_representation);
+    assert_eq!(findings[1].section, Some(8));
+    assert!(findings[1].offset >= offsets[2].offset);
+    assert!(findings.windows(2).all(|pair| pair[0].offset <= pair[1].offset));
+
+    // Sections out of order
+    let message = &Message::parse_all(&grib_data)[0];
+    let mut bytes = message.section_bytes();
+    bytes.swap(3, 4);
+    let swapped = bytes.concat();
+    let findings = Message::parse(&swapped, 0).unwrap().validate();
+    assert!(findings
+        .iter()
+        .any(|f| matches!(&f.error, GribError::InvalidSection { reason, .. } if reason == "out of order")));
+}
+
+// Every message of the test file, along with built messages for each kind of packing
+fn truncation_corpus() -> Vec<Vec<u8>> {
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let mut corpus = Message::parse_all(grib_data.as_slice())
+        .iter()
+        .map(|message| message.raw_bytes().to_vec())
+        .collect::<Vec<_>>();
+
+    let values = (0..25)
+        .map(|i| if i % 3 == 0 { f64::NAN } else { 10.0 + i as f64 * 0.5 })
+        .collect::<Vec<f64>>();
+    #[allow(unused_mut)]
+    let mut packings = vec![DataRepresentationTemplateKind::SimplePacking, DataRepresentationTemplateKind::ComplexPacking];
+    #[cfg(feature = "png")]
+    packings.push(DataRepresentationTemplateKind::Png);
+    #[cfg(feature = "jpeg2000-encoder")]
+    packings.push(DataRepresentationTemplateKind::Jpeg2000);
+
+    for packing in packings {
+        let raw = MessageBuilder::new(Discipline::Oceanographic, 0, 3)
+            .reference_date(chrono::Utc::now())
+            .grid(LatLngGrid {
+                start: (40.0, 288.0),
+                end: (41.0, 289.0),
+                shape: (5, 5),
+            })
+            .packing(packing)
+            .data(values.clone())
+            .build()
+            .unwrap();
+        corpus.push(raw);
+    }
+
+    corpus
+}
+
+// Reads everything a message has to offer, none of which may panic
+fn read_everything(raw: &[u8], options: ParseOptions) {
+    let message = match Message::parse_with_options(raw, 0, options) {
+        Ok(message) => message,
+        Err(_) => return,
+    };
+
+    let _ = message.metadata();
+    let _ = message.forecast_date();
+    let _ = message.data();
+    let _ = message.data_iter().map(|values| values.count());
+    let _ = message.masked_data().map(|masked| masked.expand());
+    let _ = message.data_into(&mut vec![0.0; 64]);
+    let _ = message.data_locations();
+    let _ = message.data_at_locations(&[(40.0, 288.0), (0.0, 0.0)]);
+    let _ = message.validate();
+    for field in message.fields() {
+        let _ = field.data();
+    }
+}
+
+#[test]
+fn survive_truncated_messages() {
+    for raw in truncation_corpus() {
+        let message = Message::parse(&raw, 0).unwrap();
+        let sections = message.section_bytes();
+
+        // Cut off at every byte
+        for length in 0..raw.len() {
+            assert!(Message::parse(&raw[..length], 0).is_err());
+            read_everything(&raw[..length], ParseOptions::lenient());
+        }
+
+        // Each section cut short, with the section and message lengths agreeing with it
+        for (index, section) in sections.iter().enumerate().take(sections.len() - 1).skip(1) {
+            for length in 5..section.len() {
+                let mut shortened = Vec::new();
+                for (i, bytes) in sections.iter().enumerate() {
+                    if i == index {
+                        shortened.extend_from_slice(&(length as u32).to_be_bytes());
+                        shortened.extend_from_slice(&bytes[4..length]);
+                    } else {
+                        shortened.extend_from_slice(bytes);
+                    }
+                }
+                let total_length = shortened.len() as u64;
+                shortened[8..16].copy_from_slice(&total_length.to_be_bytes());
+
+                read_everything(&shortened, ParseOptions::strict());
+                read_everything(&shortened, ParseOptions::lenient());
+            }
+        }
+    }
+
+    // A grid definition section that ends before its template is reported where it starts
+    let raw = &truncation_corpus()[0];
+    let sections = Message::parse(raw, 0).unwrap().section_bytes();
+    let grid_offset = sections[..2].iter().map(|s| s.len()).sum::<usize>();
+    let mut shortened = raw.clone();
+    shortened[grid_offset..grid_offset + 4].copy_from_slice(&20u32.to_be_bytes());
+    let error = Message::parse(&shortened[..grid_offset + 20], 0).err();
+    assert_eq!(error, Some(GribError::UnexpectedEof { offset: 0 }));
+    let lenient = Message::parse_with_options(&shortened[..grid_offset + 20], 0, ParseOptions::lenient()).unwrap();
+    assert!(lenient.problems().contains(&GribError::UnexpectedEof { offset: grid_offset as u64 }));
+}
+
+#[test]
+fn read_with_seekable_reader() {
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let messages = Message::parse_all(grib_data.as_slice());
+
+    let file = File::open("tests/data/multi_1.at_10m.t12z.f147.grib2").expect("file not found");
+    let mut reader = GribReader::new(file);
+    let headers = reader.headers().unwrap();
+    assert_eq!(headers.len(), messages.len());
+
+    for (header, message) in headers.iter().zip(messages.iter()) {
+        assert_eq!(header.length as usize, message.len());
+        assert_eq!(header.sections.len(), message.section_count());
+        assert_eq!(header.discipline, message.discipline().unwrap());
+
+        let raw = reader.read_message(header).unwrap();
+        assert_eq!(raw.as_slice(), message.raw_bytes());
+
+        let data_section = reader.read_section(header.section(7).unwrap()).unwrap();
+        assert_eq!(data_section.as_slice(), message.section_bytes()[header.sections.len() - 2]);
+
+        // The metadata can be read without loading the packed values
+        let metadata_bytes = reader.read_message_metadata(header).unwrap();
+        assert!(metadata_bytes.len() < raw.len());
+        let partial = Message::parse(&metadata_bytes, 0).unwrap();
+        assert_eq!(partial.variable_abbrev(), message.variable_abbrev());
+        assert_eq!(partial.forecast_date(), message.forecast_date());
+    }
+
+    let mut truncated = GribReader::new(std::io::Cursor::new(&grib_data[..grib_data.len() - 10]));
+    assert!(truncated.headers().is_err());
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn read_with_async_reader() {
+    use gribberish::async_reader::AsyncGribReader;
+
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let messages = Message::parse_all(grib_data.as_slice());
+
+    let file = tokio::fs::File::open("tests/data/multi_1.at_10m.t12z.f147.grib2").await.expect("file not found");
+    let mut reader = AsyncGribReader::new(file);
+    let headers = reader.headers().await.unwrap();
+    assert_eq!(headers.len(), messages.len());
+
+    for (header, message) in headers.iter().zip(messages.iter()) {
+        let raw = reader.read_message(header).await.unwrap();
+        assert_eq!(raw.as_slice(), message.raw_bytes());
+
+        let metadata_bytes = reader.read_message_metadata(header).await.unwrap();
+        let partial = Message::parse(&metadata_bytes, 0).unwrap();
+        assert_eq!(partial.variable_abbrev(), message.variable_abbrev());
+    }
+}
+
+#[test]
+fn read_messages_from_idx() {
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let messages = Message::parse_all(grib_data.as_slice());
+
+    let mut offset = 0;
+    let mut inventory = String::new();
+    for (i, message) in messages.iter().enumerate() {
+        inventory.push_str(&format!(
+            "{}:{}:d={}:{}:surface:anl:\n",
+            i + 1,
+            offset,
+            message.reference_date().unwrap().format("%Y%m%d%H"),
+            message.variable_abbrev().unwrap_or_default()
+        ));
+        offset += message.len();
+    }
+
+    let records = parse_idx(&inventory).unwrap();
+    assert_eq!(records.len(), messages.len());
+
+    let selected = records.iter().skip(1).step_by(2).collect::<Vec<_>>();
+    let ranges = byte_ranges(&records, |r| selected.contains(&r));
+    assert_eq!(ranges.len(), selected.len());
+    assert_eq!(ranges[0].start as usize, messages[0].len());
+
+    let mut file = File::open("tests/data/multi_1.at_10m.t12z.f147.grib2").expect("file not found");
+    let raw_messages = read_messages(&mut file, &selected).unwrap();
+    assert_eq!(raw_messages.len(), selected.len());
+    for (raw, record) in raw_messages.iter().zip(selected.iter()) {
+        let message = Message::parse(raw, 0).unwrap();
+        assert_eq!(message.variable_abbrev().unwrap_or_default(), record.variable);
+
+        let range = ranges.iter().find(|r| r.start == record.offset).unwrap();
+        if let Some(end) = range.end {
+            assert_eq!((end - range.start) as usize, raw.len());
+        }
+    }
+}
+
+#[test]
+fn generate_idx_inventory() {
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let messages = Message::parse_all(grib_data.as_slice());
+
+    let file = File::open("tests/data/multi_1.at_10m.t12z.f147.grib2").expect("file not found");
+    let inventory = generate_idx(file).unwrap();
+    let records = parse_idx(&inventory).unwrap();
+    assert_eq!(records.len(), messages.len());
+
+    let mut offset = 0;
+    for (record, message) in records.iter().zip(messages.iter()) {
+        assert_eq!(record.offset as usize, offset);
+        assert_eq!(record.reference_date, message.reference_date().unwrap());
+        assert_eq!(record.variable, message.variable_abbrev().unwrap());
+        offset += message.len();
+    }
+
+    assert_eq!(records[1].level, "surface");
+    assert_eq!(records[1].forecast, "147 hour fcst");
+    assert_eq!(records[8].level, "1 in sequence");
+}
+
+// Serves the bytes of a file, honouring Range headers, until the test process exits
+#[cfg(feature = "http")]
+fn serve_ranges(data: Vec<u8>) -> String {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::Arc;
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let data = Arc::new(data);
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let data = data.clone();
+            let mut stream = stream.unwrap();
+            stream.set_nodelay(true).unwrap();
+            std::thread::spawn(move || {
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                loop {
+                    let mut range = None;
+                    let mut line = String::new();
+                    loop {
+                        line.clear();
+                        if reader.read_line(&mut line).unwrap_or(0) == 0 {
+                            return;
+                        }
+                        if line == "\r\n" {
+                            break;
+                        }
+                        if let Some(value) = line.to_lowercase().strip_prefix("range: bytes=") {
+                            let (start, end) = value.trim().split_once('-').unwrap();
+                            range = Some((start.parse::<usize>().unwrap(), end.parse::<usize>().unwrap()));
+                        }
+                    }
+
+                    let (start, end) = range.unwrap();
+                    if start >= data.len() {
+                        stream.write_all(b"HTTP/1.1 416 Range Not Satisfiable\r\nContent-Length: 0\r\n\r\n").unwrap();
+                        continue;
+                    }
+
+                    let end = end.min(data.len() - 1);
+                    let mut response = format!(
+                        "HTTP/1.1 206 Partial Content\r\nContent-Length: {}\r\nContent-Range: bytes {}-{}/{}\r\n\r\n",
+                        end - start + 1,
+                        start,
+                        end,
+                        data.len()
+                    )
+                    .into_bytes();
+                    response.extend_from_slice(&data[start..=end]);
+                    stream.write_all(&response).unwrap();
+                }
+            });
+        }
+    });
+
+    format!("http://{}/multi.grib2", address)
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn read_with_http_ranges() {
+    use gribberish::http::HttpRangeReader;
+
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let messages = Message::parse_all(grib_data.as_slice());
+    let url = serve_ranges(grib_data.clone());
+
+    let mut reader = GribReader::new(HttpRangeReader::new(&url));
+    let headers = reader.headers().unwrap();
+    assert_eq!(headers.len(), messages.len());
+
+    let raw = reader.read_message(&headers[3]).unwrap();
+    assert_eq!(raw.as_slice(), messages[3].raw_bytes());
+
+    let records = parse_idx(&generate_idx(HttpRangeReader::new(&url)).unwrap()).unwrap();
+    let selected = records.iter().filter(|r| r.variable == "HTSGW").collect::<Vec<_>>();
+    let raw_messages = read_messages(&mut HttpRangeReader::new(&url), &selected).unwrap();
+    assert_eq!(raw_messages.len(), 1);
+    assert_eq!(Message::parse(&raw_messages[0], 0).unwrap().variable_abbrev().unwrap(), "HTSGW");
+}
+
+#[test]
+fn scan_message_offsets() {
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let messages = Message::parse_all(grib_data.as_slice());
+
+    let offsets = scan(grib_data.as_slice());
+    let headers = GribReader::new(std::io::Cursor::new(&grib_data)).headers().unwrap();
+    assert_eq!(offsets, headers);
+
+    // Selected messages can then be parsed from their offsets
+    let message = Message::parse(grib_data.as_slice(), offsets[4].offset as usize).unwrap();
+    assert_eq!(message.raw_bytes(), messages[4].raw_bytes());
+
+    let data_section = offsets[4].section(7).unwrap();
+    assert_eq!(
+        &grib_data[data_section.offset as usize..(data_section.offset + data_section.length) as usize],
+        messages[4].section_bytes()[6]
+    );
+
+    assert_eq!(scan(&grib_data[..grib_data.len() - 1]).len(), messages.len() - 1);
+}
+
+// A source too large to hold in memory, where everything but the given chunks is zero
+struct SparseSource {
+    length: u64,
+    chunks: Vec<(u64, Vec<u8>)>,
+}
+
+impl RangeRead for SparseSource {
+    fn read_range(&mut self, offset: u64, length: u64) -> Result<Vec<u8>, GribError> {
+        let end = (offset + length).min(self.length);
+        let mut data = vec![0u8; end.saturating_sub(offset) as usize];
+        for (start, chunk) in &self.chunks {
+            for (i, byte) in chunk.iter().enumerate() {
+                let position = start + i as u64;
+                if position >= offset && position < end {
+                    data[(position - offset) as usize] = *byte;
+                }
+            }
+        }
+
+        Ok(data)
+    }
+}
+
+#[test]
+fn read_message_larger_than_4_gib() {
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let messages = Message::parse_all(grib_data.as_slice());
+    let sections = messages[0].section_bytes();
+
+    // Two 3 GiB data sections after the metadata of the first message
+    let data_section_length: u64 = 3 << 30;
+    let metadata_length: u64 = sections[..sections.len() - 2].iter().map(|s| s.len() as u64).sum();
+    let length = metadata_length + 2 * data_section_length + 4;
+    assert!(length > u32::MAX as u64);
+
+    let mut metadata = sections[..sections.len() - 2].concat();
+    metadata[8..16].copy_from_slice(&length.to_be_bytes());
+
+    let mut data_section_start = (data_section_length as u32).to_be_bytes().to_vec();
+    data_section_start.push(7);
+
+    let second_data_offset = metadata_length + data_section_length;
+    let source = SparseSource {
+        length,
+        chunks: vec![
+            (0, metadata),
+            (metadata_length, data_section_start.clone()),
+            (second_data_offset, data_section_start),
+            (length - 4, b"7777".to_vec()),
+        ],
+    };
+
+    let mut reader = GribReader::new(source);
+    let headers = reader.headers().unwrap();
+    assert_eq!(headers.len(), 1);
+    assert_eq!(headers[0].length, length);
+
+    let data_sections = headers[0].sections.iter().filter(|s| s.number == 7).collect::<Vec<_>>();
+    assert_eq!(data_sections.len(), 2);
+    assert_eq!(data_sections[1].offset, second_data_offset);
+    assert_eq!(data_sections[1].length, data_section_length);
+    assert_eq!(headers[0].sections.last().unwrap().offset, length - 4);
+
+    // Both fields can be described without reading the packed values
+    let metadata_bytes = reader.read_message_metadata(&headers[0]).unwrap();
+    let message = Message::parse(&metadata_bytes, 0).unwrap();
+    let fields = message.fields();
+    assert_eq!(fields.len(), 2);
+    for field in fields {
+        assert_eq!(field.variable_abbrev(), messages[0].variable_abbrev());
+    }
+}
+
+#[test]
+fn reject_message_length_past_the_end_of_the_offsets() {
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let messages = Message::parse_all(grib_data.as_slice());
+
+    let mut corrupted = messages[0].raw_bytes().to_vec();
+    let mut indicator = messages[1].section_bytes()[0].to_vec();
+    indicator[8..16].copy_from_slice(&u64::MAX.to_be_bytes());
+    corrupted.extend(indicator);
+
+    assert_eq!(scan(&corrupted).len(), 1);
+    assert_eq!(MessageIterator::new(&corrupted).filter(|m| m.is_ok()).count(), 1);
+
+    let mut reader = GribReader::new(std::io::Cursor::new(&corrupted));
+    assert!(reader.next_header().unwrap().is_some());
+    assert!(reader.next_header().is_err());
+}
+
+#[test]
+fn reject_grib1_messages() {
+    // Only the indicator of a GRIB1 message is needed to tell the edition apart
+    let mut grib1 = b"GRIB".to_vec();
+    grib1.extend_from_slice(&[0, 0, 32, 1]);
+    grib1.extend_from_slice(&[0u8; 20]);
+    grib1.extend_from_slice(b"7777");
+
+    assert_eq!(Message::parse(&grib1, 0).err(), Some(GribError::UnsupportedEdition(1)));
+
+    let error = MessageIterator::new(&grib1).next().unwrap().err().unwrap();
+    assert_eq!(error, GribError::UnsupportedEdition(1));
+    assert!(error.to_string().contains("GRIB edition 1 is not supported"));
+
+    let error = GribReader::new(std::io::Cursor::new(&grib1)).headers().err().unwrap();
+    assert_eq!(error, GribError::UnsupportedEdition(1));
+    assert!(scan(&grib1).is_empty());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn parse_and_decode_in_parallel() {
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let messages = Message::parse_all(grib_data.as_slice());
+
+    let parallel = Message::par_parse_all(grib_data.as_slice());
+    assert_eq!(parallel.len(), messages.len());
+    for (parallel, message) in parallel.iter().zip(messages.iter()) {
+        assert_eq!(parallel.raw_bytes(), message.raw_bytes());
+    }
+
+    let decoded = Message::par_decode(&parallel);
+    for (data, message) in decoded.iter().zip(messages.iter()) {
+        let expected = message.data().unwrap();
+        let data = data.as_ref().unwrap();
+        assert_eq!(data.len(), expected.len());
+        assert!(data.iter().zip(expected.iter()).all(|(a, b)| a == b || (a.is_nan() && b.is_nan())));
+    }
+}
+
+#[test]
+fn resynchronize_after_corruption() {
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let messages = Message::parse_all(grib_data.as_slice());
+    let lengths = messages.iter().map(|m| m.len()).collect::<Vec<usize>>();
+
+    // Leading garbage, a message cut short, and the total length of another broken
+    let mut corrupted = b"garbage".to_vec();
+    corrupted.extend_from_slice(&grib_data[..lengths[0]]);
+    corrupted.extend_from_slice(&grib_data[lengths[0]..lengths[0] + lengths[1] - 30]);
+    let third_offset = corrupted.len();
+    corrupted.extend_from_slice(&grib_data[lengths[0] + lengths[1]..]);
+    let fourth_offset = third_offset + lengths[2];
+    corrupted[fourth_offset + 8..fourth_offset + 16].copy_from_slice(&u64::MAX.to_be_bytes());
+
+    assert!(Message::parse_all(corrupted.as_slice()).is_empty());
+
+    let (recovered, skipped) = Message::parse_all_resynchronizing(corrupted.as_slice());
+    assert_eq!(recovered.len(), messages.len() - 2);
+    assert_eq!(recovered[0].raw_bytes(), messages[0].raw_bytes());
+    assert_eq!(recovered[1].raw_bytes(), messages[2].raw_bytes());
+    assert_eq!(
+        skipped,
+        vec![0..7, 7 + lengths[0]..third_offset, fourth_offset..fourth_offset + lengths[3]]
+    );
+
+    // The same messages, along with why each skipped range was skipped
+    let (parsed, errors) = Message::parse_all_with_errors(corrupted.as_slice());
+    assert_eq!(parsed.len(), recovered.len());
+    assert_eq!(
+        errors.iter().map(|(offset, _)| *offset).collect::<Vec<_>>(),
+        skipped.iter().map(|range| range.start).collect::<Vec<_>>()
+    );
+    assert!(matches!(errors[0].1, GribError::InvalidMessage { offset: 0, .. }));
+    assert!(Message::parse_all_with_errors(grib_data.as_slice()).1.is_empty());
+
+    let mut iterator = MessageIterator::resynchronizing(&corrupted[..corrupted.len() - 10]);
+    assert_eq!(iterator.by_ref().count(), messages.len() - 3);
+    assert_eq!(iterator.skipped().last().unwrap().end, corrupted.len() - 10);
+}
+
+#[test]
+fn report_file_contents() {
+    let path = "tests/data/multi_1.at_10m.t12z.f147.grib2";
+    let grib_data = read_grib_messages(path);
+    let messages = Message::parse_all(grib_data.as_slice());
+
+    let report = GribReport::open(path).unwrap();
+    assert_eq!(report.message_count, messages.len());
+    let oceanographic = messages.iter().filter(|m| m.discipline() == Ok(Discipline::Oceanographic)).count();
+    assert_eq!(report.disciplines.get(&10), Some(&oceanographic));
+    assert_eq!(report.disciplines.values().sum::<usize>(), messages.len());
+    assert_eq!(report.centers.get(&7), Some(&messages.len()));
+    assert!(report.grid_templates.contains(&0));
+    assert!(report.is_clean());
+    assert!(GribReport::open("tests/data/missing.grib2").is_err());
+
+    // Leading garbage, and a grid template that can not be read in the second message
+    let offsets = scan(grib_data.as_slice());
+    let mut corrupted = grib_data.clone();
+    let grid_definition = offsets[1].section(3).unwrap().offset as usize;
+    corrupted[grid_definition + 12..grid_definition + 14].copy_from_slice(&40u16.to_be_bytes());
+    corrupted.splice(0..0, b"garbage".iter().cloned());
+
+    let report = GribReport::from_data(&corrupted);
+    assert_eq!(report.message_count, messages.len());
+    assert!(report.grid_templates.contains(&40));
+    assert_eq!(
+        report.unsupported_templates,
+        vec![(
+            offsets[1].offset as usize + 7,
+            GribError::UnsupportedTemplate { kind: TemplateType::Grid, number: 40 }
+        )]
+    );
+    assert_eq!(report.corrupt_regions.len(), 1);
+    assert_eq!(report.corrupt_regions[0].0, 0..7);
+    assert!(!report.is_clean());
+}
+
+#[test]
+fn open_grib_file() {
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let messages = Message::parse_all(grib_data.as_slice());
+
+    let mut reader = GribReader::open("tests/data/multi_1.at_10m.t12z.f147.grib2").unwrap();
+    assert_eq!(reader.headers().unwrap().len(), messages.len());
+    assert!(GribReader::open("tests/data/missing.grib2").is_err());
+}
+
+#[cfg(any(feature = "gzip", feature = "bzip2"))]
+fn check_compressed_file(path: &std::path::Path) {
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let messages = Message::parse_all(grib_data.as_slice());
+
+    let mut reader = GribReader::open(path).unwrap();
+    let first = reader.next_header().unwrap().unwrap();
+    assert_eq!(reader.read_message(&first).unwrap().as_slice(), messages[0].raw_bytes());
+
+    let headers = reader.headers().unwrap();
+    assert_eq!(headers.len(), messages.len());
+    let last = headers.last().unwrap();
+    assert_eq!(reader.read_message(last).unwrap().as_slice(), messages.last().unwrap().raw_bytes());
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn open_gzip_file() {
+    use flate2::{write::GzEncoder, Compression};
+    use std::io::Write;
+
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let path = std::env::temp_dir().join(format!("gribberish-{}.grib2.gz", std::process::id()));
+    let mut encoder = GzEncoder::new(File::create(&path).unwrap(), Compression::default());
+    encoder.write_all(&grib_data).unwrap();
+    encoder.finish().unwrap();
+
+    check_compressed_file(&path);
+}
+
+#[cfg(feature = "bzip2")]
+#[test]
+fn open_bzip2_file() {
+    use bzip2::{write::BzEncoder, Compression};
+    use std::io::Write;
+
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let path = std::env::temp_dir().join(format!("gribberish-{}.grib2.bz2", std::process::id()));
+    let mut encoder = BzEncoder::new(File::create(&path).unwrap(), Compression::default());
+    encoder.write_all(&grib_data).unwrap();
+    encoder.finish().unwrap();
+
+    check_compressed_file(&path);
+}
+
+#[cfg(not(feature = "gzip"))]
+#[test]
+fn open_gzip_file_without_feature() {
+    let path = std::env::temp_dir().join(format!("gribberish-{}-plain.grib2.gz", std::process::id()));
+    std::fs::write(&path, [0x1F, 0x8B, 0x08, 0x00]).unwrap();
+    assert!(GribReader::open(&path).is_err());
+    std::fs::remove_file(&path).unwrap();
+}
+
+// Hands out a few bytes at a time, like a pipe, and cannot seek
+struct Pipe<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Read for Pipe<'a> {
+    fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+        let count = buffer.len().min(self.data.len()).min(7);
+        buffer[..count].copy_from_slice(&self.data[..count]);
+        self.data = &self.data[count..];
+        Ok(count)
+    }
+}
+
+#[test]
+fn stream_messages_from_pipe() {
+    let grib_data = read_grib_messages("tests/data/multi_1.at_10m.t12z.f147.grib2");
+    let messages = Message::parse_all(grib_data.as_slice());
+
+    let mut stream = MessageStream::new(Pipe { data: &grib_data });
+    let mut count = 0;
+    while let Some(raw) = stream.next_message().unwrap() {
+        let message = Message::parse(&raw, 0).unwrap();
+        assert_eq!(message.raw_bytes(), messages[count].raw_bytes());
+        count += 1;
+    }
+    assert_eq!(count, messages.len());
+    assert_eq!(stream.offset() as usize, grib_data.len());
+
+    let results = MessageStream::new(Pipe { data: &grib_data[..grib_data.len() - 10] }).collect::<Vec<_>>();
+    assert_eq!(results.len(), messages.len());
+    assert!(results.last().unwrap().is_err());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn unpack_large_grid_in_parallel() {
+    let values = (0..80_000)
+        .map(|i| 280.0 + ((i / 400) as f64 * 0.05).sin() * 12.0 + (i % 400) as f64 * 0.01)
+        .collect::<Vec<f64>>();
+
+    for packing in [DataRepresentationTemplateKind::SimplePacking, DataRepresentationTemplateKind::ComplexPacking] {
+        let raw = MessageBuilder::new(Discipline::Meteorological, 0, 0)
+            .reference_date(Utc.with_ymd_and_hms(2021, 3, 4, 12, 0, 0).unwrap())
+            .grid(LatLngGrid {
+                start: (-49.75, 0.0),
+                end: (49.75, 99.75),
+                shape: (200, 400),
+            })
+            .packing(packing)
+            .decimal_places(2)
+            .data(values.clone())
+            .build()
+            .expect("failed to build grib message");
+
+        let message = Message::parse(&raw, 0).unwrap();
+        assert_eq!(message.par_data().unwrap(), message.data().unwrap());
+    }
+}
+
+#[test]
+fn interpolate_between_grid_points() {
+    let grid = LatLngGrid {
+        start: (40.0, 288.0),
+        end: (41.0, 289.0),
+        shape: (5, 5),
+    };
+    let build = |values: Vec<f64>| {
+        message_builder(Discipline::Oceanographic, 0, 3, 0, grid)
+            .data(values)
+            .build()
+            .expect("failed to build grib message")
+    };
+
+    // A plane, which both bilinear and bicubic weights reproduce away from the edges
+    let plane = |latitude: f64, longitude: f64| 10.0 + (latitude - 40.0) * 4.0 + (longitude - 288.0) * 2.0;
+    let values = (0..25).map(|i| plane(40.0 + (i / 5) as f64 * 0.25, 288.0 + (i % 5) as f64 * 0.25)).collect::<Vec<f64>>();
+    let raw = build(values.clone());
+    let message = Message::parse(&raw, 0).unwrap();
+
+    let location = (40.4, 288.6);
+    let nearest = message.data_at_location_interpolated(&location, InterpolationMethod::Nearest).unwrap();
+    assert_eq!(Ok(nearest), message.data_at_location(&location));
+    for method in [InterpolationMethod::Bilinear, InterpolationMethod::Bicubic] {
+        let value = message.data_at_location_interpolated(&location, method).unwrap();
+        assert!((value - plane(location.0, location.1)).abs() < 1e-3);
+    }
+    assert!(message.data_at_location_interpolated(&(42.0, 288.0), InterpolationMethod::Bilinear).is_err());
+
+    // Missing neighbors are left out of the weights, leaving a value between the other three
+    let mut masked = values.clone();
+    masked[6] = f64::NAN;
+    let raw = build(masked);
+    let message = Message::parse(&raw, 0).unwrap();
+    let location = (40.3, 288.3);
+    let bilinear = message.data_at_location_interpolated(&location, InterpolationMethod::Bilinear).unwrap();
+    assert!((12.0..=13.0).contains(&bilinear));
+    assert_eq!(message.data_at_location_interpolated(&location, InterpolationMethod::Bicubic), Ok(bilinear));
+
+    // Right on a missing point there is nothing else to weigh
+    assert_eq!(
+        message.data_at_location_interpolated(&(40.25, 288.25), InterpolationMethod::Bilinear),
+        Err(GribError::MissingData(6))
+    );
+}
+
+#[test]
+fn regrid_onto_target_grid() {
+    let plane = |latitude: f64, longitude: f64| 10.0 + (latitude - 40.0) * 4.0 + (longitude - 288.0) * 2.0;
+    let values = (0..25).map(|i| plane(40.0 + (i / 5) as f64 * 0.25, 288.0 + (i % 5) as f64 * 0.25)).collect::<Vec<f64>>();
+    let raw = MessageBuilder::new(Discipline::Oceanographic, 0, 3)
+        .reference_date(Utc.with_ymd_and_hms(2021, 3, 4, 12, 0, 0).unwrap())
+        .grid(LatLngGrid {
+            start: (40.0, 288.0),
+            end: (41.0, 289.0),
+            shape: (5, 5),
+        })
+        .data(values)
+        .build()
+        .expect("failed to build grib message");
+    let message = Message::parse(&raw, 0).unwrap();
+
+    // A finer grid given on -180 to 180, running north to south and out past the source grid
+    let target = LatLngGrid {
+        start: (41.2, -72.0),
+        end: (40.0, -71.0),
+        shape: (7, 6),
+    };
+    let regridded = regrid(&message, &target, InterpolationMethod::Bilinear).unwrap();
+    assert_eq!(regridded.shape, (7, 6));
+    assert_eq!(regridded.values.len(), 42);
+    assert!((regridded.latitudes[1] - 41.0).abs() < 1e-9);
+    assert!((regridded.longitudes[1] - -71.8).abs() < 1e-9);
+
+    assert!(regridded.row(0).unwrap().iter().all(|v| v.is_nan()));
+    for j in 1..7 {
+        for i in 0..6 {
+            let expected = plane(regridded.latitudes[j], regridded.longitudes[i] + 360.0);
+            assert!((regridded.value_at(i, j).unwrap() - expected).abs() < 1e-3);
+        }
+    }
+
+    // Nearest keeps the source values as they are
+    let nearest = regrid(&message, &target, InterpolationMethod::Nearest).unwrap();
+    assert_eq!(Ok(nearest.value_at(2, 3).unwrap()), message.data_at_location(&(40.6, 288.4)));
+}
+
+#[test]
+fn index_grid_points() {
+    let values = (0..12).map(|i| if i == 5 { f64::NAN } else { i as f64 }).collect::<Vec<f64>>();
+    let raw = MessageBuilder::new(Discipline::Oceanographic, 0, 3)
+        .reference_date(Utc.with_ymd_and_hms(2021, 3, 4, 12, 0, 0).unwrap())
+        .grid(LatLngGrid {
+            start: (40.0, 288.0),
+            end: (40.5, 288.75),
+            shape: (3, 4),
+        })
+        .data(values.clone())
+        .build()
+        .expect("failed to build grib message");
+
+    // Both before and after the data is unpacked
+    let message = Message::parse(&raw, 0).unwrap();
+    assert_eq!(message.shape(), Ok((3, 4)));
+    assert_eq!(message.value_at(2, 1), Ok(6.0));
+    assert!(message.value_at(1, 1).unwrap().is_nan());
+    assert_eq!(message.row(2), Ok(vec![8.0, 9.0, 10.0, 11.0]));
+    message.data_ref().unwrap();
+    assert_eq!(message.value_at(3, 2), Ok(11.0));
+    assert_eq!(message.row(0), Ok(vec![0.0, 1.0, 2.0, 3.0]));
+    assert!(message.value_at(4, 0).is_err());
+    assert!(message.row(3).is_err());
+
+    // Points scanned a column at a time, then rows running in alternating directions
+    let scanning_mode = gribberish::reader::scan(&raw)[0].section(3).unwrap().offset as usize + 71;
+    let mut by_column = raw.clone();
+    by_column[scanning_mode] = 0x20;
+    let message = Message::parse(&by_column, 0).unwrap();
+    assert_eq!(message.value_at(2, 1), Ok(7.0));
+    assert_eq!(message.row(0), Ok(vec![0.0, 3.0, 6.0, 9.0]));
+    let triples = message.data_with_locations().unwrap().collect::<Vec<_>>();
+    assert_eq!(triples.len(), 12);
+    assert_eq!(triples[7], (40.25, 288.5, 7.0));
+
+    let mut alternating = raw.clone();
+    alternating[scanning_mode] = 0x10;
+    let message = Message::parse(&alternating, 0).unwrap();
+    assert_eq!(message.value_at(0, 1), Ok(7.0));
+    assert_eq!(message.row(2), Ok(vec![8.0, 9.0, 10.0, 11.0]));
+
+    let region = Message::parse(&raw, 0).unwrap().data_in_region(40.25, 288.25, 40.5, 288.75).unwrap();
+    assert_eq!(region.shape, (2, 3));
+    assert_eq!(region.value_at(2, 1), Some(11.0));
+    assert_eq!(region.row(1), Some(&[9.0, 10.0, 11.0][..]));
+    assert_eq!(region.value_at(3, 0), None);
+    assert_eq!(region.row(2), None);
+
+    // The region is read in rows whatever order the grid is scanned in
+    let region = Message::parse(&by_column, 0).unwrap().data_in_region(40.25, 288.25, 40.5, 288.75).unwrap();
+    assert_eq!(region.shape, (2, 3));
+    assert_eq!(region.row(0), Some(&[4.0, 7.0, 10.0][..]));
+    assert_eq!(region.value_at(2, 1), Some(11.0));
+    assert!(region.value_at(0, 1).unwrap().is_nan());
+}
+
+#[test]
+fn locate_scanned_grid_points() {
+    let values = (0..12).map(|i| i as f64).collect::<Vec<f64>>();
+    let raw = MessageBuilder::new(Discipline::Oceanographic, 0, 3)
+        .reference_date(Utc.with_ymd_and_hms(2021, 3, 4, 12, 0, 0).unwrap())
+        .grid(LatLngGrid {
+            start: (40.0, 288.0),
+            end: (40.5, 288.75),
+            shape: (3, 4),
+        })
+        .data(values)
+        .build()
+        .expect("failed to build grib message");
+
+    // Every location follows the values in the order they are stored
+    let scanning_mode = gribberish::reader::scan(&raw)[0].section(3).unwrap().offset as usize + 71;
+    for flags in [0x20, 0x10, 0x30] {
+        let mut scanned = raw.clone();
+        scanned[scanning_mode] = flags;
+        let message = Message::parse(&scanned, 0).unwrap();
+        let locations = message.data_locations().unwrap();
+        let triples = message.data_with_locations().unwrap().collect::<Vec<_>>();
+        assert_eq!(locations, triples.iter().map(|(latitude, longitude, _)| (*latitude, *longitude)).collect::<Vec<_>>());
+    }
+
+    let mut by_column = raw.clone();
+    by_column[scanning_mode] = 0x20;
+    let message = Message::parse(&by_column, 0).unwrap();
+    assert_eq!(message.data_locations().unwrap()[7], (40.25, 288.5));
+    assert_eq!(message.data_locations().unwrap()[3], (40.0, 288.25));
+    assert_eq!(message.data_at_location(&(40.25, 288.5)), Ok(7.0));
+    assert_eq!(message.data_at_locations(&[(40.5, 288.0), (40.0, 288.25)]), Ok(vec![Some(2.0), Some(3.0)]));
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn decode_grid_arrays() {
+    let values = (0..12).map(|i| i as f64).collect::<Vec<f64>>();
+    let raw = MessageBuilder::new(Discipline::Oceanographic, 0, 3)
+        .reference_date(Utc.with_ymd_and_hms(2021, 3, 4, 12, 0, 0).unwrap())
+        .grid(LatLngGrid {
+            start: (40.0, 288.0),
+            end: (40.5, 288.75),
+            shape: (3, 4),
+        })
+        .data(values.clone())
+        .build()
+        .expect("failed to build grib message");
+
+    let message = Message::parse(&raw, 0).unwrap();
+    let array = message.data_array().unwrap();
+    assert_eq!(array.dim(), (3, 4));
+    assert_eq!(array[[1, 2]], values[6]);
+    assert_eq!(array.iter().copied().collect::<Vec<f64>>(), values);
+
+    let (latitudes, longitudes) = message.location_arrays().unwrap();
+    assert_eq!(latitudes.dim(), (3, 4));
+    assert_eq!(latitudes.column(0).to_vec(), message.latitudes().unwrap());
+    assert_eq!(longitudes.row(2).to_vec(), message.longitudes().unwrap());
+
+    // Points scanned a column at a time, then rows running in alternating directions
+    let scanning_mode = gribberish::reader::scan(&raw)[0].section(3).unwrap().offset as usize + 71;
+    let mut by_column = raw.clone();
+    by_column[scanning_mode] = 0x20;
+    let array = Message::parse(&by_column, 0).unwrap().data_array().unwrap();
+    assert_eq!(array[[1, 2]], values[2 * 3 + 1]);
+    assert_eq!(array.column(0).to_vec(), values[..3].to_vec());
+    let (latitudes, longitudes) = Message::parse(&by_column, 0).unwrap().location_arrays().unwrap();
+    assert_eq!(latitudes.column(3).to_vec(), vec![40.0, 40.25, 40.5]);
+    assert_eq!(longitudes.row(1).to_vec(), vec![288.0, 288.25, 288.5, 288.75]);
+
+    let mut alternating = raw.clone();
+    alternating[scanning_mode] = 0x10;
+    let array = Message::parse(&alternating, 0).unwrap().data_array().unwrap();
+    assert_eq!(array.row(0).to_vec(), vec![0.0, 1.0, 2.0, 3.0]);
+    assert_eq!(array.row(1).to_vec(), vec![7.0, 6.0, 5.0, 4.0]);
+}
+
+#[test]
+fn decode_into_buffer() {
+    let reference_date = Utc.with_ymd_and_hms(2021, 3, 4, 12, 0, 0).unwrap();
+    let values = (0..25)
+        .map(|i| if i % 4 == 1 { f64::NAN } else { -3.0 + i as f64 * 0.25 })
+        .collect::<Vec<f64>>();
+
+    // The buffer is reused and is larger than any one message
+    let mut buffer = vec![0.0; 32];
+    let mut buffer_f32 = vec![0.0f32; 32];
+    for packing in [DataRepresentationTemplateKind::SimplePacking, DataRepresentationTemplateKind::ComplexPacking] {
+        let raw = MessageBuilder::new(Discipline::Meteorological, 0, 0)
+            .reference_date(reference_date)
+            .grid(LatLngGrid {
+                start: (40.0, 288.0),
+                end: (41.0, 289.0),
+                shape: (5, 5),
+            })
+            .packing(packing)
+            .data(values.clone())
+            .build()
+            .expect("failed to build grib message");
+
+        let message = Message::parse(&raw, 0).unwrap();
+        let data = message.data().unwrap();
+
+        assert_eq!(message.data_into(&mut buffer), Ok(25));
+        assert_eq!(message.data_into_f32(&mut buffer_f32), Ok(25));
+        for i in 0..25 {
+            if data[i].is_nan() {
+                assert!(buffer[i].is_nan() && buffer_f32[i].is_nan());
+            } else {
+                assert_eq!(buffer[i], data[i]);
+                assert_eq!(buffer_f32[i], data[i] as f32);
+            }
+        }
+
+        assert!(message.data_into(&mut buffer[..24]).is_err());
+
+        let iterated = message.data_iter().unwrap().map(|v| v.to_bits()).collect::<Vec<u64>>();
+        assert_eq!(iterated, data.iter().map(|v| v.to_bits()).collect::<Vec<u64>>());
+        let data_f32 = message.data_f32().unwrap().iter().map(|v| v.to_bits()).collect::<Vec<u32>>();
+        assert_eq!(data_f32, buffer_f32[..25].iter().map(|v| v.to_bits()).collect::<Vec<u32>>());
+    }
+}
+
+#[test]
+fn keep_unknown_codes() {
+    let values = (0..16).map(|i| i as f64).collect::<Vec<f64>>();
+
+    // Neither temperature parameter 9 nor surface type 202 are in the tables
+    let raw = MessageBuilder::new(Discipline::Meteorological, 0, 9)
+        .reference_date(Utc.with_ymd_and_hms(2021, 3, 4, 0, 0, 0).unwrap())
+        .fixed_surface(FixedSurfaceTypes::Unknown(202), 1.0)
+        .grid(LatLngGrid {
+            start: (30.0, -80.0),
+            end: (31.5, -78.5),
+            shape: (4, 4),
+        })
+        .data(values.clone())
+        .build()
+        .expect("failed to build grib message");
+
+    let message = Message::parse(&raw, 0).expect("failed to parse built message");
+    assert_eq!(
+        message.parameter(),
+        Err(GribError::UnknownParameter { discipline: 0, category: 0, number: 9 })
+    );
+
+    let template = message.product_template().unwrap();
+    assert_eq!(template.first_fixed_surface_type(), FixedSurfaceTypes::Unknown(202));
+
+    let metadata = message.metadata().unwrap();
+    assert_eq!(metadata.variable_abbreviation, "var0_0_9");
+    assert_eq!(metadata.units, "unknown");
+    assert_eq!(message.data().unwrap(), values);
+}
+
+#[test]
+fn read_multi_field_message() {
+    let grid = LatLngGrid {
+        start: (30.0, -80.0),
+        end: (30.5, -79.5),
+        shape: (2, 2),
+    };
+    let build = |parameter: u8, values: Vec<f64>| {
+        message_builder(Discipline::Meteorological, 2, parameter, 0, grid)
+            .data(values)
+            .build()
+            .unwrap()
+    };
+
+    let u_values = vec![1.0, f64::NAN, 3.0, 4.0];
+    let v_values = vec![-1.0, f64::NAN, -3.0, -4.0];
+    let first = build(2, u_values.clone());
+    let second = build(3, v_values.clone());
+    let first_message = Message::parse(&first, 0).unwrap();
+    let second_message = Message::parse(&second, 0).unwrap();
+
+    // The second field repeats the product, data representation and data sections,
+    // and reuses the bitmap of the first field
+    let second_sections = second_message.section_bytes();
+    let mut raw = first[..first.len() - 4].to_vec();
+    raw.extend_from_slice(second_sections[3]);
+    raw.extend_from_slice(second_sections[4]);
+    raw.extend_from_slice(&[0, 0, 0, 6, 6, 254]);
+    raw.extend_from_slice(second_sections[6]);
+    raw.extend_from_slice(b"7777");
+    let total_length = raw.len() as u64;
+    raw[8..16].copy_from_slice(&total_length.to_be_bytes());
+
+    let message = Message::parse(&raw, 0).unwrap();
+    assert_eq!(message.section_count(), 12);
+    assert_eq!(first_message.fields().len(), 1);
+
+    let fields = message.fields();
+    assert_eq!(fields.len(), 2);
+    assert_eq!(fields[0].variable_abbrev().unwrap(), "UGRD");
+    assert_eq!(fields[1].variable_abbrev().unwrap(), "VGRD");
+    assert_eq!(fields[1].section_count(), 8);
+    assert_eq!(fields[1].section_bytes()[1], first_message.section_bytes()[1]);
+
+    for (field, values) in fields.iter().zip([u_values, v_values].iter()) {
+        let data = field.data().unwrap();
+        assert_eq!(data.len(), values.len());
+        for (decoded, value) in data.iter().zip(values.iter()) {
+            assert!((decoded.is_nan() && value.is_nan()) || decoded == value);
+        }
+    }
+
+    // A field can be written out as a message of its own
+    let repacked = fields[1].repack(DataRepresentationTemplateKind::SimplePacking).unwrap();
+    let single = Message::parse(&repacked, 0).unwrap();
+    assert_eq!(single.variable_abbrev().unwrap(), "VGRD");
+    assert_eq!(single.section_count(), 8);
+
+    // Repacking the whole message packs every field with its own values
+    let repacked = message.repack(DataRepresentationTemplateKind::ComplexPacking).unwrap();
+    let repacked = Message::parse(&repacked, 0).unwrap();
+    assert_eq!(repacked.section_count(), 12);
+    for (field, original) in repacked.fields().iter().zip(fields.iter()) {
+        let (data, original) = (field.data().unwrap(), original.data().unwrap());
+        for (decoded, value) in data.iter().zip(original.iter()) {
+            assert!((decoded.is_nan() && value.is_nan()) || (decoded - value).abs() < 1e-6);
+        }
+    }
+}
+
+#[test]
+fn inventory_multi_field_message() {
+    let grid = LatLngGrid {
+        start: (30.0, -80.0),
+        end: (30.5, -79.5),
+        shape: (2, 2),
+    };
+    let build = |parameter: u8| {
+        message_builder(Discipline::Meteorological, 2, parameter, 0, grid)
+            .data(vec![1.0, 2.0, 3.0, 4.0])
+            .build()
+            .unwrap()
+    };
+
+    let leading = common::build_message(500.0, 0, grid, vec![270.0, 271.0, 272.0, 273.0]);
+    let second = build(3);
+    let second_sections = Message::parse(&second, 0).unwrap().section_bytes();
+
+    // The second message holds both wind components, repeating sections 4 to 7
+    let first = build(2);
+    let mut raw = first[..first.len() - 4].to_vec();
+    for section in &second_sections[3..7] {
+        raw.extend_from_slice(section);
+    }
+    raw.extend_from_slice(b"7777");
+    let total_length = raw.len() as u64;
+    raw[8..16].copy_from_slice(&total_length.to_be_bytes());
+
+    let mut data = leading.clone();
+    data.extend_from_slice(&raw);
+    let inventory = generate_idx(Cursor::new(data)).unwrap();
+    let records = parse_idx(&inventory).unwrap();
+
+    let numbers = records.iter().map(|r| r.message_number.as_str()).collect::<Vec<&str>>();
+    assert_eq!(numbers, vec!["1", "2.1", "2.2"]);
+    assert_eq!(records[1].variable, "UGRD");
+    assert_eq!(records[2].variable, "VGRD");
+    assert_eq!(records[1].offset, leading.len() as u64);
+    assert_eq!(records[2].offset, leading.len() as u64);
+}
+
+#[test]
+fn apply_missing_value_policy() {
+    let values = vec![1.5, f64::NAN, 3.0, f64::NAN, 5.25, 6.0];
+    let raw = MessageBuilder::new(Discipline::Oceanographic, 0, 3)
+        .reference_date(Utc.with_ymd_and_hms(2021, 3, 4, 12, 0, 0).unwrap())
+        .grid(LatLngGrid {
+            start: (40.0, 288.0),
+            end: (40.25, 288.5),
+            shape: (2, 3),
+        })
+        .data(values)
+        .build()
+        .expect("failed to build grib message");
+    let message = Message::parse(&raw, 0).unwrap();
+
+    let expected = [Some(1.5), None, Some(3.0), None, Some(5.25), Some(6.0)];
+    assert_eq!(message.data_options().unwrap(), expected);
+
+    let sentinel = MissingValue::Sentinel(-9999.0);
+    let filled = expected.iter().map(|value| value.unwrap_or(-9999.0)).collect::<Vec<f64>>();
+    assert_eq!(message.data_with(sentinel).unwrap(), filled);
+    assert_eq!(message.data_iter_with(sentinel).unwrap().collect::<Vec<f64>>(), filled);
+    assert_eq!(message.masked_data().unwrap().expand_with(sentinel), filled);
+
+    let mut buffer = vec![0.0; 8];
+    assert_eq!(message.data_into_with(&mut buffer, sentinel), Ok(6));
+    assert_eq!(buffer[..6], filled[..]);
+
+    // NaN stays the default
+    let nan = message.data_with(MissingValue::Nan).unwrap();
+    assert!(nan[1].is_nan() && nan[3].is_nan());
+    assert_eq!(nan[4], 5.25);
+}
+
+// The serialized form is what inventories written by one version are read back with by the
+// next, so changes to it should be deliberate
+#[cfg(feature = "serde")]
+#[test]
+fn serialize_metadata_schema() {
+    let reference_date = Utc.with_ymd_and_hms(2021, 3, 4, 12, 0, 0).unwrap();
+    let raw = MessageBuilder::new(Discipline::Meteorological, 0, 0)
+        .center(Center::NCEP, 0)
+        .reference_date(reference_date)
+        .forecast_date(reference_date + chrono::Duration::hours(6))
+        .grid(LatLngGrid {
+            start: (30.0, 280.0),
+            end: (31.0, 281.0),
+            shape: (3, 3),
+        })
+        .data(vec![280.0; 9])
+        .build()
+        .expect("failed to build grib message");
+    let metadata = Message::parse(&raw, 0).unwrap().metadata().unwrap();
+
+    let json = serde_json::to_string(&metadata).unwrap();
+    assert_eq!(
+        json,
+        concat!(
+            r#"{"discipline":"Meteorological","center":"NCEP","subcenter":0,"#,
+            r#""reference_date":"2021-03-04T12:00:00Z","forecast_date":"2021-03-04T18:00:00Z","#,
+            r#""variable_name":"temperature","variable_abbreviation":"TMP","#,
+            r#""bounds":{"north":31.0,"south":30.0,"east":281.0,"west":280.0},"#,
+            r#""location_grid":[3,3],"location_resolution":[0.5,0.5],"units":"K","#,
+            r#""data_template_number":0,"data_point_count":9}"#
+        )
+    );
+    assert_eq!(serde_json::from_str::<gribberish::message::MessageMetadata>(&json).unwrap(), metadata);
+
+    // Codes outside the tables keep their number
+    let parameter = Message::parse(&raw, 0).unwrap().parameter().unwrap();
+    assert_eq!(
+        serde_json::to_string(&parameter).unwrap(),
+        r#"{"name":"temperature","unit":"K","abbrev":"TMP"}"#
+    );
+    assert_eq!(serde_json::to_string(&FixedSurfaceTypes::IsobaricSurface).unwrap(), r#""IsobaricSurface""#);
+    assert_eq!(serde_json::to_string(&FixedSurfaceTypes::Unknown(202)).unwrap(), r#"{"Unknown":202}"#);
+    assert_eq!(serde_json::from_str::<Center>(r#"{"Unknown":250}"#).unwrap(), Center::Unknown(250));
+}
+
+#[test]
+fn summarize_field_statistics() {
+    let values = vec![1.5, f64::NAN, 3.0, f64::NAN, 5.25, 6.0];
+    let raw = MessageBuilder::new(Discipline::Oceanographic, 0, 3)
+        .reference_date(Utc.with_ymd_and_hms(2021, 3, 4, 12, 0, 0).unwrap())
+        .grid(LatLngGrid {
+            start: (40.0, 288.0),
+            end: (40.25, 288.5),
+            shape: (2, 3),
+        })
+        .data(values)
+        .build()
+        .expect("failed to build grib message");
+    let message = Message::parse(&raw, 0).unwrap();
+
+    let statistics = message.statistics().unwrap();
+    assert_eq!((statistics.count, statistics.missing_count), (4, 2));
+    assert!((statistics.min - 1.5).abs() < 1e-3 && (statistics.max - 6.0).abs() < 1e-3);
+    let mean = (1.5 + 3.0 + 5.25 + 6.0) / 4.0;
+    let variance = [1.5f64, 3.0, 5.25, 6.0].iter().map(|value| (value - mean).powi(2)).sum::<f64>() / 4.0;
+    assert!((statistics.mean - mean).abs() < 1e-3);
+    assert!((statistics.std_dev - variance.sqrt()).abs() < 1e-3);
+
+    // Decoded data gives the same figures
+    message.data_ref().unwrap();
+    assert_eq!(message.statistics().unwrap(), statistics);
+
+    let empty = FieldStatistics::from_values(vec![f64::NAN; 3]);
+    assert_eq!((empty.count, empty.missing_count), (0, 3));
+    assert!(empty.mean.is_nan() && empty.std_dev.is_nan());
+}
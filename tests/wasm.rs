@@ -0,0 +1,39 @@
+#![cfg(feature = "wasm")]
+
+extern crate gribberish;
+
+mod common;
+
+use common::build_message;
+use gribberish::encoding::LatLngGrid;
+
+#[test]
+fn wasm_message_list() {
+    let grid = LatLngGrid {
+        start: (40.0, 288.0),
+        end: (41.0, 290.0),
+        shape: (2, 3),
+    };
+    let build = |level: f64, forecast_hours: i64| {
+        build_message(level, forecast_hours, grid, vec![250.0, 251.0, 252.0, 253.0, f64::NAN, 255.0])
+    };
+    let first = build(85_000.0, 0);
+    let grib_data = [first.clone(), build(50_000.0, 6)].concat();
+
+    let messages = gribberish::wasm::parse_messages(&grib_data);
+    assert_eq!(messages.len(), 2);
+    let message = &messages[1];
+    assert_eq!((message.offset(), message.length()), (first.len(), grib_data.len() - first.len()));
+    assert_eq!(message.abbreviation().ok().unwrap(), "TMP");
+    assert_eq!(message.units().ok().unwrap(), "K");
+    assert_eq!(message.level().ok().unwrap(), "500 mb");
+    assert_eq!(message.forecast().ok().unwrap(), "6 hour fcst");
+    assert_eq!(message.forecast_date().ok().unwrap(), "2021-03-04T18:00:00+00:00");
+    assert_eq!(message.shape().ok().unwrap(), vec![2, 3]);
+
+    let data = message.data().ok().unwrap();
+    assert_eq!(data.len(), 6);
+    assert_eq!(data[0], 250.0);
+    assert!(data[4].is_nan());
+    assert_eq!(message.latitudes().ok().unwrap()[3], 41.0);
+}
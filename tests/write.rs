@@ -0,0 +1,351 @@
+extern crate gribberish;
+
+use chrono::{TimeZone, Utc};
+use gribberish::encoding::{split, DataRepresentationTemplateKind, GribWriter, LatLngGrid, MessageBuilder};
+use gribberish::error::GribError;
+use gribberish::message::Message;
+use gribberish::sections::{identification::Center, indicator::Discipline};
+use gribberish::templates::grid::LatLngBounds;
+use gribberish::templates::template::TemplateType;
+use gribberish::templates::product::tables::{EnsembleForecastType, FixedSurfaceTypes, StatisticalProcess};
+
+#[test]
+fn write_simple_packed_message() {
+    let reference_date = Utc.with_ymd_and_hms(2021, 3, 4, 12, 0, 0).unwrap();
+    let forecast_date = Utc.with_ymd_and_hms(2021, 3, 4, 18, 0, 0).unwrap();
+    let values = (0..49).map(|i| 270.0 + i as f64 * 0.37).collect::<Vec<f64>>();
+
+    let raw = MessageBuilder::new(Discipline::Meteorological, 0, 0)
+        .center(Center::NCEP, 0)
+        .reference_date(reference_date)
+        .forecast_date(forecast_date)
+        .fixed_surface(FixedSurfaceTypes::SpecifiedHeightLevelAboveGround, 2.0)
+        .grid(LatLngGrid {
+            start: (30.0, -80.0),
+            end: (33.0, -77.0),
+            shape: (7, 7),
+        })
+        .data(values.clone())
+        .build()
+        .expect("failed to build grib message");
+
+    let message = Message::parse(&raw, 0).expect("failed to parse built message");
+    assert_eq!(message.len(), raw.len());
+    assert_eq!(message.section_count(), 8);
+
+    let metadata = message.metadata().unwrap();
+    assert_eq!(metadata.discipline, Discipline::Meteorological);
+    assert_eq!(metadata.center, Center::NCEP);
+    assert_eq!(metadata.variable_abbreviation, "TMP");
+    assert_eq!(metadata.reference_date, reference_date);
+    assert_eq!(metadata.forecast_date, forecast_date);
+    assert_eq!(metadata.location_grid, (7, 7));
+    assert_eq!(metadata.bounds, LatLngBounds { north: 33.0, south: 30.0, east: 283.0, west: 280.0 });
+    assert_eq!(metadata.location_resolution, (0.5, 0.5));
+    assert_eq!(metadata.data_point_count, 49);
+
+    let data = message.data().unwrap();
+    assert_eq!(data.len(), values.len());
+    for (decoded, value) in data.iter().zip(values.iter()) {
+        assert!((decoded - value).abs() < 0.001);
+    }
+
+    // Converted from the parameter's unit of kelvin
+    let celsius = message.data_in_units("degC").unwrap();
+    assert!((celsius[0] - (values[0] - 273.15)).abs() < 0.001);
+    assert_eq!(
+        message.data_in_units("knots"),
+        Err(GribError::UnitConversion { from: "K".into(), to: "knots".into() })
+    );
+}
+
+#[test]
+fn write_pinned_decimal_places() {
+    let reference_date = Utc.with_ymd_and_hms(2021, 3, 4, 12, 0, 0).unwrap();
+    let values = (0..12).map(|i| 101325.0 + i as f64 * 12.345).collect::<Vec<f64>>();
+
+    let raw = MessageBuilder::new(Discipline::Meteorological, 3, 0)
+        .reference_date(reference_date)
+        .grid(LatLngGrid {
+            start: (40.0, 10.0),
+            end: (41.0, 11.5),
+            shape: (3, 4),
+        })
+        .decimal_places(1)
+        .data(values.clone())
+        .build()
+        .expect("failed to build grib message");
+
+    let message = Message::parse(&raw, 0).expect("failed to parse built message");
+    assert_eq!(message.variable_abbrev().unwrap(), "PRES");
+
+    let data = message.data().unwrap();
+    for (decoded, value) in data.iter().zip(values.iter()) {
+        assert!((decoded - value).abs() <= 0.05 + 1e-6);
+    }
+}
+
+#[test]
+fn write_complex_packed_message() {
+    let reference_date = Utc.with_ymd_and_hms(2021, 3, 4, 12, 0, 0).unwrap();
+    let values = (0..400)
+        .map(|i| 5.0 + ((i / 20) as f64 * 0.3).cos() * 2.5 + (i % 20) as f64 * 0.125)
+        .collect::<Vec<f64>>();
+
+    let raw = MessageBuilder::new(Discipline::Meteorological, 2, 2)
+        .reference_date(reference_date)
+        .grid(LatLngGrid {
+            start: (20.0, 230.0),
+            end: (29.5, 239.5),
+            shape: (20, 20),
+        })
+        .packing(DataRepresentationTemplateKind::ComplexPacking)
+        .decimal_places(2)
+        .data(values.clone())
+        .build()
+        .expect("failed to build grib message");
+
+    let message = Message::parse(&raw, 0).expect("failed to parse built message");
+    let metadata = message.metadata().unwrap();
+    assert_eq!(metadata.variable_abbreviation, "UGRD");
+    assert_eq!(metadata.data_template_number, 3);
+
+    let data = message.data().unwrap();
+    assert_eq!(data.len(), values.len());
+    for (decoded, value) in data.iter().zip(values.iter()) {
+        assert!((decoded - value).abs() <= 0.005 + 1e-6);
+    }
+}
+
+#[test]
+fn write_missing_values_as_bitmap() {
+    let reference_date = Utc.with_ymd_and_hms(2021, 3, 4, 12, 0, 0).unwrap();
+    let values = (0..25)
+        .map(|i| if i % 3 == 0 { f64::NAN } else { 10.0 + i as f64 * 0.5 })
+        .collect::<Vec<f64>>();
+
+    for packing in [DataRepresentationTemplateKind::SimplePacking, DataRepresentationTemplateKind::ComplexPacking] {
+        let raw = MessageBuilder::new(Discipline::Oceanographic, 0, 3)
+            .reference_date(reference_date)
+            .grid(LatLngGrid {
+                start: (40.0, 288.0),
+                end: (41.0, 289.0),
+                shape: (5, 5),
+            })
+            .packing(packing)
+            .data(values.clone())
+            .build()
+            .expect("failed to build grib message");
+
+        let message = Message::parse(&raw, 0).expect("failed to parse built message");
+        let data = message.data().unwrap();
+        assert_eq!(data.len(), values.len());
+        for (decoded, value) in data.iter().zip(values.iter()) {
+            if value.is_nan() {
+                assert!(decoded.is_nan());
+            } else {
+                assert!((decoded - value).abs() < 1e-6);
+            }
+        }
+
+        let masked = message.masked_data().unwrap();
+        assert_eq!(masked.values.len(), values.iter().filter(|v| !v.is_nan()).count());
+        assert_eq!(masked.mask.len(), values.len());
+        assert_eq!(masked.iter().next(), Some(None));
+        let expanded = masked.expand().iter().map(|v| v.to_bits()).collect::<Vec<u64>>();
+        assert_eq!(expanded, data.iter().map(|v| v.to_bits()).collect::<Vec<u64>>());
+
+        assert!(message.data_at_location(&(40.0, 288.0)).is_err());
+        assert!((message.data_at_location(&(40.0, 288.25)).unwrap() - 10.5).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn repack_message() {
+    let grib_data = std::fs::read("tests/data/multi_1.at_10m.t12z.f147.grib2").expect("file not found");
+    let messages = Message::parse_all(grib_data.as_slice());
+    let message = messages.first().unwrap();
+    let original = message.data().unwrap();
+
+    for target in [DataRepresentationTemplateKind::SimplePacking, DataRepresentationTemplateKind::ComplexPacking] {
+        let raw = message.repack(target).expect("failed to repack message");
+        let repacked = Message::parse(&raw, 0).expect("failed to parse repacked message");
+
+        assert_eq!(repacked.len(), raw.len());
+        assert_eq!(repacked.variable_abbrev().unwrap(), message.variable_abbrev().unwrap());
+        assert_eq!(repacked.metadata().unwrap().data_template_number, target.template_number());
+
+        let data = repacked.data().unwrap();
+        assert_eq!(data.len(), original.len());
+        for (repacked, original) in data.iter().zip(original.iter()) {
+            if original.is_nan() {
+                assert!(repacked.is_nan());
+            } else {
+                assert!((repacked - original).abs() < 1e-3);
+            }
+        }
+    }
+}
+
+#[test]
+fn crop_message() {
+    let grib_data = std::fs::read("tests/data/multi_1.at_10m.t12z.f147.grib2").expect("file not found");
+    let messages = Message::parse_all(grib_data.as_slice());
+    let message = messages.first().unwrap();
+
+    let raw = message.crop((41.3, -71.7), (41.7, -71.3)).expect("failed to crop message");
+    let cropped = Message::parse(&raw, 0).expect("failed to parse cropped message");
+    assert_eq!(cropped.len(), raw.len());
+    assert_eq!(cropped.variable_abbrev().unwrap(), message.variable_abbrev().unwrap());
+
+    let metadata = cropped.metadata().unwrap();
+    assert_eq!(metadata.location_grid, (3, 3));
+
+    let data = cropped.data().unwrap();
+    let locations = cropped.data_locations().unwrap();
+    assert_eq!(data.len(), 9);
+
+    let original_data = message.data().unwrap();
+    let original_locations = message.data_locations().unwrap();
+    for (value, location) in data.iter().zip(locations.iter()) {
+        let index = original_locations
+            .iter()
+            .position(|l| (l.0 - location.0).abs() < 1e-4 && (l.1 - location.1).abs() < 1e-4)
+            .unwrap();
+        let original = original_data[index];
+        if original.is_nan() {
+            assert!(value.is_nan());
+        } else {
+            assert!((value - original).abs() < 1e-3);
+        }
+    }
+
+    assert!(message.crop((10.0, 0.0), (11.0, 1.0)).is_err());
+}
+
+#[test]
+fn crop_scanned_message() {
+    let values = (0..12).map(|i| i as f64).collect::<Vec<f64>>();
+    let raw = MessageBuilder::new(Discipline::Oceanographic, 0, 3)
+        .reference_date(Utc.with_ymd_and_hms(2021, 3, 4, 12, 0, 0).unwrap())
+        .grid(LatLngGrid {
+            start: (40.0, 288.0),
+            end: (40.5, 288.75),
+            shape: (3, 4),
+        })
+        .data(values)
+        .build()
+        .expect("failed to build grib message");
+    let section = gribberish::reader::scan(&raw)[0].section(3).unwrap().offset as usize;
+
+    // The crop is written row by row with the values of the same points
+    for flags in [0x60, 0x50, 0x70] {
+        let mut scanned = raw.clone();
+        scanned[section + 71] = flags;
+        let message = Message::parse(&scanned, 0).unwrap();
+        let cropped = message.crop((40.25, 288.25), (40.5, 288.75)).expect("failed to crop message");
+        let cropped = Message::parse(&cropped, 0).unwrap();
+        assert_eq!(cropped.shape(), Ok((2, 3)));
+        for j in 0..2 {
+            for i in 0..3 {
+                assert_eq!(cropped.value_at(i, j), message.value_at(i + 1, j + 1));
+            }
+        }
+    }
+
+    // Only latitude longitude grids can be rebuilt
+    let mut space_view = raw.clone();
+    space_view[section + 12..section + 14].copy_from_slice(&90u16.to_be_bytes());
+    assert_eq!(
+        Message::parse(&space_view, 0).unwrap().crop((40.25, 288.25), (40.5, 288.75)),
+        Err(GribError::UnsupportedTemplate { kind: TemplateType::Grid, number: 90 })
+    );
+}
+
+#[test]
+fn write_and_split_messages() {
+    let grib_data = std::fs::read("tests/data/multi_1.at_10m.t12z.f147.grib2").expect("file not found");
+    let messages = Message::parse_all(grib_data.as_slice());
+    assert!(messages.len() > 1);
+
+    // Unmodified messages are passed through byte for byte
+    let mut writer = GribWriter::new(Vec::new());
+    for message in &messages {
+        writer.write_message(message).unwrap();
+    }
+    assert_eq!(writer.message_count(), messages.len());
+    assert_eq!(writer.finish().unwrap(), grib_data);
+
+    let mut writer = GribWriter::new(Vec::new());
+    assert!(writer.write_raw(&grib_data[0..100]).is_err());
+    writer.write_raw(&messages[0].crop((41.3, -71.7), (41.7, -71.3)).unwrap()).unwrap();
+    let written = writer.finish().unwrap();
+    assert_eq!(Message::parse_all(written.as_slice()).len(), 1);
+
+    let directory = std::env::temp_dir().join(format!("gribberish-split-{}", std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+
+    let paths = split(&messages, |index, _| match index {
+        0 => None,
+        _ => Some(directory.join(format!("{}.grib2", index % 2))),
+    })
+    .unwrap();
+    assert_eq!(paths.len(), 2);
+
+    let split_counts = paths
+        .iter()
+        .map(|path| {
+            let data = std::fs::read(path).unwrap();
+            Message::parse_all(data.as_slice()).len()
+        })
+        .sum::<usize>();
+    assert_eq!(split_counts, messages.len() - 1);
+
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn write_ensemble_accumulation() {
+    let reference_date = Utc.with_ymd_and_hms(2021, 3, 4, 0, 0, 0).unwrap();
+    let forecast_date = Utc.with_ymd_and_hms(2021, 3, 4, 12, 0, 0).unwrap();
+    let values = (0..16).map(|i| i as f64 * 0.25).collect::<Vec<f64>>();
+
+    let raw = MessageBuilder::new(Discipline::Meteorological, 1, 8)
+        .center(Center::NCEP, 2)
+        .reference_date(reference_date)
+        .forecast_date(forecast_date)
+        .ensemble_member(EnsembleForecastType::PositivelyPerturbed, 4, 30)
+        .statistical_process(StatisticalProcess::Accumulation, chrono::Duration::hours(6))
+        .grid(LatLngGrid {
+            start: (30.0, -80.0),
+            end: (31.5, -78.5),
+            shape: (4, 4),
+        })
+        .data(values.clone())
+        .build()
+        .expect("failed to build grib message");
+
+    let message = Message::parse(&raw, 0).expect("failed to parse built message");
+    let product_definition = message.section_bytes()[3];
+    assert_eq!(&product_definition[7..9], &11u16.to_be_bytes());
+    assert_eq!(product_definition.len(), 61);
+
+    // The forecast time marks the start of the accumulation
+    let metadata = message.metadata().unwrap();
+    assert_eq!(metadata.variable_abbreviation, "APCP");
+    assert_eq!(metadata.forecast_date, Utc.with_ymd_and_hms(2021, 3, 4, 6, 0, 0).unwrap());
+    assert_eq!(message.data().unwrap(), values);
+
+    let invalid = MessageBuilder::new(Discipline::Meteorological, 1, 8)
+        .reference_date(reference_date)
+        .forecast_date(forecast_date)
+        .statistical_process(StatisticalProcess::Accumulation, chrono::Duration::hours(24))
+        .grid(LatLngGrid {
+            start: (30.0, -80.0),
+            end: (31.5, -78.5),
+            shape: (4, 4),
+        })
+        .data(values)
+        .build();
+    assert!(invalid.is_err());
+}
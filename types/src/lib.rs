@@ -1,6 +1,75 @@
+pub mod parameters;
 
+use parameters::ParameterEntry;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Parameter {
     pub name: String,
     pub unit: String,
     pub abbrev: String,
-}
\ No newline at end of file
+}
+
+impl Parameter {
+    /// Finds a parameter in the WMO master tables
+    pub fn lookup(discipline: u8, category: u8, number: u8) -> Option<Parameter> {
+        Parameter::find(None, discipline, category, number)
+    }
+
+    /// Finds a parameter in the local tables of the given originating center
+    pub fn lookup_local(center: u16, discipline: u8, category: u8, number: u8) -> Option<Parameter> {
+        Parameter::find(Some(center), discipline, category, number)
+    }
+
+    /// Finds the first entry with the given abbreviation, preferring the WMO master tables
+    /// over the center local tables
+    pub fn from_abbrev(abbrev: &str) -> Option<&'static ParameterEntry> {
+        parameters::table()
+            .iter()
+            .find(|entry| entry.parameter.abbrev == abbrev)
+    }
+
+    fn find(center: Option<u16>, discipline: u8, category: u8, number: u8) -> Option<Parameter> {
+        parameters::table()
+            .iter()
+            .find(|entry| {
+                entry.center == center
+                    && entry.discipline == discipline
+                    && entry.category == category
+                    && entry.number == number
+            })
+            .map(|entry| entry.parameter.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Parameter;
+
+    #[test]
+    fn lookup_by_triple() {
+        let parameter = Parameter::lookup(0, 0, 0).unwrap();
+        assert_eq!(parameter.abbrev, "TMP");
+        assert_eq!(parameter.unit, "K");
+
+        assert!(Parameter::lookup(0, 1, 192).is_none());
+        assert_eq!(Parameter::lookup_local(7, 0, 1, 192).unwrap().abbrev, "CRAIN");
+
+        // Numbers missing from a category are not mistaken for another parameter
+        assert!(crate::parameters::meteorological::parameter(0, 9).is_none());
+    }
+
+    #[test]
+    fn lookup_by_abbrev() {
+        let entry = Parameter::from_abbrev("TMP").unwrap();
+        assert_eq!((entry.center, entry.discipline, entry.category, entry.number), (None, 0, 0, 0));
+
+        let entry = Parameter::from_abbrev("CRAIN").unwrap();
+        assert_eq!((entry.center, entry.discipline, entry.category, entry.number), (None, 0, 1, 33));
+
+        let entry = Parameter::from_abbrev("TIPD").unwrap();
+        assert_eq!((entry.center, entry.discipline, entry.category, entry.number), (Some(7), 0, 1, 206));
+
+        assert!(Parameter::from_abbrev("NOTAPARAMETER").is_none());
+    }
+}
@@ -0,0 +1,181 @@
+use gribberish_macros::{DisplayDescription, FromValue, ToParameter};
+use crate::Parameter;
+use super::ParameterTable;
+
+pub const DISCIPLINE: u8 = 1;
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum HydrologyBasicProduct {
+	#[description = "flash flood guidance"]
+	#[abbrev = "FFLDG"]
+	#[unit = "kgm-2"]
+	FlashFloodGuidance = 0,
+	#[description = "flash flood runoff"]
+	#[abbrev = "FFLDRO"]
+	#[unit = "kgm-2"]
+	FlashFloodRunoff = 1,
+	#[description = "remotely sensed snow cover"]
+	#[abbrev = "RSSC"]
+	#[unit = "-"]
+	RemotelySensedSnowCover = 2,
+	#[description = "elevation of snow covered terrain"]
+	#[abbrev = "ESCT"]
+	#[unit = "-"]
+	SnowCoveredTerrainElevation = 3,
+	#[description = "snow water equivalent percent of normal"]
+	#[abbrev = "SWEPON"]
+	#[unit = "%"]
+	SnowWaterEquivalentPercentOfNormal = 4,
+	#[description = "baseflow-groundwater runoff"]
+	#[abbrev = "BGRUN"]
+	#[unit = "kgm-2"]
+	BaseflowGroundwaterRunoff = 5,
+	#[description = "storm surface runoff"]
+	#[abbrev = "SSRUN"]
+	#[unit = "kgm-2"]
+	StormSurfaceRunoff = 6,
+	#[description = "discharge from rivers or streams"]
+	#[abbrev = "DISRS"]
+	#[unit = "m3s-1"]
+	RiverDischarge = 7,
+	#[description = "groundwater upper storage"]
+	#[abbrev = "GWUPS"]
+	#[unit = "kgm-2"]
+	GroundwaterUpperStorage = 8,
+	#[description = "groundwater lower storage"]
+	#[abbrev = "GWLOWS"]
+	#[unit = "kgm-2"]
+	GroundwaterLowerStorage = 9,
+	#[description = "side flow into river channel"]
+	#[abbrev = "SFLORC"]
+	#[unit = "m3s-1m-1"]
+	RiverChannelSideFlow = 10,
+	#[description = "river storage of water"]
+	#[abbrev = "RVERSW"]
+	#[unit = "m3"]
+	RiverWaterStorage = 11,
+	#[description = "flood plain storage of water"]
+	#[abbrev = "FLDPSW"]
+	#[unit = "m3"]
+	FloodPlainWaterStorage = 12,
+	#[description = "depth of water on soil surface"]
+	#[abbrev = "DEPWSS"]
+	#[unit = "kgm-2"]
+	SoilSurfaceWaterDepth = 13,
+	#[description = "upstream accumulated precipitation"]
+	#[abbrev = "UPAPCP"]
+	#[unit = "kgm-2"]
+	UpstreamAccumulatedPrecipitation = 14,
+	#[description = "upstream accumulated snow melt"]
+	#[abbrev = "UPASM"]
+	#[unit = "kgm-2"]
+	UpstreamAccumulatedSnowMelt = 15,
+	#[description = "percolation rate"]
+	#[abbrev = "PERRATE"]
+	#[unit = "kgm-2s-1"]
+	PercolationRate = 16,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum HydrologyProbabilityProduct {
+	#[description = "conditional percent precipitation amount fractile for an overall period"]
+	#[abbrev = "CPPOP"]
+	#[unit = "kgm-2"]
+	ConditionalPrecipitationFractile = 0,
+	#[description = "percent precipitation in a sub-period of an overall period"]
+	#[abbrev = "PPOSP"]
+	#[unit = "%"]
+	SubPeriodPrecipitationPercent = 1,
+	#[description = "probability of 0.01 inch of precipitation (POP)"]
+	#[abbrev = "POP"]
+	#[unit = "%"]
+	PrecipitationProbability = 2,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum InlandWaterProduct {
+	#[description = "water depth"]
+	#[abbrev = "WDPTHIL"]
+	#[unit = "m"]
+	WaterDepth = 0,
+	#[description = "water temperature"]
+	#[abbrev = "WTMPIL"]
+	#[unit = "K"]
+	WaterTemperature = 1,
+	#[description = "water fraction"]
+	#[abbrev = "WFRACT"]
+	#[unit = "proportion"]
+	WaterFraction = 2,
+	#[description = "sediment thickness"]
+	#[abbrev = "SEDTK"]
+	#[unit = "m"]
+	SedimentThickness = 3,
+	#[description = "sediment temperature"]
+	#[abbrev = "SEDTMP"]
+	#[unit = "K"]
+	SedimentTemperature = 4,
+	#[description = "ice thickness"]
+	#[abbrev = "ICTKIL"]
+	#[unit = "m"]
+	IceThickness = 5,
+	#[description = "ice temperature"]
+	#[abbrev = "ICETIL"]
+	#[unit = "K"]
+	IceTemperature = 6,
+	#[description = "ice cover"]
+	#[abbrev = "ICECIL"]
+	#[unit = "proportion"]
+	IceCover = 7,
+	#[description = "land cover"]
+	#[abbrev = "LANDIL"]
+	#[unit = "proportion"]
+	LandCover = 8,
+	#[description = "shape factor with respect to salinity profile"]
+	#[abbrev = "SFSAL"]
+	#[unit = "-"]
+	SalinityProfileShapeFactor = 9,
+	#[description = "shape factor with respect to temperature profile in thermocline"]
+	#[abbrev = "SFTMP"]
+	#[unit = "-"]
+	ThermoclineTemperatureShapeFactor = 10,
+	#[description = "attenuation coefficient of water with respect to solar radiation"]
+	#[abbrev = "ACWSR"]
+	#[unit = "m-1"]
+	SolarRadiationAttenuationCoefficient = 11,
+	#[abbrev = "SALTIL"]
+	#[unit = "kgkg-1"]
+	Salinity = 12,
+	#[description = "cross-sectional area of flow in channel"]
+	#[abbrev = "CSAFC"]
+	#[unit = "m2"]
+	ChannelFlowCrossSectionalArea = 13,
+}
+
+pub fn parameter(category: u8, parameter: u8) -> Option<Parameter> {
+	match category {
+		0 => HydrologyBasicProduct::from_value(parameter).map(Parameter::from),
+		1 => HydrologyProbabilityProduct::from_value(parameter).map(Parameter::from),
+		2 => InlandWaterProduct::from_value(parameter).map(Parameter::from),
+		_ => None,
+	}
+}
+
+pub fn table() -> Vec<(u8, ParameterTable)> {
+	vec![
+		(0, HydrologyBasicProduct::parameters()),
+		(1, HydrologyProbabilityProduct::parameters()),
+		(2, InlandWaterProduct::parameters()),
+	]
+}
+
+pub fn category(category: u8) -> &'static str {
+	match category {
+		0 => "hydrology basic",
+		1 => "hydrology probabilities",
+		2 => "inland water and sediment properties",
+		_ => "other",
+	}
+}
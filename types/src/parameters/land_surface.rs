@@ -0,0 +1,377 @@
+use gribberish_macros::{DisplayDescription, FromValue, ToParameter};
+use crate::Parameter;
+use super::ParameterTable;
+
+pub const DISCIPLINE: u8 = 2;
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum VegetationProduct {
+	#[description = "land cover"]
+	#[abbrev = "LAND"]
+	#[unit = "proportion"]
+	LandCover = 0,
+	#[description = "surface roughness"]
+	#[abbrev = "SFCR"]
+	#[unit = "m"]
+	SurfaceRoughness = 1,
+	#[description = "soil temperature"]
+	#[abbrev = "TSOIL"]
+	#[unit = "K"]
+	SoilTemperature = 2,
+	#[description = "soil moisture content"]
+	#[abbrev = "SOILM"]
+	#[unit = "kgm-2"]
+	SoilMoistureContent = 3,
+	#[abbrev = "VEG"]
+	#[unit = "%"]
+	Vegetation = 4,
+	#[description = "water runoff"]
+	#[abbrev = "WATR"]
+	#[unit = "kgm-2"]
+	WaterRunoff = 5,
+	#[abbrev = "EVAPT"]
+	#[unit = "kgm-2s-1"]
+	Evapotranspiration = 6,
+	#[description = "model terrain height"]
+	#[abbrev = "MTERH"]
+	#[unit = "m"]
+	ModelTerrainHeight = 7,
+	#[description = "land use"]
+	#[abbrev = "LANDU"]
+	#[unit = "-"]
+	LandUse = 8,
+	#[description = "volumetric soil moisture content"]
+	#[abbrev = "SOILW"]
+	#[unit = "proportion"]
+	VolumetricSoilMoistureContent = 9,
+	#[description = "ground heat flux"]
+	#[abbrev = "GFLUX"]
+	#[unit = "Wm-2"]
+	GroundHeatFlux = 10,
+	#[description = "moisture availability"]
+	#[abbrev = "MSTAV"]
+	#[unit = "%"]
+	MoistureAvailability = 11,
+	#[description = "exchange coefficient"]
+	#[abbrev = "SFEXC"]
+	#[unit = "kgm-2s-1"]
+	ExchangeCoefficient = 12,
+	#[description = "plant canopy surface water"]
+	#[abbrev = "CNWAT"]
+	#[unit = "kgm-2"]
+	PlantCanopySurfaceWater = 13,
+	#[description = "blackadar's mixing length scale"]
+	#[abbrev = "BMIXL"]
+	#[unit = "m"]
+	BlackadarMixingLengthScale = 14,
+	#[description = "canopy conductance"]
+	#[abbrev = "CCOND"]
+	#[unit = "ms-1"]
+	CanopyConductance = 15,
+	#[description = "minimal stomatal resistance"]
+	#[abbrev = "RSMIN"]
+	#[unit = "sm-1"]
+	MinimalStomatalResistance = 16,
+	#[description = "wilting point"]
+	#[abbrev = "WILT"]
+	#[unit = "proportion"]
+	WiltingPoint = 17,
+	#[description = "solar parameter in canopy conductance"]
+	#[abbrev = "RCS"]
+	#[unit = "proportion"]
+	CanopyConductanceSolarParameter = 18,
+	#[description = "temperature parameter in canopy conductance"]
+	#[abbrev = "RCT"]
+	#[unit = "proportion"]
+	CanopyConductanceTemperatureParameter = 19,
+	#[description = "humidity parameter in canopy conductance"]
+	#[abbrev = "RCQ"]
+	#[unit = "proportion"]
+	CanopyConductanceHumidityParameter = 20,
+	#[description = "soil moisture parameter in canopy conductance"]
+	#[abbrev = "RCSOL"]
+	#[unit = "proportion"]
+	CanopyConductanceSoilMoistureParameter = 21,
+	#[description = "soil moisture"]
+	#[abbrev = "SOILM"]
+	#[unit = "kgm-3"]
+	SoilMoisture = 22,
+	#[description = "column-integrated soil water"]
+	#[abbrev = "CISOILW"]
+	#[unit = "kgm-2"]
+	ColumnIntegratedSoilWater = 23,
+	#[description = "heat flux"]
+	#[abbrev = "HFLUX"]
+	#[unit = "Wm-2"]
+	HeatFlux = 24,
+	#[description = "volumetric soil moisture"]
+	#[abbrev = "VSOILM"]
+	#[unit = "m3m-3"]
+	VolumetricSoilMoisture = 25,
+	#[description = "wilting point"]
+	#[abbrev = "WILT"]
+	#[unit = "kgm-3"]
+	WiltingPointDensity = 26,
+	#[description = "volumetric wilting point"]
+	#[abbrev = "VWILTM"]
+	#[unit = "m3m-3"]
+	VolumetricWiltingPoint = 27,
+	#[description = "leaf area index"]
+	#[abbrev = "LEAINX"]
+	#[unit = "-"]
+	LeafAreaIndex = 28,
+	#[description = "evergreen forest cover"]
+	#[abbrev = "EVGFC"]
+	#[unit = "proportion"]
+	EvergreenForestCover = 29,
+	#[description = "deciduous forest cover"]
+	#[abbrev = "DECFC"]
+	#[unit = "proportion"]
+	DeciduousForestCover = 30,
+	#[description = "normalized differential vegetation index"]
+	#[abbrev = "NDVINX"]
+	#[unit = "-"]
+	NormalizedDifferentialVegetationIndex = 31,
+	#[description = "root depth of vegetation"]
+	#[abbrev = "RDVEG"]
+	#[unit = "m"]
+	VegetationRootDepth = 32,
+	#[description = "water runoff and drainage"]
+	#[abbrev = "WROD"]
+	#[unit = "kgm-2"]
+	WaterRunoffAndDrainage = 33,
+	#[description = "surface water runoff"]
+	#[abbrev = "SFCWRO"]
+	#[unit = "kgm-2"]
+	SurfaceWaterRunoff = 34,
+	#[description = "tile class"]
+	#[abbrev = "TCLASS"]
+	#[unit = "-"]
+	TileClass = 35,
+	#[description = "tile fraction"]
+	#[abbrev = "TFRCT"]
+	#[unit = "proportion"]
+	TileFraction = 36,
+	#[description = "tile percentage"]
+	#[abbrev = "PERMSK"]
+	#[unit = "%"]
+	TilePercentage = 37,
+	#[description = "soil volumetric ice content (water equivalent)"]
+	#[abbrev = "SOILVIC"]
+	#[unit = "m3m-3"]
+	SoilVolumetricIceContent = 38,
+	#[description = "evapotranspiration rate"]
+	#[abbrev = "EVAPTRAT"]
+	#[unit = "kgm-2s-1"]
+	EvapotranspirationRate = 39,
+	#[description = "potential evapotranspiration rate"]
+	#[abbrev = "PEVAPTRAT"]
+	#[unit = "kgm-2s-1"]
+	PotentialEvapotranspirationRate = 40,
+	#[description = "snow melt rate"]
+	#[abbrev = "SMRATE"]
+	#[unit = "kgm-2s-1"]
+	SnowMeltRate = 41,
+	#[description = "water runoff and drainage rate"]
+	#[abbrev = "WRDRATE"]
+	#[unit = "kgm-2s-1"]
+	WaterRunoffAndDrainageRate = 42,
+	#[description = "drainage direction"]
+	#[abbrev = "DRAINDIR"]
+	#[unit = "-"]
+	DrainageDirection = 43,
+	#[description = "upstream area"]
+	#[abbrev = "UPAREA"]
+	#[unit = "m2"]
+	UpstreamArea = 44,
+	#[description = "wetland cover"]
+	#[abbrev = "WETCOV"]
+	#[unit = "proportion"]
+	WetlandCover = 45,
+	#[description = "wetland type"]
+	#[abbrev = "WETTYPE"]
+	#[unit = "-"]
+	WetlandType = 46,
+	#[description = "irrigation cover"]
+	#[abbrev = "IRRCOV"]
+	#[unit = "proportion"]
+	IrrigationCover = 47,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum SoilProduct {
+	#[description = "soil type"]
+	#[abbrev = "SOTYP"]
+	#[unit = "-"]
+	SoilType = 0,
+	#[description = "upper layer soil temperature"]
+	#[abbrev = "UPLST"]
+	#[unit = "K"]
+	UpperLayerSoilTemperature = 1,
+	#[description = "upper layer soil moisture"]
+	#[abbrev = "UPLSM"]
+	#[unit = "kgm-3"]
+	UpperLayerSoilMoisture = 2,
+	#[description = "lower layer soil moisture"]
+	#[abbrev = "LOWLSM"]
+	#[unit = "kgm-3"]
+	LowerLayerSoilMoisture = 3,
+	#[description = "bottom layer soil temperature"]
+	#[abbrev = "BOTLST"]
+	#[unit = "K"]
+	BottomLayerSoilTemperature = 4,
+	#[description = "liquid volumetric soil moisture (non-frozen)"]
+	#[abbrev = "SOILL"]
+	#[unit = "proportion"]
+	LiquidVolumetricSoilMoisture = 5,
+	#[description = "number of soil layers in root zone"]
+	#[abbrev = "RLYRS"]
+	#[unit = "-"]
+	RootZoneSoilLayerCount = 6,
+	#[description = "transpiration stress-onset (soil moisture)"]
+	#[abbrev = "SMREF"]
+	#[unit = "proportion"]
+	TranspirationStressOnset = 7,
+	#[description = "direct evaporation cease (soil moisture)"]
+	#[abbrev = "SMDRY"]
+	#[unit = "proportion"]
+	DirectEvaporationCease = 8,
+	#[description = "soil porosity"]
+	#[abbrev = "POROS"]
+	#[unit = "proportion"]
+	SoilPorosity = 9,
+	#[description = "liquid volumetric soil moisture (non-frozen)"]
+	#[abbrev = "LIQVSM"]
+	#[unit = "m3m-3"]
+	LiquidVolumetricSoilMoistureContent = 10,
+	#[description = "volumetric transpiration stress-onset (soil moisture)"]
+	#[abbrev = "VOLTSO"]
+	#[unit = "m3m-3"]
+	VolumetricTranspirationStressOnset = 11,
+	#[description = "transpiration stress-onset (soil moisture)"]
+	#[abbrev = "TRANSO"]
+	#[unit = "kgm-3"]
+	TranspirationStressOnsetDensity = 12,
+	#[description = "volumetric direct evaporation cease (soil moisture)"]
+	#[abbrev = "VOLDEC"]
+	#[unit = "m3m-3"]
+	VolumetricDirectEvaporationCease = 13,
+	#[description = "direct evaporation cease (soil moisture)"]
+	#[abbrev = "DIREC"]
+	#[unit = "kgm-3"]
+	DirectEvaporationCeaseDensity = 14,
+	#[description = "soil porosity"]
+	#[abbrev = "SOILP"]
+	#[unit = "m3m-3"]
+	VolumetricSoilPorosity = 15,
+	#[description = "volumetric saturation of soil moisture"]
+	#[abbrev = "VSOSM"]
+	#[unit = "m3m-3"]
+	VolumetricSoilMoistureSaturation = 16,
+	#[description = "saturation of soil moisture"]
+	#[abbrev = "SATOSM"]
+	#[unit = "kgm-3"]
+	SoilMoistureSaturation = 17,
+	#[description = "soil temperature"]
+	#[abbrev = "SOILTMP"]
+	#[unit = "K"]
+	SoilTemperature = 18,
+	#[description = "soil moisture"]
+	#[abbrev = "SOILMOI"]
+	#[unit = "kgm-3"]
+	SoilMoisture = 19,
+	#[description = "column-integrated soil moisture"]
+	#[abbrev = "CISOILM"]
+	#[unit = "kgm-2"]
+	ColumnIntegratedSoilMoisture = 20,
+	#[description = "soil ice"]
+	#[abbrev = "SOILICE"]
+	#[unit = "kgm-3"]
+	SoilIce = 21,
+	#[description = "column-integrated soil ice"]
+	#[abbrev = "CISICE"]
+	#[unit = "kgm-2"]
+	ColumnIntegratedSoilIce = 22,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum FireWeatherProduct {
+	#[description = "fire outlook"]
+	#[abbrev = "FIREOLK"]
+	#[unit = "-"]
+	FireOutlook = 0,
+	#[description = "fire outlook due to dry thunderstorm"]
+	#[abbrev = "FIREODT"]
+	#[unit = "-"]
+	DryThunderstormFireOutlook = 1,
+	#[description = "haines index"]
+	#[abbrev = "HINDEX"]
+	#[unit = "-"]
+	HainesIndex = 2,
+	#[description = "fire burned area"]
+	#[abbrev = "FBAREA"]
+	#[unit = "%"]
+	FireBurnedArea = 3,
+	#[description = "fosberg index"]
+	#[abbrev = "FOSINDX"]
+	#[unit = "-"]
+	FosbergIndex = 4,
+	#[description = "fire weather index"]
+	#[abbrev = "FWINX"]
+	#[unit = "-"]
+	FireWeatherIndex = 5,
+	#[description = "fine fuel moisture code"]
+	#[abbrev = "FFMCODE"]
+	#[unit = "-"]
+	FineFuelMoistureCode = 6,
+	#[description = "duff moisture code"]
+	#[abbrev = "DUFMCODE"]
+	#[unit = "-"]
+	DuffMoistureCode = 7,
+	#[description = "drought code"]
+	#[abbrev = "DRTCODE"]
+	#[unit = "-"]
+	DroughtCode = 8,
+	#[description = "initial fire spread index"]
+	#[abbrev = "INFSINX"]
+	#[unit = "-"]
+	InitialFireSpreadIndex = 9,
+	#[description = "fire build up index"]
+	#[abbrev = "FBUPINX"]
+	#[unit = "-"]
+	FireBuildUpIndex = 10,
+	#[description = "fire daily severity rating"]
+	#[abbrev = "FDSRTE"]
+	#[unit = "-"]
+	FireDailySeverityRating = 11,
+}
+
+pub fn parameter(category: u8, parameter: u8) -> Option<Parameter> {
+	match category {
+		0 => VegetationProduct::from_value(parameter).map(Parameter::from),
+		3 => SoilProduct::from_value(parameter).map(Parameter::from),
+		4 => FireWeatherProduct::from_value(parameter).map(Parameter::from),
+		_ => None,
+	}
+}
+
+pub fn table() -> Vec<(u8, ParameterTable)> {
+	vec![
+		(0, VegetationProduct::parameters()),
+		(3, SoilProduct::parameters()),
+		(4, FireWeatherProduct::parameters()),
+	]
+}
+
+pub fn category(category: u8) -> &'static str {
+	match category {
+		0 => "vegetation/biomass",
+		1 => "agricultural/aquacultural special products",
+		3 => "soil",
+		4 => "fire weather",
+		_ => "other",
+	}
+}
@@ -0,0 +1,57 @@
+use gribberish_macros::{DisplayDescription, FromValue, ToParameter};
+use crate::Parameter;
+use crate::parameters::ParameterTable;
+
+pub const CENTER: u16 = 78;
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum CloudProduct {
+	#[description = "height of top of dry convection"]
+	#[abbrev = "HTOP_DC"]
+	#[unit = "m"]
+	DryConvectionTopHeight = 196,
+	#[description = "modified total cloud cover for media"]
+	#[abbrev = "CLCT_MOD"]
+	#[unit = "proportion"]
+	ModifiedTotalCloudCover = 199,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum StabilityProduct {
+	#[description = "supercell detection index 1 (rotating updrafts)"]
+	#[abbrev = "SDI_1"]
+	#[unit = "s-1"]
+	SupercellDetectionIndex1 = 192,
+	#[description = "supercell detection index 2 (rotating updrafts and downdrafts)"]
+	#[abbrev = "SDI_2"]
+	#[unit = "s-1"]
+	SupercellDetectionIndex2 = 193,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum ElectrodynamicsProduct {
+	#[description = "lightning potential index"]
+	#[abbrev = "LPI"]
+	#[unit = "Jkg-1"]
+	LightningPotentialIndex = 192,
+}
+
+pub fn parameter(discipline: u8, category: u8, parameter: u8) -> Option<Parameter> {
+	match (discipline, category) {
+		(0, 6) => CloudProduct::from_value(parameter).map(Parameter::from),
+		(0, 7) => StabilityProduct::from_value(parameter).map(Parameter::from),
+		(0, 17) => ElectrodynamicsProduct::from_value(parameter).map(Parameter::from),
+		_ => None,
+	}
+}
+
+pub fn table() -> Vec<(u8, u8, ParameterTable)> {
+	vec![
+		(0, 6, CloudProduct::parameters()),
+		(0, 7, StabilityProduct::parameters()),
+		(0, 17, ElectrodynamicsProduct::parameters()),
+	]
+}
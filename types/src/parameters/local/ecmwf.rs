@@ -0,0 +1,385 @@
+use gribberish_macros::{DisplayDescription, FromValue, ToParameter};
+use crate::Parameter;
+use crate::parameters::ParameterTable;
+
+pub const CENTER: u16 = 98;
+
+// ECMWF encodes parameters without a WMO equivalent in discipline 192, using the GRIB1
+// parameter table number as the category and the parameter id within that table as the number
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum Table128Product {
+	#[description = "surface runoff"]
+	#[abbrev = "sro"]
+	#[unit = "m"]
+	SurfaceRunoff = 8,
+	#[description = "sub-surface runoff"]
+	#[abbrev = "ssro"]
+	#[unit = "m"]
+	SubSurfaceRunoff = 9,
+	#[description = "sea ice area fraction"]
+	#[abbrev = "ci"]
+	#[unit = "proportion"]
+	SeaIceAreaFraction = 31,
+	#[description = "snow density"]
+	#[abbrev = "rsn"]
+	#[unit = "kgm-3"]
+	SnowDensity = 33,
+	#[description = "sea surface temperature"]
+	#[abbrev = "sst"]
+	#[unit = "K"]
+	SeaSurfaceTemperature = 34,
+	#[description = "volumetric soil water layer 1"]
+	#[abbrev = "swvl1"]
+	#[unit = "m3m-3"]
+	VolumetricSoilWaterLayer1 = 39,
+	#[description = "volumetric soil water layer 2"]
+	#[abbrev = "swvl2"]
+	#[unit = "m3m-3"]
+	VolumetricSoilWaterLayer2 = 40,
+	#[description = "volumetric soil water layer 3"]
+	#[abbrev = "swvl3"]
+	#[unit = "m3m-3"]
+	VolumetricSoilWaterLayer3 = 41,
+	#[description = "volumetric soil water layer 4"]
+	#[abbrev = "swvl4"]
+	#[unit = "m3m-3"]
+	VolumetricSoilWaterLayer4 = 42,
+	#[description = "snow evaporation"]
+	#[abbrev = "es"]
+	#[unit = "m"]
+	SnowEvaporation = 44,
+	#[abbrev = "smlt"]
+	#[unit = "m"]
+	Snowmelt = 45,
+	#[description = "10 metre wind gust since previous post-processing"]
+	#[abbrev = "10fg"]
+	#[unit = "ms-1"]
+	TenMetreWindGust = 49,
+	#[description = "convective available potential energy"]
+	#[abbrev = "cape"]
+	#[unit = "Jkg-1"]
+	ConvectiveAvailablePotentialEnergy = 59,
+	#[description = "maximum temperature at 2 metres in the last 6 hours"]
+	#[abbrev = "mx2t6"]
+	#[unit = "K"]
+	MaximumTwoMetreTemperatureSixHours = 121,
+	#[description = "minimum temperature at 2 metres in the last 6 hours"]
+	#[abbrev = "mn2t6"]
+	#[unit = "K"]
+	MinimumTwoMetreTemperatureSixHours = 122,
+	#[description = "10 metre wind gust in the last 6 hours"]
+	#[abbrev = "10fg6"]
+	#[unit = "ms-1"]
+	TenMetreWindGustSixHours = 123,
+	#[abbrev = "z"]
+	#[unit = "m2s-2"]
+	Geopotential = 129,
+	#[abbrev = "t"]
+	#[unit = "K"]
+	Temperature = 130,
+	#[description = "u component of wind"]
+	#[abbrev = "u"]
+	#[unit = "ms-1"]
+	UComponentOfWind = 131,
+	#[description = "v component of wind"]
+	#[abbrev = "v"]
+	#[unit = "ms-1"]
+	VComponentOfWind = 132,
+	#[description = "specific humidity"]
+	#[abbrev = "q"]
+	#[unit = "kgkg-1"]
+	SpecificHumidity = 133,
+	#[description = "surface pressure"]
+	#[abbrev = "sp"]
+	#[unit = "pa"]
+	SurfacePressure = 134,
+	#[description = "vertical velocity"]
+	#[abbrev = "w"]
+	#[unit = "pas-1"]
+	VerticalVelocity = 135,
+	#[description = "total column water"]
+	#[abbrev = "tcw"]
+	#[unit = "kgm-2"]
+	TotalColumnWater = 136,
+	#[description = "total column vertically-integrated water vapour"]
+	#[abbrev = "tcwv"]
+	#[unit = "kgm-2"]
+	TotalColumnWaterVapour = 137,
+	#[description = "vorticity (relative)"]
+	#[abbrev = "vo"]
+	#[unit = "s-1"]
+	Vorticity = 138,
+	#[description = "soil temperature level 1"]
+	#[abbrev = "stl1"]
+	#[unit = "K"]
+	SoilTemperatureLevel1 = 139,
+	#[description = "snow depth"]
+	#[abbrev = "sd"]
+	#[unit = "m"]
+	SnowDepth = 141,
+	#[description = "large-scale precipitation"]
+	#[abbrev = "lsp"]
+	#[unit = "m"]
+	LargeScalePrecipitation = 142,
+	#[description = "convective precipitation"]
+	#[abbrev = "cp"]
+	#[unit = "m"]
+	ConvectivePrecipitation = 143,
+	#[abbrev = "sf"]
+	#[unit = "m"]
+	Snowfall = 144,
+	#[description = "surface sensible heat flux"]
+	#[abbrev = "sshf"]
+	#[unit = "Jm-2"]
+	SurfaceSensibleHeatFlux = 146,
+	#[description = "surface latent heat flux"]
+	#[abbrev = "slhf"]
+	#[unit = "Jm-2"]
+	SurfaceLatentHeatFlux = 147,
+	#[description = "mean sea level pressure"]
+	#[abbrev = "msl"]
+	#[unit = "pa"]
+	MeanSeaLevelPressure = 151,
+	#[abbrev = "d"]
+	#[unit = "s-1"]
+	Divergence = 155,
+	#[description = "relative humidity"]
+	#[abbrev = "r"]
+	#[unit = "%"]
+	RelativeHumidity = 157,
+	#[description = "boundary layer height"]
+	#[abbrev = "blh"]
+	#[unit = "m"]
+	BoundaryLayerHeight = 159,
+	#[description = "total cloud cover"]
+	#[abbrev = "tcc"]
+	#[unit = "proportion"]
+	TotalCloudCover = 164,
+	#[description = "10 metre u wind component"]
+	#[abbrev = "10u"]
+	#[unit = "ms-1"]
+	TenMetreUWind = 165,
+	#[description = "10 metre v wind component"]
+	#[abbrev = "10v"]
+	#[unit = "ms-1"]
+	TenMetreVWind = 166,
+	#[description = "2 metre temperature"]
+	#[abbrev = "2t"]
+	#[unit = "K"]
+	TwoMetreTemperature = 167,
+	#[description = "2 metre dewpoint temperature"]
+	#[abbrev = "2d"]
+	#[unit = "K"]
+	TwoMetreDewpoint = 168,
+	#[description = "surface solar radiation downwards"]
+	#[abbrev = "ssrd"]
+	#[unit = "Jm-2"]
+	SurfaceSolarRadiationDownwards = 169,
+	#[description = "soil temperature level 2"]
+	#[abbrev = "stl2"]
+	#[unit = "K"]
+	SoilTemperatureLevel2 = 170,
+	#[description = "land-sea mask"]
+	#[abbrev = "lsm"]
+	#[unit = "proportion"]
+	LandSeaMask = 172,
+	#[description = "surface thermal radiation downwards"]
+	#[abbrev = "strd"]
+	#[unit = "Jm-2"]
+	SurfaceThermalRadiationDownwards = 175,
+	#[description = "surface net solar radiation"]
+	#[abbrev = "ssr"]
+	#[unit = "Jm-2"]
+	SurfaceNetSolarRadiation = 176,
+	#[description = "surface net thermal radiation"]
+	#[abbrev = "str"]
+	#[unit = "Jm-2"]
+	SurfaceNetThermalRadiation = 177,
+	#[description = "top net solar radiation"]
+	#[abbrev = "tsr"]
+	#[unit = "Jm-2"]
+	TopNetSolarRadiation = 178,
+	#[description = "top net thermal radiation"]
+	#[abbrev = "ttr"]
+	#[unit = "Jm-2"]
+	TopNetThermalRadiation = 179,
+	#[description = "eastward turbulent surface stress"]
+	#[abbrev = "ewss"]
+	#[unit = "Nm-2s"]
+	EastwardTurbulentSurfaceStress = 180,
+	#[description = "northward turbulent surface stress"]
+	#[abbrev = "nsss"]
+	#[unit = "Nm-2s"]
+	NorthwardTurbulentSurfaceStress = 181,
+	#[abbrev = "e"]
+	#[unit = "m"]
+	Evaporation = 182,
+	#[description = "soil temperature level 3"]
+	#[abbrev = "stl3"]
+	#[unit = "K"]
+	SoilTemperatureLevel3 = 183,
+	#[description = "low cloud cover"]
+	#[abbrev = "lcc"]
+	#[unit = "proportion"]
+	LowCloudCover = 186,
+	#[description = "medium cloud cover"]
+	#[abbrev = "mcc"]
+	#[unit = "proportion"]
+	MediumCloudCover = 187,
+	#[description = "high cloud cover"]
+	#[abbrev = "hcc"]
+	#[unit = "proportion"]
+	HighCloudCover = 188,
+	#[description = "maximum temperature at 2 metres since previous post-processing"]
+	#[abbrev = "mx2t"]
+	#[unit = "K"]
+	MaximumTwoMetreTemperature = 201,
+	#[description = "minimum temperature at 2 metres since previous post-processing"]
+	#[abbrev = "mn2t"]
+	#[unit = "K"]
+	MinimumTwoMetreTemperature = 202,
+	#[abbrev = "ro"]
+	#[unit = "m"]
+	Runoff = 205,
+	#[description = "10 metre wind speed"]
+	#[abbrev = "10si"]
+	#[unit = "ms-1"]
+	TenMetreWindSpeed = 207,
+	#[description = "total precipitation"]
+	#[abbrev = "tp"]
+	#[unit = "m"]
+	TotalPrecipitation = 228,
+	#[description = "skin temperature"]
+	#[abbrev = "skt"]
+	#[unit = "K"]
+	SkinTemperature = 235,
+	#[description = "soil temperature level 4"]
+	#[abbrev = "stl4"]
+	#[unit = "K"]
+	SoilTemperatureLevel4 = 236,
+	#[description = "temperature of snow layer"]
+	#[abbrev = "tsn"]
+	#[unit = "K"]
+	SnowLayerTemperature = 238,
+	#[description = "forecast albedo"]
+	#[abbrev = "fal"]
+	#[unit = "proportion"]
+	ForecastAlbedo = 243,
+	#[description = "forecast surface roughness"]
+	#[abbrev = "fsr"]
+	#[unit = "m"]
+	ForecastSurfaceRoughness = 244,
+	#[description = "specific cloud liquid water content"]
+	#[abbrev = "clwc"]
+	#[unit = "kgkg-1"]
+	SpecificCloudLiquidWaterContent = 246,
+	#[description = "specific cloud ice water content"]
+	#[abbrev = "ciwc"]
+	#[unit = "kgkg-1"]
+	SpecificCloudIceWaterContent = 247,
+	#[description = "fraction of cloud cover"]
+	#[abbrev = "cc"]
+	#[unit = "proportion"]
+	FractionOfCloudCover = 248,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum Table140Product {
+	#[description = "significant height of combined wind waves and swell"]
+	#[abbrev = "swh"]
+	#[unit = "m"]
+	SignificantWaveHeight = 229,
+	#[description = "mean wave direction"]
+	#[abbrev = "mwd"]
+	#[unit = "degree true"]
+	MeanWaveDirection = 230,
+	#[description = "peak wave period"]
+	#[abbrev = "pp1d"]
+	#[unit = "s"]
+	PeakWavePeriod = 231,
+	#[description = "mean wave period"]
+	#[abbrev = "mwp"]
+	#[unit = "s"]
+	MeanWavePeriod = 232,
+	#[description = "significant height of wind waves"]
+	#[abbrev = "shww"]
+	#[unit = "m"]
+	WindWaveHeight = 234,
+	#[description = "mean direction of wind waves"]
+	#[abbrev = "mdww"]
+	#[unit = "degree true"]
+	WindWaveDirection = 235,
+	#[description = "mean period of wind waves"]
+	#[abbrev = "mpww"]
+	#[unit = "s"]
+	WindWavePeriod = 236,
+	#[description = "significant height of total swell"]
+	#[abbrev = "shts"]
+	#[unit = "m"]
+	TotalSwellHeight = 237,
+	#[description = "mean direction of total swell"]
+	#[abbrev = "mdts"]
+	#[unit = "degree true"]
+	TotalSwellDirection = 238,
+	#[description = "mean period of total swell"]
+	#[abbrev = "mpts"]
+	#[unit = "s"]
+	TotalSwellPeriod = 239,
+	#[description = "10 metre wind speed"]
+	#[abbrev = "wind"]
+	#[unit = "ms-1"]
+	TenMetreWindSpeed = 245,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum Table228Product {
+	#[description = "cloud base height"]
+	#[abbrev = "cbh"]
+	#[unit = "m"]
+	CloudBaseHeight = 23,
+	#[description = "instantaneous 10 metre wind gust"]
+	#[abbrev = "i10fg"]
+	#[unit = "ms-1"]
+	InstantaneousTenMetreWindGust = 29,
+	#[description = "total column rain water"]
+	#[abbrev = "tcrw"]
+	#[unit = "kgm-2"]
+	TotalColumnRainWater = 89,
+	#[description = "total column snow water"]
+	#[abbrev = "tcsw"]
+	#[unit = "kgm-2"]
+	TotalColumnSnowWater = 90,
+	#[description = "100 metre u wind component"]
+	#[abbrev = "100u"]
+	#[unit = "ms-1"]
+	HundredMetreUWind = 246,
+	#[description = "100 metre v wind component"]
+	#[abbrev = "100v"]
+	#[unit = "ms-1"]
+	HundredMetreVWind = 247,
+	#[description = "100 metre wind speed"]
+	#[abbrev = "100si"]
+	#[unit = "ms-1"]
+	HundredMetreWindSpeed = 249,
+}
+
+pub fn parameter(discipline: u8, category: u8, parameter: u8) -> Option<Parameter> {
+	match (discipline, category) {
+		(192, 128) => Table128Product::from_value(parameter).map(Parameter::from),
+		(192, 140) => Table140Product::from_value(parameter).map(Parameter::from),
+		(192, 228) => Table228Product::from_value(parameter).map(Parameter::from),
+		_ => None,
+	}
+}
+
+pub fn table() -> Vec<(u8, u8, ParameterTable)> {
+	vec![
+		(192, 128, Table128Product::parameters()),
+		(192, 140, Table140Product::parameters()),
+		(192, 228, Table228Product::parameters()),
+	]
+}
@@ -0,0 +1,3 @@
+pub mod ncep;
+pub mod ecmwf;
+pub mod dwd;
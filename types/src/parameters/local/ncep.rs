@@ -0,0 +1,1203 @@
+use gribberish_macros::{DisplayDescription, FromValue, ToParameter};
+use crate::Parameter;
+use crate::parameters::ParameterTable;
+
+pub const CENTER: u16 = 7;
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum MoistureProduct {
+	#[description = "categorical rain"]
+	#[abbrev = "CRAIN"]
+	#[unit = "-"]
+	CategoricalRain = 192,
+	#[description = "categorical freezing rain"]
+	#[abbrev = "CFRZR"]
+	#[unit = "-"]
+	CategoricalFreezingRain = 193,
+	#[description = "categorical ice pellets"]
+	#[abbrev = "CICEP"]
+	#[unit = "-"]
+	CategoricalIcePellets = 194,
+	#[description = "categorical snow"]
+	#[abbrev = "CSNOW"]
+	#[unit = "-"]
+	CategoricalSnow = 195,
+	#[description = "convective precipitation rate"]
+	#[abbrev = "CPRAT"]
+	#[unit = "kgm-2s-1"]
+	ConvectivePrecipitationRate = 196,
+	#[description = "horizontal moisture divergence"]
+	#[abbrev = "MDIV"]
+	#[unit = "kgkg-1s-1"]
+	HorizontalMoistureDivergence = 197,
+	#[description = "minimum relative humidity"]
+	#[abbrev = "MINRH"]
+	#[unit = "%"]
+	MinimumRelativeHumidity = 198,
+	#[description = "potential evaporation"]
+	#[abbrev = "PEVAP"]
+	#[unit = "kgm-2"]
+	PotentialEvaporation = 199,
+	#[description = "potential evaporation rate"]
+	#[abbrev = "PEVPR"]
+	#[unit = "Wm-2"]
+	PotentialEvaporationRate = 200,
+	#[description = "snow cover"]
+	#[abbrev = "SNOWC"]
+	#[unit = "%"]
+	SnowCover = 201,
+	#[description = "rain fraction of total liquid water"]
+	#[abbrev = "FRAIN"]
+	#[unit = "-"]
+	RainFractionOfTotalLiquidWater = 202,
+	#[description = "rime factor"]
+	#[abbrev = "RIME"]
+	#[unit = "-"]
+	RimeFactor = 203,
+	#[description = "total column integrated rain"]
+	#[abbrev = "TCOLR"]
+	#[unit = "kgm-2"]
+	TotalColumnRain = 204,
+	#[description = "total column integrated snow"]
+	#[abbrev = "TCOLS"]
+	#[unit = "kgm-2"]
+	TotalColumnSnow = 205,
+	#[description = "total icing potential diagnostic"]
+	#[abbrev = "TIPD"]
+	#[unit = "-"]
+	TotalIcingPotentialDiagnostic = 206,
+	#[description = "number concentration for ice particles"]
+	#[abbrev = "NCIP"]
+	#[unit = "-"]
+	IceParticleNumberConcentration = 207,
+	#[description = "snow temperature"]
+	#[abbrev = "SNOT"]
+	#[unit = "K"]
+	SnowTemperature = 208,
+	#[description = "total column-integrated supercooled liquid water"]
+	#[abbrev = "TCLSW"]
+	#[unit = "kgm-2"]
+	TotalColumnSupercooledLiquidWater = 209,
+	#[description = "total column-integrated melting ice"]
+	#[abbrev = "TCOLM"]
+	#[unit = "kgm-2"]
+	TotalColumnMeltingIce = 210,
+	#[description = "evaporation - precipitation"]
+	#[abbrev = "EMNP"]
+	#[unit = "cmday-1"]
+	EvaporationMinusPrecipitation = 211,
+	#[description = "sublimation (evaporation from snow)"]
+	#[abbrev = "SBSNO"]
+	#[unit = "Wm-2"]
+	Sublimation = 212,
+	#[description = "deep convective moistening rate"]
+	#[abbrev = "CNVMR"]
+	#[unit = "kgkg-1s-1"]
+	DeepConvectiveMoisteningRate = 213,
+	#[description = "shallow convective moistening rate"]
+	#[abbrev = "SHAMR"]
+	#[unit = "kgkg-1s-1"]
+	ShallowConvectiveMoisteningRate = 214,
+	#[description = "vertical diffusion moistening rate"]
+	#[abbrev = "VDFMR"]
+	#[unit = "kgkg-1s-1"]
+	VerticalDiffusionMoisteningRate = 215,
+	#[description = "condensation pressure of parcel lifted from indicated surface"]
+	#[abbrev = "CONDP"]
+	#[unit = "pa"]
+	ParcelCondensationPressure = 216,
+	#[description = "large scale moistening rate"]
+	#[abbrev = "LRGMR"]
+	#[unit = "kgkg-1s-1"]
+	LargeScaleMoisteningRate = 217,
+	#[description = "specific humidity at top of viscous sublayer"]
+	#[abbrev = "QZ0"]
+	#[unit = "kgkg-1"]
+	ViscousSublayerSpecificHumidity = 218,
+	#[description = "maximum specific humidity at 2m"]
+	#[abbrev = "QMAX"]
+	#[unit = "kgkg-1"]
+	MaximumSpecificHumidity = 219,
+	#[description = "minimum specific humidity at 2m"]
+	#[abbrev = "QMIN"]
+	#[unit = "kgkg-1"]
+	MinimumSpecificHumidity = 220,
+	#[description = "liquid precipitation (rainfall)"]
+	#[abbrev = "ARAIN"]
+	#[unit = "kgm-2"]
+	LiquidPrecipitation = 221,
+	#[description = "snow temperature, depth-avg"]
+	#[abbrev = "SNOWT"]
+	#[unit = "K"]
+	DepthAverageSnowTemperature = 222,
+	#[description = "total precipitation (nearest grid point)"]
+	#[abbrev = "APCPN"]
+	#[unit = "kgm-2"]
+	TotalPrecipitationNearestGridPoint = 223,
+	#[description = "convective precipitation (nearest grid point)"]
+	#[abbrev = "ACPCPN"]
+	#[unit = "kgm-2"]
+	ConvectivePrecipitationNearestGridPoint = 224,
+	#[description = "freezing rain"]
+	#[abbrev = "FRZR"]
+	#[unit = "kgm-2"]
+	FreezingRain = 225,
+	#[description = "predominant weather"]
+	#[abbrev = "PWTHER"]
+	#[unit = "-"]
+	PredominantWeather = 226,
+	#[description = "frozen rain"]
+	#[abbrev = "FROZR"]
+	#[unit = "kgm-2"]
+	FrozenRain = 227,
+	#[description = "total snow"]
+	#[abbrev = "TSNOW"]
+	#[unit = "kgm-2"]
+	TotalSnow = 241,
+	#[description = "relative humidity with respect to precipitable water"]
+	#[abbrev = "RHPW"]
+	#[unit = "%"]
+	RelativeHumidityPrecipitableWater = 242,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum MomentumProduct {
+	#[description = "vertical speed shear"]
+	#[abbrev = "VWSH"]
+	#[unit = "s-1"]
+	VerticalSpeedShear = 192,
+	#[description = "horizontal momentum flux"]
+	#[abbrev = "MFLX"]
+	#[unit = "Nm-2"]
+	HorizontalMomentumFlux = 193,
+	#[description = "u-component storm motion"]
+	#[abbrev = "USTM"]
+	#[unit = "ms-1"]
+	UComponentStormMotion = 194,
+	#[description = "v-component storm motion"]
+	#[abbrev = "VSTM"]
+	#[unit = "ms-1"]
+	VComponentStormMotion = 195,
+	#[description = "drag coefficient"]
+	#[abbrev = "CD"]
+	#[unit = "-"]
+	DragCoefficient = 196,
+	#[description = "frictional velocity"]
+	#[abbrev = "FRICV"]
+	#[unit = "ms-1"]
+	FrictionalVelocity = 197,
+	#[description = "latitude of u wind component of velocity"]
+	#[abbrev = "LAUV"]
+	#[unit = "degrees"]
+	UWindLatitude = 198,
+	#[description = "longitude of u wind component of velocity"]
+	#[abbrev = "LOUV"]
+	#[unit = "degrees"]
+	UWindLongitude = 199,
+	#[description = "latitude of v wind component of velocity"]
+	#[abbrev = "LAVV"]
+	#[unit = "degrees"]
+	VWindLatitude = 200,
+	#[description = "longitude of v wind component of velocity"]
+	#[abbrev = "LOVV"]
+	#[unit = "degrees"]
+	VWindLongitude = 201,
+	#[description = "latitude of pressure point"]
+	#[abbrev = "LAPP"]
+	#[unit = "degrees"]
+	PressurePointLatitude = 202,
+	#[description = "longitude of pressure point"]
+	#[abbrev = "LOPP"]
+	#[unit = "degrees"]
+	PressurePointLongitude = 203,
+	#[description = "vertical eddy diffusivity heat exchange"]
+	#[abbrev = "VEDH"]
+	#[unit = "m2s-1"]
+	VerticalEddyDiffusivityHeatExchange = 204,
+	#[description = "covariance between meridional and zonal components of the wind"]
+	#[abbrev = "COVMZ"]
+	#[unit = "m2s-2"]
+	MeridionalZonalWindCovariance = 205,
+	#[description = "covariance between temperature and zonal components of the wind"]
+	#[abbrev = "COVTZ"]
+	#[unit = "Kms-1"]
+	TemperatureZonalWindCovariance = 206,
+	#[description = "covariance between temperature and meridional components of the wind"]
+	#[abbrev = "COVTM"]
+	#[unit = "Kms-1"]
+	TemperatureMeridionalWindCovariance = 207,
+	#[description = "vertical diffusion zonal acceleration"]
+	#[abbrev = "VDFUA"]
+	#[unit = "ms-2"]
+	VerticalDiffusionZonalAcceleration = 208,
+	#[description = "vertical diffusion meridional acceleration"]
+	#[abbrev = "VDFVA"]
+	#[unit = "ms-2"]
+	VerticalDiffusionMeridionalAcceleration = 209,
+	#[description = "gravity wave drag zonal acceleration"]
+	#[abbrev = "GWDU"]
+	#[unit = "ms-2"]
+	GravityWaveDragZonalAcceleration = 210,
+	#[description = "gravity wave drag meridional acceleration"]
+	#[abbrev = "GWDV"]
+	#[unit = "ms-2"]
+	GravityWaveDragMeridionalAcceleration = 211,
+	#[description = "convective zonal momentum mixing acceleration"]
+	#[abbrev = "CNVU"]
+	#[unit = "ms-2"]
+	ConvectiveZonalMomentumMixing = 212,
+	#[description = "convective meridional momentum mixing acceleration"]
+	#[abbrev = "CNVV"]
+	#[unit = "ms-2"]
+	ConvectiveMeridionalMomentumMixing = 213,
+	#[description = "tendency of vertical velocity"]
+	#[abbrev = "WTEND"]
+	#[unit = "ms-2"]
+	VerticalVelocityTendency = 214,
+	#[description = "omega (dp/dt) divide by density"]
+	#[abbrev = "OMGALF"]
+	#[unit = "K"]
+	OmegaDividedByDensity = 215,
+	#[description = "convective gravity wave drag zonal acceleration"]
+	#[abbrev = "CNGWDU"]
+	#[unit = "ms-2"]
+	ConvectiveGravityWaveDragZonalAcceleration = 216,
+	#[description = "convective gravity wave drag meridional acceleration"]
+	#[abbrev = "CNGWDV"]
+	#[unit = "ms-2"]
+	ConvectiveGravityWaveDragMeridionalAcceleration = 217,
+	#[description = "velocity point model surface"]
+	#[abbrev = "LMV"]
+	#[unit = "-"]
+	VelocityPointModelSurface = 218,
+	#[description = "potential vorticity (mass-weighted)"]
+	#[abbrev = "PVMWW"]
+	#[unit = "m-1s-1"]
+	MassWeightedPotentialVorticity = 219,
+	#[description = "hourly maximum of upward vertical velocity"]
+	#[abbrev = "MAXUVV"]
+	#[unit = "ms-1"]
+	HourlyMaximumUpwardVerticalVelocity = 220,
+	#[description = "hourly maximum of downward vertical velocity"]
+	#[abbrev = "MAXDVV"]
+	#[unit = "ms-1"]
+	HourlyMaximumDownwardVerticalVelocity = 221,
+	#[description = "u component of hourly maximum 10m wind speed"]
+	#[abbrev = "MAXUW"]
+	#[unit = "ms-1"]
+	HourlyMaximumUComponentWindSpeed = 222,
+	#[description = "v component of hourly maximum 10m wind speed"]
+	#[abbrev = "MAXVW"]
+	#[unit = "ms-1"]
+	HourlyMaximumVComponentWindSpeed = 223,
+	#[description = "ventilation rate"]
+	#[abbrev = "VRATE"]
+	#[unit = "m2s-1"]
+	VentilationRate = 224,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum MassProduct {
+	#[description = "mean sea level pressure (eta reduction)"]
+	#[abbrev = "MSLET"]
+	#[unit = "pa"]
+	MSLPEtaReduction = 192,
+	#[description = "MSL pressure (MAPS system reduction)"]
+	#[abbrev = "MSLMA"]
+	#[unit = "pa"]
+	MSLPMAPSReduction = 198,
+	#[description = "3-hr pressure tendency (std. atmos. reduction)"]
+	#[abbrev = "TSLSA"]
+	#[unit = "pas-1"]
+	ThreeHourPressureTendency = 199,
+	#[description = "pressure of level from which parcel was lifted"]
+	#[abbrev = "PLPL"]
+	#[unit = "pa"]
+	ParcelLiftedPressure = 200,
+	#[description = "x-gradient of log pressure"]
+	#[abbrev = "LPSX"]
+	#[unit = "m-1"]
+	XGradientLogPressure = 201,
+	#[description = "y-gradient of log pressure"]
+	#[abbrev = "LPSY"]
+	#[unit = "m-1"]
+	YGradientLogPressure = 202,
+	#[description = "x-gradient of height"]
+	#[abbrev = "HGTX"]
+	#[unit = "m-1"]
+	XGradientHeight = 203,
+	#[description = "y-gradient of height"]
+	#[abbrev = "HGTY"]
+	#[unit = "m-1"]
+	YGradientHeight = 204,
+	#[description = "layer thickness"]
+	#[abbrev = "LAYTH"]
+	#[unit = "m"]
+	LayerThickness = 205,
+	#[description = "natural log of surface pressure"]
+	#[abbrev = "NLGSP"]
+	#[unit = "ln(kPa)"]
+	NaturalLogSurfacePressure = 206,
+	#[description = "geopotential height (nearest grid point)"]
+	#[abbrev = "HGTN"]
+	#[unit = "gpm"]
+	GeopotentialHeightNearestGridPoint = 211,
+	#[description = "pressure (nearest grid point)"]
+	#[abbrev = "PRESN"]
+	#[unit = "pa"]
+	PressureNearestGridPoint = 212,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum ShortWaveRadiationProduct {
+	#[description = "downward short-wave radiation flux"]
+	#[abbrev = "DSWRF"]
+	#[unit = "Wm-2"]
+	DownwardShortWaveRadiationFlux = 192,
+	#[description = "upward short-wave radiation flux"]
+	#[abbrev = "USWRF"]
+	#[unit = "Wm-2"]
+	UpwardShortWaveRadiationFlux = 193,
+	#[description = "UV-B downward solar flux"]
+	#[abbrev = "DUVB"]
+	#[unit = "Wm-2"]
+	UVBDownwardSolarFlux = 194,
+	#[description = "clear sky UV-B downward solar flux"]
+	#[abbrev = "CDUVB"]
+	#[unit = "Wm-2"]
+	ClearSkyUVBDownwardSolarFlux = 195,
+	#[description = "clear sky downward solar flux"]
+	#[abbrev = "CSDSF"]
+	#[unit = "Wm-2"]
+	ClearSkyDownwardSolarFlux = 196,
+	#[description = "solar radiative heating rate"]
+	#[abbrev = "SWHR"]
+	#[unit = "Ks-1"]
+	SolarRadiativeHeatingRate = 197,
+	#[description = "clear sky upward solar flux"]
+	#[abbrev = "CSUSF"]
+	#[unit = "Wm-2"]
+	ClearSkyUpwardSolarFlux = 198,
+	#[description = "cloud forcing net solar flux"]
+	#[abbrev = "CFNSF"]
+	#[unit = "Wm-2"]
+	CloudForcingNetSolarFlux = 199,
+	#[description = "visible beam downward solar flux"]
+	#[abbrev = "VBDSF"]
+	#[unit = "Wm-2"]
+	VisibleBeamDownwardSolarFlux = 200,
+	#[description = "visible diffuse downward solar flux"]
+	#[abbrev = "VDDSF"]
+	#[unit = "Wm-2"]
+	VisibleDiffuseDownwardSolarFlux = 201,
+	#[description = "near IR beam downward solar flux"]
+	#[abbrev = "NBDSF"]
+	#[unit = "Wm-2"]
+	NearIRBeamDownwardSolarFlux = 202,
+	#[description = "near IR diffuse downward solar flux"]
+	#[abbrev = "NDDSF"]
+	#[unit = "Wm-2"]
+	NearIRDiffuseDownwardSolarFlux = 203,
+	#[description = "downward total radiation flux"]
+	#[abbrev = "DTRF"]
+	#[unit = "Wm-2"]
+	DownwardTotalRadiationFlux = 204,
+	#[description = "upward total radiation flux"]
+	#[abbrev = "UTRF"]
+	#[unit = "Wm-2"]
+	UpwardTotalRadiationFlux = 205,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum LongWaveRadiationProduct {
+	#[description = "downward long-wave radiation flux"]
+	#[abbrev = "DLWRF"]
+	#[unit = "Wm-2"]
+	DownwardLongWaveRadiationFlux = 192,
+	#[description = "upward long-wave radiation flux"]
+	#[abbrev = "ULWRF"]
+	#[unit = "Wm-2"]
+	UpwardLongWaveRadiationFlux = 193,
+	#[description = "long-wave radiative heating rate"]
+	#[abbrev = "LWHR"]
+	#[unit = "Ks-1"]
+	LongWaveRadiativeHeatingRate = 194,
+	#[description = "clear sky upward long-wave flux"]
+	#[abbrev = "CSULF"]
+	#[unit = "Wm-2"]
+	ClearSkyUpwardLongWaveFlux = 195,
+	#[description = "clear sky downward long-wave flux"]
+	#[abbrev = "CSDLF"]
+	#[unit = "Wm-2"]
+	ClearSkyDownwardLongWaveFlux = 196,
+	#[description = "cloud forcing net long-wave flux"]
+	#[abbrev = "CFNLF"]
+	#[unit = "Wm-2"]
+	CloudForcingNetLongWaveFlux = 197,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum CloudProduct {
+	#[description = "non-convective cloud cover"]
+	#[abbrev = "CDLYR"]
+	#[unit = "%"]
+	NonConvectiveCloudCover = 192,
+	#[description = "cloud work function"]
+	#[abbrev = "CWORK"]
+	#[unit = "Jkg-1"]
+	CloudWorkFunction = 193,
+	#[description = "convective cloud efficiency"]
+	#[abbrev = "CUEFI"]
+	#[unit = "-"]
+	ConvectiveCloudEfficiency = 194,
+	#[description = "total condensate"]
+	#[abbrev = "TCOND"]
+	#[unit = "kgkg-1"]
+	TotalCondensate = 195,
+	#[description = "total column-integrated cloud water"]
+	#[abbrev = "TCOLW"]
+	#[unit = "kgm-2"]
+	TotalColumnCloudWater = 196,
+	#[description = "total column-integrated cloud ice"]
+	#[abbrev = "TCOLI"]
+	#[unit = "kgm-2"]
+	TotalColumnCloudIce = 197,
+	#[description = "total column-integrated condensate"]
+	#[abbrev = "TCOLC"]
+	#[unit = "kgm-2"]
+	TotalColumnCondensate = 198,
+	#[description = "ice fraction of total condensate"]
+	#[abbrev = "FICE"]
+	#[unit = "-"]
+	IceFractionOfTotalCondensate = 199,
+	#[description = "convective cloud mass flux"]
+	#[abbrev = "MFLUX"]
+	#[unit = "pas-1"]
+	ConvectiveCloudMassFlux = 200,
+	#[description = "sunshine duration"]
+	#[abbrev = "SUNSD"]
+	#[unit = "s"]
+	SunshineDuration = 201,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum StabilityProduct {
+	#[description = "surface lifted index"]
+	#[abbrev = "LFTX"]
+	#[unit = "K"]
+	SurfaceLiftedIndex = 192,
+	#[description = "best (4 layer) lifted index"]
+	#[abbrev = "4LFTX"]
+	#[unit = "K"]
+	BestFourLayerLiftedIndex = 193,
+	#[description = "richardson number"]
+	#[abbrev = "RI"]
+	#[unit = "-"]
+	RichardsonNumber = 194,
+	#[description = "convective weather detection index"]
+	#[abbrev = "CWDI"]
+	#[unit = "-"]
+	ConvectiveWeatherDetectionIndex = 195,
+	#[description = "ultra violet index"]
+	#[abbrev = "UVI"]
+	#[unit = "Wm-2"]
+	UltraVioletIndex = 196,
+	#[description = "updraft helicity"]
+	#[abbrev = "UPHL"]
+	#[unit = "m2s-2"]
+	UpdraftHelicity = 197,
+	#[description = "leaf area index"]
+	#[abbrev = "LAI"]
+	#[unit = "-"]
+	LeafAreaIndex = 198,
+	#[description = "hourly maximum of updraft helicity"]
+	#[abbrev = "MXUPHL"]
+	#[unit = "m2s-2"]
+	HourlyMaximumUpdraftHelicity = 199,
+	#[description = "hourly minimum of updraft helicity"]
+	#[abbrev = "MNUPHL"]
+	#[unit = "m2s-2"]
+	HourlyMinimumUpdraftHelicity = 200,
+	#[description = "bourgouin negative energy layer (surface to freezing level)"]
+	#[abbrev = "BNEGELAY"]
+	#[unit = "Jkg-1"]
+	BourgouinNegativeEnergyLayer = 201,
+	#[description = "bourgouin positive energy layer (2k above freezing level)"]
+	#[abbrev = "BPOSELAY"]
+	#[unit = "Jkg-1"]
+	BourgouinPositiveEnergyLayer = 202,
+	#[description = "downdraft CAPE"]
+	#[abbrev = "DCAPE"]
+	#[unit = "Jkg-1"]
+	DowndraftCAPE = 203,
+	#[description = "effective storm relative helicity"]
+	#[abbrev = "EFHL"]
+	#[unit = "m2s-2"]
+	EffectiveStormRelativeHelicity = 204,
+	#[description = "enhanced stretching potential"]
+	#[abbrev = "ESP"]
+	#[unit = "-"]
+	EnhancedStretchingPotential = 205,
+	#[description = "critical angle"]
+	#[abbrev = "CANGLE"]
+	#[unit = "degrees"]
+	CriticalAngle = 206,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum ForecastRadarImageryProduct {
+	#[description = "equivalent radar reflectivity factor for rain"]
+	#[abbrev = "REFZR"]
+	#[unit = "mm6m-3"]
+	RainReflectivityFactor = 192,
+	#[description = "equivalent radar reflectivity factor for snow"]
+	#[abbrev = "REFZI"]
+	#[unit = "mm6m-3"]
+	SnowReflectivityFactor = 193,
+	#[description = "equivalent radar reflectivity factor for parameterized convection"]
+	#[abbrev = "REFZC"]
+	#[unit = "mm6m-3"]
+	ConvectionReflectivityFactor = 194,
+	#[description = "reflectivity"]
+	#[abbrev = "REFD"]
+	#[unit = "dB"]
+	Reflectivity = 195,
+	#[description = "composite reflectivity"]
+	#[abbrev = "REFC"]
+	#[unit = "dB"]
+	CompositeReflectivity = 196,
+	#[description = "echo top"]
+	#[abbrev = "RETOP"]
+	#[unit = "m"]
+	EchoTop = 197,
+	#[description = "hourly maximum of simulated reflectivity at 1 km AGL"]
+	#[abbrev = "MAXREF"]
+	#[unit = "dB"]
+	HourlyMaximumSimulatedReflectivity = 198,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum PhysicalAtmosphericProduct {
+	#[description = "maximum snow albedo"]
+	#[abbrev = "MXSALB"]
+	#[unit = "%"]
+	MaximumSnowAlbedo = 192,
+	#[description = "snow free albedo"]
+	#[abbrev = "SNFALB"]
+	#[unit = "%"]
+	SnowFreeAlbedo = 193,
+	#[description = "slight risk convective outlook"]
+	#[abbrev = "SRCONO"]
+	#[unit = "-"]
+	SlightRiskConvectiveOutlook = 194,
+	#[description = "moderate risk convective outlook"]
+	#[abbrev = "MRCONO"]
+	#[unit = "-"]
+	ModerateRiskConvectiveOutlook = 195,
+	#[description = "high risk convective outlook"]
+	#[abbrev = "HRCONO"]
+	#[unit = "-"]
+	HighRiskConvectiveOutlook = 196,
+	#[description = "tornado probability"]
+	#[abbrev = "TORPROB"]
+	#[unit = "%"]
+	TornadoProbability = 197,
+	#[description = "hail probability"]
+	#[abbrev = "HAILPROB"]
+	#[unit = "%"]
+	HailProbability = 198,
+	#[description = "wind probability"]
+	#[abbrev = "WINDPROB"]
+	#[unit = "%"]
+	WindProbability = 199,
+	#[description = "significant tornado probability"]
+	#[abbrev = "STORPROB"]
+	#[unit = "%"]
+	SignificantTornadoProbability = 200,
+	#[description = "significant hail probability"]
+	#[abbrev = "SHAILPRO"]
+	#[unit = "%"]
+	SignificantHailProbability = 201,
+	#[description = "significant wind probability"]
+	#[abbrev = "SWINDPRO"]
+	#[unit = "%"]
+	SignificantWindProbability = 202,
+	#[description = "categorical thunderstorm"]
+	#[abbrev = "TSTMC"]
+	#[unit = "-"]
+	CategoricalThunderstorm = 203,
+	#[description = "number of mixed layers next to surface"]
+	#[abbrev = "MIXLY"]
+	#[unit = "-"]
+	MixedLayerCount = 204,
+	#[description = "flight category"]
+	#[abbrev = "FLGHT"]
+	#[unit = "-"]
+	FlightCategory = 205,
+	#[description = "confidence - ceiling"]
+	#[abbrev = "CICEL"]
+	#[unit = "-"]
+	CeilingConfidence = 206,
+	#[description = "confidence - visibility"]
+	#[abbrev = "CIVIS"]
+	#[unit = "-"]
+	VisibilityConfidence = 207,
+	#[description = "confidence - flight category"]
+	#[abbrev = "CIFLT"]
+	#[unit = "-"]
+	FlightCategoryConfidence = 208,
+	#[description = "low-level aviation interest"]
+	#[abbrev = "LAVNI"]
+	#[unit = "-"]
+	LowLevelAviationInterest = 209,
+	#[description = "high-level aviation interest"]
+	#[abbrev = "HAVNI"]
+	#[unit = "-"]
+	HighLevelAviationInterest = 210,
+	#[description = "visible, black sky albedo"]
+	#[abbrev = "SBSALB"]
+	#[unit = "%"]
+	VisibleBlackSkyAlbedo = 211,
+	#[description = "visible, white sky albedo"]
+	#[abbrev = "SWSALB"]
+	#[unit = "%"]
+	VisibleWhiteSkyAlbedo = 212,
+	#[description = "near IR, black sky albedo"]
+	#[abbrev = "NBSALB"]
+	#[unit = "%"]
+	NearIRBlackSkyAlbedo = 213,
+	#[description = "near IR, white sky albedo"]
+	#[abbrev = "NWSALB"]
+	#[unit = "%"]
+	NearIRWhiteSkyAlbedo = 214,
+	#[description = "total probability of severe thunderstorms"]
+	#[abbrev = "PRSVR"]
+	#[unit = "%"]
+	SevereThunderstormProbability = 215,
+	#[description = "total probability of extreme severe thunderstorms"]
+	#[abbrev = "PRSIGSVR"]
+	#[unit = "%"]
+	ExtremeSevereThunderstormProbability = 216,
+	#[description = "supercooled large droplet (SLD) icing"]
+	#[abbrev = "SIPD"]
+	#[unit = "-"]
+	SupercooledLargeDropletIcing = 217,
+	#[description = "radiative emissivity"]
+	#[abbrev = "EPSR"]
+	#[unit = "-"]
+	RadiativeEmissivity = 218,
+	#[description = "turbulence potential forecast index"]
+	#[abbrev = "TPFI"]
+	#[unit = "-"]
+	TurbulencePotentialForecastIndex = 219,
+	#[description = "categorical severe thunderstorm"]
+	#[abbrev = "SVRTS"]
+	#[unit = "-"]
+	CategoricalSevereThunderstorm = 220,
+	#[description = "probability of convection"]
+	#[abbrev = "PROCON"]
+	#[unit = "%"]
+	ConvectionProbability = 221,
+	#[description = "convection potential"]
+	#[abbrev = "CONVP"]
+	#[unit = "-"]
+	ConvectionPotential = 222,
+	#[description = "volcanic ash forecast transport and dispersion"]
+	#[abbrev = "VAFTD"]
+	#[unit = "log10(kgm-3)"]
+	VolcanicAshDispersion = 232,
+	#[description = "icing probability"]
+	#[abbrev = "ICPRB"]
+	#[unit = "-"]
+	IcingProbability = 233,
+	#[description = "icing severity"]
+	#[abbrev = "ICSEV"]
+	#[unit = "-"]
+	IcingSeverity = 234,
+	#[description = "joint fire weather probability"]
+	#[abbrev = "JFWPRB"]
+	#[unit = "%"]
+	JointFireWeatherProbability = 235,
+	#[description = "snow level"]
+	#[abbrev = "SNOWLVL"]
+	#[unit = "m"]
+	SnowLevel = 236,
+	#[description = "dry thunderstorm probability"]
+	#[abbrev = "DRYTPROB"]
+	#[unit = "%"]
+	DryThunderstormProbability = 237,
+	#[description = "ellrod index"]
+	#[abbrev = "ELLINX"]
+	#[unit = "-"]
+	EllrodIndex = 238,
+	#[description = "craven-wiedenfeld aggregate severe parameter"]
+	#[abbrev = "CWASP"]
+	#[unit = "-"]
+	CravenWiedenfeldAggregate = 239,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum HydrologyBasicProduct {
+	#[description = "baseflow-groundwater runoff"]
+	#[abbrev = "BGRUN"]
+	#[unit = "kgm-2"]
+	BaseflowGroundwaterRunoff = 192,
+	#[description = "storm surface runoff"]
+	#[abbrev = "SSRUN"]
+	#[unit = "kgm-2"]
+	StormSurfaceRunoff = 193,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum HydrologyProbabilityProduct {
+	#[description = "probability of freezing precipitation"]
+	#[abbrev = "CPOZP"]
+	#[unit = "%"]
+	FreezingPrecipitationProbability = 192,
+	#[description = "probability of frozen precipitation"]
+	#[abbrev = "CPOFP"]
+	#[unit = "%"]
+	FrozenPrecipitationProbability = 193,
+	#[description = "probability of precipitation exceeding flash flood guidance values"]
+	#[abbrev = "PPFFG"]
+	#[unit = "%"]
+	FlashFloodGuidanceExceedanceProbability = 194,
+	#[description = "probability of wetting rain, exceeding in 0.10 inch in a given time period"]
+	#[abbrev = "CWR"]
+	#[unit = "%"]
+	WettingRainProbability = 195,
+	#[description = "binary probability of precipitation exceeding average recurrence intervals"]
+	#[abbrev = "QPFARI"]
+	#[unit = "-"]
+	AverageRecurrenceIntervalExceedance = 196,
+	#[description = "binary probability of precipitation exceeding flash flood guidance"]
+	#[abbrev = "QPFFFG"]
+	#[unit = "-"]
+	FlashFloodGuidanceExceedance = 197,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum VegetationProduct {
+	#[description = "volumetric soil moisture content"]
+	#[abbrev = "SOILW"]
+	#[unit = "proportion"]
+	VolumetricSoilMoistureContent = 192,
+	#[description = "ground heat flux"]
+	#[abbrev = "GFLUX"]
+	#[unit = "Wm-2"]
+	GroundHeatFlux = 193,
+	#[description = "moisture availability"]
+	#[abbrev = "MSTAV"]
+	#[unit = "%"]
+	MoistureAvailability = 194,
+	#[description = "exchange coefficient"]
+	#[abbrev = "SFEXC"]
+	#[unit = "kgm-2s-1"]
+	ExchangeCoefficient = 195,
+	#[description = "plant canopy surface water"]
+	#[abbrev = "CNWAT"]
+	#[unit = "kgm-2"]
+	PlantCanopySurfaceWater = 196,
+	#[description = "blackadar's mixing length scale"]
+	#[abbrev = "BMIXL"]
+	#[unit = "m"]
+	BlackadarMixingLengthScale = 197,
+	#[description = "vegetation type"]
+	#[abbrev = "VGTYP"]
+	#[unit = "-"]
+	VegetationType = 198,
+	#[description = "canopy conductance"]
+	#[abbrev = "CCOND"]
+	#[unit = "ms-1"]
+	CanopyConductance = 199,
+	#[description = "minimal stomatal resistance"]
+	#[abbrev = "RSMIN"]
+	#[unit = "sm-1"]
+	MinimalStomatalResistance = 200,
+	#[description = "wilting point"]
+	#[abbrev = "WILT"]
+	#[unit = "proportion"]
+	WiltingPoint = 201,
+	#[description = "solar parameter in canopy conductance"]
+	#[abbrev = "RCS"]
+	#[unit = "proportion"]
+	CanopyConductanceSolarParameter = 202,
+	#[description = "temperature parameter in canopy conductance"]
+	#[abbrev = "RCT"]
+	#[unit = "proportion"]
+	CanopyConductanceTemperatureParameter = 203,
+	#[description = "humidity parameter in canopy conductance"]
+	#[abbrev = "RCQ"]
+	#[unit = "proportion"]
+	CanopyConductanceHumidityParameter = 204,
+	#[description = "soil moisture parameter in canopy conductance"]
+	#[abbrev = "RCSOL"]
+	#[unit = "proportion"]
+	CanopyConductanceSoilMoistureParameter = 205,
+	#[description = "rate of water dropping from canopy to ground"]
+	#[abbrev = "RDRIP"]
+	#[unit = "-"]
+	CanopyDripRate = 206,
+	#[description = "ice-free water surface"]
+	#[abbrev = "ICWAT"]
+	#[unit = "%"]
+	IceFreeWaterSurface = 207,
+	#[description = "surface exchange coefficients for T and Q divided by delta z"]
+	#[abbrev = "AKHS"]
+	#[unit = "ms-1"]
+	HeatMoistureExchangeCoefficient = 208,
+	#[description = "surface exchange coefficients for U and V divided by delta z"]
+	#[abbrev = "AKMS"]
+	#[unit = "ms-1"]
+	MomentumExchangeCoefficient = 209,
+	#[description = "vegetation canopy temperature"]
+	#[abbrev = "VEGT"]
+	#[unit = "K"]
+	VegetationCanopyTemperature = 210,
+	#[description = "surface water storage"]
+	#[abbrev = "SSTOR"]
+	#[unit = "kgm-2"]
+	SurfaceWaterStorage = 211,
+	#[description = "liquid soil moisture content (non-frozen)"]
+	#[abbrev = "LSOIL"]
+	#[unit = "kgm-2"]
+	LiquidSoilMoistureContent = 212,
+	#[description = "open water evaporation (standing water)"]
+	#[abbrev = "EWATR"]
+	#[unit = "Wm-2"]
+	OpenWaterEvaporation = 213,
+	#[description = "groundwater recharge"]
+	#[abbrev = "GWREC"]
+	#[unit = "kgm-2"]
+	GroundwaterRecharge = 214,
+	#[description = "flood plain recharge"]
+	#[abbrev = "QREC"]
+	#[unit = "kgm-2"]
+	FloodPlainRecharge = 215,
+	#[description = "roughness length for heat"]
+	#[abbrev = "SFCRH"]
+	#[unit = "m"]
+	HeatRoughnessLength = 216,
+	#[description = "normalized difference vegetation index"]
+	#[abbrev = "NDVI"]
+	#[unit = "-"]
+	NormalizedDifferenceVegetationIndex = 217,
+	#[description = "land-sea coverage (nearest neighbor)"]
+	#[abbrev = "LANDN"]
+	#[unit = "-"]
+	LandSeaCoverageNearestNeighbor = 218,
+	#[description = "asymptotic mixing length scale"]
+	#[abbrev = "AMIXL"]
+	#[unit = "m"]
+	AsymptoticMixingLengthScale = 219,
+	#[description = "water vapor added by precip assimilation"]
+	#[abbrev = "WVINC"]
+	#[unit = "kgm-2"]
+	PrecipAssimilationWaterVapor = 220,
+	#[description = "water condensate added by precip assimilation"]
+	#[abbrev = "WCINC"]
+	#[unit = "kgm-2"]
+	PrecipAssimilationWaterCondensate = 221,
+	#[description = "water vapor flux convergence (vertical int)"]
+	#[abbrev = "WVCONV"]
+	#[unit = "kgm-2"]
+	WaterVaporFluxConvergence = 222,
+	#[description = "water condensate flux convergence (vertical int)"]
+	#[abbrev = "WCCONV"]
+	#[unit = "kgm-2"]
+	WaterCondensateFluxConvergence = 223,
+	#[description = "water vapor zonal flux (vertical int)"]
+	#[abbrev = "WVUFLX"]
+	#[unit = "kgm-2"]
+	WaterVaporZonalFlux = 224,
+	#[description = "water vapor meridional flux (vertical int)"]
+	#[abbrev = "WVVFLX"]
+	#[unit = "kgm-2"]
+	WaterVaporMeridionalFlux = 225,
+	#[description = "water condensate zonal flux (vertical int)"]
+	#[abbrev = "WCUFLX"]
+	#[unit = "kgm-2"]
+	WaterCondensateZonalFlux = 226,
+	#[description = "water condensate meridional flux (vertical int)"]
+	#[abbrev = "WCVFLX"]
+	#[unit = "kgm-2"]
+	WaterCondensateMeridionalFlux = 227,
+	#[description = "aerodynamic conductance"]
+	#[abbrev = "ACOND"]
+	#[unit = "ms-1"]
+	AerodynamicConductance = 228,
+	#[description = "canopy water evaporation"]
+	#[abbrev = "EVCW"]
+	#[unit = "Wm-2"]
+	CanopyWaterEvaporation = 229,
+	#[abbrev = "TRANS"]
+	#[unit = "Wm-2"]
+	Transpiration = 230,
+	#[description = "seasonally minimum green vegetation fraction"]
+	#[abbrev = "VEGMIN"]
+	#[unit = "%"]
+	SeasonalMinimumGreenVegetation = 231,
+	#[description = "seasonally maximum green vegetation fraction"]
+	#[abbrev = "VEGMAX"]
+	#[unit = "%"]
+	SeasonalMaximumGreenVegetation = 232,
+	#[description = "land fraction"]
+	#[abbrev = "LANDFRC"]
+	#[unit = "-"]
+	LandFraction = 233,
+	#[description = "lake fraction"]
+	#[abbrev = "LAKEFRC"]
+	#[unit = "-"]
+	LakeFraction = 234,
+	#[description = "precipitation advected heat flux"]
+	#[abbrev = "PAHFLX"]
+	#[unit = "Wm-2"]
+	PrecipitationAdvectedHeatFlux = 235,
+	#[description = "water storage in aquifer"]
+	#[abbrev = "WATERSA"]
+	#[unit = "kgm-2"]
+	AquiferWaterStorage = 236,
+	#[description = "evaporation of intercepted water"]
+	#[abbrev = "EIWATER"]
+	#[unit = "kgm-2"]
+	InterceptedWaterEvaporation = 237,
+	#[description = "plant transpiration"]
+	#[abbrev = "PLANTTR"]
+	#[unit = "kgm-2"]
+	PlantTranspiration = 238,
+	#[description = "soil surface evaporation"]
+	#[abbrev = "SOILSE"]
+	#[unit = "kgm-2"]
+	SoilSurfaceEvaporation = 239,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum SoilProduct {
+	#[description = "liquid volumetric soil moisture (non-frozen)"]
+	#[abbrev = "SOILL"]
+	#[unit = "proportion"]
+	LiquidVolumetricSoilMoisture = 192,
+	#[description = "number of soil layers in root zone"]
+	#[abbrev = "RLYRS"]
+	#[unit = "-"]
+	RootZoneSoilLayerCount = 193,
+	#[description = "surface slope type"]
+	#[abbrev = "SLTYP"]
+	#[unit = "-"]
+	SurfaceSlopeType = 194,
+	#[description = "transpiration stress-onset (soil moisture)"]
+	#[abbrev = "SMREF"]
+	#[unit = "proportion"]
+	TranspirationStressOnset = 195,
+	#[description = "direct evaporation cease (soil moisture)"]
+	#[abbrev = "SMDRY"]
+	#[unit = "proportion"]
+	DirectEvaporationCease = 196,
+	#[description = "soil porosity"]
+	#[abbrev = "POROS"]
+	#[unit = "proportion"]
+	SoilPorosity = 197,
+	#[description = "direct evaporation from bare soil"]
+	#[abbrev = "EVBS"]
+	#[unit = "Wm-2"]
+	BareSoilEvaporation = 198,
+	#[description = "land surface precipitation accumulation"]
+	#[abbrev = "LSPA"]
+	#[unit = "kgm-2"]
+	LandSurfacePrecipitationAccumulation = 199,
+	#[description = "bare soil surface skin temperature"]
+	#[abbrev = "BARET"]
+	#[unit = "K"]
+	BareSoilSkinTemperature = 200,
+	#[description = "average surface skin temperature"]
+	#[abbrev = "AVSFT"]
+	#[unit = "K"]
+	AverageSkinTemperature = 201,
+	#[description = "effective radiative skin temperature"]
+	#[abbrev = "RADT"]
+	#[unit = "K"]
+	EffectiveRadiativeSkinTemperature = 202,
+	#[description = "field capacity"]
+	#[abbrev = "FLDCP"]
+	#[unit = "fraction"]
+	FieldCapacity = 203,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum WavesProduct {
+	#[description = "wave steepness"]
+	#[abbrev = "WSTP"]
+	#[unit = "porportion"]
+	WaveSteepness = 192, 
+	#[description = "wave length"]
+	#[abbrev = "WLENG"]
+	#[unit = "-"]
+	WaveLength = 193,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum CurrentsProduct {
+	#[description = "ocean mixed layer u velocity"]
+	#[abbrev = "OMLU"]
+	#[unit = "ms-1"]
+	OceanMixedLayerUVelocity = 192,
+	#[description = "ocean mixed layer v velocity"]
+	#[abbrev = "OMLV"]
+	#[unit = "ms-1"]
+	OceanMixedLayerVVelocity = 193,
+	#[description = "barotropic u velocity"]
+	#[abbrev = "UBARO"]
+	#[unit = "ms-1"]
+	BarotropicUVelocity = 194,
+	#[description = "barotropic v velocity"]
+	#[abbrev = "VBARO"]
+	#[unit = "ms-1"]
+	BarotropicVVelocity = 195,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum OceanSurfaceProduct {
+	#[description = "hurricane storm surge"]
+	#[abbrev = "SURGE"]
+	#[unit = "m"]
+	HurricaneStormSurge = 192,
+	#[description = "extra tropical storm surge"]
+	#[abbrev = "ETSRG"]
+	#[unit = "m"]
+	ExtraTropicalStormSurge = 193,
+	#[description = "ocean surface elevation relative to geoid"]
+	#[abbrev = "ELEV"]
+	#[unit = "m"]
+	OceanSurfaceElevation = 194,
+	#[description = "sea surface height relative to geoid"]
+	#[abbrev = "SSHG"]
+	#[unit = "m"]
+	SeaSurfaceHeight = 195,
+	#[description = "ocean mixed layer potential density (reference 2000m)"]
+	#[abbrev = "P2OMLT"]
+	#[unit = "kgm-3"]
+	OceanMixedLayerPotentialDensity = 196,
+	#[description = "net air-ocean heat flux"]
+	#[abbrev = "AOHFLX"]
+	#[unit = "Wm-2"]
+	NetAirOceanHeatFlux = 197,
+	#[description = "assimilative heat flux"]
+	#[abbrev = "ASHFL"]
+	#[unit = "Wm-2"]
+	AssimilativeHeatFlux = 198,
+	#[description = "surface temperature trend"]
+	#[abbrev = "SSTT"]
+	#[unit = "degreeday-1"]
+	SurfaceTemperatureTrend = 199,
+	#[description = "surface salinity trend"]
+	#[abbrev = "SSST"]
+	#[unit = "psuday-1"]
+	SurfaceSalinityTrend = 200,
+	#[description = "kinetic energy"]
+	#[abbrev = "KENG"]
+	#[unit = "Jkg-1"]
+	KineticEnergy = 201,
+	#[description = "salt flux"]
+	#[abbrev = "SLTFL"]
+	#[unit = "kgm-2s-1"]
+	SaltFlux = 202,
+	#[description = "20% tropical cyclone storm surge exceedance"]
+	#[abbrev = "TCSRG20"]
+	#[unit = "m"]
+	TropicalCycloneStormSurge20 = 242,
+	#[description = "30% tropical cyclone storm surge exceedance"]
+	#[abbrev = "TCSRG30"]
+	#[unit = "m"]
+	TropicalCycloneStormSurge30 = 243,
+	#[description = "40% tropical cyclone storm surge exceedance"]
+	#[abbrev = "TCSRG40"]
+	#[unit = "m"]
+	TropicalCycloneStormSurge40 = 244,
+	#[description = "50% tropical cyclone storm surge exceedance"]
+	#[abbrev = "TCSRG50"]
+	#[unit = "m"]
+	TropicalCycloneStormSurge50 = 245,
+	#[description = "60% tropical cyclone storm surge exceedance"]
+	#[abbrev = "TCSRG60"]
+	#[unit = "m"]
+	TropicalCycloneStormSurge60 = 246,
+	#[description = "70% tropical cyclone storm surge exceedance"]
+	#[abbrev = "TCSRG70"]
+	#[unit = "m"]
+	TropicalCycloneStormSurge70 = 247,
+	#[description = "80% tropical cyclone storm surge exceedance"]
+	#[abbrev = "TCSRG80"]
+	#[unit = "m"]
+	TropicalCycloneStormSurge80 = 248,
+	#[description = "90% tropical cyclone storm surge exceedance"]
+	#[abbrev = "TCSRG90"]
+	#[unit = "m"]
+	TropicalCycloneStormSurge90 = 249,
+	#[description = "extra tropical storm surge combined surge and tide"]
+	#[abbrev = "ETCWL"]
+	#[unit = "m"]
+	ExtraTropicalStormSurgeAndTide = 250,
+	#[description = "10% tropical cyclone storm surge exceedance"]
+	#[abbrev = "TCSRG10"]
+	#[unit = "m"]
+	TropicalCycloneStormSurge10 = 251,
+}
+
+pub fn parameter(discipline: u8, category: u8, parameter: u8) -> Option<Parameter> {
+	match (discipline, category) {
+		(0, 1) => MoistureProduct::from_value(parameter).map(Parameter::from),
+		(0, 2) => MomentumProduct::from_value(parameter).map(Parameter::from),
+		(0, 3) => MassProduct::from_value(parameter).map(Parameter::from),
+		(0, 4) => ShortWaveRadiationProduct::from_value(parameter).map(Parameter::from),
+		(0, 5) => LongWaveRadiationProduct::from_value(parameter).map(Parameter::from),
+		(0, 6) => CloudProduct::from_value(parameter).map(Parameter::from),
+		(0, 7) => StabilityProduct::from_value(parameter).map(Parameter::from),
+		(0, 16) => ForecastRadarImageryProduct::from_value(parameter).map(Parameter::from),
+		(0, 19) => PhysicalAtmosphericProduct::from_value(parameter).map(Parameter::from),
+		(1, 0) => HydrologyBasicProduct::from_value(parameter).map(Parameter::from),
+		(1, 1) => HydrologyProbabilityProduct::from_value(parameter).map(Parameter::from),
+		(2, 0) => VegetationProduct::from_value(parameter).map(Parameter::from),
+		(2, 3) => SoilProduct::from_value(parameter).map(Parameter::from),
+		(10, 0) => WavesProduct::from_value(parameter).map(Parameter::from),
+		(10, 1) => CurrentsProduct::from_value(parameter).map(Parameter::from),
+		(10, 3) => OceanSurfaceProduct::from_value(parameter).map(Parameter::from),
+		_ => None,
+	}
+}
+
+pub fn table() -> Vec<(u8, u8, ParameterTable)> {
+	vec![
+		(0, 1, MoistureProduct::parameters()),
+		(0, 2, MomentumProduct::parameters()),
+		(0, 3, MassProduct::parameters()),
+		(0, 4, ShortWaveRadiationProduct::parameters()),
+		(0, 5, LongWaveRadiationProduct::parameters()),
+		(0, 6, CloudProduct::parameters()),
+		(0, 7, StabilityProduct::parameters()),
+		(0, 16, ForecastRadarImageryProduct::parameters()),
+		(0, 19, PhysicalAtmosphericProduct::parameters()),
+		(1, 0, HydrologyBasicProduct::parameters()),
+		(1, 1, HydrologyProbabilityProduct::parameters()),
+		(2, 0, VegetationProduct::parameters()),
+		(2, 3, SoilProduct::parameters()),
+		(10, 0, WavesProduct::parameters()),
+		(10, 1, CurrentsProduct::parameters()),
+		(10, 3, OceanSurfaceProduct::parameters()),
+	]
+}
@@ -0,0 +1,1485 @@
+use gribberish_macros::{DisplayDescription, FromValue, ToParameter};
+use crate::Parameter;
+use super::ParameterTable;
+
+pub const DISCIPLINE: u8 = 0;
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum TemperatureProduct {
+	#[abbrev = "TMP"]
+	#[unit = "K"]
+	Temperature = 0,
+	#[description = "virtual temperature"]
+	#[abbrev = "VTMP"]
+	#[unit = "K"]
+	VirtualTemperature = 1,
+	#[description = "potential temperature"]
+	#[abbrev = "POT"]
+	#[unit = "K"]
+	PotentialTemperature = 2,
+	#[description = "pseudo-adiabatic potential temperature"]
+	#[abbrev = "EPOT"]
+	#[unit = "K"]
+	PseudoAdiabaticPotentialTemperature = 3,
+	#[description = "maximum temperature"]
+	#[abbrev = "TMAX"]
+	#[unit = "K"]
+	MaximumTemperature = 4,
+	#[description = "minimum temperature"]
+	#[abbrev = "TMIN"]
+	#[unit = "K"]
+	MinimumTemperature = 5,
+	#[description = "depoint temperature"]
+	#[abbrev = "DPT"]
+	#[unit = "K"]
+	DewpointTemperature = 6,
+	#[description = "dewpoint depression"]
+	#[abbrev = "DEPR"]
+	#[unit = "K"]
+	DewpointDepression = 7,
+	#[description = "lapse rate"]
+	#[abbrev = "LAPR"]
+	#[unit = "Km-1"]
+	LapseRate = 8,
+	#[description = "heat index"]
+	#[abbrev = "HEATX"]
+	#[unit = "K"]
+	HeatIndex = 12, 
+	#[description = "wind chill factor"]
+	#[abbrev = "WCF"]
+	#[unit = "K"]
+	WindChillFactor = 13,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum MoistureProduct {
+	#[description = "specific humidity"]
+	#[abbrev = "SPFH"]
+	#[unit = "kgkg-1"]
+	SpecificHumidity = 0,
+	#[description = "relative humidity"]
+	#[abbrev = "RH"]
+	#[unit = "%"]
+	RelativeHumidity = 1,
+	#[description = "humidity mixing ratio"]
+	#[abbrev = "MIXR"]
+	#[unit = "kgkg-1"]
+	HumidityMixingRatio = 2,
+	#[description = "precipitable water"]
+	#[abbrev = "PWAT"]
+	#[unit = "kgm-2"]
+	PrecipitableWater = 3,
+	#[description = "vapour pressure"]
+	#[abbrev = "VAPP"]
+	#[unit = "pa"]
+	VapourPressure = 4,
+	#[description = "saturation deficit"]
+	#[abbrev = "SATD"]
+	#[unit = "pa"]
+	SaturationDeficit = 5,
+	#[abbrev = "EVP"]
+	#[unit = "kgm-2"]
+	Evaporation = 6,
+	#[description = "precipitation rate"]
+	#[abbrev = "PRATE"]
+	#[unit = "kgm-2s-1"]
+	PrecipitationRate = 7,
+	#[description = "total precipitation"]
+	#[abbrev = "APCP"]
+	#[unit = "kgm-2"]
+	TotalPrecipitation = 8,
+	#[description = "large scale precipitation (non-convective)"]
+	#[abbrev = "NCPCP"]
+	#[unit = "kgm-2"]
+	LargeScalePrecipitation = 9,
+	#[description = "convective precipitation"]
+	#[abbrev = "ACPCP"]
+	#[unit = "kgm-2"]
+	ConvectivePrecipitation = 10,
+	#[description = "snow depth"]
+	#[abbrev = "SNOD"]
+	#[unit = "m"]
+	SnowDepth = 11,
+	#[description = "snowfall rate water equivalent"]
+	#[abbrev = "SRWEQ"]
+	#[unit = "kgm-2s-1"]
+	SnowfallRateWaterEquivalent = 12,
+	#[description = "water equivalent of accumulated snow depth"]
+	#[abbrev = "WEASD"]
+	#[unit = "kgm-2"]
+	WaterEquivalentSnowDepth = 13,
+	#[description = "convective snow"]
+	#[abbrev = "SNOC"]
+	#[unit = "kgm-2"]
+	ConvectiveSnow = 14,
+	#[description = "large scale snow"]
+	#[abbrev = "SNOL"]
+	#[unit = "kgm-2"]
+	LargeScaleSnow = 15,
+	#[description = "snow melt"]
+	#[abbrev = "SNOM"]
+	#[unit = "kgm-2"]
+	SnowMelt = 16,
+	#[description = "snow age"]
+	#[abbrev = "SNOAG"]
+	#[unit = "day"]
+	SnowAge = 17,
+	#[description = "absolute humidity"]
+	#[abbrev = "ABSH"]
+	#[unit = "kgm-3"]
+	AbsoluteHumidity = 18,
+	#[description = "precipitation type"]
+	#[abbrev = "PTYPE"]
+	#[unit = "-"]
+	PrecipitationType = 19,
+	#[description = "integrated liquid water"]
+	#[abbrev = "ILIQW"]
+	#[unit = "kgm-2"]
+	IntegratedLiquidWater = 20,
+	#[description = "condensate"]
+	#[abbrev = "TCOND"]
+	#[unit = "kgkg-1"]
+	Condensate = 21,
+	#[description = "cloud mixing ratio"]
+	#[abbrev = "CLMR"]
+	#[unit = "kgkg-1"]
+	CloudMixingRatio = 22,
+	#[description = "ice water mixing ratio"]
+	#[abbrev = "ICMR"]
+	#[unit = "kgkg-1"]
+	IceWaterMixingRatio = 23,
+	#[description = "rain mixing ratio"]
+	#[abbrev = "RWMR"]
+	#[unit = "kgkg-1"]
+	RainMixingRatio = 24,
+	#[description = "snow mixing ratio"]
+	#[abbrev = "SNMR"]
+	#[unit = "kgkg-1"]
+	SnowMixingRatio = 25,
+	#[description = "horizontal moisture convergence"]
+	#[abbrev = "MCONV"]
+	#[unit = "kgkg-1s-1"]
+	HorizontalMoistureConvergence = 26,
+	#[description = "maximum relative humidity"]
+	#[abbrev = "MAXRH"]
+	#[unit = "%"]
+	MaximumRelativeHumidity = 27,
+	#[description = "maximum absolute humidity"]
+	#[abbrev = "MAXAH"]
+	#[unit = "kgm-3"]
+	MaximumAbsoluteHumidity = 28,
+	#[description = "total snowfall"]
+	#[abbrev = "ASNOW"]
+	#[unit = "m"]
+	TotalSnowfall = 29,
+	#[description = "precipitable water category"]
+	#[abbrev = "PWCAT"]
+	#[unit = "-"]
+	PrecipitableWaterCategory = 30,
+	#[abbrev = "HAIL"]
+	#[unit = "m"]
+	Hail = 31,
+	#[description = "graupel (snow pellets)"]
+	#[abbrev = "GRLE"]
+	#[unit = "kgkg-1"]
+	Graupel = 32,
+	#[description = "categorical rain"]
+	#[abbrev = "CRAIN"]
+	#[unit = "-"]
+	CategoricalRain = 33,
+	#[description = "categorical freezing rain"]
+	#[abbrev = "CFRZR"]
+	#[unit = "-"]
+	CategoricalFreezingRain = 34,
+	#[description = "categorical ice pellets"]
+	#[abbrev = "CICEP"]
+	#[unit = "-"]
+	CategoricalIcePellets = 35,
+	#[description = "categorical snow"]
+	#[abbrev = "CSNOW"]
+	#[unit = "-"]
+	CategoricalSnow = 36,
+	#[description = "convective precipitation rate"]
+	#[abbrev = "CPRAT"]
+	#[unit = "kgm-2s-1"]
+	ConvectivePrecipitationRate = 37,
+	#[description = "horizontal moisture divergence"]
+	#[abbrev = "MDIVER"]
+	#[unit = "kgkg-1s-1"]
+	HorizontalMoistureDivergence = 38,
+	#[description = "percent frozen precipitation"]
+	#[abbrev = "CPOFP"]
+	#[unit = "%"]
+	PercentFrozenPrecipitation = 39,
+	#[description = "potential evaporation"]
+	#[abbrev = "PEVAP"]
+	#[unit = "kgm-2"]
+	PotentialEvaporation = 40,
+	#[description = "potential evaporation rate"]
+	#[abbrev = "PEVPR"]
+	#[unit = "Wm-2"]
+	PotentialEvaporationRate = 41,
+	#[description = "snow cover"]
+	#[abbrev = "SNOWC"]
+	#[unit = "%"]
+	SnowCover = 42,
+	#[description = "rain fraction of total cloud water"]
+	#[abbrev = "FRAIN"]
+	#[unit = "proportion"]
+	RainFractionOfTotalCloudWater = 43,
+	#[description = "rime factor"]
+	#[abbrev = "RIME"]
+	#[unit = "-"]
+	RimeFactor = 44,
+	#[description = "total column integrated rain"]
+	#[abbrev = "TCOLR"]
+	#[unit = "kgm-2"]
+	TotalColumnRain = 45,
+	#[description = "total column integrated snow"]
+	#[abbrev = "TCOLS"]
+	#[unit = "kgm-2"]
+	TotalColumnSnow = 46,
+	#[description = "large scale water precipitation (non-convective)"]
+	#[abbrev = "LSWP"]
+	#[unit = "kgm-2"]
+	LargeScaleWaterPrecipitation = 47,
+	#[description = "convective water precipitation"]
+	#[abbrev = "CWP"]
+	#[unit = "kgm-2"]
+	ConvectiveWaterPrecipitation = 48,
+	#[description = "total water precipitation"]
+	#[abbrev = "TWATP"]
+	#[unit = "kgm-2"]
+	TotalWaterPrecipitation = 49,
+	#[description = "total snow precipitation"]
+	#[abbrev = "TSNOWP"]
+	#[unit = "kgm-2"]
+	TotalSnowPrecipitation = 50,
+	#[description = "total column water"]
+	#[abbrev = "TCWAT"]
+	#[unit = "kgm-2"]
+	TotalColumnWater = 51,
+	#[description = "total precipitation rate"]
+	#[abbrev = "TPRATE"]
+	#[unit = "kgm-2s-1"]
+	TotalPrecipitationRate = 52,
+	#[description = "total snowfall rate water equivalent"]
+	#[abbrev = "TSRWE"]
+	#[unit = "kgm-2s-1"]
+	TotalSnowfallRateWaterEquivalent = 53,
+	#[description = "large scale precipitation rate"]
+	#[abbrev = "LSPRATE"]
+	#[unit = "kgm-2s-1"]
+	LargeScalePrecipitationRate = 54,
+	#[description = "convective snowfall rate water equivalent"]
+	#[abbrev = "CSRWE"]
+	#[unit = "kgm-2s-1"]
+	ConvectiveSnowfallRateWaterEquivalent = 55,
+	#[description = "large scale snowfall rate water equivalent"]
+	#[abbrev = "LSSRWE"]
+	#[unit = "kgm-2s-1"]
+	LargeScaleSnowfallRateWaterEquivalent = 56,
+	#[description = "total snowfall rate"]
+	#[abbrev = "TSRATE"]
+	#[unit = "ms-1"]
+	TotalSnowfallRate = 57,
+	#[description = "convective snowfall rate"]
+	#[abbrev = "CSRATE"]
+	#[unit = "ms-1"]
+	ConvectiveSnowfallRate = 58,
+	#[description = "large scale snowfall rate"]
+	#[abbrev = "LSSRATE"]
+	#[unit = "ms-1"]
+	LargeScaleSnowfallRate = 59,
+	#[description = "snow depth water equivalent"]
+	#[abbrev = "SDWE"]
+	#[unit = "kgm-2"]
+	SnowDepthWaterEquivalent = 60,
+	#[description = "snow density"]
+	#[abbrev = "SDEN"]
+	#[unit = "kgm-3"]
+	SnowDensity = 61,
+	#[description = "snow evaporation"]
+	#[abbrev = "SEVAP"]
+	#[unit = "kgm-2"]
+	SnowEvaporation = 62,
+	#[description = "total column integrated water vapour"]
+	#[abbrev = "TCIWV"]
+	#[unit = "kgm-2"]
+	TotalColumnWaterVapour = 64,
+	#[description = "rain precipitation rate"]
+	#[abbrev = "RPRATE"]
+	#[unit = "kgm-2s-1"]
+	RainPrecipitationRate = 65,
+	#[description = "snow precipitation rate"]
+	#[abbrev = "SPRATE"]
+	#[unit = "kgm-2s-1"]
+	SnowPrecipitationRate = 66,
+	#[description = "freezing rain precipitation rate"]
+	#[abbrev = "FPRATE"]
+	#[unit = "kgm-2s-1"]
+	FreezingRainPrecipitationRate = 67,
+	#[description = "ice pellets precipitation rate"]
+	#[abbrev = "IPRATE"]
+	#[unit = "kgm-2s-1"]
+	IcePelletsPrecipitationRate = 68,
+	#[description = "total column integrated cloud water"]
+	#[abbrev = "TCOLW"]
+	#[unit = "kgm-2"]
+	TotalColumnCloudWater = 69,
+	#[description = "total column integrated cloud ice"]
+	#[abbrev = "TCOLI"]
+	#[unit = "kgm-2"]
+	TotalColumnCloudIce = 70,
+	#[description = "hail mixing ratio"]
+	#[abbrev = "HAILMXR"]
+	#[unit = "kgkg-1"]
+	HailMixingRatio = 71,
+	#[description = "total column integrated hail"]
+	#[abbrev = "TCOLH"]
+	#[unit = "kgm-2"]
+	TotalColumnHail = 72,
+	#[description = "hail precipitation rate"]
+	#[abbrev = "HAILPR"]
+	#[unit = "kgm-2s-1"]
+	HailPrecipitationRate = 73,
+	#[description = "total column integrated graupel"]
+	#[abbrev = "TCOLG"]
+	#[unit = "kgm-2"]
+	TotalColumnGraupel = 74,
+	#[description = "graupel (snow pellets) precipitation rate"]
+	#[abbrev = "GPRATE"]
+	#[unit = "kgm-2s-1"]
+	GraupelPrecipitationRate = 75,
+	#[description = "convective rain rate"]
+	#[abbrev = "CRRATE"]
+	#[unit = "kgm-2s-1"]
+	ConvectiveRainRate = 76,
+	#[description = "large scale rain rate"]
+	#[abbrev = "LSRRATE"]
+	#[unit = "kgm-2s-1"]
+	LargeScaleRainRate = 77,
+	#[description = "total column integrated water (all components including precipitation)"]
+	#[abbrev = "TCOLWA"]
+	#[unit = "kgm-2"]
+	TotalColumnWaterAllComponents = 78,
+	#[description = "evaporation rate"]
+	#[abbrev = "EVARATE"]
+	#[unit = "kgm-2s-1"]
+	EvaporationRate = 79,
+	#[description = "total condensate"]
+	#[abbrev = "TOTCON"]
+	#[unit = "kgkg-1"]
+	TotalCondensate = 80,
+	#[description = "total column-integrated condensate"]
+	#[abbrev = "TCICON"]
+	#[unit = "kgm-2"]
+	TotalColumnCondensate = 81,
+	#[description = "cloud ice mixing-ratio"]
+	#[abbrev = "CIMIXR"]
+	#[unit = "kgkg-1"]
+	CloudIceMixingRatio = 82,
+	#[description = "specific cloud liquid water content"]
+	#[abbrev = "SCLLWC"]
+	#[unit = "kgkg-1"]
+	SpecificCloudLiquidWaterContent = 83,
+	#[description = "specific cloud ice water content"]
+	#[abbrev = "SCLIWC"]
+	#[unit = "kgkg-1"]
+	SpecificCloudIceWaterContent = 84,
+	#[description = "specific rain water content"]
+	#[abbrev = "SRAINW"]
+	#[unit = "kgkg-1"]
+	SpecificRainWaterContent = 85,
+	#[description = "specific snow water content"]
+	#[abbrev = "SSNOWW"]
+	#[unit = "kgkg-1"]
+	SpecificSnowWaterContent = 86,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum MomentumProduct {
+	#[description = "wind direction"]
+	#[abbrev = "WDIR"]
+	#[unit = "degrees"]
+	WindDirection = 0,
+	#[description = "wind speed"]
+	#[abbrev = "WIND"]
+	#[unit = "ms-1"]
+	WindSpeed = 1,
+	#[description = "u-component of wind speed"]
+	#[abbrev = "UGRD"]
+	#[unit = "ms-1"]
+	UComponentWindSpeed = 2,
+	#[description = "v-component of wind speed"]
+	#[abbrev = "VGRD"]
+	#[unit = "ms-1"]
+	VComponentWindSpeed = 3,
+	#[description = "stream function"]
+	#[abbrev = "STRM"]
+	#[unit = "m2s-1"]
+	StreamFunction = 4,
+	#[description = "velocity potential"]
+	#[abbrev = "VPOT"]
+	#[unit = "m2s-1"]
+	VelocityPotential = 5,
+	#[description = "montgomery stream function"]
+	#[abbrev = "MNTSF"]
+	#[unit = "m2s-2"]
+	MontgomeryStreamFunction = 6,
+	#[description = "sigma coordinate vertical velocity"]
+	#[abbrev = "SGCVV"]
+	#[unit = "s-1"]
+	SigmaCoordinateVerticalVelocity = 7,
+	#[description = "vertical velocity (pressure)"]
+	#[abbrev = "VVEL"]
+	#[unit = "pas-1"]
+	VerticalVelocityPressure = 8,
+	#[description = "vertical velocity (geometric)"]
+	#[abbrev = "DZDT"]
+	#[unit = "ms-1"]
+	VerticalVelocityGeometric = 9,
+	#[description = "absolute vorticity"]
+	#[abbrev = "ABSV"]
+	#[unit = "s-1"]
+	AbsoluteVorticity = 10,
+	#[description = "absolute divergence"]
+	#[abbrev = "ABSD"]
+	#[unit = "s-1"]
+	AbsoluteDivergence = 11,
+	#[description = "relative vorticity"]
+	#[abbrev = "RELV"]
+	#[unit = "s-1"]
+	RelativeVorticity = 12,
+	#[description = "relative divergence"]
+	#[abbrev = "RELD"]
+	#[unit = "s-1"]
+	RelativeDivergence = 13,
+	#[description = "potential vorticity"]
+	#[abbrev = "PVORT"]
+	#[unit = "Km2kg-1s-1"]
+	PotentialVorticity = 14,
+	#[description = "vertical u-component shear"]
+	#[abbrev = "VUCSH"]
+	#[unit = "s-1"]
+	VerticalUComponentShear = 15,
+	#[description = "vertical v-component shear"]
+	#[abbrev = "VVCSH"]
+	#[unit = "s-1"]
+	VerticalVComponentShear = 16,
+	#[description = "momentum flux, u component"]
+	#[abbrev = "UFLX"]
+	#[unit = "Nm-2"]
+	UComponentMomentumFlux = 17,
+	#[description = "momentum flux, v component"]
+	#[abbrev = "VFLX"]
+	#[unit = "Nm-2"]
+	VComponentMomentumFlux = 18,
+	#[description = "wind mixing energy"]
+	#[abbrev = "WMIXE"]
+	#[unit = "J"]
+	WindMixingEnergy = 19,
+	#[description = "boundary layer dissipation"]
+	#[abbrev = "BLYDP"]
+	#[unit = "Wm-2"]
+	BoundaryLayerDissipation = 20,
+	#[description = "Maximum wind speed"]
+	#[abbrev = "MAXGUST"]
+	#[unit = "ms-1"]
+	MaximumWindSpeed = 21,
+	#[description = "wind gust speed"]
+	#[abbrev = "GUST"]
+	#[unit = "ms-1"]
+	WindGust = 22,
+	#[description = "u-component of wind gust"]
+	#[abbrev = "UGUST"]
+	#[unit = "ms-1"]
+	UComponentWindGust = 23,
+	#[description = "v-component of wind gust"]
+	#[abbrev = "VGUST"]
+	#[unit = "ms-1"]
+	VComponentWindGust = 24,
+	#[description = "vertical speed shear"]
+	#[abbrev = "VWSH"]
+	#[unit = "s-1"]
+	VerticalSpeedShear = 25,
+	#[description = "horizontal momentum flux"]
+	#[abbrev = "MFLX"]
+	#[unit = "Nm-2"]
+	HorizontalMomentumFlux = 26,
+	#[description = "u-component storm motion"]
+	#[abbrev = "USTM"]
+	#[unit = "ms-1"]
+	UComponentStormMotion = 27,
+	#[description = "v-component storm motion"]
+	#[abbrev = "VSTM"]
+	#[unit = "ms-1"]
+	VComponentStormMotion = 28,
+	#[description = "drag coefficient"]
+	#[abbrev = "CD"]
+	#[unit = "-"]
+	DragCoefficient = 29,
+	#[description = "frictional velocity"]
+	#[abbrev = "FRICV"]
+	#[unit = "ms-1"]
+	FrictionalVelocity = 30,
+	#[description = "turbulent diffusion coefficient for momentum"]
+	#[abbrev = "TDCMOM"]
+	#[unit = "m2s-1"]
+	TurbulentDiffusionCoefficient = 31,
+	#[description = "eta coordinate vertical velocity"]
+	#[abbrev = "ETACVV"]
+	#[unit = "s-1"]
+	EtaCoordinateVerticalVelocity = 32,
+	#[description = "wind fetch"]
+	#[abbrev = "WINDF"]
+	#[unit = "m"]
+	WindFetch = 33,
+	#[description = "normal wind component"]
+	#[abbrev = "NWIND"]
+	#[unit = "ms-1"]
+	NormalWindComponent = 34,
+	#[description = "tangential wind component"]
+	#[abbrev = "TWIND"]
+	#[unit = "ms-1"]
+	TangentialWindComponent = 35,
+	#[description = "amplitude function for rossby wave envelope for meridional wind"]
+	#[abbrev = "AFRWE"]
+	#[unit = "ms-1"]
+	RossbyWaveEnvelopeAmplitude = 36,
+	#[description = "northward turbulent surface stress"]
+	#[abbrev = "NTSS"]
+	#[unit = "Nm-2s"]
+	NorthwardTurbulentSurfaceStress = 37,
+	#[description = "eastward turbulent surface stress"]
+	#[abbrev = "ETSS"]
+	#[unit = "Nm-2s"]
+	EastwardTurbulentSurfaceStress = 38,
+	#[description = "eastward wind tendency due to parameterizations"]
+	#[abbrev = "EWTPARM"]
+	#[unit = "ms-2"]
+	EastwardWindTendency = 39,
+	#[description = "northward wind tendency due to parameterizations"]
+	#[abbrev = "NWTPARM"]
+	#[unit = "ms-2"]
+	NorthwardWindTendency = 40,
+	#[description = "u-component of geostrophic wind"]
+	#[abbrev = "UGWIND"]
+	#[unit = "ms-1"]
+	UComponentGeostrophicWind = 41,
+	#[description = "v-component of geostrophic wind"]
+	#[abbrev = "VGWIND"]
+	#[unit = "ms-1"]
+	VComponentGeostrophicWind = 42,
+	#[description = "geostrophic wind direction"]
+	#[abbrev = "GEOWD"]
+	#[unit = "degrees"]
+	GeostrophicWindDirection = 43,
+	#[description = "geostrophic wind speed"]
+	#[abbrev = "GEOWS"]
+	#[unit = "ms-1"]
+	GeostrophicWindSpeed = 44,
+	#[description = "unbalanced component of divergence"]
+	#[abbrev = "UNDIV"]
+	#[unit = "s-1"]
+	UnbalancedDivergence = 45,
+	#[description = "vorticity advection"]
+	#[abbrev = "VORTADV"]
+	#[unit = "s-2"]
+	VorticityAdvection = 46,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum MassProduct {
+	#[abbrev = "PRES"]
+	#[unit = "pa"]
+	Pressure = 0, 
+	#[description = "pressure reduced to MSL"]
+	#[abbrev = "PRMSL"]
+	#[unit = "pa"]
+	PressureReducedMSL = 1, 
+	#[description = "pressure tendency"]
+	#[abbrev = "PTEND"]
+	#[unit = "pas-1"]
+	PressureTendency = 2,
+	#[description = "ICAO standard atmosphere reference height"]
+	#[abbrev = "ICAHT"]
+	#[unit = "m"]
+	ICAOStandardAtmosphereReferenceHeight = 3,
+	#[description = "geopotential"]
+	#[abbrev = "GP"]
+	#[unit = "m2s-2"]
+	Geopotential = 4,
+	#[description = "geopotential height"]
+	#[abbrev = "HGT"]
+	#[unit = "gpm"]
+	GeopotentialHeight = 5,
+	#[description = "geometric height"]
+	#[abbrev = "DIST"]
+	#[unit = "m"]
+	GeometricHeight = 6,
+	#[description = "standard deviation of height"]
+	#[abbrev = "HSTDV"]
+	#[unit = "m"]
+	HeightStandardDeviation = 7,
+	#[description = "pressure anomaly"]
+	#[abbrev = "PRESA"]
+	#[unit = "pa"]
+	PressureAnomaly = 8,
+	#[description = "geopotential height anomaly"]
+	#[abbrev = "GPA"]
+	#[unit = "gpm"]
+	GeopotentialHeightAnomaly = 9,
+	#[description = "density"]
+	#[abbrev = "DEN"]
+	#[unit = "kgm-3"]
+	Density = 10,
+	#[description = "altimeter setting"]
+	#[abbrev = "ALTS"]
+	#[unit = "pa"]
+	AltimeterSetting = 11,
+	#[description = "thickness"]
+	#[abbrev = "THICK"]
+	#[unit = "m"]
+	Thickness = 12,
+	#[description = "pressure altitude"]
+	#[abbrev = "PRESALT"]
+	#[unit = "m"]
+	PressureAltitude = 13,
+	#[description = "density altitude"]
+	#[abbrev = "DENALT"]
+	#[unit = "m"]
+	DensityAltitude = 14,
+	#[description = "5-wave geopotential height"]
+	#[abbrev = "5WAVH"]
+	#[unit = "gpm"]
+	FiveWaveGeopotentialHeight = 15,
+	#[description = "zonal flux of gravity wave stress"]
+	#[abbrev = "U-GWD"]
+	#[unit = "Nm-2"]
+	ZonalGravityWaveStress = 16,
+	#[description = "meridional flux of gravity wave stress"]
+	#[abbrev = "V-GWD"]
+	#[unit = "Nm-2"]
+	MeridionalGravityWaveStress = 17,
+	#[description = "planetary boundary layer height"]
+	#[abbrev = "HPBL"]
+	#[unit = "m"]
+	PlanetaryBoundaryLayerHeight = 18,
+	#[description = "5-wave geopotential height anomaly"]
+	#[abbrev = "5WAVA"]
+	#[unit = "gpm"]
+	FiveWaveGeopotentialHeightAnomaly = 19,
+	#[description = "standard deviation of sub-grid scale orography"]
+	#[abbrev = "SDSGSO"]
+	#[unit = "m"]
+	SubGridOrographyStandardDeviation = 20,
+	#[description = "angle of sub-grid scale orography"]
+	#[abbrev = "AOSGSO"]
+	#[unit = "rad"]
+	SubGridOrographyAngle = 21,
+	#[description = "slope of sub-grid scale orography"]
+	#[abbrev = "SSGSO"]
+	#[unit = "-"]
+	SubGridOrographySlope = 22,
+	#[description = "gravity wave dissipation"]
+	#[abbrev = "GWD"]
+	#[unit = "Wm-2"]
+	GravityWaveDissipation = 23,
+	#[description = "anisotropy of sub-grid scale orography"]
+	#[abbrev = "ASGSO"]
+	#[unit = "-"]
+	SubGridOrographyAnisotropy = 24,
+	#[description = "natural logarithm of pressure in Pa"]
+	#[abbrev = "NLPRES"]
+	#[unit = "-"]
+	NaturalLogarithmOfPressure = 25,
+	#[description = "exner pressure"]
+	#[abbrev = "EXPRES"]
+	#[unit = "-"]
+	ExnerPressure = 26,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum ShortWaveRadiationProduct {
+	#[description = "net short-wave radiation flux (surface)"]
+	#[abbrev = "NSWRS"]
+	#[unit = "Wm-2"]
+	NetSurfaceShortWaveRadiationFlux = 0,
+	#[description = "net short-wave radiation flux (top of atmosphere)"]
+	#[abbrev = "NSWRT"]
+	#[unit = "Wm-2"]
+	NetTopOfAtmosphereShortWaveRadiationFlux = 1,
+	#[description = "short-wave radiation flux"]
+	#[abbrev = "SWAVR"]
+	#[unit = "Wm-2"]
+	ShortWaveRadiationFlux = 2,
+	#[description = "global radiation flux"]
+	#[abbrev = "GRAD"]
+	#[unit = "Wm-2"]
+	GlobalRadiationFlux = 3,
+	#[description = "brightness temperature"]
+	#[abbrev = "BRTMP"]
+	#[unit = "K"]
+	BrightnessTemperature = 4,
+	#[description = "radiance (with respect to wave number)"]
+	#[abbrev = "LWRAD"]
+	#[unit = "Wm-1sr-1"]
+	WaveNumberRadiance = 5,
+	#[description = "radiance (with respect to wavelength)"]
+	#[abbrev = "SWRAD"]
+	#[unit = "Wm-3sr-1"]
+	WavelengthRadiance = 6,
+	#[description = "downward short-wave radiation flux"]
+	#[abbrev = "DSWRF"]
+	#[unit = "Wm-2"]
+	DownwardShortWaveRadiationFlux = 7,
+	#[description = "upward short-wave radiation flux"]
+	#[abbrev = "USWRF"]
+	#[unit = "Wm-2"]
+	UpwardShortWaveRadiationFlux = 8,
+	#[description = "net short-wave radiation flux"]
+	#[abbrev = "NSWRF"]
+	#[unit = "Wm-2"]
+	NetShortWaveRadiationFlux = 9,
+	#[description = "photosynthetically active radiation"]
+	#[abbrev = "PHOTAR"]
+	#[unit = "Wm-2"]
+	PhotosyntheticallyActiveRadiation = 10,
+	#[description = "net short-wave radiation flux, clear sky"]
+	#[abbrev = "NSWRFCS"]
+	#[unit = "Wm-2"]
+	ClearSkyNetShortWaveRadiationFlux = 11,
+	#[description = "downward UV radiation"]
+	#[abbrev = "DWUVR"]
+	#[unit = "Wm-2"]
+	DownwardUVRadiation = 12,
+	#[description = "direct short-wave radiation flux"]
+	#[abbrev = "DSWRFLX"]
+	#[unit = "Wm-2"]
+	DirectShortWaveRadiationFlux = 13,
+	#[description = "diffuse short-wave radiation flux"]
+	#[abbrev = "DIFSWRF"]
+	#[unit = "Wm-2"]
+	DiffuseShortWaveRadiationFlux = 14,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum LongWaveRadiationProduct {
+	#[description = "net long-wave radiation flux (surface)"]
+	#[abbrev = "NLWRS"]
+	#[unit = "Wm-2"]
+	NetSurfaceLongWaveRadiationFlux = 0,
+	#[description = "net long-wave radiation flux (top of atmosphere)"]
+	#[abbrev = "NLWRT"]
+	#[unit = "Wm-2"]
+	NetTopOfAtmosphereLongWaveRadiationFlux = 1,
+	#[description = "long-wave radiation flux"]
+	#[abbrev = "LWAVR"]
+	#[unit = "Wm-2"]
+	LongWaveRadiationFlux = 2,
+	#[description = "downward long-wave radiation flux"]
+	#[abbrev = "DLWRF"]
+	#[unit = "Wm-2"]
+	DownwardLongWaveRadiationFlux = 3,
+	#[description = "upward long-wave radiation flux"]
+	#[abbrev = "ULWRF"]
+	#[unit = "Wm-2"]
+	UpwardLongWaveRadiationFlux = 4,
+	#[description = "net long-wave radiation flux"]
+	#[abbrev = "NLWRF"]
+	#[unit = "Wm-2"]
+	NetLongWaveRadiationFlux = 5,
+	#[description = "net long-wave radiation flux, clear sky"]
+	#[abbrev = "NLWRCS"]
+	#[unit = "Wm-2"]
+	ClearSkyNetLongWaveRadiationFlux = 6,
+	#[description = "brightness temperature"]
+	#[abbrev = "BRTEMP"]
+	#[unit = "K"]
+	BrightnessTemperature = 7,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum CloudProduct {
+	#[description = "cloud ice"]
+	#[abbrev = "CICE"]
+	#[unit = "kgm-2"]
+	CloudIce = 0,
+	#[description = "total cloud cover"]
+	#[abbrev = "TCDC"]
+	#[unit = "%"]
+	TotalCloudCover = 1,
+	#[description = "convective cloud cover"]
+	#[abbrev = "CDCON"]
+	#[unit = "%"]
+	ConvectiveCloudCover = 2,
+	#[description = "low cloud cover"]
+	#[abbrev = "LCDC"]
+	#[unit = "%"]
+	LowCloudCover = 3,
+	#[description = "medium cloud cover"]
+	#[abbrev = "MCDC"]
+	#[unit = "%"]
+	MediumCloudCover = 4,
+	#[description = "high cloud cover"]
+	#[abbrev = "HCDC"]
+	#[unit = "%"]
+	HighCloudCover = 5,
+	#[description = "cloud water"]
+	#[abbrev = "CWAT"]
+	#[unit = "kgm-2"]
+	CloudWater = 6,
+	#[description = "cloud amount"]
+	#[abbrev = "CDCA"]
+	#[unit = "%"]
+	CloudAmount = 7,
+	#[description = "cloud type"]
+	#[abbrev = "CDCT"]
+	#[unit = "-"]
+	CloudType = 8,
+	#[description = "thunderstorm maximum tops"]
+	#[abbrev = "TMAXT"]
+	#[unit = "m"]
+	ThunderstormMaximumTops = 9,
+	#[description = "thunderstorm coverage"]
+	#[abbrev = "THUNC"]
+	#[unit = "-"]
+	ThunderstormCoverage = 10,
+	#[description = "cloud base"]
+	#[abbrev = "CDCB"]
+	#[unit = "m"]
+	CloudBase = 11,
+	#[description = "cloud top"]
+	#[abbrev = "CDCTOP"]
+	#[unit = "m"]
+	CloudTop = 12,
+	#[description = "ceiling"]
+	#[abbrev = "CEIL"]
+	#[unit = "m"]
+	Ceiling = 13,
+	#[description = "non-convective cloud cover"]
+	#[abbrev = "CDLYR"]
+	#[unit = "%"]
+	NonConvectiveCloudCover = 14,
+	#[description = "cloud work function"]
+	#[abbrev = "CWORK"]
+	#[unit = "Jkg-1"]
+	CloudWorkFunction = 15,
+	#[description = "convective cloud efficiency"]
+	#[abbrev = "CUEFI"]
+	#[unit = "proportion"]
+	ConvectiveCloudEfficiency = 16,
+	#[description = "total condensate"]
+	#[abbrev = "TCOND"]
+	#[unit = "kgkg-1"]
+	TotalCondensate = 17,
+	#[description = "total column-integrated cloud water"]
+	#[abbrev = "TCOLW"]
+	#[unit = "kgm-2"]
+	TotalColumnCloudWater = 18,
+	#[description = "total column-integrated cloud ice"]
+	#[abbrev = "TCOLI"]
+	#[unit = "kgm-2"]
+	TotalColumnCloudIce = 19,
+	#[description = "total column-integrated condensate"]
+	#[abbrev = "TCOLC"]
+	#[unit = "kgm-2"]
+	TotalColumnCondensate = 20,
+	#[description = "ice fraction of total condensate"]
+	#[abbrev = "FICE"]
+	#[unit = "proportion"]
+	IceFractionOfTotalCondensate = 21,
+	#[description = "cloud cover"]
+	#[abbrev = "CDCC"]
+	#[unit = "%"]
+	CloudCover = 22,
+	#[description = "cloud ice mixing ratio"]
+	#[abbrev = "CDCIMR"]
+	#[unit = "kgkg-1"]
+	CloudIceMixingRatio = 23,
+	#[description = "sunshine"]
+	#[abbrev = "SUNS"]
+	#[unit = "-"]
+	Sunshine = 24,
+	#[description = "horizontal extent of cumulonimbus"]
+	#[abbrev = "CBHE"]
+	#[unit = "%"]
+	CumulonimbusHorizontalExtent = 25,
+	#[description = "height of convective cloud base"]
+	#[abbrev = "HCONCB"]
+	#[unit = "m"]
+	ConvectiveCloudBaseHeight = 26,
+	#[description = "height of convective cloud top"]
+	#[abbrev = "HCONCT"]
+	#[unit = "m"]
+	ConvectiveCloudTopHeight = 27,
+	#[description = "number concentration of cloud droplets"]
+	#[abbrev = "NCONCD"]
+	#[unit = "kg-1"]
+	CloudDropletNumberConcentration = 28,
+	#[description = "number concentration of cloud ice"]
+	#[abbrev = "NCCICE"]
+	#[unit = "kg-1"]
+	CloudIceNumberConcentration = 29,
+	#[description = "number density of cloud droplets"]
+	#[abbrev = "NDENCD"]
+	#[unit = "m-3"]
+	CloudDropletNumberDensity = 30,
+	#[description = "number density of cloud ice"]
+	#[abbrev = "NDCICE"]
+	#[unit = "m-3"]
+	CloudIceNumberDensity = 31,
+	#[description = "fraction of cloud cover"]
+	#[abbrev = "FRACCC"]
+	#[unit = "-"]
+	CloudCoverFraction = 32,
+	#[description = "sunshine duration"]
+	#[abbrev = "SUNSD"]
+	#[unit = "s"]
+	SunshineDuration = 33,
+	#[description = "surface long wave effective total cloudiness"]
+	#[abbrev = "SLWTC"]
+	#[unit = "-"]
+	SurfaceLongWaveEffectiveCloudiness = 34,
+	#[description = "surface short wave effective total cloudiness"]
+	#[abbrev = "SSWTC"]
+	#[unit = "-"]
+	SurfaceShortWaveEffectiveCloudiness = 35,
+	#[description = "fractional stratiform precipitation cover"]
+	#[abbrev = "FSTPC"]
+	#[unit = "proportion"]
+	StratiformPrecipitationCover = 36,
+	#[description = "fractional convective precipitation cover"]
+	#[abbrev = "FCONPC"]
+	#[unit = "proportion"]
+	ConvectivePrecipitationCover = 37,
+	#[description = "mass density of cloud droplets"]
+	#[abbrev = "MASSDCD"]
+	#[unit = "kgm-3"]
+	CloudDropletMassDensity = 38,
+	#[description = "mass density of cloud ice"]
+	#[abbrev = "MASSDCI"]
+	#[unit = "kgm-3"]
+	CloudIceMassDensity = 39,
+	#[description = "mass density of convective cloud water droplets"]
+	#[abbrev = "MDCCWD"]
+	#[unit = "kgm-3"]
+	ConvectiveCloudWaterMassDensity = 40,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum StabilityProduct {
+	#[description = "parcel lifted index (to 500 hPa)"]
+	#[abbrev = "PLI"]
+	#[unit = "K"]
+	ParcelLiftedIndex = 0,
+	#[description = "best lifted index (to 500 hPa)"]
+	#[abbrev = "BLI"]
+	#[unit = "K"]
+	BestLiftedIndex = 1,
+	#[description = "k index"]
+	#[abbrev = "KX"]
+	#[unit = "K"]
+	KIndex = 2,
+	#[description = "ko index"]
+	#[abbrev = "KOX"]
+	#[unit = "K"]
+	KOIndex = 3,
+	#[description = "total totals index"]
+	#[abbrev = "TOTALX"]
+	#[unit = "K"]
+	TotalTotalsIndex = 4,
+	#[description = "sweat index"]
+	#[abbrev = "SX"]
+	#[unit = "-"]
+	SweatIndex = 5,
+	#[description = "convective available potential energy"]
+	#[abbrev = "CAPE"]
+	#[unit = "Jkg-1"]
+	ConvectiveAvailablePotentialEnergy = 6,
+	#[description = "convective inhibition"]
+	#[abbrev = "CIN"]
+	#[unit = "Jkg-1"]
+	ConvectiveInhibition = 7,
+	#[description = "storm relative helicity"]
+	#[abbrev = "HLCY"]
+	#[unit = "m2s-2"]
+	StormRelativeHelicity = 8,
+	#[description = "energy helicity index"]
+	#[abbrev = "EHLX"]
+	#[unit = "-"]
+	EnergyHelicityIndex = 9,
+	#[description = "surface lifted index"]
+	#[abbrev = "LFTX"]
+	#[unit = "K"]
+	SurfaceLiftedIndex = 10,
+	#[description = "best (4 layer) lifted index"]
+	#[abbrev = "4LFTX"]
+	#[unit = "K"]
+	BestFourLayerLiftedIndex = 11,
+	#[description = "richardson number"]
+	#[abbrev = "RI"]
+	#[unit = "-"]
+	RichardsonNumber = 12,
+	#[description = "showalter index"]
+	#[abbrev = "SHWINX"]
+	#[unit = "K"]
+	ShowalterIndex = 13,
+	#[description = "updraft helicity"]
+	#[abbrev = "UPHL"]
+	#[unit = "m2s-2"]
+	UpdraftHelicity = 15,
+	#[description = "bulk richardson number"]
+	#[abbrev = "BLKRN"]
+	#[unit = "-"]
+	BulkRichardsonNumber = 16,
+	#[description = "gradient richardson number"]
+	#[abbrev = "GRDRN"]
+	#[unit = "-"]
+	GradientRichardsonNumber = 17,
+	#[description = "flux richardson number"]
+	#[abbrev = "FLXRN"]
+	#[unit = "-"]
+	FluxRichardsonNumber = 18,
+	#[description = "convective available potential energy shear"]
+	#[abbrev = "CONAPES"]
+	#[unit = "m2s-2"]
+	ConvectiveAvailablePotentialEnergyShear = 19,
+	#[description = "thunderstorm intensity index"]
+	#[abbrev = "TIIDEX"]
+	#[unit = "-"]
+	ThunderstormIntensityIndex = 20,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum ForecastRadarImageryProduct {
+	#[description = "equivalent radar reflectivity factor for rain"]
+	#[abbrev = "REFZR"]
+	#[unit = "mm6m-3"]
+	RainReflectivityFactor = 0,
+	#[description = "equivalent radar reflectivity factor for snow"]
+	#[abbrev = "REFZI"]
+	#[unit = "mm6m-3"]
+	SnowReflectivityFactor = 1,
+	#[description = "equivalent radar reflectivity factor for parameterized convection"]
+	#[abbrev = "REFZC"]
+	#[unit = "mm6m-3"]
+	ConvectionReflectivityFactor = 2,
+	#[description = "echo top"]
+	#[abbrev = "RETOP"]
+	#[unit = "m"]
+	EchoTop = 3,
+	#[abbrev = "REFD"]
+	#[unit = "dB"]
+	Reflectivity = 4,
+	#[description = "composite reflectivity"]
+	#[abbrev = "REFC"]
+	#[unit = "dB"]
+	CompositeReflectivity = 5,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum PhysicalAtmosphericProduct {
+	#[abbrev = "VIS"]
+	#[unit = "m"]
+	Visibility = 0,
+	#[abbrev = "ALBDO"]
+	#[unit = "%"]
+	Albedo = 1,
+	#[description = "thunderstorm probability"]
+	#[abbrev = "TSTM"]
+	#[unit = "%"]
+	ThunderstormProbability = 2,
+	#[description = "mixed layer depth"]
+	#[abbrev = "MIXHT"]
+	#[unit = "m"]
+	MixedLayerDepth = 3,
+	#[description = "volcanic ash"]
+	#[abbrev = "VOLASH"]
+	#[unit = "-"]
+	VolcanicAsh = 4,
+	#[description = "icing top"]
+	#[abbrev = "ICIT"]
+	#[unit = "m"]
+	IcingTop = 5,
+	#[description = "icing base"]
+	#[abbrev = "ICIB"]
+	#[unit = "m"]
+	IcingBase = 6,
+	#[abbrev = "ICI"]
+	#[unit = "-"]
+	Icing = 7,
+	#[description = "turbulence top"]
+	#[abbrev = "TURBT"]
+	#[unit = "m"]
+	TurbulenceTop = 8,
+	#[description = "turbulence base"]
+	#[abbrev = "TURBB"]
+	#[unit = "m"]
+	TurbulenceBase = 9,
+	#[abbrev = "TURB"]
+	#[unit = "-"]
+	Turbulence = 10,
+	#[description = "turbulent kinetic energy"]
+	#[abbrev = "TKE"]
+	#[unit = "Jkg-1"]
+	TurbulentKineticEnergy = 11,
+	#[description = "planetary boundary layer regime"]
+	#[abbrev = "PBLREG"]
+	#[unit = "-"]
+	PlanetaryBoundaryLayerRegime = 12,
+	#[description = "contrail intensity"]
+	#[abbrev = "CONTI"]
+	#[unit = "-"]
+	ContrailIntensity = 13,
+	#[description = "contrail engine type"]
+	#[abbrev = "CONTET"]
+	#[unit = "-"]
+	ContrailEngineType = 14,
+	#[description = "contrail top"]
+	#[abbrev = "CONTT"]
+	#[unit = "m"]
+	ContrailTop = 15,
+	#[description = "contrail base"]
+	#[abbrev = "CONTB"]
+	#[unit = "m"]
+	ContrailBase = 16,
+	#[description = "maximum snow albedo"]
+	#[abbrev = "MXSALB"]
+	#[unit = "%"]
+	MaximumSnowAlbedo = 17,
+	#[description = "snow free albedo"]
+	#[abbrev = "SNFALB"]
+	#[unit = "%"]
+	SnowFreeAlbedo = 18,
+	#[description = "snow albedo"]
+	#[abbrev = "SALBD"]
+	#[unit = "%"]
+	SnowAlbedo = 19,
+	#[description = "icing"]
+	#[abbrev = "ICIP"]
+	#[unit = "%"]
+	IcingProbability = 20,
+	#[description = "in-cloud turbulence"]
+	#[abbrev = "CTP"]
+	#[unit = "%"]
+	InCloudTurbulence = 21,
+	#[description = "clear air turbulence (CAT)"]
+	#[abbrev = "CAT"]
+	#[unit = "%"]
+	ClearAirTurbulence = 22,
+	#[description = "supercooled large droplet probability"]
+	#[abbrev = "SLDP"]
+	#[unit = "%"]
+	SupercooledLargeDropletProbability = 23,
+	#[description = "convective turbulent kinetic energy"]
+	#[abbrev = "CONTKE"]
+	#[unit = "Jkg-1"]
+	ConvectiveTurbulentKineticEnergy = 24,
+	#[description = "weather"]
+	#[abbrev = "WIWW"]
+	#[unit = "-"]
+	Weather = 25,
+	#[description = "convective outlook"]
+	#[abbrev = "CONVO"]
+	#[unit = "-"]
+	ConvectiveOutlook = 26,
+	#[description = "icing scenario"]
+	#[abbrev = "ICESC"]
+	#[unit = "-"]
+	IcingScenario = 27,
+	#[description = "mountain wave turbulence (eddy dissipation rate)"]
+	#[abbrev = "MWTURB"]
+	#[unit = "m2/3s-1"]
+	MountainWaveTurbulence = 28,
+	#[description = "clear air turbulence (eddy dissipation rate)"]
+	#[abbrev = "CATEDR"]
+	#[unit = "m2/3s-1"]
+	ClearAirTurbulenceEddyDissipationRate = 29,
+	#[description = "eddy dissipation parameter"]
+	#[abbrev = "EDPARM"]
+	#[unit = "m2/3s-1"]
+	EddyDissipationParameter = 30,
+	#[description = "maximum of eddy dissipation parameter in layer"]
+	#[abbrev = "MXEDPRM"]
+	#[unit = "m2/3s-1"]
+	MaximumEddyDissipationParameter = 31,
+	#[description = "highest freezing level"]
+	#[abbrev = "HIFREL"]
+	#[unit = "m"]
+	HighestFreezingLevel = 32,
+	#[description = "visibility through liquid fog"]
+	#[abbrev = "VISLFOG"]
+	#[unit = "m"]
+	LiquidFogVisibility = 33,
+	#[description = "visibility through ice fog"]
+	#[abbrev = "VISIFOG"]
+	#[unit = "m"]
+	IceFogVisibility = 34,
+	#[description = "visibility through blowing snow"]
+	#[abbrev = "VISBSN"]
+	#[unit = "m"]
+	BlowingSnowVisibility = 35,
+	#[description = "presence of snow squalls"]
+	#[abbrev = "SNOWSQ"]
+	#[unit = "-"]
+	SnowSqualls = 36,
+	#[description = "icing severity"]
+	#[abbrev = "ICESEV"]
+	#[unit = "-"]
+	IcingSeverity = 37,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum AtmosphericChemicalProduct {
+	#[description = "mass density (concentration)"]
+	#[abbrev = "MASSDEN"]
+	#[unit = "kgm-3"]
+	MassDensity = 0,
+	#[description = "column-integrated mass density"]
+	#[abbrev = "COLMD"]
+	#[unit = "kgm-2"]
+	ColumnIntegratedMassDensity = 1,
+	#[description = "mass mixing ratio (mass fraction in air)"]
+	#[abbrev = "MASSMR"]
+	#[unit = "kgkg-1"]
+	MassMixingRatio = 2,
+	#[description = "atmosphere emission mass flux"]
+	#[abbrev = "AEMFLX"]
+	#[unit = "kgm-2s-1"]
+	AtmosphereEmissionMassFlux = 3,
+	#[description = "atmosphere net production mass flux"]
+	#[abbrev = "ANPMFLX"]
+	#[unit = "kgm-2s-1"]
+	AtmosphereNetProductionMassFlux = 4,
+	#[description = "atmosphere net production and emission mass flux"]
+	#[abbrev = "ANPEMFLX"]
+	#[unit = "kgm-2s-1"]
+	AtmosphereNetProductionEmissionMassFlux = 5,
+	#[description = "surface dry deposition mass flux"]
+	#[abbrev = "SDDMFLX"]
+	#[unit = "kgm-2s-1"]
+	SurfaceDryDepositionMassFlux = 6,
+	#[description = "surface wet deposition mass flux"]
+	#[abbrev = "SWDMFLX"]
+	#[unit = "kgm-2s-1"]
+	SurfaceWetDepositionMassFlux = 7,
+	#[description = "atmosphere re-emission mass flux"]
+	#[abbrev = "AREMFLX"]
+	#[unit = "kgm-2s-1"]
+	AtmosphereReEmissionMassFlux = 8,
+	#[description = "wet deposition by large-scale precipitation mass flux"]
+	#[abbrev = "WLSMFLX"]
+	#[unit = "kgm-2s-1"]
+	LargeScaleWetDepositionMassFlux = 9,
+	#[description = "wet deposition by convective precipitation mass flux"]
+	#[abbrev = "WDCPMFLX"]
+	#[unit = "kgm-2s-1"]
+	ConvectiveWetDepositionMassFlux = 10,
+	#[description = "sedimentation mass flux"]
+	#[abbrev = "SEDMFLX"]
+	#[unit = "kgm-2s-1"]
+	SedimentationMassFlux = 11,
+	#[description = "dry deposition mass flux"]
+	#[abbrev = "DDMFLX"]
+	#[unit = "kgm-2s-1"]
+	DryDepositionMassFlux = 12,
+	#[description = "transfer from hydrophobic to hydrophilic"]
+	#[abbrev = "TRANHH"]
+	#[unit = "kgkg-1s-1"]
+	HydrophobicToHydrophilicTransfer = 13,
+	#[description = "transfer from SO2 (sulphur dioxide) to SO4 (sulphate)"]
+	#[abbrev = "TRSDS"]
+	#[unit = "kgkg-1s-1"]
+	SulphurDioxideToSulphateTransfer = 14,
+	#[description = "dry deposition velocity"]
+	#[abbrev = "DDVEL"]
+	#[unit = "ms-1"]
+	DryDepositionVelocity = 15,
+	#[description = "mass mixing ratio with respect to dry air"]
+	#[abbrev = "MSSRDRYA"]
+	#[unit = "kgkg-1"]
+	DryAirMassMixingRatio = 16,
+	#[description = "mass mixing ratio with respect to wet air"]
+	#[abbrev = "MSSRWETA"]
+	#[unit = "kgkg-1"]
+	WetAirMassMixingRatio = 17,
+	#[description = "amount in atmosphere"]
+	#[abbrev = "AIA"]
+	#[unit = "mol"]
+	AmountInAtmosphere = 50,
+	#[description = "concentration in air"]
+	#[abbrev = "CONAIR"]
+	#[unit = "molm-3"]
+	ConcentrationInAir = 51,
+	#[description = "volume mixing ratio (fraction in air)"]
+	#[abbrev = "VMXR"]
+	#[unit = "molmol-1"]
+	VolumeMixingRatio = 52,
+	#[description = "chemical gross production rate of concentration"]
+	#[abbrev = "CGPRC"]
+	#[unit = "molm-3s-1"]
+	ChemicalGrossProductionRate = 53,
+	#[description = "chemical gross destruction rate of concentration"]
+	#[abbrev = "CGDRC"]
+	#[unit = "molm-3s-1"]
+	ChemicalGrossDestructionRate = 54,
+	#[description = "surface flux"]
+	#[abbrev = "SFLUX"]
+	#[unit = "molm-2s-1"]
+	SurfaceFlux = 55,
+	#[description = "changes of amount in atmosphere"]
+	#[abbrev = "COAIA"]
+	#[unit = "mols-1"]
+	ChangesOfAmountInAtmosphere = 56,
+	#[description = "total yearly average burden of the atmosphere"]
+	#[abbrev = "TYABA"]
+	#[unit = "mol"]
+	TotalYearlyAverageBurden = 57,
+	#[description = "total yearly average atmospheric loss"]
+	#[abbrev = "TYAAL"]
+	#[unit = "mols-1"]
+	TotalYearlyAverageAtmosphericLoss = 58,
+	#[description = "aerosol number concentration"]
+	#[abbrev = "ANCON"]
+	#[unit = "m-3"]
+	AerosolNumberConcentration = 59,
+	#[description = "aerosol specific number concentration"]
+	#[abbrev = "ASNCON"]
+	#[unit = "kg-1"]
+	AerosolSpecificNumberConcentration = 60,
+	#[description = "maximum of mass density"]
+	#[abbrev = "MXMASSD"]
+	#[unit = "kgm-3"]
+	MaximumMassDensity = 61,
+	#[description = "height of mass density"]
+	#[abbrev = "HGTMD"]
+	#[unit = "m"]
+	MassDensityHeight = 62,
+	#[description = "column-averaged mass density in layer"]
+	#[abbrev = "CAVEMDL"]
+	#[unit = "kgm-3"]
+	ColumnAveragedMassDensity = 63,
+	#[description = "mole fraction with respect to dry air"]
+	#[abbrev = "MOLRDRYA"]
+	#[unit = "molmol-1"]
+	DryAirMoleFraction = 64,
+	#[description = "mole fraction with respect to wet air"]
+	#[abbrev = "MOLRWETA"]
+	#[unit = "molmol-1"]
+	WetAirMoleFraction = 65,
+	#[description = "surface area density (aerosol)"]
+	#[abbrev = "SADEN"]
+	#[unit = "m-1"]
+	SurfaceAreaDensity = 100,
+	#[description = "vertical visual range"]
+	#[abbrev = "ATMTK"]
+	#[unit = "m"]
+	VerticalVisualRange = 101,
+	#[description = "aerosol optical thickness"]
+	#[abbrev = "AOTK"]
+	#[unit = "-"]
+	AerosolOpticalThickness = 102,
+	#[description = "single scattering albedo"]
+	#[abbrev = "SSALBK"]
+	#[unit = "-"]
+	SingleScatteringAlbedo = 103,
+	#[description = "asymmetry factor"]
+	#[abbrev = "ASYSFK"]
+	#[unit = "-"]
+	AsymmetryFactor = 104,
+	#[description = "aerosol extinction coefficient"]
+	#[abbrev = "AECOEF"]
+	#[unit = "m-1"]
+	AerosolExtinctionCoefficient = 105,
+	#[description = "aerosol absorption coefficient"]
+	#[abbrev = "AACOEF"]
+	#[unit = "m-1"]
+	AerosolAbsorptionCoefficient = 106,
+	#[description = "aerosol lidar backscatter from satellite"]
+	#[abbrev = "ALBSAT"]
+	#[unit = "m-1sr-1"]
+	SatelliteLidarBackscatter = 107,
+	#[description = "aerosol lidar backscatter from the ground"]
+	#[abbrev = "ALBGRD"]
+	#[unit = "m-1sr-1"]
+	GroundLidarBackscatter = 108,
+	#[description = "aerosol lidar extinction from satellite"]
+	#[abbrev = "ALESAT"]
+	#[unit = "m-1"]
+	SatelliteLidarExtinction = 109,
+	#[description = "aerosol lidar extinction from the ground"]
+	#[abbrev = "ALEGRD"]
+	#[unit = "m-1"]
+	GroundLidarExtinction = 110,
+	#[description = "angstrom exponent"]
+	#[abbrev = "ANGSTEXP"]
+	#[unit = "-"]
+	AngstromExponent = 111,
+	#[description = "scattering aerosol optical thickness"]
+	#[abbrev = "SCTAOTK"]
+	#[unit = "-"]
+	ScatteringAerosolOpticalThickness = 112,
+}
+
+pub fn parameter(category: u8, parameter: u8) -> Option<Parameter> {
+	match category {
+		0 => TemperatureProduct::from_value(parameter).map(Parameter::from),
+		1 => MoistureProduct::from_value(parameter).map(Parameter::from),
+		2 => MomentumProduct::from_value(parameter).map(Parameter::from),
+		3 => MassProduct::from_value(parameter).map(Parameter::from),
+		4 => ShortWaveRadiationProduct::from_value(parameter).map(Parameter::from),
+		5 => LongWaveRadiationProduct::from_value(parameter).map(Parameter::from),
+		6 => CloudProduct::from_value(parameter).map(Parameter::from),
+		7 => StabilityProduct::from_value(parameter).map(Parameter::from),
+		16 => ForecastRadarImageryProduct::from_value(parameter).map(Parameter::from),
+		19 => PhysicalAtmosphericProduct::from_value(parameter).map(Parameter::from),
+		20 => AtmosphericChemicalProduct::from_value(parameter).map(Parameter::from),
+		_ => None,
+	}
+}
+
+pub fn table() -> Vec<(u8, ParameterTable)> {
+	vec![
+		(0, TemperatureProduct::parameters()),
+		(1, MoistureProduct::parameters()),
+		(2, MomentumProduct::parameters()),
+		(3, MassProduct::parameters()),
+		(4, ShortWaveRadiationProduct::parameters()),
+		(5, LongWaveRadiationProduct::parameters()),
+		(6, CloudProduct::parameters()),
+		(7, StabilityProduct::parameters()),
+		(16, ForecastRadarImageryProduct::parameters()),
+		(19, PhysicalAtmosphericProduct::parameters()),
+		(20, AtmosphericChemicalProduct::parameters()),
+	]
+}
+
+pub fn category(category: u8) -> &'static str {
+	match category {
+		0 => "temperature",
+		1 => "moisture",
+		2 => "momentum",
+		3 => "mass",
+		4 => "short-wave radiation",
+		5 => "long-wave radiation",
+		6 => "cloud",
+		7 => "thermodynamic stability indices",
+		16 => "forecast radar imagery",
+		19 => "physical atmospheric properties",
+		20 => "atmospheric chemical constituents",
+		_ => "other",
+	}
+}
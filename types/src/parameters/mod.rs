@@ -0,0 +1,64 @@
+pub mod meteorological;
+pub mod hydrological;
+pub mod land_surface;
+pub mod space_weather;
+pub mod oceanographic;
+pub mod local;
+
+use crate::Parameter;
+
+use std::sync::OnceLock;
+
+pub type ParameterTable = Vec<(u8, Parameter)>;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParameterEntry {
+	pub center: Option<u16>,
+	pub discipline: u8,
+	pub category: u8,
+	pub number: u8,
+	pub parameter: Parameter,
+}
+
+static TABLE: OnceLock<Vec<ParameterEntry>> = OnceLock::new();
+
+/// Every known parameter, WMO master table entries first followed by the center local tables
+pub fn table() -> &'static [ParameterEntry] {
+	TABLE.get_or_init(build_table)
+}
+
+fn build_table() -> Vec<ParameterEntry> {
+	let wmo = vec![
+		(meteorological::DISCIPLINE, meteorological::table()),
+		(hydrological::DISCIPLINE, hydrological::table()),
+		(land_surface::DISCIPLINE, land_surface::table()),
+		(space_weather::DISCIPLINE, space_weather::table()),
+		(oceanographic::DISCIPLINE, oceanographic::table()),
+	];
+
+	let local = vec![
+		(local::ncep::CENTER, local::ncep::table()),
+		(local::ecmwf::CENTER, local::ecmwf::table()),
+		(local::dwd::CENTER, local::dwd::table()),
+	];
+
+	let mut entries = Vec::new();
+
+	for (discipline, categories) in wmo {
+		for (category, parameters) in categories {
+			for (number, parameter) in parameters {
+				entries.push(ParameterEntry { center: None, discipline, category, number, parameter });
+			}
+		}
+	}
+
+	for (center, categories) in local {
+		for (discipline, category, parameters) in categories {
+			for (number, parameter) in parameters {
+				entries.push(ParameterEntry { center: Some(center), discipline, category, number, parameter });
+			}
+		}
+	}
+
+	entries
+}
@@ -0,0 +1,458 @@
+use gribberish_macros::{DisplayDescription, FromValue, ToParameter};
+use crate::Parameter;
+use super::ParameterTable;
+
+pub const DISCIPLINE: u8 = 10;
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum WavesProduct {
+	#[description = "primary wave spectra"]
+	#[abbrev = "WVSP1"]
+	#[unit = "-"]
+	WaveSpectra1 = 0,
+	#[description = "secondary wave spectra"]
+	#[abbrev = "-"]
+	#[unit = "WVSP2"]
+	WaveSpectra2 = 1, 
+	#[description = "tertiary wave spectra"]
+	#[abbrev = "-"]
+	#[unit = "WVSP3"]
+	WaveSpectra3 = 2, 
+	#[description = "significant wave height of combined wind and swell waves"]
+	#[abbrev = "HTSGW"]
+	#[unit = "m"]
+	SignificantWaveHeight = 3, 
+	#[description = "direction of wind waves"]
+	#[abbrev = "WVDIR"]
+	#[unit = "degree"]
+	WindWaveDirection = 4, 
+	#[description = "significant height of wind waves"]
+	#[abbrev = "WVHGT"]
+	#[unit = "m"]
+	WindSignificantWaveHeight = 5,
+	#[description = "mean period of wind waves"]
+	#[abbrev = "WVPER"]
+	#[unit = "s"]
+	WindWaveMeanPeriod = 6,
+	#[description = "direction of swell waves"]
+	#[abbrev = "SWDIR"]
+	#[unit = "degree"]
+	SwellWaveDirection = 7, 
+	#[description = "significant height of swell waves"]
+	#[abbrev = "SWELL"]
+	#[unit = "m"]
+	SwellSignificantWaveHeight = 8,
+	#[description = "mean period of swell waves"]
+	#[abbrev = "SWPER"]
+	#[unit = "s"]
+	SwellMeanPeriod = 9,
+	#[description = "primary wave direction"]
+	#[abbrev = "DIRPW"]
+	#[unit = "degree"]
+	PrimaryWaveDirection = 10,
+	#[description = "primary wave mean period"]
+	#[abbrev = "PERPW"]
+	#[unit = "s"]
+	PrimaryMeanPeriod = 11,
+	#[description = "secondary wave direction"]
+	#[abbrev = "DIRSW"]
+	#[unit = "degree"]
+	SecondaryWaveDirection = 12, 
+	#[description = "secondary wave mean period"]
+	#[abbrev = "PERSW"]
+	#[unit = "s"]
+	SecondaryMeanPeriod = 13,
+	#[description = "direction of combined wind and swell waves"]
+	#[abbrev = "WWSDIR"]
+	#[unit = "degree"]
+	CombinedWaveDirection = 14, 
+	#[description = "mean period of combined wind and swell waves"]
+	#[abbrev = "MWSPER"]
+	#[unit = "s"]
+	CombinedMeanPeriod = 15,
+	#[description = "coefficient of drag with waves"]
+	#[abbrev = "CDWW"]
+	#[unit = "-"]
+	WaveDragCoefficient = 16, 
+	#[description = "friction velocity"]
+	#[abbrev = "FRICV"]
+	#[unit = "ms-1"]
+	FrictionVelocity = 17,
+	#[description = "wave stress"]
+	#[abbrev = "WSTR"]
+	#[unit = "Nm-2"]
+	WaveStress = 18,
+	#[description = "normalized wave stress"]
+	#[abbrev = "NWSTR"]
+	#[unit = "-"]
+	NormalizedWaveStress = 19,
+	#[description = "mean square slope of waves"]
+	#[abbrev = "MSSW"]
+	#[unit = "-"]
+	MeanSquareWaveSlope = 20,
+	#[description = "u-component of stokes drift"]
+	#[abbrev = "USSD"]
+	#[unit = "ms-1"]
+	UComponentStokesDrift = 21, 
+	#[description = "v-component of stokes drift"]
+	#[abbrev = "VSSD"]
+	#[unit = "ms-1"]
+	VComponentStokesDrift = 22,
+	#[description = "period of maximumm individual wave height"]
+	#[abbrev = "PMAXWH"]
+	#[unit = "s"]
+	MaxWaveHeightPeriod = 23,
+	#[description = "maximum individual wave height"]
+	#[abbrev = "MAXWH"]
+	#[unit = "m"]
+	MaxWaveHeight = 24, 
+	#[description = "inverse mean wave frequency"]
+	#[abbrev = "IMWF"]
+	#[unit = "s"]
+	InverseMeanWaveFrequency = 25, 
+	#[description = "inverse mean frequency of the wind waves"]
+	#[abbrev = "IMFWW"]
+	#[unit = "s"]
+	InverseMeanWindWaveFrequency = 26, 
+	#[description = "inverse mean frequency of the total swell"]
+	#[abbrev = "IMFTSW"]
+	#[unit = "s"]
+	InverseMeanTotalSwellFrequency = 27,
+	#[description = "mean zero crossing wave period"]
+	#[abbrev = "MZWPER"]
+	#[unit = "s"]
+	MeanZeroCrossingWavePeriod = 28,
+	#[description = "mean zero crossing period of the wind waves"]
+	#[abbrev = "MZPWW"]
+	#[unit = "s"]
+	MeanZeroCrossingWindWavePeriod = 29,
+	#[description = "mean zero crossing of the total swell"]
+	#[abbrev = "MZPTSW"]
+	#[unit = "s"]
+	MeanZeroCrossingTotalSwellPeriod = 30,
+	#[description = "wave directional width"]
+	#[abbrev = "WDIRW"]
+	#[unit = "-"]
+	DirectionalWidthWaves = 31, 
+	#[description = "directional width of the wind waves"]
+	#[abbrev = "DIRWWW"]
+	#[unit = "-"]
+	DirectionalWidthWindWaves = 32, 
+	#[description = "directional width of the total swell"]
+	#[abbrev = "DIRWTS"]
+	#[unit = "-"]
+	DirectionalWidthTotalSwell = 33,
+	#[description = "peak wave period"]
+	#[abbrev = "PWPER"]
+	#[unit = "s"]
+	PeakWavePeriod = 34, 
+	#[description = "peak period of the wind waves"]
+	#[abbrev = "PPERWW"]
+	#[unit = "s"]
+	PeakWindWavePeriod = 35, 
+	#[description = "peak period of the total swell"]
+	#[abbrev = "PPERTS"]
+	#[unit = "s"]
+	PeakTotalSwellPeriod = 36,
+	#[description = "altimeter wave height"]
+	#[abbrev = "ALTWH"]
+	#[unit = "m"]
+	AltimeterWaveHeight = 37, 
+	#[description = "altimeter corrected wave height"]
+	#[abbrev = "ALCWH"]
+	#[unit = "m"]
+	AltimeterCorrectedWaveHeight = 38, 
+	#[description = "altimeter range relative correction"]
+	#[abbrev = "ALRRC"]
+	#[unit = "-"]
+	AltimeterRangeRelativeCorrection = 39, 
+	#[description = "10 meter neutral wind speed over waves"]
+	#[abbrev = "MNWSOW"]
+	#[unit = "ms-1"]
+	NeutralWindSpeedOverWaves = 40, 
+	#[description = "10 meter wind direction over waves"]
+	#[abbrev = "MWDIRW"]
+	#[unit = "degree"]
+	WindDirectionOverWaves = 41, 
+	#[description = "wave energy spectrum"]
+	#[abbrev = "WESP"]
+	#[unit = "m-2 s rad-1"]
+	WaveEnergySpectrum = 42, 
+	#[description = "kurtosis of the sea surface elevation due to waves"]
+	#[abbrev = "KSSEW"]
+	#[unit = "-"]
+	KurtosisFromWaves = 43, 
+	#[description = "benjamin-feir index"]
+	#[abbrev = "BENINX"]
+	#[unit = "-"]
+	BenjaminFeirIndex = 44, 
+	#[description = "spectral peakedness factor"]
+	#[abbrev = "SPFTR"]
+	#[unit = "s-1"]
+	SpectralPeakednessFactor = 45, 
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum CurrentsProduct {
+	#[description = "current direction"]
+	#[abbrev = "DIRC"]
+	#[unit = "degree"]
+	CurrentDirection = 0,
+	#[description = "current speed"]
+	#[abbrev = "SPC"]
+	#[unit = "ms-1"]
+	CurrentSpeed = 1,
+	#[description = "u-component of current"]
+	#[abbrev = "UOGRD"]
+	#[unit = "ms-1"]
+	UComponentCurrent = 2,
+	#[description = "v-component of current"]
+	#[abbrev = "VOGRD"]
+	#[unit = "ms-1"]
+	VComponentCurrent = 3,
+	#[description = "rip current occurrence probability"]
+	#[abbrev = "RIPCOP"]
+	#[unit = "%"]
+	RipCurrentOccurrenceProbability = 4,
+	#[description = "eastward current"]
+	#[abbrev = "EASTCUR"]
+	#[unit = "ms-1"]
+	EastwardCurrent = 5,
+	#[description = "northward current"]
+	#[abbrev = "NRTHCUR"]
+	#[unit = "ms-1"]
+	NorthwardCurrent = 6,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum IceProduct {
+	#[description = "ice cover"]
+	#[abbrev = "ICEC"]
+	#[unit = "proportion"]
+	IceCover = 0,
+	#[description = "ice thickness"]
+	#[abbrev = "ICETK"]
+	#[unit = "m"]
+	IceThickness = 1,
+	#[description = "direction of ice drift"]
+	#[abbrev = "DICED"]
+	#[unit = "degree"]
+	IceDriftDirection = 2,
+	#[description = "speed of ice drift"]
+	#[abbrev = "SICED"]
+	#[unit = "ms-1"]
+	IceDriftSpeed = 3,
+	#[description = "u-component of ice drift"]
+	#[abbrev = "UICE"]
+	#[unit = "ms-1"]
+	UComponentIceDrift = 4,
+	#[description = "v-component of ice drift"]
+	#[abbrev = "VICE"]
+	#[unit = "ms-1"]
+	VComponentIceDrift = 5,
+	#[description = "ice growth rate"]
+	#[abbrev = "ICEG"]
+	#[unit = "ms-1"]
+	IceGrowthRate = 6,
+	#[description = "ice divergence"]
+	#[abbrev = "ICED"]
+	#[unit = "s-1"]
+	IceDivergence = 7,
+	#[description = "ice temperature"]
+	#[abbrev = "ICETMP"]
+	#[unit = "K"]
+	IceTemperature = 8,
+	#[description = "module of ice internal pressure"]
+	#[abbrev = "ICEPRS"]
+	#[unit = "pam"]
+	IceInternalPressure = 9,
+	#[description = "zonal vector component of vertically integrated ice internal pressure"]
+	#[abbrev = "ZVCICEP"]
+	#[unit = "pam"]
+	ZonalIceInternalPressure = 10,
+	#[description = "meridional vector component of vertically integrated ice internal pressure"]
+	#[abbrev = "MVCICEP"]
+	#[unit = "pam"]
+	MeridionalIceInternalPressure = 11,
+	#[description = "compressive ice strength"]
+	#[abbrev = "CICES"]
+	#[unit = "Nm-1"]
+	CompressiveIceStrength = 12,
+	#[description = "snow temperature (over sea ice)"]
+	#[abbrev = "SNOWTSI"]
+	#[unit = "K"]
+	SeaIceSnowTemperature = 13,
+	#[description = "albedo"]
+	#[abbrev = "ALBDOICE"]
+	#[unit = "-"]
+	IceAlbedo = 14,
+	#[description = "sea ice volume per unit area"]
+	#[abbrev = "SICEVOL"]
+	#[unit = "m3m-2"]
+	SeaIceVolume = 15,
+	#[description = "snow volume over sea ice per unit area"]
+	#[abbrev = "SNVOLSI"]
+	#[unit = "m3m-2"]
+	SeaIceSnowVolume = 16,
+	#[description = "sea ice heat content"]
+	#[abbrev = "SICEHC"]
+	#[unit = "Jm-2"]
+	SeaIceHeatContent = 17,
+	#[description = "snow over sea ice heat content"]
+	#[abbrev = "SNCEHC"]
+	#[unit = "Jm-2"]
+	SeaIceSnowHeatContent = 18,
+	#[description = "ice freeboard thickness"]
+	#[abbrev = "ICEFTHCK"]
+	#[unit = "m"]
+	IceFreeboardThickness = 19,
+	#[description = "ice melt pond fraction"]
+	#[abbrev = "ICEMPF"]
+	#[unit = "proportion"]
+	IceMeltPondFraction = 20,
+	#[description = "ice melt pond depth"]
+	#[abbrev = "ICEMPD"]
+	#[unit = "m"]
+	IceMeltPondDepth = 21,
+	#[description = "ice melt pond volume per unit area"]
+	#[abbrev = "ICEMPV"]
+	#[unit = "m3m-2"]
+	IceMeltPondVolume = 22,
+	#[description = "sea ice fraction tendency due to parameterization"]
+	#[abbrev = "SIFTP"]
+	#[unit = "s-1"]
+	SeaIceFractionTendency = 23,
+	#[description = "x-component of ice drift"]
+	#[abbrev = "XICE"]
+	#[unit = "ms-1"]
+	XComponentIceDrift = 24,
+	#[description = "y-component of ice drift"]
+	#[abbrev = "YICE"]
+	#[unit = "ms-1"]
+	YComponentIceDrift = 25,
+}
+
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug, DisplayDescription, FromValue, ToParameter)]
+pub enum OceanSurfaceProduct {
+	#[description = "water temperature"]
+	#[abbrev = "WTMP"]
+	#[unit = "K"]
+	WaterTemperature = 0,
+	#[description = "deviation of sea level from mean"]
+	#[abbrev = "DSLM"]
+	#[unit = "m"]
+	SeaLevelDeviation = 1,
+	#[description = "heat exchange coefficient"]
+	#[abbrev = "CH"]
+	#[unit = "-"]
+	HeatExchangeCoefficient = 2,
+	#[description = "practical salinity"]
+	#[abbrev = "PRACTSAL"]
+	#[unit = "-"]
+	PracticalSalinity = 3,
+	#[description = "downward heat flux"]
+	#[abbrev = "DWHFLUX"]
+	#[unit = "Wm-2"]
+	DownwardHeatFlux = 4,
+	#[description = "eastward surface stress"]
+	#[abbrev = "EASTWSS"]
+	#[unit = "Nm-2"]
+	EastwardSurfaceStress = 5,
+	#[description = "northward surface stress"]
+	#[abbrev = "NORTHWSS"]
+	#[unit = "Nm-2"]
+	NorthwardSurfaceStress = 6,
+	#[description = "x-component surface stress"]
+	#[abbrev = "XCOMPSS"]
+	#[unit = "Nm-2"]
+	XComponentSurfaceStress = 7,
+	#[description = "y-component surface stress"]
+	#[abbrev = "YCOMPSS"]
+	#[unit = "Nm-2"]
+	YComponentSurfaceStress = 8,
+	#[description = "thermosteric change in sea surface height"]
+	#[abbrev = "THERCSSH"]
+	#[unit = "m"]
+	ThermostericSeaSurfaceHeightChange = 9,
+	#[description = "halosteric change in sea surface height"]
+	#[abbrev = "HALOCSSH"]
+	#[unit = "m"]
+	HalostericSeaSurfaceHeightChange = 10,
+	#[description = "steric change in sea surface height"]
+	#[abbrev = "STERCSSH"]
+	#[unit = "m"]
+	StericSeaSurfaceHeightChange = 11,
+	#[description = "sea salt flux"]
+	#[abbrev = "SEASFLUX"]
+	#[unit = "kgm-2s-1"]
+	SeaSaltFlux = 12,
+	#[description = "net upward water flux"]
+	#[abbrev = "NETUPWFLUX"]
+	#[unit = "kgm-2s-1"]
+	NetUpwardWaterFlux = 13,
+	#[description = "eastward surface water velocity"]
+	#[abbrev = "ESURFWVEL"]
+	#[unit = "ms-1"]
+	EastwardSurfaceWaterVelocity = 14,
+	#[description = "northward surface water velocity"]
+	#[abbrev = "NSURFWVEL"]
+	#[unit = "ms-1"]
+	NorthwardSurfaceWaterVelocity = 15,
+	#[description = "x-component of surface water velocity"]
+	#[abbrev = "XSURFWVEL"]
+	#[unit = "ms-1"]
+	XComponentSurfaceWaterVelocity = 16,
+	#[description = "y-component of surface water velocity"]
+	#[abbrev = "YSURFWVEL"]
+	#[unit = "ms-1"]
+	YComponentSurfaceWaterVelocity = 17,
+	#[description = "heat flux correction"]
+	#[abbrev = "HFLUXC"]
+	#[unit = "Wm-2"]
+	HeatFluxCorrection = 18,
+	#[description = "sea surface height tendency due to parameterization"]
+	#[abbrev = "SSHGTPARM"]
+	#[unit = "ms-1"]
+	SeaSurfaceHeightTendency = 19,
+	#[description = "deviation of sea level from mean with inverse barometer correction"]
+	#[abbrev = "DSLIBARCOR"]
+	#[unit = "m"]
+	InverseBarometerSeaLevelDeviation = 20,
+	#[abbrev = "SALIN"]
+	#[unit = "kgkg-1"]
+	Salinity = 21,
+}
+
+pub fn parameter(category: u8, parameter: u8) -> Option<Parameter> {
+	match category {
+		0 => WavesProduct::from_value(parameter).map(Parameter::from),
+		1 => CurrentsProduct::from_value(parameter).map(Parameter::from),
+		2 => IceProduct::from_value(parameter).map(Parameter::from),
+		3 => OceanSurfaceProduct::from_value(parameter).map(Parameter::from),
+		_ => None,
+	}
+}
+
+pub fn table() -> Vec<(u8, ParameterTable)> {
+	vec![
+		(0, WavesProduct::parameters()),
+		(1, CurrentsProduct::parameters()),
+		(2, IceProduct::parameters()),
+		(3, OceanSurfaceProduct::parameters()),
+	]
+}
+
+pub fn category(category: u8) -> &'static str {
+	match category {
+		0 => "waves",
+		1 => "currents",
+		2 => "ice",
+		3 => "surface",
+		4 => "subsurface",
+		_ => "misc",
+	}
+}